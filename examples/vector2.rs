@@ -14,7 +14,7 @@ fn main() {
     println!("({}) * {} = ({})", a, k, a * k);
     // division by a constant
     println!("({}) / {} = ({})", b, n, b / n);
-    let (r, theta) = (2.0, 3.14);
+    let (r, theta) = (2.0, std::f64::consts::PI);
     // initialize zero vector
     println!("Vec2::zero() = ({})", Vec2::zero());
     // transformation from the polar coordinate system
@@ -14,7 +14,7 @@ fn main() {
     println!("({}) * {} = ({})", a, k, a * k);
     // division by a constant
     println!("({}) / {} = ({})", b, n, b / n);
-    let (r, theta, phi) = (2.0, 1.57, 3.14);
+    let (r, theta, phi) = (2.0, std::f64::consts::FRAC_PI_2, std::f64::consts::PI);
     // initialize zero vector
     println!("Vec3::zero() = ({})", Vec3::zero());
     // transformation from the polar coordinate system
@@ -0,0 +1,292 @@
+//! Axis-aligned boxes in 3-dimensional euclidian space.
+use vec3::Vec3;
+
+/// Axis-aligned bounding box, defined by minimal and maximal corners
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Aabb3 {
+    /// corner with minimal coordinates
+    pub min: Vec3,
+    /// corner with maximal coordinates
+    pub max: Vec3,
+}
+
+impl Aabb3 {
+    /// Constructs a new `Aabb3` from two opposite corners.
+    ///
+    /// Corners can be given in any order.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Aabb3, Vec3};
+    /// let b = Aabb3::new(Vec3::new(3, 0, 1), Vec3::new(1, 2, 0));
+    /// assert_eq!(b.min, Vec3::new(1, 0, 0));
+    /// assert_eq!(b.max, Vec3::new(3, 2, 1));
+    /// ```
+    pub fn new(a: Vec3, b: Vec3) -> Aabb3 {
+        Aabb3 {
+            min: Vec3::new(a.x.min(b.x), a.y.min(b.y), a.z.min(b.z)),
+            max: Vec3::new(a.x.max(b.x), a.y.max(b.y), a.z.max(b.z)),
+        }
+    }
+    /// Constructs a new `Aabb3` from center and half-extents.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Aabb3, Vec3};
+    /// let b = Aabb3::from_center(Vec3::new(2, 1, 0), Vec3::new(1, 3, 1));
+    /// assert_eq!(b, Aabb3::new(Vec3::new(1, -2, -1), Vec3::new(3, 4, 1)));
+    /// ```
+    pub fn from_center(center: Vec3, half_extents: Vec3) -> Aabb3 {
+        Aabb3::new(center - half_extents, center + half_extents)
+    }
+    /// Smallest box containing all points
+    ///
+    /// Returns `None` for empty iterator. NaN coordinates are ignored.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Aabb3, Vec3};
+    /// let points = [Vec3::new(1, 5, 0), Vec3::new(-2, 3, 7), Vec3::new(4, -1, 2)];
+    /// let b = Aabb3::from_points(points.iter().cloned()).unwrap();
+    /// assert_eq!(b, Aabb3::new(Vec3::new(-2, -1, 0), Vec3::new(4, 5, 7)));
+    /// ```
+    pub fn from_points<T: IntoIterator<Item = Vec3>>(points: T) -> Option<Aabb3> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(points.fold(Aabb3 { min: first, max: first }, |b, p| Aabb3 {
+            min: Vec3::new(b.min.x.min(p.x), b.min.y.min(p.y), b.min.z.min(p.z)),
+            max: Vec3::new(b.max.x.max(p.x), b.max.y.max(p.y), b.max.z.max(p.z)),
+        }))
+    }
+    /// Bounding box of points, expanded by `margin` on each side
+    ///
+    /// Same as [`expand`](#method.expand), negative `margin` never inverts box.
+    pub fn from_iter_with_margin<T, I>(points: T, margin: I) -> Option<Aabb3>
+        where T: IntoIterator<Item = Vec3>, I: Into<f64>
+    {
+        Aabb3::from_points(points).map(|b| b.expand(margin))
+    }
+    /// Center of box
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Aabb3, Vec3};
+    /// let b = Aabb3::new(Vec3::new(0, 0, 0), Vec3::new(4, 2, 6));
+    /// assert_eq!(b.center(), Vec3::new(2, 1, 3));
+    /// ```
+    pub fn center(self) -> Vec3 {
+        (self.min + self.max) * 0.5
+    }
+    /// Half-extents of box
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Aabb3, Vec3};
+    /// let b = Aabb3::new(Vec3::new(0, 0, 0), Vec3::new(4, 2, 6));
+    /// assert_eq!(b.half_extents(), Vec3::new(2, 1, 3));
+    /// ```
+    pub fn half_extents(self) -> Vec3 {
+        (self.max - self.min) * 0.5
+    }
+    /// Checks if point lies inside box or on its boundary
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Aabb3, Vec3};
+    /// let b = Aabb3::new(Vec3::new(0, 0, 0), Vec3::new(4, 2, 6));
+    /// assert!(b.contains(Vec3::new(1, 1, 1)));
+    /// assert!(b.contains(Vec3::new(4, 2, 6)));
+    /// assert!(!b.contains(Vec3::new(1, 1, 7)));
+    /// ```
+    pub fn contains(self, point: Vec3) -> bool {
+        self.min.x <= point.x && point.x <= self.max.x &&
+        self.min.y <= point.y && point.y <= self.max.y &&
+        self.min.z <= point.z && point.z <= self.max.z
+    }
+    /// Checks if boxes overlap or touch
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Aabb3, Vec3};
+    /// let a = Aabb3::new(Vec3::new(0, 0, 0), Vec3::new(4, 2, 2));
+    /// let b = Aabb3::new(Vec3::new(3, 1, 1), Vec3::new(5, 5, 5));
+    /// let c = Aabb3::new(Vec3::new(3, 1, 3), Vec3::new(5, 5, 5));
+    /// assert!(a.intersects(b));
+    /// assert!(!a.intersects(c));
+    /// ```
+    pub fn intersects(self, other: Aabb3) -> bool {
+        self.min.x <= other.max.x && other.min.x <= self.max.x &&
+        self.min.y <= other.max.y && other.min.y <= self.max.y &&
+        self.min.z <= other.max.z && other.min.z <= self.max.z
+    }
+    /// Point of box closest to given
+    ///
+    /// Returns point itself if it lies inside box.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Aabb3, Vec3};
+    /// let b = Aabb3::new(Vec3::new(0, 0, 0), Vec3::new(4, 2, 6));
+    /// assert_eq!(b.closest_point(Vec3::new(6, 1, -1)), Vec3::new(4, 1, 0));
+    /// assert_eq!(b.closest_point(Vec3::new(1, 1, 1)), Vec3::new(1, 1, 1));
+    /// ```
+    pub fn closest_point(self, point: Vec3) -> Vec3 {
+        Vec3::new(point.x.clamp(self.min.x, self.max.x),
+                  point.y.clamp(self.min.y, self.max.y),
+                  point.z.clamp(self.min.z, self.max.z))
+    }
+    /// Smallest box containing both boxs
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Aabb3, Vec3};
+    /// let a = Aabb3::new(Vec3::new(0, 0, 0), Vec3::new(1, 1, 1));
+    /// let b = Aabb3::new(Vec3::new(2, 2, 2), Vec3::new(3, 3, 3));
+    /// assert_eq!(a.union(b), Aabb3::new(Vec3::new(0, 0, 0), Vec3::new(3, 3, 3)));
+    /// ```
+    pub fn union(self, other: Aabb3) -> Aabb3 {
+        Aabb3 {
+            min: Vec3::new(self.min.x.min(other.min.x), self.min.y.min(other.min.y), self.min.z.min(other.min.z)),
+            max: Vec3::new(self.max.x.max(other.max.x), self.max.y.max(other.max.y), self.max.z.max(other.max.z)),
+        }
+    }
+    /// Common part of boxs
+    ///
+    /// Returns `None` if boxs don't intersect, touching boxs give
+    /// degenerate box.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Aabb3, Vec3};
+    /// let a = Aabb3::new(Vec3::new(0, 0, 0), Vec3::new(2, 2, 2));
+    /// let b = Aabb3::new(Vec3::new(1, 1, 1), Vec3::new(3, 3, 3));
+    /// assert_eq!(a.intersection(b), Some(Aabb3::new(Vec3::new(1, 1, 1), Vec3::new(2, 2, 2))));
+    /// assert_eq!(a.intersection(Aabb3::new(Vec3::new(3, 3, 3), Vec3::new(4, 4, 4))), None);
+    /// ```
+    pub fn intersection(self, other: Aabb3) -> Option<Aabb3> {
+        if !self.intersects(other) {
+            return None;
+        }
+        Some(Aabb3 {
+            min: Vec3::new(self.min.x.max(other.min.x), self.min.y.max(other.min.y), self.min.z.max(other.min.z)),
+            max: Vec3::new(self.max.x.min(other.max.x), self.max.y.min(other.max.y), self.max.z.min(other.max.z)),
+        })
+    }
+    /// Box expanded by `margin` on each side
+    ///
+    /// Negative `margin` shrinks box, sides that would pass each other
+    /// collapse to the center.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Aabb3, Vec3};
+    /// let r = Aabb3::new(Vec3::new(0, 0, 0), Vec3::new(4, 2, 6));
+    /// assert_eq!(r.expand(1), Aabb3::new(Vec3::new(-1, -1, -1), Vec3::new(5, 3, 7)));
+    /// assert_eq!(r.expand(-1.5), Aabb3::new(Vec3::new(1.5, 1.0, 1.5), Vec3::new(2.5, 1.0, 4.5)));
+    /// ```
+    pub fn expand<I: Into<f64>>(self, margin: I) -> Aabb3 {
+        let margin = margin.into();
+        let (d, c) = (Vec3::new(margin, margin, margin), self.center());
+        let (min, max) = (self.min - d, self.max + d);
+        Aabb3 {
+            min: Vec3::new(min.x.min(c.x), min.y.min(c.y), min.z.min(c.z)),
+            max: Vec3::new(max.x.max(c.x), max.y.max(c.y), max.z.max(c.z)),
+        }
+    }
+    /// Checks if other box lies inside this one, boundaries may touch
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Aabb3, Vec3};
+    /// let a = Aabb3::new(Vec3::new(0, 0, 0), Vec3::new(3, 3, 3));
+    /// assert!(a.contains_aabb(Aabb3::new(Vec3::new(1, 1, 1), Vec3::new(3, 3, 3))));
+    /// assert!(!a.contains_aabb(Aabb3::new(Vec3::new(1, 1, 1), Vec3::new(4, 4, 4))));
+    /// ```
+    pub fn contains_aabb(self, other: Aabb3) -> bool {
+        self.contains(other.min) && self.contains(other.max)
+    }
+    /// Smallest box containing this one and all points
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Aabb3, Vec3};
+    /// let r = Aabb3::new(Vec3::new(0, 0, 0), Vec3::new(1, 1, 1));
+    /// let merged = r.merge_points(&[Vec3::new(3, 3, 3), Vec3::new(-1, -1, -1)]);
+    /// assert_eq!(merged, Aabb3::new(Vec3::new(-1, -1, -1), Vec3::new(3, 3, 3)));
+    /// ```
+    pub fn merge_points(self, points: &[Vec3]) -> Aabb3 {
+        points.iter().fold(self, |r, &p| r.union(Aabb3 { min: p, max: p }))
+    }
+    /// Corners of box
+    ///
+    /// Corner with index `i` takes maximal `x` if bit 0 of `i` is set,
+    /// maximal `y` for bit 1 and maximal `z` for bit 2.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Aabb3, Vec3};
+    /// let b = Aabb3::new(Vec3::new(0, 0, 0), Vec3::new(4, 2, 6));
+    /// let corners = b.corners();
+    /// assert_eq!(corners[0], b.min);
+    /// assert_eq!(corners[5], Vec3::new(4, 0, 6));
+    /// assert_eq!(corners[7], b.max);
+    /// ```
+    pub fn corners(self) -> [Vec3; 8] {
+        let mut corners = [self.min; 8];
+        for (i, corner) in corners.iter_mut().enumerate() {
+            for k in 0..3 {
+                if i & (1 << k) != 0 {
+                    corner[k] = self.max[k];
+                }
+            }
+        }
+        corners
+    }
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    #[test]
+    fn aabb3_intersects() {
+        let a = Aabb3::new(Vec3::new(0, 0, 0), Vec3::new(2, 2, 2));
+        assert!(a.intersects(a));
+        assert!(a.intersects(Aabb3::new(Vec3::new(2, 2, 2), Vec3::new(3, 3, 3))));
+        assert!(a.intersects(Aabb3::new(Vec3::new(-1, -1, -1), Vec3::new(3, 3, 3))));
+        assert!(!a.intersects(Aabb3::new(Vec3::new(0, 0, 3), Vec3::new(2, 2, 4))));
+    }
+
+    #[test]
+    fn aabb3_closest_point() {
+        let b = Aabb3::from_center(Vec3::zero(), Vec3::new(1, 2, 3));
+        assert_eq!(b.closest_point(Vec3::new(5, 5, 5)), Vec3::new(1, 2, 3));
+        assert_eq!(b.closest_point(Vec3::new(0, -5, 0)), Vec3::new(0, -2, 0));
+    }
+
+    #[test]
+    fn aabb3_corners() {
+        let b = Aabb3::from_center(Vec3::new(1, 1, 1), Vec3::new(1, 1, 1));
+        let corners = b.corners();
+        for (i, &c) in corners.iter().enumerate() {
+            assert!(b.contains(c));
+            for &other in corners[i + 1..].iter() {
+                assert!(c != other);
+            }
+        }
+    }
+
+    #[test]
+    fn aabb3_set_operations() {
+        let a = Aabb3::new(Vec3::new(0, 0, 0), Vec3::new(2, 2, 2));
+        let b = Aabb3::new(Vec3::new(1, -1, 1), Vec3::new(3, 1, 5));
+        let u = a.union(b);
+        assert!(u.contains_aabb(a) && u.contains_aabb(b));
+        let i = a.intersection(b).unwrap();
+        assert_eq!(i, Aabb3::new(Vec3::new(1, 0, 1), Vec3::new(2, 1, 2)));
+        assert!(a.intersection(Aabb3::new(Vec3::new(0, 0, 3), Vec3::new(1, 1, 4))).is_none());
+        assert_eq!(a.expand(-5), Aabb3::new(Vec3::new(1, 1, 1), Vec3::new(1, 1, 1)));
+        let merged = a.merge_points(&[Vec3::new(5, 0, 0), Vec3::new(0, -1, 0)]);
+        assert_eq!(merged, Aabb3::new(Vec3::new(0, -1, 0), Vec3::new(5, 2, 2)));
+    }
+}
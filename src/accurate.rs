@@ -0,0 +1,149 @@
+//! Compensated sums and scalar products over slices of vectors.
+//!
+//! Naive summation loses low-order bits of every term once accumulated
+//! value becomes large; error grows with number of terms. Functions here use
+//! Neumaier's variant of Kahan summation, which carries lost bits in separate
+//! compensation term, so error stays at rounding of the final result
+//! independently of number of terms. In scalar products rounding error of
+//! every product is recovered with fused multiply-add and summed as well.
+use std::ops::IndexMut;
+use finite::Coordinates;
+use vec2::Vec2;
+use vec3::Vec3;
+use vec4::Vec4;
+
+// running sum with compensation of lost low-order bits
+#[derive(Clone, Copy, Default)]
+struct Neumaier {
+    sum: f64,
+    compensation: f64,
+}
+
+impl Neumaier {
+    fn add(&mut self, value: f64) {
+        let t = self.sum + value;
+        if self.sum.abs() >= value.abs() {
+            self.compensation += (self.sum - t) + value;
+        } else {
+            self.compensation += (value - t) + self.sum;
+        }
+        self.sum = t;
+    }
+    // product is split into rounded value and its exact rounding error
+    fn add_product(&mut self, a: f64, b: f64) {
+        let p = a * b;
+        self.add(p);
+        self.compensation += a.mul_add(b, -p);
+    }
+    fn value(self) -> f64 {
+        self.sum + self.compensation
+    }
+}
+
+/// Vector which can be summed with compensation
+pub trait Summable: Coordinates + IndexMut<usize> {
+    /// Zero vector, sum of empty slice
+    fn zero() -> Self;
+}
+
+impl Summable for Vec2 {
+    fn zero() -> Vec2 {
+        Vec2::zero()
+    }
+}
+
+impl Summable for Vec3 {
+    fn zero() -> Vec3 {
+        Vec3::zero()
+    }
+}
+
+impl Summable for Vec4 {
+    fn zero() -> Vec4 {
+        Vec4::zero()
+    }
+}
+
+/// Compensated sum of vectors
+///
+/// # Example
+/// ```
+/// # use linal::{Vec2, Vec3};
+/// # use linal::accurate::sum;
+/// let v = [Vec2::new(1e16, 1.0), Vec2::new(1.0, 1e-16), Vec2::new(-1e16, -1.0)];
+/// assert_eq!(sum(&v), Vec2::new(1.0, 1e-16));
+/// let forces = vec![Vec3::new(0.1, 0.2, 0.3); 1_000_000];
+/// assert_eq!(sum(&forces), Vec3::new(100000.0, 200000.0, 300000.0));
+/// ```
+pub fn sum<V: Summable>(points: &[V]) -> V {
+    let mut acc = vec![Neumaier::default(); V::DIM];
+    for p in points {
+        for (i, s) in acc.iter_mut().enumerate() {
+            s.add(p[i]);
+        }
+    }
+    let mut total = V::zero();
+    for (i, s) in acc.into_iter().enumerate() {
+        total[i] = s.value();
+    }
+    total
+}
+
+/// Compensated sum of scalar products of vectors taken pairwise,
+/// $\sum_i a_i \cdot b_i$
+///
+/// Products are added as separate terms together with their rounding
+/// errors, so cancellation between components is compensated as well.
+///
+/// # Panics
+/// Panics if slices have different lengths.
+///
+/// # Example
+/// ```
+/// # use linal::Vec3;
+/// # use linal::accurate::dot;
+/// let a = [Vec3::new(1e8, 1.0, -1e8)];
+/// let b = [Vec3::new(1e8, 1.0, 1e8)];
+/// assert_eq!(dot(&a, &b), 1.0);
+/// ```
+pub fn dot<V: Coordinates>(a: &[V], b: &[V]) -> f64 {
+    assert_eq!(a.len(), b.len(), "slices must have equal lengths");
+    let mut s = Neumaier::default();
+    for (a, b) in a.iter().zip(b) {
+        for i in 0..V::DIM {
+            s.add_product(a[i], b[i]);
+        }
+    }
+    s.value()
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    #[test]
+    fn accurate_sum_cancellation() {
+        // naive sum of these loses small terms entirely
+        let mut v = vec![Vec3::new(1e17, -1e17, 1.0)];
+        v.extend(vec![Vec3::new(1.0, 1.0, 1e-17); 1000]);
+        v.push(Vec3::new(-1e17, 1e17, -1.0));
+        let naive = v.iter().fold(Vec3::zero(), |a, &b| a + b);
+        assert_eq!(naive.x, 0.0);
+        let s = sum(&v);
+        assert_eq!((s.x, s.y), (1000.0, 1000.0));
+        assert!((s.z - 1e-14).abs() < 1e-26);
+        let ones = vec![Vec2::new(1, 1); v.len()];
+        let flat: Vec<Vec2> = v.iter().map(|p| Vec2::new(p.x, p.y)).collect();
+        assert_eq!(dot(&flat, &ones), 2000.0);
+        assert!(sum::<Vec2>(&[]) == Vec2::zero());
+    }
+
+    #[test]
+    fn accurate_dot_products_rounding() {
+        // 1 + 2^-30 squared has 2^-60 term lost by rounded product
+        let x = 1.0 + 2f64.powi(-30);
+        let a = [Vec2::new(x, 1.0)];
+        let b = [Vec2::new(x, -1.0 - 2f64.powi(-29))];
+        assert_eq!(dot(&a, &b), 2f64.powi(-60));
+    }
+}
@@ -0,0 +1,291 @@
+//! Rigid alignment of corresponding point sets.
+use isometry2::Isometry2;
+use isometry3::Isometry3;
+use mat3::Mat3;
+use spatial::KdTree;
+use vec2::Vec2;
+use vec3::Vec3;
+
+/// Optimal rigid motion mapping `src` points to corresponding `dst` points
+///
+/// Kabsch algorithm: minimizes sum of squared distances between transformed
+/// `src[i]` and `dst[i]`, proper rotation is guaranteed (no reflections).
+/// Returns `None` for empty input.
+///
+/// # Panics
+/// Panics if slices have different lengths.
+///
+/// # Example
+/// ```
+/// # use linal::{Isometry3, Mat3, Vec3};
+/// # use linal::align::{kabsch, rmsd};
+/// let motion = Isometry3::new(Mat3::rotation(Vec3::new(1, 1, 0), 0.5), Vec3::new(1, 2, 3));
+/// let src = [Vec3::new(0, 0, 0), Vec3::new(1, 0, 0), Vec3::new(0, 2, 0), Vec3::new(0, 0, 3)];
+/// let dst: Vec<Vec3> = src.iter().map(|&p| motion.transform_point(p)).collect();
+/// let found = kabsch(&src, &dst).unwrap();
+/// assert!(rmsd(found, &src, &dst) < 1e-12);
+/// assert!((found.translation - motion.translation).len() < 1e-12);
+/// ```
+pub fn kabsch(src: &[Vec3], dst: &[Vec3]) -> Option<Isometry3> {
+    assert_eq!(src.len(), dst.len(), "point sets must have equal lengths");
+    if src.is_empty() {
+        return None;
+    }
+    let (cs, cd) = (Vec3::centroid(src), Vec3::centroid(dst));
+    // cross-covariance, H = sum of (a - cs) (b - cd)^T
+    let h = src.iter().zip(dst).fold(Mat3::zero(), |acc, (&a, &b)| {
+        let (a, b) = (a - cs, b - cd);
+        acc + Mat3::new(b * a.x, b * a.y, b * a.z)
+    });
+    let (u, _, vt) = h.svd();
+    let (v, ut) = (vt.transpose(), u.transpose());
+    let d = if (v * ut).det() < 0.0 { -1.0 } else { 1.0 };
+    let rotation = v * Mat3::diag(Vec3::new(1.0, 1.0, d)) * ut;
+    Some(Isometry3::new(rotation, cd - rotation * cs))
+}
+
+/// Optimal rigid motion of plane mapping `src` points to `dst` points
+///
+/// Planar version of [`kabsch`], rotation angle is found in closed form.
+/// Returns `None` for empty input.
+///
+/// # Panics
+/// Panics if slices have different lengths.
+///
+/// # Example
+/// ```
+/// # use linal::{Isometry2, Vec2};
+/// # use linal::align::{kabsch2, rmsd2};
+/// let motion = Isometry2::from_angle(2.5, Vec2::new(-1, 4));
+/// let src = [Vec2::new(0, 0), Vec2::new(3, 0), Vec2::new(1, 1)];
+/// let dst: Vec<Vec2> = src.iter().map(|&p| motion.transform_point(p)).collect();
+/// let found = kabsch2(&src, &dst).unwrap();
+/// assert!((found.angle() - 2.5).abs() < 1e-12);
+/// assert!(rmsd2(found, &src, &dst) < 1e-12);
+/// ```
+pub fn kabsch2(src: &[Vec2], dst: &[Vec2]) -> Option<Isometry2> {
+    assert_eq!(src.len(), dst.len(), "point sets must have equal lengths");
+    if src.is_empty() {
+        return None;
+    }
+    let (cs, cd) = (Vec2::centroid(src), Vec2::centroid(dst));
+    let (sin, cos) = src.iter().zip(dst).fold((0.0, 0.0), |(s, c), (&a, &b)| {
+        let (a, b) = (a - cs, b - cd);
+        (s + a.area(b), c + a.dot(b))
+    });
+    let motion = Isometry2::from_angle(sin.atan2(cos), Vec2::zero());
+    Some(Isometry2::new(motion.rotation, cd - motion.rotation * cs))
+}
+
+/// Root-mean-square distance between transformed `src` points and `dst` points
+///
+/// Returns zero for empty input.
+///
+/// # Panics
+/// Panics if slices have different lengths.
+pub fn rmsd(motion: Isometry3, src: &[Vec3], dst: &[Vec3]) -> f64 {
+    assert_eq!(src.len(), dst.len(), "point sets must have equal lengths");
+    if src.is_empty() {
+        return 0.0;
+    }
+    let sum: f64 = src.iter().zip(dst).map(|(&a, &b)| {
+        let d = motion.transform_point(a) - b;
+        d.dot(d)
+    }).sum();
+    (sum / src.len() as f64).sqrt()
+}
+
+/// Root-mean-square distance between transformed `src` points and `dst` points on a plane
+///
+/// Returns zero for empty input.
+///
+/// # Panics
+/// Panics if slices have different lengths.
+pub fn rmsd2(motion: Isometry2, src: &[Vec2], dst: &[Vec2]) -> f64 {
+    assert_eq!(src.len(), dst.len(), "point sets must have equal lengths");
+    if src.is_empty() {
+        return 0.0;
+    }
+    let sum: f64 = src.iter().zip(dst).map(|(&a, &b)| {
+        let d = motion.transform_point(a) - b;
+        d.dot(d)
+    }).sum();
+    (sum / src.len() as f64).sqrt()
+}
+
+/// Parameters of [`icp`] registration
+///
+/// # Example
+/// ```
+/// # use linal::align::IcpOptions;
+/// let options = IcpOptions::new().with_max_iterations(100).with_max_distance(0.5);
+/// assert_eq!(options.max_iterations, 100);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IcpOptions {
+    /// maximal number of iterations
+    pub max_iterations: usize,
+    /// iterations stop when RMSD improves by less than this value
+    pub tolerance: f64,
+    /// pairs of points farther than this distance are rejected as outliers
+    pub max_distance: f64,
+    /// initial guess of motion
+    pub initial: Isometry3,
+}
+
+impl IcpOptions {
+    /// 50 iterations, tolerance `1e-9`, no outlier rejection, identity guess
+    pub fn new() -> IcpOptions {
+        IcpOptions {
+            max_iterations: 50,
+            tolerance: 1e-9,
+            max_distance: f64::INFINITY,
+            initial: Isometry3::identity(),
+        }
+    }
+    /// Sets maximal number of iterations
+    pub fn with_max_iterations(mut self, max_iterations: usize) -> IcpOptions {
+        self.max_iterations = max_iterations;
+        self
+    }
+    /// Sets convergence threshold of RMSD improvement
+    pub fn with_tolerance<I: Into<f64>>(mut self, tolerance: I) -> IcpOptions {
+        self.tolerance = tolerance.into();
+        self
+    }
+    /// Sets distance of outlier rejection
+    pub fn with_max_distance<I: Into<f64>>(mut self, max_distance: I) -> IcpOptions {
+        self.max_distance = max_distance.into();
+        self
+    }
+    /// Sets initial guess of motion
+    pub fn with_initial(mut self, initial: Isometry3) -> IcpOptions {
+        self.initial = initial;
+        self
+    }
+}
+
+impl Default for IcpOptions {
+    fn default() -> IcpOptions {
+        IcpOptions::new()
+    }
+}
+
+/// Result of [`icp`] registration
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IcpResult {
+    /// motion mapping source points onto target
+    pub motion: Isometry3,
+    /// RMSD over accepted pairs of the last iteration
+    pub rmsd: f64,
+    /// number of accepted pairs of the last iteration
+    pub inliers: usize,
+    /// number of performed iterations
+    pub iterations: usize,
+    /// if RMSD improvement fell below tolerance
+    pub converged: bool,
+}
+
+/// Point-to-point iterative closest point registration of `src` onto `dst`
+///
+/// Every iteration pairs transformed source points with nearest target
+/// points (found with [`KdTree`]), rejects pairs farther than
+/// `max_distance`, and solves for motion with [`kabsch`]. Converges to local
+/// minimum, so initial guess should be close enough. Returns `None` if fewer
+/// than 3 pairs are accepted or `max_iterations` is zero.
+///
+/// # Example
+/// ```
+/// # use linal::{Isometry3, Mat3, Vec3};
+/// # use linal::align::{icp, IcpOptions};
+/// let grid: Vec<Vec3> = (0..64).map(|i| Vec3::new(i % 4, (i / 4) % 4, i / 16)).collect();
+/// let cloud: Vec<Vec3> = grid.iter().map(|&p| Vec3::new(p.x * p.x, p.y, p.z + 0.3 * p.x)).collect();
+/// let motion = Isometry3::new(Mat3::rotation(Vec3::new(0, 0, 1), 0.05), Vec3::new(0.1, -0.05, 0.0));
+/// let moved: Vec<Vec3> = cloud.iter().map(|&p| motion.transform_point(p)).collect();
+/// let result = icp(&cloud, &moved, &IcpOptions::new()).unwrap();
+/// assert!(result.converged);
+/// assert!(result.rmsd < 1e-6);
+/// ```
+pub fn icp(src: &[Vec3], dst: &[Vec3], options: &IcpOptions) -> Option<IcpResult> {
+    let tree = KdTree::new(dst);
+    let mut motion = options.initial;
+    let mut previous = f64::INFINITY;
+    let mut result = None;
+    for iteration in 1..=options.max_iterations {
+        let (mut a, mut b) = (Vec::new(), Vec::new());
+        for &p in src {
+            if let Some((j, d)) = tree.nearest(motion.transform_point(p)) {
+                if d <= options.max_distance {
+                    a.push(p);
+                    b.push(dst[j]);
+                }
+            }
+        }
+        if a.len() < 3 {
+            return None;
+        }
+        motion = kabsch(&a, &b)?;
+        let error = rmsd(motion, &a, &b);
+        let converged = (previous - error).abs() < options.tolerance;
+        result = Some(IcpResult { motion, rmsd: error, inliers: a.len(), iterations: iteration, converged });
+        if converged {
+            break;
+        }
+        previous = error;
+    }
+    result
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    #[test]
+    fn kabsch_noisy() {
+        let motion = Isometry3::new(Mat3::rotation(Vec3::new(-1.0, 2.0, 0.5), 2.9), Vec3::new(5, -2, 0));
+        let src: Vec<Vec3> = (0..20)
+            .map(|i| Vec3::new((i * 7 % 11) as f64, (i * 3 % 5) as f64, (i * 13 % 17) as f64))
+            .collect();
+        let dst: Vec<Vec3> = src.iter().enumerate()
+            .map(|(i, &p)| motion.transform_point(p) + Vec3::new(1, -1, 1) * (0.01 * (i % 3) as f64 - 0.01))
+            .collect();
+        let found = kabsch(&src, &dst).unwrap();
+        assert!((found.rotation.det() - 1.0).abs() < 1e-12);
+        assert!(rmsd(found, &src, &dst) <= rmsd(motion, &src, &dst) + 1e-12);
+        assert!((found.rotation - motion.rotation).x.len() < 1e-3);
+    }
+
+    #[test]
+    fn kabsch_rejects_reflection() {
+        // mirrored set: best proper rotation, not reflection
+        let src = [Vec3::new(1, 0, 0), Vec3::new(0, 1, 0), Vec3::new(0, 0, 1), Vec3::new(0, 0, 0)];
+        let dst: Vec<Vec3> = src.iter().map(|p| Vec3::new(p.x, p.y, -p.z)).collect();
+        let found = kabsch(&src, &dst).unwrap();
+        assert!((found.rotation.det() - 1.0).abs() < 1e-12);
+        assert!(kabsch(&[], &[]).is_none());
+        let planar = [Vec2::new(1, 0), Vec2::new(0, 1)];
+        let flipped = [Vec2::new(-1, 0), Vec2::new(0, 1)];
+        assert!((kabsch2(&planar, &flipped).unwrap().rotation.det() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn icp_outliers() {
+        let cloud: Vec<Vec3> = (0..125)
+            .map(|i| Vec3::new((i % 5) as f64, ((i / 5) % 5) as f64 * 1.3, (i / 25) as f64 * 0.7))
+            .map(|p| p + Vec3::new(0.0, 0.1 * p.x * p.x, 0.05 * p.y * p.x))
+            .collect();
+        let motion = Isometry3::new(Mat3::rotation(Vec3::new(1, 2, 3), 0.04), Vec3::new(0.1, 0.0, -0.1));
+        let mut moved: Vec<Vec3> = cloud.iter().map(|&p| motion.transform_point(p)).collect();
+        // far outliers in target are ignored
+        moved.extend((0..10).map(|i| Vec3::new(50, i, 0)));
+        let mut src = cloud.clone();
+        src.push(Vec3::new(-40, 0, 0));
+        let options = IcpOptions::new().with_max_distance(1.0);
+        let result = icp(&src, &moved, &options).unwrap();
+        assert_eq!(result.inliers, cloud.len());
+        assert!(result.rmsd < 1e-6);
+        assert!((result.motion.translation - motion.translation).len() < 1e-6);
+        assert!(icp(&src, &moved, &options.with_max_distance(0.0).with_initial(Isometry3::translation(Vec3::new(0.5, 0.5, 0.5)))).is_none());
+        assert!(icp(&src, &moved, &options.with_max_iterations(0)).is_none());
+    }
+}
@@ -0,0 +1,152 @@
+//! Generation of vectors for property-based testing, available with
+//! `proptest` feature.
+//!
+//! `Arbitrary` implementations produce vectors with any finite coordinates,
+//! including huge and subnormal ones. Invariants that hold only up to
+//! rounding are easier to check with [`bounded_vec2`] and [`bounded_vec3`],
+//! directions come from [`unit_vec2`] and [`unit_vec3`].
+//!
+//! # Example
+//! ```
+//! # #[macro_use] extern crate proptest;
+//! # extern crate linal;
+//! # use linal::Vec3;
+//! # use linal::arbitrary::{bounded_vec3, unit_vec3};
+//! proptest! {
+//!     fn projection_is_shorter(v in bounded_vec3(1e3), n in unit_vec3()) {
+//!         prop_assert!(v.project_onto_plane(n).len() <= v.len() * (1.0 + 1e-12));
+//!     }
+//! }
+//! # fn main() { projection_is_shorter(); }
+//! ```
+use std::f64::consts::PI;
+use proptest::arbitrary::Arbitrary;
+use proptest::num::f64::{NEGATIVE, NORMAL, POSITIVE, SUBNORMAL, ZERO};
+use proptest::strategy::{BoxedStrategy, Strategy};
+use vec2::Vec2;
+use vec3::Vec3;
+use vec4::Vec4;
+
+// finite numbers of both signs: normal, subnormal and zeros
+fn finite() -> impl Strategy<Value = f64> {
+    POSITIVE | NEGATIVE | NORMAL | SUBNORMAL | ZERO
+}
+
+// vector shortened to given length if it is longer
+fn clamp_len2(mut v: Vec2, max_len: f64) -> Vec2 {
+    let len = v.len();
+    if len > max_len {
+        v *= max_len / len;
+        // rounding may leave length an ulp above bound
+        while v.len() > max_len {
+            v *= 1.0 - f64::EPSILON;
+        }
+    }
+    v
+}
+
+fn clamp_len3(mut v: Vec3, max_len: f64) -> Vec3 {
+    let len = v.len();
+    if len > max_len {
+        v *= max_len / len;
+        // rounding may leave length an ulp above bound
+        while v.len() > max_len {
+            v *= 1.0 - f64::EPSILON;
+        }
+    }
+    v
+}
+
+/// Vectors with any finite coordinates
+pub fn finite_vec2() -> impl Strategy<Value = Vec2> {
+    (finite(), finite()).prop_map(|(x, y)| Vec2::new(x, y))
+}
+
+/// Vectors with any finite coordinates
+pub fn finite_vec3() -> impl Strategy<Value = Vec3> {
+    (finite(), finite(), finite()).prop_map(|(x, y, z)| Vec3::new(x, y, z))
+}
+
+/// Vectors of length at most `max_len`
+///
+/// # Panics
+/// Panics if `max_len` is negative or not finite.
+pub fn bounded_vec2(max_len: f64) -> impl Strategy<Value = Vec2> {
+    assert!(max_len >= 0.0 && max_len.is_finite(), "length bound must be non-negative");
+    (-max_len..=max_len, -max_len..=max_len)
+        .prop_map(move |(x, y)| clamp_len2(Vec2::new(x, y), max_len))
+}
+
+/// Vectors of length at most `max_len`
+///
+/// # Panics
+/// Panics if `max_len` is negative or not finite.
+pub fn bounded_vec3(max_len: f64) -> impl Strategy<Value = Vec3> {
+    assert!(max_len >= 0.0 && max_len.is_finite(), "length bound must be non-negative");
+    (-max_len..=max_len, -max_len..=max_len, -max_len..=max_len)
+        .prop_map(move |(x, y, z)| clamp_len3(Vec3::new(x, y, z), max_len))
+}
+
+/// Unit vectors, uniformly distributed over circle
+pub fn unit_vec2() -> impl Strategy<Value = Vec2> {
+    (0.0..2.0 * PI).prop_map(|angle| Vec2::from_polar(1.0, angle))
+}
+
+/// Unit vectors, uniformly distributed over sphere
+pub fn unit_vec3() -> impl Strategy<Value = Vec3> {
+    (-1.0..=1.0, 0.0..2.0 * PI).prop_map(|(z, phi): (f64, f64)| {
+        let r = (1.0 - z * z).max(0.0).sqrt();
+        Vec3::new(r * phi.cos(), r * phi.sin(), z).ort()
+    })
+}
+
+impl Arbitrary for Vec2 {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Vec2>;
+    fn arbitrary_with(_: ()) -> BoxedStrategy<Vec2> {
+        finite_vec2().boxed()
+    }
+}
+
+impl Arbitrary for Vec3 {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Vec3>;
+    fn arbitrary_with(_: ()) -> BoxedStrategy<Vec3> {
+        finite_vec3().boxed()
+    }
+}
+
+impl Arbitrary for Vec4 {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Vec4>;
+    fn arbitrary_with(_: ()) -> BoxedStrategy<Vec4> {
+        (finite(), finite(), finite(), finite())
+            .prop_map(|(x, y, z, w)| Vec4::new(x, y, z, w))
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+    use proptest::prelude::any;
+    use proptest::strategy::ValueTree;
+    use proptest::test_runner::TestRunner;
+
+    #[test]
+    fn strategies_respect_bounds() {
+        let mut runner = TestRunner::deterministic();
+        for _ in 0..500 {
+            let v = any::<Vec3>().new_tree(&mut runner).unwrap().current();
+            assert!(v.x.is_finite() && v.y.is_finite() && v.z.is_finite());
+            let b = bounded_vec2(2.5).new_tree(&mut runner).unwrap().current();
+            assert!(b.len() <= 2.5);
+            let b = bounded_vec3(1e-3).new_tree(&mut runner).unwrap().current();
+            assert!(b.len() <= 1e-3);
+            let u = unit_vec3().new_tree(&mut runner).unwrap().current();
+            assert!((u.len() - 1.0).abs() < 1e-15);
+            let u = unit_vec2().new_tree(&mut runner).unwrap().current();
+            assert!((u.len() - 1.0).abs() < 1e-15);
+        }
+    }
+}
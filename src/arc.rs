@@ -0,0 +1,239 @@
+//! Circular arcs on a plane.
+use std::f64::consts::{FRAC_PI_2, PI};
+use circle::Circle;
+use polyline::Polyline;
+use rect::Rect;
+use vec2::Vec2;
+
+// full turn in radians
+const TAU: f64 = 2.0 * PI;
+// upper bound on number of pieces in flattened arc
+const MAX_PIECES: usize = 1 << 16;
+
+/// Direction of travel along arc
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArcDirection {
+    /// angle increases from start to end
+    CounterClockwise,
+    /// angle decreases from start to end
+    Clockwise,
+}
+
+/// Arc of circle, traversed from `start` to `end` angle in given direction
+///
+/// Angles are measured counterclockwise from $x$ axis. Arc with different
+/// start and end angles that coincide modulo $2\pi$ is full circle, equal
+/// angles give degenerate arc of single point.
+///
+/// # Example
+/// ```
+/// # use linal::{Arc, ArcDirection, Vec2};
+/// # use std::f64::consts::{FRAC_PI_2, PI};
+/// let arc = Arc::new(Vec2::zero(), 2.0, 0.0, FRAC_PI_2, ArcDirection::Clockwise);
+/// assert!((arc.length() - 3.0 * PI).abs() < 1e-12);
+/// assert!((arc.point_at(1.0 / 3.0) - Vec2::new(0, -2)).len() < 1e-12);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Arc {
+    /// center of circle
+    pub center: Vec2,
+    /// radius of circle
+    pub radius: f64,
+    /// angle of starting point
+    pub start: f64,
+    /// angle of ending point
+    pub end: f64,
+    /// direction of travel from start to end
+    pub direction: ArcDirection,
+}
+
+impl Arc {
+    /// Constructs a new `Arc`
+    pub fn new<I: Into<f64>>(center: Vec2, radius: I, start: I, end: I, direction: ArcDirection) -> Arc {
+        Arc { center, radius: radius.into(), start: start.into(), end: end.into(), direction }
+    }
+    /// Arc from `a` through `b` to `c`
+    ///
+    /// Returns `None` if points are collinear.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Arc, ArcDirection, Vec2};
+    /// let arc = Arc::through(Vec2::new(1, 0), Vec2::new(0, -1), Vec2::new(-1, 0)).unwrap();
+    /// assert_eq!(arc.direction, ArcDirection::Clockwise);
+    /// assert!((arc.point_at(0.5) - Vec2::new(0, -1)).len() < 1e-12);
+    /// assert!(Arc::through(Vec2::new(0, 0), Vec2::new(1, 1), Vec2::new(2, 2)).is_none());
+    /// ```
+    pub fn through(a: Vec2, b: Vec2, c: Vec2) -> Option<Arc> {
+        let circle = Circle::through(a, b, c)?;
+        let angle = |p: Vec2| {
+            let d = p - circle.center;
+            d.y.atan2(d.x)
+        };
+        let direction = if (b - a).area(c - b) > 0.0 {
+            ArcDirection::CounterClockwise
+        } else {
+            ArcDirection::Clockwise
+        };
+        Some(Arc::new(circle.center, circle.radius, angle(a), angle(c), direction))
+    }
+    /// Signed angle swept from start to end, positive counterclockwise
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Arc, ArcDirection, Vec2};
+    /// # use std::f64::consts::PI;
+    /// let arc = Arc::new(Vec2::zero(), 1.0, 0.0, 3.0 * PI, ArcDirection::CounterClockwise);
+    /// assert!((arc.sweep() - PI).abs() < 1e-12);
+    /// let full = Arc::new(Vec2::zero(), 1.0, 0.0, 2.0 * PI, ArcDirection::Clockwise);
+    /// assert_eq!(full.sweep(), -2.0 * PI);
+    /// ```
+    pub fn sweep(self) -> f64 {
+        let delta = match self.direction {
+            ArcDirection::CounterClockwise => self.end - self.start,
+            ArcDirection::Clockwise => self.start - self.end,
+        };
+        let mut sweep = delta.rem_euclid(TAU);
+        if sweep == 0.0 && delta != 0.0 {
+            sweep = TAU;
+        }
+        match self.direction {
+            ArcDirection::CounterClockwise => sweep,
+            ArcDirection::Clockwise => -sweep,
+        }
+    }
+    /// Point of arc at fraction `t` of its sweep, $t \in [0, 1]$
+    pub fn point_at<I: Into<f64>>(self, t: I) -> Vec2 {
+        self.point_at_angle(self.start + self.sweep() * t.into())
+    }
+    /// Starting point of arc
+    pub fn start_point(self) -> Vec2 {
+        self.point_at_angle(self.start)
+    }
+    /// Ending point of arc
+    pub fn end_point(self) -> Vec2 {
+        self.point_at_angle(self.end)
+    }
+    /// Length of arc
+    pub fn length(self) -> f64 {
+        self.radius * self.sweep().abs()
+    }
+    /// Smallest rectangle containing arc
+    ///
+    /// Besides endpoints, includes extreme points of circle along axes that
+    /// lie on arc.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Arc, ArcDirection, Rect, Vec2};
+    /// # use std::f64::consts::PI;
+    /// let arc = Arc::new(Vec2::zero(), 1.0, -PI / 4.0, PI / 4.0, ArcDirection::CounterClockwise);
+    /// let r = arc.bounding_box();
+    /// assert!((r.max.x - 1.0).abs() < 1e-12);
+    /// assert!((r.min.x - 0.5f64.sqrt()).abs() < 1e-12);
+    /// ```
+    pub fn bounding_box(self) -> Rect {
+        let sweep = self.sweep();
+        let (from, to) = if sweep >= 0.0 {
+            (self.start, self.start + sweep)
+        } else {
+            (self.start + sweep, self.start)
+        };
+        // first multiple of right angle not before lower angle
+        let first = (from / FRAC_PI_2).ceil() as i64;
+        let extremes = (first..)
+            .map(|k| k as f64 * FRAC_PI_2)
+            .take_while(|&angle| angle <= to)
+            .take(4)
+            .map(|angle| self.point_at_angle(angle));
+        let points = [self.start_point(), self.end_point()];
+        Rect::from_points(points.iter().cloned().chain(extremes)).unwrap()
+    }
+    /// Approximates arc with polyline
+    ///
+    /// Arc is split into equal pieces so that distance between arc and
+    /// chords (sagitta) is within `tolerance`. Polyline starts and ends at
+    /// endpoints of arc. Number of pieces is limited, so tolerance that is
+    /// tiny compared to radius is not reached.
+    ///
+    /// # Panics
+    /// Panics if `tolerance` is not positive
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Arc, ArcDirection, Vec2};
+    /// # use std::f64::consts::PI;
+    /// let arc = Arc::new(Vec2::zero(), 10.0, 0.0, PI, ArcDirection::CounterClockwise);
+    /// let line = arc.flatten(0.01);
+    /// assert_eq!(line.vertices[0], arc.start_point());
+    /// assert!(line.vertices.iter().all(|p| (p.len() - 10.0).abs() < 1e-12));
+    /// assert!((line.length() - arc.length()).abs() < 0.1);
+    /// ```
+    pub fn flatten<I: Into<f64>>(self, tolerance: I) -> Polyline {
+        let tolerance = tolerance.into();
+        assert!(tolerance > 0.0, "tolerance must be positive");
+        let sweep = self.sweep();
+        let step = if self.radius > 0.0 {
+            2.0 * (1.0 - tolerance / self.radius).max(-1.0).acos()
+        } else {
+            TAU
+        };
+        let pieces = (sweep.abs() / step).ceil().clamp(1.0, MAX_PIECES as f64) as usize;
+        let mut vertices: Vec<Vec2> = (0..pieces)
+            .map(|i| self.point_at_angle(self.start + sweep * i as f64 / pieces as f64))
+            .collect();
+        vertices.push(self.end_point());
+        Polyline::new(vertices)
+    }
+    fn point_at_angle(self, angle: f64) -> Vec2 {
+        self.center + Vec2::from_polar(self.radius, angle)
+    }
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    #[test]
+    fn arc_through_points() {
+        let (a, b, c) = (Vec2::new(3, 1), Vec2::new(-1, 4), Vec2::new(-2, -3));
+        let arc = Arc::through(a, b, c).unwrap();
+        assert_eq!(arc.direction, ArcDirection::CounterClockwise);
+        assert!((arc.start_point() - a).len() < 1e-12);
+        assert!((arc.end_point() - c).len() < 1e-12);
+        // middle point lies on arc: reversed arc passes through it as well
+        let back = Arc::through(c, b, a).unwrap();
+        assert!((arc.sweep() + back.sweep()).abs() < 1e-12);
+        let polyline = arc.flatten(1e-3);
+        let bounds = arc.bounding_box();
+        assert!(polyline.vertices.iter().all(|&p| bounds.expand(1e-12).contains(p)));
+        assert!(polyline.vertices.iter().any(|&p| (p.y - bounds.max.y).abs() < 1e-3));
+    }
+
+    #[test]
+    fn arc_full_circle() {
+        let arc = Arc::new(Vec2::new(1, 1), 2.0, 1.0, 1.0 + TAU, ArcDirection::Clockwise);
+        assert!((arc.length() - 4.0 * PI).abs() < 1e-12);
+        let r = arc.bounding_box();
+        assert!((r.min - Vec2::new(-1, -1)).len() < 1e-12);
+        assert!((r.max - Vec2::new(3, 3)).len() < 1e-12);
+        let point = Arc::new(Vec2::zero(), 1, 2, 2, ArcDirection::Clockwise);
+        assert_eq!(point.length(), 0.0);
+        assert_eq!(point.flatten(0.1).vertices.len(), 2);
+    }
+
+    #[test]
+    fn arc_flatten_tiny_tolerance() {
+        let arc = Arc::new(Vec2::zero(), 1e10, 0.0, 1.0, ArcDirection::CounterClockwise);
+        let line = arc.flatten(1e-300);
+        assert_eq!(line.vertices.len(), MAX_PIECES + 1);
+        assert_eq!(*line.vertices.last().unwrap(), arc.end_point());
+    }
+
+    #[test]
+    #[should_panic]
+    fn arc_flatten_zero_tolerance() {
+        let arc = Arc::new(Vec2::zero(), 1.0, 0.0, 1.0, ArcDirection::CounterClockwise);
+        arc.flatten(0.0);
+    }
+}
@@ -0,0 +1,123 @@
+//! Bulk kernels over slices of vectors.
+//!
+//! Kernels process fixed-size groups of lanes with straight-line code, so
+//! compiler can map them onto SIMD registers regardless of `Vec3` layout.
+use vec3::Vec3;
+
+// number of vectors processed at once
+const LANES: usize = 4;
+
+/// Scalar products of vectors from two slices taken pairwise
+///
+/// # Panics
+/// Panics if slices have different lengths.
+///
+/// # Example
+/// ```
+/// # use linal::Vec3;
+/// # use linal::batch::dot_slices;
+/// let a = [Vec3::new(1, 2, 3), Vec3::new(1, 0, 0)];
+/// let b = [Vec3::new(1, 1, 1), Vec3::new(0, 1, 0)];
+/// assert_eq!(dot_slices(&a, &b), vec![6.0, 0.0]);
+/// ```
+pub fn dot_slices(a: &[Vec3], b: &[Vec3]) -> Vec<f64> {
+    assert_eq!(a.len(), b.len(), "slices must have equal lengths");
+    let mut out = vec![0.0; a.len()];
+    {
+        let mut out_chunks = out.chunks_exact_mut(LANES);
+        let (mut a_chunks, mut b_chunks) = (a.chunks_exact(LANES), b.chunks_exact(LANES));
+        for ((o, a), b) in (&mut out_chunks).zip(&mut a_chunks).zip(&mut b_chunks) {
+            for l in 0..LANES {
+                o[l] = a[l].x * b[l].x + a[l].y * b[l].y + a[l].z * b[l].z;
+            }
+        }
+        let tail = out_chunks.into_remainder();
+        for ((o, a), b) in tail.iter_mut().zip(a_chunks.remainder()).zip(b_chunks.remainder()) {
+            *o = a.dot(*b);
+        }
+    }
+    out
+}
+
+/// Adds `x` scaled by `k` to `y`, i.e. $y_i \leftarrow k x_i + y_i$
+///
+/// # Panics
+/// Panics if slices have different lengths.
+///
+/// # Example
+/// ```
+/// # use linal::Vec3;
+/// # use linal::batch::axpy;
+/// let x = [Vec3::new(1, 2, 3), Vec3::new(1, 0, 0)];
+/// let mut y = [Vec3::new(1, 1, 1), Vec3::new(0, 1, 0)];
+/// axpy(2, &x, &mut y);
+/// assert_eq!(y, [Vec3::new(3, 5, 7), Vec3::new(2, 1, 0)]);
+/// ```
+pub fn axpy<I: Into<f64>>(k: I, x: &[Vec3], y: &mut [Vec3]) {
+    assert_eq!(x.len(), y.len(), "slices must have equal lengths");
+    let k = k.into();
+    let mut y_chunks = y.chunks_exact_mut(LANES);
+    let mut x_chunks = x.chunks_exact(LANES);
+    for (y, x) in (&mut y_chunks).zip(&mut x_chunks) {
+        for l in 0..LANES {
+            y[l].x += k * x[l].x;
+            y[l].y += k * x[l].y;
+            y[l].z += k * x[l].z;
+        }
+    }
+    for (y, x) in y_chunks.into_remainder().iter_mut().zip(x_chunks.remainder()) {
+        *y += *x * k;
+    }
+}
+
+/// Normalizes all vectors of slice to unit length
+///
+/// Zero vectors become NaN, like with [`Vec3::ort`](../vec3/struct.Vec3.html#method.ort).
+///
+/// # Example
+/// ```
+/// # use linal::Vec3;
+/// # use linal::batch::normalize_slice;
+/// let mut v = [Vec3::new(3, 0, 4), Vec3::new(0, 2, 0)];
+/// normalize_slice(&mut v);
+/// assert_eq!(v, [Vec3::new(0.6, 0.0, 0.8), Vec3::new(0, 1, 0)]);
+/// ```
+pub fn normalize_slice(v: &mut [Vec3]) {
+    let mut chunks = v.chunks_exact_mut(LANES);
+    for c in &mut chunks {
+        let mut inv = [0.0; LANES];
+        for l in 0..LANES {
+            inv[l] = 1.0 / (c[l].x * c[l].x + c[l].y * c[l].y + c[l].z * c[l].z).sqrt();
+        }
+        for l in 0..LANES {
+            c[l].x *= inv[l];
+            c[l].y *= inv[l];
+            c[l].z *= inv[l];
+        }
+    }
+    for v in chunks.into_remainder() {
+        *v = v.ort();
+    }
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    #[test]
+    fn batch_matches_scalar_code() {
+        // length not divisible by lane count to cover remainder
+        let a: Vec<Vec3> = (0..23).map(|i| Vec3::from_spherical(1.0 + i as f64, i as f64, 0.3 * i as f64)).collect();
+        let b: Vec<Vec3> = (0..23).map(|i| Vec3::new(i, 1 - i, 2)).collect();
+        let dots = dot_slices(&a, &b);
+        let mut y = b.clone();
+        axpy(0.5, &a, &mut y);
+        let mut n = y.clone();
+        normalize_slice(&mut n);
+        for i in 0..23 {
+            assert_eq!(dots[i], a[i].dot(b[i]));
+            assert_eq!(y[i], b[i] + a[i] * 0.5);
+            assert!((n[i] - y[i].ort()).len() < 1e-15);
+        }
+    }
+}
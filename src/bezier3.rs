@@ -0,0 +1,311 @@
+//! Quadratic and cubic Bézier curves in space.
+use vec3::Vec3;
+use segment3::Segment3;
+
+// subdivision limit for flattening and length estimation
+const MAX_DEPTH: u32 = 16;
+
+/// Quadratic Bézier curve in space
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct QuadBezier3 {
+    /// start point
+    pub p0: Vec3,
+    /// control point
+    pub p1: Vec3,
+    /// end point
+    pub p2: Vec3,
+}
+
+impl QuadBezier3 {
+    /// Constructs a new `QuadBezier3` from control points.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{QuadBezier3, Vec3};
+    /// let b = QuadBezier3::new(Vec3::new(0, 0, 0), Vec3::new(1, 2, 2), Vec3::new(2, 0, 0));
+    /// assert_eq!(b.p1, Vec3::new(1, 2, 2));
+    /// ```
+    pub fn new(p0: Vec3, p1: Vec3, p2: Vec3) -> QuadBezier3 {
+        QuadBezier3 { p0, p1, p2 }
+    }
+    /// Point of curve at parameter `t` from $[0, 1]$
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{QuadBezier3, Vec3};
+    /// let b = QuadBezier3::new(Vec3::new(0, 0, 0), Vec3::new(1, 2, 2), Vec3::new(2, 0, 0));
+    /// assert_eq!(b.eval(0), Vec3::new(0, 0, 0));
+    /// assert_eq!(b.eval(0.5), Vec3::new(1, 1, 1));
+    /// assert_eq!(b.eval(1), Vec3::new(2, 0, 0));
+    /// ```
+    pub fn eval<I: Into<f64>>(self, t: I) -> Vec3 {
+        let t = t.into();
+        let s = 1.0 - t;
+        self.p0 * (s * s) + self.p1 * (2.0 * s * t) + self.p2 * (t * t)
+    }
+    /// Derivative of curve by parameter at `t`
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{QuadBezier3, Vec3};
+    /// let b = QuadBezier3::new(Vec3::new(0, 0, 0), Vec3::new(1, 2, 2), Vec3::new(2, 0, 0));
+    /// assert_eq!(b.derivative(0), Vec3::new(2, 4, 4));
+    /// assert_eq!(b.derivative(0.5), Vec3::new(2, 0, 0));
+    /// ```
+    pub fn derivative<I: Into<f64>>(self, t: I) -> Vec3 {
+        let t = t.into();
+        (self.p1 - self.p0) * (2.0 * (1.0 - t)) + (self.p2 - self.p1) * (2.0 * t)
+    }
+    /// Splits curve at parameter `t` into two curves
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{QuadBezier3, Vec3};
+    /// let b = QuadBezier3::new(Vec3::new(0, 0, 0), Vec3::new(1, 2, 2), Vec3::new(2, 0, 0));
+    /// let (l, r) = b.split(0.5);
+    /// assert_eq!(l.p2, b.eval(0.5));
+    /// assert_eq!(r.eval(0.5), b.eval(0.75));
+    /// ```
+    pub fn split<I: Into<f64>>(self, t: I) -> (QuadBezier3, QuadBezier3) {
+        let t = t.into();
+        let a = self.p0 + (self.p1 - self.p0) * t;
+        let b = self.p1 + (self.p2 - self.p1) * t;
+        let m = a + (b - a) * t;
+        (QuadBezier3::new(self.p0, a, m), QuadBezier3::new(m, b, self.p2))
+    }
+    /// Approximates curve with polyline, returning its vertices
+    ///
+    /// Curve is recursively subdivided until distance between control points
+    /// and chord (which bounds deviation of curve) is within `tolerance`.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{QuadBezier3, Vec3};
+    /// let b = QuadBezier3::new(Vec3::new(0, 0, 0), Vec3::new(1, 2, 2), Vec3::new(2, 0, 0));
+    /// let line = b.flatten(0.01);
+    /// assert_eq!(line[0], b.p0);
+    /// assert_eq!(*line.last().unwrap(), b.p2);
+    /// ```
+    pub fn flatten<I: Into<f64>>(self, tolerance: I) -> Vec<Vec3> {
+        let tolerance = tolerance.into();
+        let mut vertices = vec![self.p0];
+        self.flatten_into(tolerance, MAX_DEPTH, &mut vertices);
+        vertices
+    }
+    /// Approximate length of curve
+    ///
+    /// Uses recursive subdivision until control polygon and chord lengths
+    /// differ less than `tolerance`.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{QuadBezier3, Vec3};
+    /// // degenerate curve is straight line
+    /// let b = QuadBezier3::new(Vec3::new(0, 0, 0), Vec3::new(1, 0, 0), Vec3::new(3, 0, 0));
+    /// assert!((b.arc_length(1e-9) - 3.0).abs() < 1e-9);
+    /// ```
+    pub fn arc_length<I: Into<f64>>(self, tolerance: I) -> f64 {
+        self.arc_length_rec(tolerance.into(), MAX_DEPTH)
+    }
+    fn flatness(self) -> f64 {
+        Segment3::new(self.p0, self.p2).distance_to(self.p1)
+    }
+    fn flatten_into(self, tolerance: f64, depth: u32, vertices: &mut Vec<Vec3>) {
+        if depth == 0 || self.flatness() <= tolerance {
+            vertices.push(self.p2);
+        } else {
+            let (l, r) = self.split(0.5);
+            l.flatten_into(tolerance, depth - 1, vertices);
+            r.flatten_into(tolerance, depth - 1, vertices);
+        }
+    }
+    fn arc_length_rec(self, tolerance: f64, depth: u32) -> f64 {
+        let chord = (self.p2 - self.p0).len();
+        let polygon = (self.p1 - self.p0).len() + (self.p2 - self.p1).len();
+        if depth == 0 || polygon - chord <= tolerance {
+            // Gravesen's estimate for degree 2
+            return (2.0 * chord + polygon) / 3.0;
+        }
+        let (l, r) = self.split(0.5);
+        l.arc_length_rec(tolerance * 0.5, depth - 1) + r.arc_length_rec(tolerance * 0.5, depth - 1)
+    }
+}
+
+/// Cubic Bézier curve in space
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CubicBezier3 {
+    /// start point
+    pub p0: Vec3,
+    /// first control point
+    pub p1: Vec3,
+    /// second control point
+    pub p2: Vec3,
+    /// end point
+    pub p3: Vec3,
+}
+
+impl CubicBezier3 {
+    /// Constructs a new `CubicBezier3` from control points.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{CubicBezier3, Vec3};
+    /// let b = CubicBezier3::new(Vec3::new(0, 0, 0), Vec3::new(0, 1, 0), Vec3::new(1, 1, 0), Vec3::new(1, 0, 0));
+    /// assert_eq!(b.p3, Vec3::new(1, 0, 0));
+    /// ```
+    pub fn new(p0: Vec3, p1: Vec3, p2: Vec3, p3: Vec3) -> CubicBezier3 {
+        CubicBezier3 { p0, p1, p2, p3 }
+    }
+    /// Point of curve at parameter `t` from $[0, 1]$
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{CubicBezier3, Vec3};
+    /// let b = CubicBezier3::new(Vec3::new(0, 0, 0), Vec3::new(0, 4, 0), Vec3::new(4, 4, 0), Vec3::new(4, 0, 0));
+    /// assert_eq!(b.eval(0), Vec3::new(0, 0, 0));
+    /// assert_eq!(b.eval(0.5), Vec3::new(2, 3, 0));
+    /// assert_eq!(b.eval(1), Vec3::new(4, 0, 0));
+    /// ```
+    pub fn eval<I: Into<f64>>(self, t: I) -> Vec3 {
+        let t = t.into();
+        let s = 1.0 - t;
+        self.p0 * (s * s * s) + self.p1 * (3.0 * s * s * t) +
+        self.p2 * (3.0 * s * t * t) + self.p3 * (t * t * t)
+    }
+    /// Derivative of curve by parameter at `t`
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{CubicBezier3, Vec3};
+    /// let b = CubicBezier3::new(Vec3::new(0, 0, 0), Vec3::new(0, 4, 0), Vec3::new(4, 4, 0), Vec3::new(4, 0, 0));
+    /// assert_eq!(b.derivative(0), Vec3::new(0, 12, 0));
+    /// assert_eq!(b.derivative(0.5), Vec3::new(6, 0, 0));
+    /// ```
+    pub fn derivative<I: Into<f64>>(self, t: I) -> Vec3 {
+        let t = t.into();
+        let s = 1.0 - t;
+        (self.p1 - self.p0) * (3.0 * s * s) + (self.p2 - self.p1) * (6.0 * s * t) +
+        (self.p3 - self.p2) * (3.0 * t * t)
+    }
+    /// Splits curve at parameter `t` into two curves
+    ///
+    /// Uses de Casteljau's algorithm.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{CubicBezier3, Vec3};
+    /// let b = CubicBezier3::new(Vec3::new(0, 0, 0), Vec3::new(0, 4, 0), Vec3::new(4, 4, 0), Vec3::new(4, 0, 0));
+    /// let (l, r) = b.split(0.5);
+    /// assert_eq!(l.p3, b.eval(0.5));
+    /// assert_eq!(r.eval(0.5), b.eval(0.75));
+    /// ```
+    pub fn split<I: Into<f64>>(self, t: I) -> (CubicBezier3, CubicBezier3) {
+        let t = t.into();
+        let a = self.p0 + (self.p1 - self.p0) * t;
+        let b = self.p1 + (self.p2 - self.p1) * t;
+        let c = self.p2 + (self.p3 - self.p2) * t;
+        let ab = a + (b - a) * t;
+        let bc = b + (c - b) * t;
+        let m = ab + (bc - ab) * t;
+        (CubicBezier3::new(self.p0, a, ab, m), CubicBezier3::new(m, bc, c, self.p3))
+    }
+    /// Approximates curve with polyline, returning its vertices
+    ///
+    /// Curve is recursively subdivided until distance between control points
+    /// and chord (which bounds deviation of curve) is within `tolerance`.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{CubicBezier3, Vec3};
+    /// let b = CubicBezier3::new(Vec3::new(0, 0, 0), Vec3::new(0, 4, 0), Vec3::new(4, 4, 0), Vec3::new(4, 0, 0));
+    /// let line = b.flatten(0.01);
+    /// assert!(line.len() > 2);
+    /// assert_eq!(*line.last().unwrap(), b.p3);
+    /// ```
+    pub fn flatten<I: Into<f64>>(self, tolerance: I) -> Vec<Vec3> {
+        let tolerance = tolerance.into();
+        let mut vertices = vec![self.p0];
+        self.flatten_into(tolerance, MAX_DEPTH, &mut vertices);
+        vertices
+    }
+    /// Approximate length of curve
+    ///
+    /// Uses recursive subdivision until control polygon and chord lengths
+    /// differ less than `tolerance`.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{CubicBezier3, Vec3};
+    /// let b = CubicBezier3::new(Vec3::new(0, 0, 0), Vec3::new(1, 0, 0), Vec3::new(2, 0, 0), Vec3::new(3, 0, 0));
+    /// assert!((b.arc_length(1e-9) - 3.0).abs() < 1e-9);
+    /// ```
+    pub fn arc_length<I: Into<f64>>(self, tolerance: I) -> f64 {
+        self.arc_length_rec(tolerance.into(), MAX_DEPTH)
+    }
+    fn flatness(self) -> f64 {
+        let chord = Segment3::new(self.p0, self.p3);
+        chord.distance_to(self.p1).max(chord.distance_to(self.p2))
+    }
+    fn flatten_into(self, tolerance: f64, depth: u32, vertices: &mut Vec<Vec3>) {
+        if depth == 0 || self.flatness() <= tolerance {
+            vertices.push(self.p3);
+        } else {
+            let (l, r) = self.split(0.5);
+            l.flatten_into(tolerance, depth - 1, vertices);
+            r.flatten_into(tolerance, depth - 1, vertices);
+        }
+    }
+    fn arc_length_rec(self, tolerance: f64, depth: u32) -> f64 {
+        let chord = (self.p3 - self.p0).len();
+        let polygon = (self.p1 - self.p0).len() + (self.p2 - self.p1).len() +
+                      (self.p3 - self.p2).len();
+        if depth == 0 || polygon - chord <= tolerance {
+            // Gravesen's estimate for degree 3
+            return (2.0 * chord + 2.0 * polygon) / 4.0;
+        }
+        let (l, r) = self.split(0.5);
+        l.arc_length_rec(tolerance * 0.5, depth - 1) + r.arc_length_rec(tolerance * 0.5, depth - 1)
+    }
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    #[test]
+    fn bezier3_flatten_tolerance() {
+        let b = CubicBezier3::new(Vec3::new(0, 0, 0), Vec3::new(5, 10, -4), Vec3::new(-5, 10, 6), Vec3::new(3, -1, 2));
+        let tolerance = 1e-3;
+        let line = b.flatten(tolerance);
+        // every curve point is close to approximation
+        for i in 0..=1000 {
+            let p = b.eval(i as f64 / 1000.0);
+            let d = line.windows(2)
+                .map(|w| Segment3::new(w[0], w[1]).distance_to(p))
+                .fold(f64::MAX, f64::min);
+            assert!(d <= tolerance);
+        }
+    }
+
+    #[test]
+    fn bezier3_arc_length() {
+        // quarter of unit circle, standard cubic approximation
+        let k = 4.0 / 3.0 * (2f64.sqrt() - 1.0);
+        let b = CubicBezier3::new(Vec3::new(1, 0, 0), Vec3::new(1.0, k, 0.0), Vec3::new(k, 1.0, 0.0), Vec3::new(0, 1, 0));
+        assert!((b.arc_length(1e-10) - std::f64::consts::FRAC_PI_2).abs() < 1e-3);
+        // fine polyline agrees with length estimate
+        let q = QuadBezier3::new(Vec3::new(0, 0, 0), Vec3::new(1, 3, 2), Vec3::new(4, 0, 0));
+        let length: f64 = q.flatten(1e-7).windows(2).map(|w| (w[1] - w[0]).len()).sum();
+        assert!((q.arc_length(1e-10) - length).abs() < 1e-6);
+    }
+
+    #[test]
+    fn bezier3_derivative() {
+        let b = CubicBezier3::new(Vec3::new(0, 0, 0), Vec3::new(1, 3, -2), Vec3::new(2, -1, 5), Vec3::new(4, 1, 1));
+        let h = 1e-6;
+        for &t in &[0.1, 0.4, 0.9] {
+            let numeric = (b.eval(t + h) - b.eval(t - h)) / (2.0 * h);
+            assert!((numeric - b.derivative(t)).len() < 1e-6);
+        }
+    }
+}
@@ -0,0 +1,363 @@
+//! Boolean operations on simple polygons.
+//!
+//! Operations are computed by overlay: boundaries of both polygons are split
+//! at all their intersections, every piece of boundary is classified as
+//! lying inside, outside or on boundary of the other polygon, and selected
+//! pieces are linked back into closed rings.
+use std::collections::HashMap;
+use containment::winding_number;
+use polygon::Polygon;
+use vec2::Vec2;
+
+// tolerance relative to size of polygons
+const RELATIVE_EPS: f64 = 1e-10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operation {
+    Union,
+    Intersection,
+    Difference,
+}
+
+// position of boundary piece relative to the other polygon
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Location {
+    Inside,
+    Outside,
+    // lies on boundary of the other polygon, with the same or opposite direction
+    SameEdge,
+    OppositeEdge,
+}
+
+// exact key of point, negative zero normalized
+fn key(p: Vec2) -> (u64, u64) {
+    ((p.x + 0.0).to_bits(), (p.y + 0.0).to_bits())
+}
+
+// counterclockwise copy of vertices
+fn counterclockwise(polygon: &Polygon) -> Vec<Vec2> {
+    let mut vertices = polygon.vertices.clone();
+    if polygon.signed_area() < 0.0 {
+        vertices.reverse();
+    }
+    vertices
+}
+
+// split points of every edge, as parameters along edge and points
+type Splits = Vec<Vec<(f64, Vec2)>>;
+
+// parameters along edges of `a` where they are split by edges of `b` and
+// vice versa, with exact points shared by both polygons
+fn split_points(a: &[Vec2], b: &[Vec2], eps: f64) -> (Splits, Splits) {
+    let mut sa = vec![Vec::new(); a.len()];
+    let mut sb = vec![Vec::new(); b.len()];
+    for i in 0..a.len() {
+        let (p, p2) = (a[i], a[(i + 1) % a.len()]);
+        let r = p2 - p;
+        for j in 0..b.len() {
+            let (q, q2) = (b[j], b[(j + 1) % b.len()]);
+            let s = q2 - q;
+            let denom = r.area(s);
+            let (rl, sl) = (r.len(), s.len());
+            if rl == 0.0 || sl == 0.0 {
+                continue;
+            }
+            if denom.abs() > eps * rl.max(sl) {
+                let t = (q - p).area(s) / denom;
+                let u = (q - p).area(r) / denom;
+                let (te, ue) = (eps / rl, eps / sl);
+                if t < -te || t > 1.0 + te || u < -ue || u > 1.0 + ue {
+                    continue;
+                }
+                // snap to existing vertices, so shared points are exact
+                let point = if u.abs() <= ue {
+                    q
+                } else if (u - 1.0).abs() <= ue {
+                    q2
+                } else if t.abs() <= te {
+                    p
+                } else if (t - 1.0).abs() <= te {
+                    p2
+                } else {
+                    p + r * t
+                };
+                sa[i].push((r.dot(point - p) / (rl * rl), point));
+                sb[j].push((s.dot(point - q) / (sl * sl), point));
+            } else if r.area(q - p).abs() <= eps * rl {
+                // collinear edges, endpoints of each split the other
+                for &point in &[q, q2] {
+                    let t = r.dot(point - p) / (rl * rl);
+                    if t > 0.0 && t < 1.0 {
+                        sa[i].push((t, point));
+                    }
+                }
+                for &point in &[p, p2] {
+                    let u = s.dot(point - q) / (sl * sl);
+                    if u > 0.0 && u < 1.0 {
+                        sb[j].push((u, point));
+                    }
+                }
+            }
+        }
+    }
+    (sa, sb)
+}
+
+// pieces of ring boundary after splitting, as directed segments
+fn pieces(ring: &[Vec2], splits: Splits) -> Vec<(Vec2, Vec2)> {
+    let mut result = Vec::new();
+    for (i, mut split) in splits.into_iter().enumerate() {
+        let (p, p2) = (ring[i], ring[(i + 1) % ring.len()]);
+        split.push((0.0, p));
+        split.push((1.0, p2));
+        split.sort_by(|x, y| x.0.total_cmp(&y.0));
+        for w in split.windows(2) {
+            if key(w[0].1) != key(w[1].1) {
+                result.push((w[0].1, w[1].1));
+            }
+        }
+    }
+    result
+}
+
+// location of directed piece relative to ring
+fn locate(piece: (Vec2, Vec2), ring: &[Vec2], eps: f64) -> Location {
+    let (a, b) = piece;
+    let mid = (a + b) * 0.5;
+    let dir = b - a;
+    for i in 0..ring.len() {
+        let (q, q2) = (ring[i], ring[(i + 1) % ring.len()]);
+        let s = q2 - q;
+        let len2 = s.dot(s);
+        if len2 == 0.0 {
+            continue;
+        }
+        let t = s.dot(mid - q) / len2;
+        if (0.0..=1.0).contains(&t) && (q + s * t - mid).len() <= eps {
+            return if s.dot(dir) > 0.0 { Location::SameEdge } else { Location::OppositeEdge };
+        }
+    }
+    if winding_number(mid, ring) != 0 { Location::Inside } else { Location::Outside }
+}
+
+// links directed pieces into closed rings
+fn link(pieces: Vec<(Vec2, Vec2)>) -> Vec<Polygon> {
+    let mut outgoing: HashMap<(u64, u64), Vec<usize>> = HashMap::new();
+    for (i, &(a, _)) in pieces.iter().enumerate() {
+        outgoing.entry(key(a)).or_default().push(i);
+    }
+    let mut used = vec![false; pieces.len()];
+    let mut result = Vec::new();
+    for start in 0..pieces.len() {
+        if used[start] {
+            continue;
+        }
+        used[start] = true;
+        let mut ring = vec![pieces[start].0];
+        let mut current = start;
+        let closed = loop {
+            let (a, b) = pieces[current];
+            if key(b) == key(pieces[start].0) {
+                break true;
+            }
+            ring.push(b);
+            // sharpest right turn keeps rings touching at vertex apart
+            let incoming = b - a;
+            let next = outgoing.get(&key(b)).and_then(|candidates| {
+                candidates.iter()
+                    .cloned()
+                    .filter(|&j| !used[j])
+                    .min_by(|&x, &y| turn(incoming, pieces[x]).total_cmp(&turn(incoming, pieces[y])))
+            });
+            match next {
+                Some(j) => {
+                    used[j] = true;
+                    current = j;
+                }
+                None => break false,
+            }
+        };
+        if closed {
+            let ring = simplify(ring);
+            if ring.len() >= 3 {
+                result.push(Polygon::new(ring));
+            }
+        }
+    }
+    result
+}
+
+// counterclockwise turn angle from incoming direction to outgoing piece
+fn turn(incoming: Vec2, piece: (Vec2, Vec2)) -> f64 {
+    let out = piece.1 - piece.0;
+    incoming.area(out).atan2(incoming.dot(out))
+}
+
+// removes vertices lying on straight line between neighbours
+fn simplify(ring: Vec<Vec2>) -> Vec<Vec2> {
+    let n = ring.len();
+    (0..n)
+        .filter(|&i| {
+            let (prev, p, next) = (ring[(i + n - 1) % n], ring[i], ring[(i + 1) % n]);
+            let (u, v) = (p - prev, next - p);
+            u.area(v).abs() > RELATIVE_EPS * u.len() * v.len() || u.dot(v) < 0.0
+        })
+        .map(|i| ring[i])
+        .collect()
+}
+
+fn overlay(subject: &Polygon, clip: &Polygon, operation: Operation) -> Vec<Polygon> {
+    let a = counterclockwise(subject);
+    let b = counterclockwise(clip);
+    let scale = a.iter().chain(b.iter()).fold(0.0f64, |m, p| m.max(p.x.abs()).max(p.y.abs()));
+    let eps = RELATIVE_EPS * scale.max(f64::MIN_POSITIVE);
+    let (sa, sb) = split_points(&a, &b, eps);
+    let mut selected = Vec::new();
+    for piece in pieces(&a, sa) {
+        let keep = matches!((operation, locate(piece, &b, eps)),
+            (Operation::Union, Location::Outside) |
+            (Operation::Union, Location::SameEdge) |
+            (Operation::Intersection, Location::Inside) |
+            (Operation::Intersection, Location::SameEdge) |
+            (Operation::Difference, Location::Outside) |
+            (Operation::Difference, Location::OppositeEdge));
+        if keep {
+            selected.push(piece);
+        }
+    }
+    // pieces of `b` on boundary of `a` are already taken from `a`
+    for piece in pieces(&b, sb) {
+        match (operation, locate(piece, &a, eps)) {
+            (Operation::Union, Location::Outside) => selected.push(piece),
+            (Operation::Intersection, Location::Inside) => selected.push(piece),
+            (Operation::Difference, Location::Inside) => selected.push((piece.1, piece.0)),
+            _ => {}
+        }
+    }
+    link(selected)
+}
+
+impl Polygon {
+    /// Union of simple polygons
+    ///
+    /// Result may consist of several polygons: counterclockwise outer
+    /// boundaries and clockwise holes. Polygons of any orientation are
+    /// accepted; degenerate pieces of zero area are dropped.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Polygon, Vec2};
+    /// let a = Polygon::new(vec![Vec2::new(0, 0), Vec2::new(2, 0), Vec2::new(2, 2), Vec2::new(0, 2)]);
+    /// let b = Polygon::new(vec![Vec2::new(1, 1), Vec2::new(3, 1), Vec2::new(3, 3), Vec2::new(1, 3)]);
+    /// let union = a.union(&b);
+    /// assert_eq!(union.len(), 1);
+    /// assert!((union[0].area() - 7.0).abs() < 1e-12);
+    /// assert_eq!(union[0].vertices.len(), 8);
+    /// ```
+    pub fn union(&self, other: &Polygon) -> Vec<Polygon> {
+        overlay(self, other, Operation::Union)
+    }
+    /// Intersection of simple polygons
+    ///
+    /// Result may consist of several counterclockwise polygons.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Polygon, Vec2};
+    /// // U-shape intersected with horizontal bar gives two pieces
+    /// let u = Polygon::new(vec![Vec2::new(0, 0), Vec2::new(3, 0), Vec2::new(3, 3), Vec2::new(2, 3),
+    ///                           Vec2::new(2, 1), Vec2::new(1, 1), Vec2::new(1, 3), Vec2::new(0, 3)]);
+    /// let bar = Polygon::new(vec![Vec2::new(-1, 2), Vec2::new(4, 2), Vec2::new(4, 4), Vec2::new(-1, 4)]);
+    /// let pieces = u.intersection(&bar);
+    /// assert_eq!(pieces.len(), 2);
+    /// assert!(pieces.iter().all(|p| (p.area() - 1.0).abs() < 1e-12));
+    /// ```
+    pub fn intersection(&self, other: &Polygon) -> Vec<Polygon> {
+        overlay(self, other, Operation::Intersection)
+    }
+    /// Difference of simple polygons, part of `self` outside of `other`
+    ///
+    /// Result may consist of several polygons: counterclockwise outer
+    /// boundaries and clockwise holes.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Polygon, Vec2};
+    /// let a = Polygon::new(vec![Vec2::new(0, 0), Vec2::new(4, 0), Vec2::new(4, 4), Vec2::new(0, 4)]);
+    /// let b = Polygon::new(vec![Vec2::new(1, 1), Vec2::new(2, 1), Vec2::new(2, 2), Vec2::new(1, 2)]);
+    /// let diff = a.difference(&b);
+    /// // outer square and clockwise hole
+    /// assert_eq!(diff.len(), 2);
+    /// let area: f64 = diff.iter().map(|p| p.signed_area()).sum();
+    /// assert!((area - 15.0).abs() < 1e-12);
+    /// ```
+    pub fn difference(&self, other: &Polygon) -> Vec<Polygon> {
+        overlay(self, other, Operation::Difference)
+    }
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    fn square(x: f64, y: f64, size: f64) -> Polygon {
+        Polygon::new(vec![Vec2::new(x, y), Vec2::new(x + size, y),
+                          Vec2::new(x + size, y + size), Vec2::new(x, y + size)])
+    }
+
+    fn total_area(polygons: &[Polygon]) -> f64 {
+        polygons.iter().map(Polygon::signed_area).sum()
+    }
+
+    #[test]
+    fn boolean_areas_consistent() {
+        let a = Polygon::new(vec![Vec2::new(0, 0), Vec2::new(5, 1), Vec2::new(4, 5),
+                                  Vec2::new(2, 2), Vec2::new(0, 4)]);
+        let b = Polygon::new(vec![Vec2::new(1, -1), Vec2::new(6, 3), Vec2::new(1, 6)]);
+        let union = total_area(&a.union(&b));
+        let inter = total_area(&a.intersection(&b));
+        let diff_ab = total_area(&a.difference(&b));
+        let diff_ba = total_area(&b.difference(&a));
+        assert!((union - (a.area() + b.area() - inter)).abs() < 1e-9);
+        assert!((diff_ab - (a.area() - inter)).abs() < 1e-9);
+        assert!((diff_ba - (b.area() - inter)).abs() < 1e-9);
+        assert!(inter > 0.0);
+    }
+
+    #[test]
+    fn boolean_shared_edges() {
+        // squares sharing an edge merge into rectangle
+        let a = square(0.0, 0.0, 1.0);
+        let b = square(1.0, 0.0, 1.0);
+        let union = a.union(&b);
+        assert_eq!(union.len(), 1);
+        assert_eq!(union[0].vertices.len(), 4);
+        assert!((union[0].area() - 2.0).abs() < 1e-12);
+        assert!(a.intersection(&b).is_empty());
+        assert_eq!(a.difference(&b), vec![a.clone()]);
+        // identical polygons
+        let same = a.intersection(&a);
+        assert_eq!(same.len(), 1);
+        assert!((same[0].area() - 1.0).abs() < 1e-12);
+        assert!(a.difference(&a).is_empty());
+        // clockwise input gives the same result
+        let reversed = Polygon::new(b.vertices.iter().rev().cloned().collect());
+        assert!((total_area(&a.union(&reversed)) - 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn boolean_disjoint_and_nested() {
+        let a = square(0.0, 0.0, 1.0);
+        let far = square(5.0, 5.0, 1.0);
+        assert_eq!(a.union(&far).len(), 2);
+        assert!(a.intersection(&far).is_empty());
+        let big = square(-1.0, -1.0, 4.0);
+        assert_eq!(a.intersection(&big), vec![a.clone()]);
+        assert_eq!(a.union(&big), vec![big.clone()]);
+        assert!(a.difference(&big).is_empty());
+        // squares touching at corner stay separate
+        let corner = square(1.0, 1.0, 1.0);
+        let union = a.union(&corner);
+        assert!((total_area(&union) - 2.0).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,109 @@
+//! Circles on a plane.
+use vec2::Vec2;
+
+/// Circle, defined by center and radius
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Circle {
+    /// center of circle
+    pub center: Vec2,
+    /// radius of circle
+    pub radius: f64,
+}
+
+impl Circle {
+    /// Constructs a new `Circle`
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Circle, Vec2};
+    /// let c = Circle::new(Vec2::new(1, 2), 3);
+    /// assert_eq!(c.radius, 3.0);
+    /// ```
+    pub fn new<I: Into<f64>>(center: Vec2, radius: I) -> Circle {
+        Circle { center, radius: radius.into() }
+    }
+    /// Smallest circle having two points as diameter ends
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Circle, Vec2};
+    /// let c = Circle::from_diameter(Vec2::new(0, 0), Vec2::new(4, 0));
+    /// assert_eq!(c, Circle::new(Vec2::new(2, 0), 2));
+    /// ```
+    pub fn from_diameter(a: Vec2, b: Vec2) -> Circle {
+        let center = (a + b) * 0.5;
+        Circle::new(center, (a - center).len())
+    }
+    /// Circumscribed circle of three points
+    ///
+    /// Returns `None` if points are collinear.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Circle, Vec2};
+    /// let c = Circle::through(Vec2::new(2, 0), Vec2::new(0, 2), Vec2::new(-2, 0)).unwrap();
+    /// assert!((c.center - Vec2::zero()).len() < 1e-12);
+    /// assert!((c.radius - 2.0).abs() < 1e-12);
+    /// assert!(Circle::through(Vec2::new(0, 0), Vec2::new(1, 1), Vec2::new(2, 2)).is_none());
+    /// ```
+    pub fn through(a: Vec2, b: Vec2, c: Vec2) -> Option<Circle> {
+        let (ab, ac) = (b - a, c - a);
+        let d = 2.0 * ab.area(ac);
+        if d == 0.0 {
+            return None;
+        }
+        let (ab2, ac2) = (ab.dot(ab), ac.dot(ac));
+        let offset = Vec2::new(ac.y * ab2 - ab.y * ac2, ab.x * ac2 - ac.x * ab2) / d;
+        Some(Circle::new(a + offset, offset.len()))
+    }
+    /// Checks if point lies inside circle or on its boundary
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Circle, Vec2};
+    /// let c = Circle::new(Vec2::new(0, 0), 2);
+    /// assert!(c.contains(Vec2::new(1, 1)));
+    /// assert!(c.contains(Vec2::new(0, 2)));
+    /// assert!(!c.contains(Vec2::new(2, 2)));
+    /// ```
+    pub fn contains(self, point: Vec2) -> bool {
+        let d = point - self.center;
+        d.dot(d) <= self.radius * self.radius
+    }
+    /// Area of circle
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Circle, Vec2};
+    /// let c = Circle::new(Vec2::zero(), 2);
+    /// assert_eq!(c.area(), 4.0 * std::f64::consts::PI);
+    /// ```
+    pub fn area(self) -> f64 {
+        std::f64::consts::PI * self.radius * self.radius
+    }
+    /// Length of circle boundary
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Circle, Vec2};
+    /// let c = Circle::new(Vec2::zero(), 2);
+    /// assert_eq!(c.circumference(), 4.0 * std::f64::consts::PI);
+    /// ```
+    pub fn circumference(self) -> f64 {
+        2.0 * std::f64::consts::PI * self.radius
+    }
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    #[test]
+    fn circle_through() {
+        let (a, b, c) = (Vec2::new(1, 7), Vec2::new(-3, 2), Vec2::new(5, -1));
+        let circle = Circle::through(a, b, c).unwrap();
+        for &p in &[a, b, c] {
+            assert!(((p - circle.center).len() - circle.radius).abs() < 1e-12);
+        }
+    }
+}
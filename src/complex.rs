@@ -0,0 +1,57 @@
+//! Conversions with complex numbers, available with `complex` feature.
+//!
+//! Vector $(x, y)$ corresponds to complex number $x + iy$.
+use num_complex::Complex;
+use vec2::Vec2;
+
+impl From<Complex<f64>> for Vec2 {
+    /// Vector of real and imaginary parts
+    ///
+    /// # Example
+    /// ```
+    /// # extern crate num_complex;
+    /// # extern crate linal;
+    /// # use linal::Vec2;
+    /// # use num_complex::Complex;
+    /// # fn main() {
+    /// assert_eq!(Vec2::from(Complex::new(1.0, -2.0)), Vec2::new(1, -2));
+    /// # }
+    /// ```
+    fn from(z: Complex<f64>) -> Vec2 {
+        Vec2::new(z.re, z.im)
+    }
+}
+
+impl From<Vec2> for Complex<f64> {
+    /// Complex number $x + iy$
+    ///
+    /// # Example
+    /// ```
+    /// # extern crate num_complex;
+    /// # extern crate linal;
+    /// # use linal::Vec2;
+    /// # use num_complex::Complex;
+    /// # fn main() {
+    /// let z: Complex<f64> = Vec2::new(3, 4).into();
+    /// assert_eq!(z.norm(), 5.0);
+    /// # }
+    /// ```
+    fn from(v: Vec2) -> Complex<f64> {
+        Complex::new(v.x, v.y)
+    }
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    #[test]
+    fn complex_mul_matches_num_complex() {
+        let a = Vec2::new(1.5, -2.0);
+        let b = Vec2::new(-0.5, 3.0);
+        let z = Complex::from(a) * Complex::from(b);
+        assert_eq!(Vec2::from(z), a.complex_mul(b));
+        let rotated = a.complex_mul(Vec2::from_polar(1.0, 0.3));
+        assert!((rotated.len() - a.len()).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,164 @@
+//! Point containment tests for planar shapes.
+//!
+//! All tests treat points on the boundary as contained.
+use vec2::Vec2;
+use segment2::Segment2;
+use triangle2::Triangle2;
+
+/// Checks if point lies inside triangle
+///
+/// # Example
+/// ```
+/// # use linal::Vec2;
+/// # use linal::containment::point_in_triangle;
+/// let (a, b, c) = (Vec2::new(0, 0), Vec2::new(4, 0), Vec2::new(0, 4));
+/// assert!(point_in_triangle(Vec2::new(1, 1), a, b, c));
+/// assert!(!point_in_triangle(Vec2::new(3, 3), a, b, c));
+/// ```
+pub fn point_in_triangle(point: Vec2, a: Vec2, b: Vec2, c: Vec2) -> bool {
+    Triangle2::new(a, b, c).contains(point)
+}
+
+/// Checks if point lies inside convex polygon
+///
+/// Vertices of polygon must go in counterclockwise order. Test takes
+/// $O(\log n)$ time using binary search over the fan of triangles from the
+/// first vertex. Returns `false` for polygons with less than three vertices.
+///
+/// # Example
+/// ```
+/// # use linal::Vec2;
+/// # use linal::containment::point_in_convex_polygon;
+/// let square = [Vec2::new(0, 0), Vec2::new(2, 0), Vec2::new(2, 2), Vec2::new(0, 2)];
+/// assert!(point_in_convex_polygon(Vec2::new(1, 1), &square));
+/// assert!(point_in_convex_polygon(Vec2::new(2, 1), &square));
+/// assert!(!point_in_convex_polygon(Vec2::new(3, 1), &square));
+/// ```
+pub fn point_in_convex_polygon(point: Vec2, polygon: &[Vec2]) -> bool {
+    let n = polygon.len();
+    if n < 3 {
+        return false;
+    }
+    let origin = polygon[0];
+    let p = point - origin;
+    if (polygon[1] - origin).area(p) < 0.0 || (polygon[n - 1] - origin).area(p) > 0.0 {
+        return false;
+    }
+    // find wedge `polygon[lo], polygon[lo + 1]` containing point
+    let (mut lo, mut hi) = (1, n - 1);
+    while hi - lo > 1 {
+        let mid = (lo + hi) / 2;
+        if (polygon[mid] - origin).area(p) >= 0.0 {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    (polygon[lo + 1] - polygon[lo]).area(point - polygon[lo]) >= 0.0
+}
+
+/// Winding number of polygon around point
+///
+/// Counts how many times polygon winds counterclockwise around point,
+/// clockwise turns are counted negative. Result is unspecified for points on
+/// the boundary.
+///
+/// # Example
+/// ```
+/// # use linal::Vec2;
+/// # use linal::containment::winding_number;
+/// let square = [Vec2::new(0, 0), Vec2::new(2, 0), Vec2::new(2, 2), Vec2::new(0, 2)];
+/// assert_eq!(winding_number(Vec2::new(1, 1), &square), 1);
+/// let reversed: Vec<Vec2> = square.iter().rev().cloned().collect();
+/// assert_eq!(winding_number(Vec2::new(1, 1), &reversed), -1);
+/// assert_eq!(winding_number(Vec2::new(3, 1), &square), 0);
+/// ```
+pub fn winding_number(point: Vec2, polygon: &[Vec2]) -> i32 {
+    let mut winding = 0;
+    for (i, &a) in polygon.iter().enumerate() {
+        let b = polygon[(i + 1) % polygon.len()];
+        let side = (b - a).area(point - a);
+        if a.y <= point.y {
+            if b.y > point.y && side > 0.0 {
+                winding += 1;
+            }
+        } else if b.y <= point.y && side < 0.0 {
+            winding -= 1;
+        }
+    }
+    winding
+}
+
+/// Checks if point lies inside arbitrary polygon
+///
+/// Uses non-zero winding rule, so self-intersecting polygons are handled as
+/// union of regions they wind around.
+///
+/// # Example
+/// ```
+/// # use linal::Vec2;
+/// # use linal::containment::point_in_polygon;
+/// // non-convex "L" shape
+/// let shape = [Vec2::new(0, 0), Vec2::new(3, 0), Vec2::new(3, 1),
+///              Vec2::new(1, 1), Vec2::new(1, 3), Vec2::new(0, 3)];
+/// assert!(point_in_polygon(Vec2::new(0.5, 2.5), &shape));
+/// assert!(point_in_polygon(Vec2::new(1, 2), &shape));
+/// assert!(!point_in_polygon(Vec2::new(2, 2), &shape));
+/// ```
+pub fn point_in_polygon(point: Vec2, polygon: &[Vec2]) -> bool {
+    let on_boundary = polygon.iter().enumerate().any(|(i, &a)| {
+        let b = polygon[(i + 1) % polygon.len()];
+        Segment2::new(a, b).distance_to(point) == 0.0
+    });
+    on_boundary || winding_number(point, polygon) != 0
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    fn hexagon() -> Vec<Vec2> {
+        (0..6).map(|i| Vec2::from_polar(2.0, i as f64 * std::f64::consts::PI / 3.0)).collect()
+    }
+
+    #[test]
+    fn convex_polygon_matches_general() {
+        let hex = hexagon();
+        for i in -30..30 {
+            for j in -30..30 {
+                let p = Vec2::new(i, j) * 0.1;
+                assert_eq!(point_in_convex_polygon(p, &hex), point_in_polygon(p, &hex),
+                           "point {}", p);
+            }
+        }
+    }
+
+    #[test]
+    fn convex_polygon_degenerate() {
+        assert!(!point_in_convex_polygon(Vec2::zero(), &[]));
+        assert!(!point_in_convex_polygon(Vec2::zero(), &[Vec2::zero(), Vec2::new(1, 0)]));
+        let tri = [Vec2::new(0, 0), Vec2::new(2, 0), Vec2::new(0, 2)];
+        assert!(point_in_convex_polygon(Vec2::new(0, 0), &tri));
+        assert!(point_in_convex_polygon(Vec2::new(1, 1), &tri));
+        assert!(!point_in_convex_polygon(Vec2::new(-1, 1), &tri));
+    }
+
+    #[test]
+    fn polygon_self_intersecting() {
+        // pentagram, center is wound twice
+        let star: Vec<Vec2> = (0..5)
+            .map(|i| Vec2::from_polar(2.0, i as f64 * 4.0 * std::f64::consts::PI / 5.0))
+            .collect();
+        assert_eq!(winding_number(Vec2::zero(), &star), 2);
+        assert!(point_in_polygon(Vec2::zero(), &star));
+        assert!(!point_in_polygon(Vec2::new(3, 0), &star));
+    }
+
+    #[test]
+    fn polygon_boundary() {
+        let square = [Vec2::new(0, 0), Vec2::new(2, 0), Vec2::new(2, 2), Vec2::new(0, 2)];
+        assert!(point_in_polygon(Vec2::new(2, 2), &square));
+        assert!(point_in_polygon(Vec2::new(0, 1), &square));
+        assert!(point_in_polygon(Vec2::new(1, 2), &square));
+    }
+}
@@ -0,0 +1,139 @@
+//! Iso-lines of scalar fields.
+use std::collections::{BTreeMap, BTreeSet};
+use field::Field2;
+use polyline::Polyline;
+use vec2::Vec2;
+
+// edge between nodes of field: horizontal from (i, j) to (i + 1, j) or
+// vertical from (i, j) to (i, j + 1)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Edge {
+    Horizontal(usize, usize),
+    Vertical(usize, usize),
+}
+
+// point of edge where linear interpolation of field equals iso
+fn crossing(field: &Field2<f64>, edge: Edge, iso: f64) -> Vec2 {
+    let (a, b) = match edge {
+        Edge::Horizontal(i, j) => ((i, j), (i + 1, j)),
+        Edge::Vertical(i, j) => ((i, j), (i, j + 1)),
+    };
+    let (va, vb) = (field[a], field[b]);
+    let t = (iso - va) / (vb - va);
+    let (pa, pb) = (field.node(a.0, a.1), field.node(b.0, b.1));
+    pa + (pb - pa) * t
+}
+
+/// Iso-lines of field at level `iso` by marching squares
+///
+/// Crossings of level are found by linear interpolation along edges between
+/// nodes, ambiguous saddle cells are resolved by average of their corners.
+/// Lines are oriented so that values above `iso` lie on the left; closed
+/// lines end with their first vertex, open ones start and end on border of
+/// field. Nodes equal to `iso` are treated as lying above it, cells with
+/// NaN at any corner are skipped.
+///
+/// # Example
+/// ```
+/// # use linal::{Rect, Vec2};
+/// # use linal::field::Field2;
+/// # use linal::contour::marching_squares;
+/// let bounds = Rect::new(Vec2::new(-2, -2), Vec2::new(2, 2));
+/// let field = Field2::from_fn(bounds, 41, 41, |p| p.len());
+/// let lines = marching_squares(&field, 1.0);
+/// assert_eq!(lines.len(), 1);
+/// let circle = &lines[0].vertices;
+/// assert_eq!(circle.first(), circle.last());
+/// assert!(circle.iter().all(|p| (p.len() - 1.0).abs() < 0.01));
+/// ```
+pub fn marching_squares(field: &Field2<f64>, iso: f64) -> Vec<Polyline> {
+    let (width, height) = field.dims();
+    // segments of lines, from edge to edge
+    let mut next: BTreeMap<Edge, Edge> = BTreeMap::new();
+    for j in 0..height.saturating_sub(1) {
+        for i in 0..width.saturating_sub(1) {
+            // corners and sides of cell in counterclockwise order
+            let corners = [(i, j), (i + 1, j), (i + 1, j + 1), (i, j + 1)];
+            let edges = [Edge::Horizontal(i, j), Edge::Vertical(i + 1, j),
+                         Edge::Horizontal(i, j + 1), Edge::Vertical(i, j)];
+            let values: Vec<f64> = corners.iter().map(|&c| field[c]).collect();
+            if values.iter().any(|v| v.is_nan()) {
+                continue;
+            }
+            let above: Vec<bool> = values.iter().map(|&v| v >= iso).collect();
+            // sides crossed by level, with flag of leaving region above it
+            let crossed: Vec<(Edge, bool)> = (0..4)
+                .filter(|&k| above[k] != above[(k + 1) % 4])
+                .map(|k| (edges[k], above[k]))
+                .collect();
+            let n = crossed.len();
+            let center_above = values.iter().sum::<f64>() / 4.0 >= iso;
+            for (k, &(edge, leaving)) in crossed.iter().enumerate() {
+                if !leaving {
+                    continue;
+                }
+                // in saddle with low center regions above level are separated
+                let partner = if n == 4 && !center_above { (k + n - 1) % n } else { (k + 1) % n };
+                next.insert(edge, crossed[partner].0);
+            }
+        }
+    }
+    let mut lines = Vec::new();
+    let ends: BTreeSet<Edge> = next.values().cloned().collect();
+    let mut starts: Vec<Edge> = next.keys().filter(|e| !ends.contains(e)).cloned().collect();
+    // open lines first, then closed loops from whatever remains
+    while let Some(start) = starts.pop().or_else(|| next.keys().next().cloned()) {
+        let mut vertices = vec![crossing(field, start, iso)];
+        let mut edge = start;
+        while let Some(e) = next.remove(&edge) {
+            vertices.push(crossing(field, e, iso));
+            edge = e;
+        }
+        lines.push(Polyline::new(vertices));
+    }
+    lines
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+    use rect::Rect;
+
+    #[test]
+    fn marching_squares_open_lines() {
+        let bounds = Rect::new(Vec2::new(0, 0), Vec2::new(3, 3));
+        // plane rising along x, level crosses grid vertically
+        let field = Field2::from_fn(bounds, 4, 4, |p| p.x);
+        let lines = marching_squares(&field, 1.5);
+        assert_eq!(lines.len(), 1);
+        let v = &lines[0].vertices;
+        assert_eq!(v.len(), 4);
+        assert!(v.iter().all(|p| (p.x - 1.5).abs() < 1e-12));
+        // values above level lie on the left: line goes down
+        assert_eq!(v[0].y, 3.0);
+        assert_eq!(v[3].y, 0.0);
+        assert!(marching_squares(&field, 5.0).is_empty());
+    }
+
+    #[test]
+    fn marching_squares_saddle() {
+        let bounds = Rect::new(Vec2::new(-1, -1), Vec2::new(1, 1));
+        let field = Field2::from_fn(bounds, 2, 2, |p| p.x * p.y);
+        // center equals average 0: regions above are connected
+        let lines = marching_squares(&field, -0.5);
+        assert_eq!(lines.len(), 2);
+        assert!(lines.iter().all(|l| l.vertices.len() == 2));
+        let lines = marching_squares(&field, 0.5);
+        assert_eq!(lines.len(), 2);
+        // two loops around peaks, separate
+        let bumps = Field2::from_fn(Rect::new(Vec2::new(-3, -2), Vec2::new(3, 2)), 61, 41, |p| {
+            (-(p - Vec2::new(-1.5, 0.0)).dot(p - Vec2::new(-1.5, 0.0))).exp()
+                + (-(p - Vec2::new(1.5, 0.0)).dot(p - Vec2::new(1.5, 0.0))).exp()
+        });
+        let loops = marching_squares(&bumps, 0.5);
+        assert_eq!(loops.len(), 2);
+        for l in &loops {
+            assert_eq!(l.vertices.first(), l.vertices.last());
+        }
+    }
+}
@@ -0,0 +1,239 @@
+//! Common interface for parametric curves and their arc-length parametrization.
+use vec2::Vec2;
+use vec3::Vec3;
+use polyline::Polyline;
+use arc::Arc;
+use bezier2::{QuadBezier2, CubicBezier2};
+use bezier3::{QuadBezier3, CubicBezier3};
+use spline2::{Hermite2, CatmullRom2};
+use spline3::{Hermite3, CatmullRom3};
+
+/// Point type of curve
+pub trait CurvePoint: Copy {
+    /// Euclidean distance between points
+    fn distance(self, other: Self) -> f64;
+}
+
+impl CurvePoint for Vec2 {
+    fn distance(self, other: Vec2) -> f64 {
+        (self - other).len()
+    }
+}
+
+impl CurvePoint for Vec3 {
+    fn distance(self, other: Vec3) -> f64 {
+        (self - other).len()
+    }
+}
+
+/// Parametric curve
+pub trait Curve {
+    /// type of curve points
+    type Point: CurvePoint;
+    /// Range of curve parameter
+    fn domain(&self) -> (f64, f64);
+    /// Point of curve at parameter `t`
+    fn point(&self, t: f64) -> Self::Point;
+}
+
+macro_rules! bezier_curve {
+    ($type:ident, $point:ident) => (
+        impl Curve for $type {
+            type Point = $point;
+            fn domain(&self) -> (f64, f64) {
+                (0.0, 1.0)
+            }
+            fn point(&self, t: f64) -> $point {
+                self.eval(t)
+            }
+        }
+    )
+}
+
+macro_rules! spline_curve {
+    ($type:ident, $point:ident) => (
+        impl Curve for $type {
+            type Point = $point;
+            fn domain(&self) -> (f64, f64) {
+                (0.0, (self.points.len() - 1) as f64)
+            }
+            fn point(&self, t: f64) -> $point {
+                self.eval(t)
+            }
+        }
+    )
+}
+
+bezier_curve!(QuadBezier2, Vec2);
+bezier_curve!(CubicBezier2, Vec2);
+bezier_curve!(QuadBezier3, Vec3);
+bezier_curve!(CubicBezier3, Vec3);
+spline_curve!(Hermite2, Vec2);
+spline_curve!(CatmullRom2, Vec2);
+spline_curve!(Hermite3, Vec3);
+spline_curve!(CatmullRom3, Vec3);
+
+/// Arc parameter is fraction of its sweep.
+impl Curve for Arc {
+    type Point = Vec2;
+    fn domain(&self) -> (f64, f64) {
+        (0.0, 1.0)
+    }
+    fn point(&self, t: f64) -> Vec2 {
+        self.point_at(t)
+    }
+}
+
+/// Polyline parameter runs over vertex indices, fractional part
+/// interpolates along the edge.
+///
+/// # Panics
+/// `point` panics for empty polyline.
+impl Curve for Polyline {
+    type Point = Vec2;
+    fn domain(&self) -> (f64, f64) {
+        (0.0, self.vertices.len().saturating_sub(1) as f64)
+    }
+    fn point(&self, t: f64) -> Vec2 {
+        let last = self.vertices.len() - 1;
+        let t = t.clamp(0.0, last as f64);
+        let i = (t.floor() as usize).min(last.saturating_sub(1));
+        match self.vertices.get(i + 1) {
+            Some(&b) => {
+                let a = self.vertices[i];
+                a + (b - a) * (t - i as f64)
+            }
+            None => self.vertices[i],
+        }
+    }
+}
+
+/// Arc-length parametrization of curve
+///
+/// Keeps table of curve lengths at uniformly spaced parameters and inverts
+/// it by binary search with linear interpolation, so points can be taken at
+/// given distance along curve.
+#[derive(Debug, Clone)]
+pub struct ArcLengthParam<C: Curve> {
+    /// parametrized curve
+    pub curve: C,
+    params: Vec<f64>,
+    lengths: Vec<f64>,
+}
+
+impl<C: Curve> ArcLengthParam<C> {
+    /// Builds length table from `samples` chords of curve
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{ArcLengthParam, QuadBezier2, Vec2};
+    /// let b = QuadBezier2::new(Vec2::new(0, 0), Vec2::new(1, 0), Vec2::new(4, 0));
+    /// let p = ArcLengthParam::new(b, 100);
+    /// assert!((p.length() - 4.0).abs() < 1e-12);
+    /// ```
+    pub fn new(curve: C, samples: usize) -> ArcLengthParam<C> {
+        let samples = samples.max(1);
+        let (start, end) = curve.domain();
+        let params: Vec<f64> = (0..=samples)
+            .map(|i| start + (end - start) * i as f64 / samples as f64)
+            .collect();
+        let mut lengths = Vec::with_capacity(params.len());
+        let mut total = 0.0;
+        let mut prev = curve.point(start);
+        for &t in &params {
+            let point = curve.point(t);
+            total += prev.distance(point);
+            lengths.push(total);
+            prev = point;
+        }
+        ArcLengthParam { curve, params, lengths }
+    }
+    /// Total length of curve
+    pub fn length(&self) -> f64 {
+        *self.lengths.last().unwrap()
+    }
+    /// Curve parameter at given arc length
+    ///
+    /// Arc length is clamped to $[0, L]$, where $L$ is curve length. NaN arc
+    /// length gives NaN parameter.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{ArcLengthParam, QuadBezier2, Vec2};
+    /// let b = QuadBezier2::new(Vec2::new(0, 0), Vec2::new(1, 0), Vec2::new(2, 0));
+    /// let p = ArcLengthParam::new(b, 10);
+    /// assert!((p.param_at_arclength(1) - 0.5).abs() < 1e-12);
+    /// ```
+    pub fn param_at_arclength<I: Into<f64>>(&self, s: I) -> f64 {
+        let s = s.into().clamp(0.0, self.length());
+        let i = match self.lengths.binary_search_by(|l| l.total_cmp(&s)) {
+            Ok(i) => return self.params[i],
+            Err(i) => i.clamp(1, self.lengths.len() - 1),
+        };
+        let (l0, l1) = (self.lengths[i - 1], self.lengths[i]);
+        let (t0, t1) = (self.params[i - 1], self.params[i]);
+        if l1 > l0 { t0 + (t1 - t0) * (s - l0) / (l1 - l0) } else { t0 }
+    }
+    /// Point of curve at given arc length
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{ArcLengthParam, CubicBezier2, Vec2};
+    /// // control points bunched at start make naive parametrization uneven
+    /// let b = CubicBezier2::new(Vec2::new(0, 0), Vec2::new(0, 0), Vec2::new(0, 0), Vec2::new(3, 0));
+    /// let p = ArcLengthParam::new(b, 1000);
+    /// assert!((p.point_at_arclength(1.5) - Vec2::new(1.5, 0.0)).len() < 1e-3);
+    /// ```
+    pub fn point_at_arclength<I: Into<f64>>(&self, s: I) -> C::Point {
+        self.curve.point(self.param_at_arclength(s))
+    }
+    /// Samples `n` points evenly spaced along curve
+    ///
+    /// First and last samples are curve ends.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{ArcLengthParam, Polyline, Vec2};
+    /// let line = Polyline::new(vec![Vec2::new(0, 0), Vec2::new(1, 0), Vec2::new(4, 0)]);
+    /// let p = ArcLengthParam::new(line, 8);
+    /// let points = p.sample(5);
+    /// assert_eq!(points[1], Vec2::new(1, 0));
+    /// assert_eq!(points[3], Vec2::new(3, 0));
+    /// ```
+    pub fn sample(&self, n: usize) -> Vec<C::Point> {
+        if n < 2 {
+            return (0..n).map(|_| self.point_at_arclength(0)).collect();
+        }
+        let step = self.length() / (n - 1) as f64;
+        (0..n).map(|i| self.point_at_arclength(i as f64 * step)).collect()
+    }
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    #[test]
+    fn arc_length_constant_speed() {
+        let b = CubicBezier2::new(Vec2::new(0, 0), Vec2::new(0, 3), Vec2::new(4, 4), Vec2::new(5, 0));
+        let p = ArcLengthParam::new(b, 2000);
+        assert!((p.length() - b.arc_length(1e-9)).abs() < 1e-4);
+        let points = p.sample(50);
+        let step = p.length() / 49.0;
+        for w in points.windows(2) {
+            assert!((w[1] - w[0]).len() <= step + 1e-9);
+            assert!((w[1] - w[0]).len() > 0.99 * step);
+        }
+    }
+
+    #[test]
+    fn arc_length_spline3() {
+        let s = CatmullRom3::new(vec![Vec3::new(0, 0, 0), Vec3::new(1, 0, 0), Vec3::new(2, 0, 0)]);
+        let p = ArcLengthParam::new(s, 50);
+        assert!((p.length() - 2.0).abs() < 1e-12);
+        assert!((p.point_at_arclength(0.5) - Vec3::new(0.5, 0.0, 0.0)).len() < 1e-12);
+        assert_eq!(p.param_at_arclength(-1), 0.0);
+        assert_eq!(p.param_at_arclength(5), 2.0);
+        assert!(p.param_at_arclength(f64::NAN).is_nan());
+    }
+}
@@ -0,0 +1,82 @@
+//! Merging of coincident and nearly coincident points.
+use spatial::SpatialHash;
+use vec2::Vec2;
+
+/// Unique points and index of unique point for every input point
+///
+/// Points are scanned in order; point within distance `eps` from already
+/// kept one is merged into the nearest such point, otherwise it is kept.
+/// Kept points are first occurrences, not averages, so result depends on
+/// order of input, and chains of points with steps below `eps` are not
+/// collapsed into one. With zero `eps` only exact duplicates are merged.
+///
+/// # Panics
+/// Panics if `eps` is negative, infinite or NaN.
+///
+/// # Example
+/// ```
+/// # use linal::Vec2;
+/// # use linal::dedup::merge_close_points;
+/// let points = [Vec2::new(0, 0), Vec2::new(1, 0), Vec2::new(0.001, 0.0), Vec2::new(1.0, 1e-4)];
+/// let (unique, remap) = merge_close_points(&points, 0.01);
+/// assert_eq!(unique, vec![Vec2::new(0, 0), Vec2::new(1, 0)]);
+/// assert_eq!(remap, vec![0, 1, 0, 1]);
+/// ```
+pub fn merge_close_points(points: &[Vec2], eps: f64) -> (Vec<Vec2>, Vec<usize>) {
+    assert!(eps >= 0.0 && eps.is_finite(), "tolerance must be non-negative");
+    let mut grid = SpatialHash::new(if eps > 0.0 { eps } else { 1.0 });
+    let mut unique: Vec<Vec2> = Vec::new();
+    let remap = points.iter().map(|&p| {
+        // only cells around point are visited, unlike k-nearest search
+        let nearest = grid.query_radius(p, eps).into_iter()
+            .min_by(|&a, &b| (unique[a] - p).len().total_cmp(&(unique[b] - p).len()));
+        match nearest {
+            Some(id) => id,
+            None => {
+                grid.insert(unique.len(), p);
+                unique.push(p);
+                unique.len() - 1
+            }
+        }
+    }).collect();
+    (unique, remap)
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    #[test]
+    fn merge_close_points_welds() {
+        // grid of points, each duplicated with tiny offsets
+        let mut points = Vec::new();
+        for k in 0..3 {
+            for i in 0..10 {
+                let p = Vec2::new((i % 5) as f64, (i / 5) as f64) - Vec2::new(2.5, 0.5);
+                points.push(p + Vec2::new(1e-9 * k as f64, -1e-9 * k as f64));
+            }
+        }
+        let (unique, remap) = merge_close_points(&points, 1e-6);
+        assert_eq!(unique.len(), 10);
+        assert_eq!(&unique[..], &points[..10]);
+        for (i, &j) in remap.iter().enumerate() {
+            assert_eq!(j, i % 10);
+            assert!((unique[j] - points[i]).len() <= 1e-6);
+        }
+        // without tolerance offset copies stay, exact ones are merged
+        points.extend_from_slice(&unique);
+        let (exact, remap) = merge_close_points(&points, 0.0);
+        assert_eq!(exact.len(), 30);
+        assert_eq!(&remap[30..], &remap[..10]);
+    }
+
+    #[test]
+    fn merge_close_points_scales() {
+        // well separated points on a large grid are all kept
+        let n = 40_000;
+        let points: Vec<Vec2> = (0..n).map(|i| Vec2::new((i % 200) as f64, (i / 200) as f64)).collect();
+        let (unique, remap) = merge_close_points(&points, 1e-6);
+        assert_eq!(unique.len(), n);
+        assert!(remap.iter().enumerate().all(|(i, &j)| i == j));
+    }
+}
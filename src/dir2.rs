@@ -0,0 +1,139 @@
+//! Unit vectors on a plane.
+use std::ops::{Deref, Neg};
+use vec2::Vec2;
+
+/// Unit vector on a plane
+///
+/// Unit length is guaranteed by construction and preserved by all methods,
+/// underlying vector is available through `Deref`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Dir2(Vec2);
+
+impl Dir2 {
+    /// Direction of x axis
+    pub const X: Dir2 = Dir2(Vec2 { x: 1.0, y: 0.0 });
+    /// Direction of y axis
+    pub const Y: Dir2 = Dir2(Vec2 { x: 0.0, y: 1.0 });
+    /// Constructs a new `Dir2` by normalizing vector
+    ///
+    /// Returns `None` for zero or non-finite vector.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Dir2, Vec2};
+    /// let d = Dir2::new(Vec2::new(3, 4)).unwrap();
+    /// assert_eq!(*d, Vec2::new(0.6, 0.8));
+    /// assert_eq!(d.x, 0.6);
+    /// assert!(Dir2::new(Vec2::zero()).is_none());
+    /// ```
+    pub fn new(v: Vec2) -> Option<Dir2> {
+        let len = v.len();
+        if len > 0.0 && len.is_finite() {
+            Some(Dir2(v / len))
+        } else {
+            None
+        }
+    }
+    /// Direction at given angle from x axis
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Dir2;
+    /// # use std::f64::consts::FRAC_PI_2;
+    /// let d = Dir2::from_angle(FRAC_PI_2);
+    /// assert!((d.y - 1.0).abs() < 1e-12);
+    /// ```
+    pub fn from_angle<I: Into<f64>>(angle: I) -> Dir2 {
+        Dir2(Vec2::from_polar(1.0, angle.into()))
+    }
+    /// Underlying unit vector
+    pub fn vec(self) -> Vec2 {
+        self.0
+    }
+    /// Angle from x axis in $(-\pi, \pi]$
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Dir2;
+    /// assert_eq!(Dir2::Y.angle(), std::f64::consts::FRAC_PI_2);
+    /// ```
+    pub fn angle(self) -> f64 {
+        self.0.y.atan2(self.0.x)
+    }
+    /// Direction rotated counterclockwise by angle
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Dir2;
+    /// let d = Dir2::X.rotate(std::f64::consts::PI);
+    /// assert!((d.x + 1.0).abs() < 1e-12);
+    /// ```
+    pub fn rotate<I: Into<f64>>(self, angle: I) -> Dir2 {
+        let (sin, cos) = angle.into().sin_cos();
+        let v = self.0;
+        Dir2::renormalize(Vec2::new(v.x * cos - v.y * sin, v.x * sin + v.y * cos))
+    }
+    /// Direction rotated counterclockwise by right angle
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Dir2;
+    /// assert_eq!(Dir2::X.perp(), Dir2::Y);
+    /// ```
+    pub fn perp(self) -> Dir2 {
+        Dir2(Vec2::new(-self.0.y, self.0.x))
+    }
+    /// Direction reflected about line with given normal
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Dir2, Vec2};
+    /// let d = Dir2::new(Vec2::new(1, -1)).unwrap();
+    /// let r = d.reflect(Dir2::Y);
+    /// assert!((*r - *Dir2::new(Vec2::new(1, 1)).unwrap()).len() < 1e-12);
+    /// ```
+    pub fn reflect(self, normal: Dir2) -> Dir2 {
+        Dir2::renormalize(self.0 - normal.0 * (2.0 * self.0.dot(normal.0)))
+    }
+    // removes rounding drift of vector which is unit up to rounding
+    fn renormalize(v: Vec2) -> Dir2 {
+        Dir2(v / v.len())
+    }
+}
+
+impl Deref for Dir2 {
+    type Target = Vec2;
+    fn deref(&self) -> &Vec2 {
+        &self.0
+    }
+}
+
+impl Neg for Dir2 {
+    type Output = Dir2;
+    fn neg(self) -> Dir2 {
+        Dir2(-self.0)
+    }
+}
+
+impl From<Dir2> for Vec2 {
+    fn from(d: Dir2) -> Vec2 {
+        d.0
+    }
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    #[test]
+    fn dir2_stays_unit() {
+        let mut d = Dir2::new(Vec2::new(1, 2)).unwrap();
+        let n = Dir2::from_angle(0.3);
+        for i in 0..1000 {
+            d = d.rotate(0.1 * i as f64).reflect(n);
+        }
+        assert!((d.len() - 1.0).abs() < 1e-15);
+        assert!(Dir2::new(Vec2::new(f64::NAN, 0.0)).is_none());
+        assert!(Dir2::new(Vec2::new(f64::INFINITY, 0.0)).is_none());
+    }
+}
@@ -0,0 +1,132 @@
+//! Unit vectors in space.
+use std::ops::{Deref, Neg};
+use vec3::Vec3;
+
+/// Unit vector in space
+///
+/// Unit length is guaranteed by construction and preserved by all methods,
+/// underlying vector is available through `Deref`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Dir3(Vec3);
+
+impl Dir3 {
+    /// Direction of x axis
+    pub const X: Dir3 = Dir3(Vec3 { x: 1.0, y: 0.0, z: 0.0 });
+    /// Direction of y axis
+    pub const Y: Dir3 = Dir3(Vec3 { x: 0.0, y: 1.0, z: 0.0 });
+    /// Direction of z axis
+    pub const Z: Dir3 = Dir3(Vec3 { x: 0.0, y: 0.0, z: 1.0 });
+    /// Constructs a new `Dir3` by normalizing vector
+    ///
+    /// Returns `None` for zero or non-finite vector.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Dir3, Vec3};
+    /// let d = Dir3::new(Vec3::new(0, 3, 4)).unwrap();
+    /// assert_eq!(*d, Vec3::new(0.0, 0.6, 0.8));
+    /// assert_eq!(d.z, 0.8);
+    /// assert!(Dir3::new(Vec3::zero()).is_none());
+    /// ```
+    pub fn new(v: Vec3) -> Option<Dir3> {
+        let len = v.len();
+        if len > 0.0 && len.is_finite() {
+            Some(Dir3(v / len))
+        } else {
+            None
+        }
+    }
+    /// Underlying unit vector
+    pub fn vec(self) -> Vec3 {
+        self.0
+    }
+    /// Direction rotated around axis by angle
+    ///
+    /// Rotation is counterclockwise when looking against `axis`.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Dir3;
+    /// let d = Dir3::X.rotate(Dir3::Z, std::f64::consts::FRAC_PI_2);
+    /// assert!((*d - *Dir3::Y).len() < 1e-12);
+    /// ```
+    pub fn rotate<I: Into<f64>>(self, axis: Dir3, angle: I) -> Dir3 {
+        let (sin, cos) = angle.into().sin_cos();
+        let (v, k) = (self.0, axis.0);
+        // Rodrigues' rotation formula
+        let r = v * cos + k.cross(v) * sin + k * (k.dot(v) * (1.0 - cos));
+        Dir3::renormalize(r)
+    }
+    /// Direction reflected about plane with given normal
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Dir3, Vec3};
+    /// let d = Dir3::new(Vec3::new(1, 1, -1)).unwrap();
+    /// let r = d.reflect(Dir3::Z);
+    /// assert!((*r - *Dir3::new(Vec3::new(1, 1, 1)).unwrap()).len() < 1e-12);
+    /// ```
+    pub fn reflect(self, normal: Dir3) -> Dir3 {
+        Dir3::renormalize(self.0 - normal.0 * (2.0 * self.0.dot(normal.0)))
+    }
+    /// Direction of cross product with other direction
+    ///
+    /// Returns `None` for parallel directions.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Dir3;
+    /// assert_eq!(Dir3::X.cross(Dir3::Y), Some(Dir3::Z));
+    /// assert_eq!(Dir3::X.cross(-Dir3::X), None);
+    /// ```
+    pub fn cross(self, other: Dir3) -> Option<Dir3> {
+        Dir3::new(self.0.cross(other.0))
+    }
+    // removes rounding drift of vector which is unit up to rounding
+    fn renormalize(v: Vec3) -> Dir3 {
+        Dir3(v / v.len())
+    }
+}
+
+impl Deref for Dir3 {
+    type Target = Vec3;
+    fn deref(&self) -> &Vec3 {
+        &self.0
+    }
+}
+
+impl Neg for Dir3 {
+    type Output = Dir3;
+    fn neg(self) -> Dir3 {
+        Dir3(-self.0)
+    }
+}
+
+impl From<Dir3> for Vec3 {
+    fn from(d: Dir3) -> Vec3 {
+        d.0
+    }
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    #[test]
+    fn dir3_stays_unit() {
+        let mut d = Dir3::new(Vec3::new(1, 2, 3)).unwrap();
+        let axis = Dir3::new(Vec3::new(-1, 1, 2)).unwrap();
+        for i in 0..1000 {
+            d = d.rotate(axis, 0.1 * i as f64).reflect(Dir3::Y);
+        }
+        assert!((d.len() - 1.0).abs() < 1e-15);
+    }
+
+    #[test]
+    fn dir3_rotate_keeps_axis_component() {
+        let d = Dir3::new(Vec3::new(1, 2, 3)).unwrap();
+        let axis = Dir3::new(Vec3::new(0, 1, 1)).unwrap();
+        let r = d.rotate(axis, 1.2);
+        assert!((r.dot(*axis) - d.dot(*axis)).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,226 @@
+//! Closest points and distances between geometric primitives in space.
+//!
+//! Functions return closest points on both primitives and distance between
+//! them. Queries for planar primitives are available as methods, e.g.
+//! [`Segment2::closest_point`](../segment2/struct.Segment2.html#method.closest_point).
+use vec3::Vec3;
+use line3::Line3;
+use segment3::Segment3;
+use triangle3::Triangle3;
+use aabb3::Aabb3;
+
+/// Closest point of line to point and distance between them
+///
+/// # Example
+/// ```
+/// # use linal::{Line3, Vec3};
+/// # use linal::distances::point_line;
+/// let line = Line3::new(Vec3::new(0, 0, 1), Vec3::new(1, 0, 0));
+/// assert_eq!(point_line(Vec3::new(3, 4, 1), line), (Vec3::new(3, 0, 1), 4.0));
+/// ```
+pub fn point_line(point: Vec3, line: Line3) -> (Vec3, f64) {
+    let q = line.project_point(point);
+    (q, (point - q).len())
+}
+
+/// Closest point of segment to point and distance between them
+///
+/// # Example
+/// ```
+/// # use linal::{Segment3, Vec3};
+/// # use linal::distances::point_segment;
+/// let s = Segment3::new(Vec3::new(0, 0, 0), Vec3::new(2, 0, 0));
+/// assert_eq!(point_segment(Vec3::new(5, 4, 0), s), (Vec3::new(2, 0, 0), 5.0));
+/// ```
+pub fn point_segment(point: Vec3, segment: Segment3) -> (Vec3, f64) {
+    let q = segment.closest_point(point);
+    (q, (point - q).len())
+}
+
+/// Closest point of triangle to point and distance between them
+///
+/// Point is classified against Voronoi regions of triangle vertices, edges
+/// and face.
+///
+/// # Example
+/// ```
+/// # use linal::{Triangle3, Vec3};
+/// # use linal::distances::point_triangle;
+/// let t = Triangle3::new(Vec3::new(0, 0, 0), Vec3::new(4, 0, 0), Vec3::new(0, 4, 0));
+/// assert_eq!(point_triangle(Vec3::new(1, 1, 3), t), (Vec3::new(1, 1, 0), 3.0));
+/// assert_eq!(point_triangle(Vec3::new(-1, -1, 0), t).0, Vec3::new(0, 0, 0));
+/// ```
+pub fn point_triangle(point: Vec3, triangle: Triangle3) -> (Vec3, f64) {
+    let q = closest_on_triangle(point, triangle);
+    (q, (point - q).len())
+}
+
+fn closest_on_triangle(p: Vec3, t: Triangle3) -> Vec3 {
+    let (a, b, c) = (t.a, t.b, t.c);
+    let (ab, ac, ap) = (b - a, c - a, p - a);
+    let (d1, d2) = (ab.dot(ap), ac.dot(ap));
+    if d1 <= 0.0 && d2 <= 0.0 {
+        return a;
+    }
+    let bp = p - b;
+    let (d3, d4) = (ab.dot(bp), ac.dot(bp));
+    if d3 >= 0.0 && d4 <= d3 {
+        return b;
+    }
+    let vc = d1 * d4 - d3 * d2;
+    if vc <= 0.0 && d1 >= 0.0 && d3 <= 0.0 {
+        return a + ab * (d1 / (d1 - d3));
+    }
+    let cp = p - c;
+    let (d5, d6) = (ab.dot(cp), ac.dot(cp));
+    if d6 >= 0.0 && d5 <= d6 {
+        return c;
+    }
+    let vb = d5 * d2 - d1 * d6;
+    if vb <= 0.0 && d2 >= 0.0 && d6 <= 0.0 {
+        return a + ac * (d2 / (d2 - d6));
+    }
+    let va = d3 * d6 - d5 * d4;
+    if va <= 0.0 && d4 - d3 >= 0.0 && d5 - d6 >= 0.0 {
+        return b + (c - b) * ((d4 - d3) / ((d4 - d3) + (d5 - d6)));
+    }
+    let denom = va + vb + vc;
+    a + ab * (vb / denom) + ac * (vc / denom)
+}
+
+/// Closest point of box to point and distance between them
+///
+/// Distance is zero for point inside box.
+///
+/// # Example
+/// ```
+/// # use linal::{Aabb3, Vec3};
+/// # use linal::distances::point_aabb;
+/// let b = Aabb3::new(Vec3::new(0, 0, 0), Vec3::new(1, 1, 1));
+/// assert_eq!(point_aabb(Vec3::new(4.0, 5.0, 0.5), b), (Vec3::new(1.0, 1.0, 0.5), 5.0));
+/// ```
+pub fn point_aabb(point: Vec3, aabb: Aabb3) -> (Vec3, f64) {
+    let q = aabb.closest_point(point);
+    (q, (point - q).len())
+}
+
+/// Closest points of two segments and distance between them
+///
+/// For parallel segments one of closest point pairs is returned.
+///
+/// # Example
+/// ```
+/// # use linal::{Segment3, Vec3};
+/// # use linal::distances::segment_segment;
+/// let a = Segment3::new(Vec3::new(-1, 0, 0), Vec3::new(1, 0, 0));
+/// let b = Segment3::new(Vec3::new(0, -1, 2), Vec3::new(0, 1, 2));
+/// assert_eq!(segment_segment(a, b), (Vec3::new(0, 0, 0), Vec3::new(0, 0, 2), 2.0));
+/// ```
+pub fn segment_segment(first: Segment3, second: Segment3) -> (Vec3, Vec3, f64) {
+    let (d1, d2) = (first.b - first.a, second.b - second.a);
+    let r = first.a - second.a;
+    let (a, e, f) = (d1.dot(d1), d2.dot(d2), d2.dot(r));
+    let (s, t) = if a == 0.0 && e == 0.0 {
+        (0.0, 0.0)
+    } else if a == 0.0 {
+        (0.0, (f / e).clamp(0.0, 1.0))
+    } else {
+        let c = d1.dot(r);
+        if e == 0.0 {
+            ((-c / a).clamp(0.0, 1.0), 0.0)
+        } else {
+            let b = d1.dot(d2);
+            let denom = a * e - b * b;
+            let s = if denom > 0.0 { ((b * f - c * e) / denom).clamp(0.0, 1.0) } else { 0.0 };
+            let t = (b * s + f) / e;
+            if t < 0.0 {
+                ((-c / a).clamp(0.0, 1.0), 0.0)
+            } else if t > 1.0 {
+                (((b - c) / a).clamp(0.0, 1.0), 1.0)
+            } else {
+                (s, t)
+            }
+        }
+    };
+    let (p, q) = (first.point_at(s), second.point_at(t));
+    (p, q, (p - q).len())
+}
+
+/// Closest points of two lines and distance between them
+///
+/// For parallel lines base point of `first` and its projection onto `second`
+/// are returned.
+///
+/// # Example
+/// ```
+/// # use linal::{Line3, Vec3};
+/// # use linal::distances::line_line;
+/// let a = Line3::new(Vec3::new(5, 0, 0), Vec3::new(1, 0, 0));
+/// let b = Line3::new(Vec3::new(0, 7, 2), Vec3::new(0, 1, 0));
+/// assert_eq!(line_line(a, b), (Vec3::new(0, 0, 0), Vec3::new(0, 0, 2), 2.0));
+/// ```
+pub fn line_line(first: Line3, second: Line3) -> (Vec3, Vec3, f64) {
+    let r = first.point - second.point;
+    let b = first.dir.dot(second.dir);
+    let denom = 1.0 - b * b;
+    let (p, q) = if denom <= 0.0 {
+        (first.point, second.project_point(first.point))
+    } else {
+        let (c, f) = (first.dir.dot(r), second.dir.dot(r));
+        let s = (b * f - c) / denom;
+        let t = (f - b * c) / denom;
+        (first.point + first.dir * s, second.point + second.dir * t)
+    };
+    (p, q, (p - q).len())
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    #[test]
+    fn point_triangle_regions() {
+        let t = Triangle3::new(Vec3::new(0, 0, 0), Vec3::new(4, 0, 0), Vec3::new(0, 4, 0));
+        assert_eq!(point_triangle(Vec3::new(5, -1, 1), t).0, t.b);
+        assert_eq!(point_triangle(Vec3::new(-1, 5, 1), t).0, t.c);
+        assert_eq!(point_triangle(Vec3::new(2, -3, 0), t).0, Vec3::new(2, 0, 0));
+        assert_eq!(point_triangle(Vec3::new(-3, 2, 0), t).0, Vec3::new(0, 2, 0));
+        let (q, d) = point_triangle(Vec3::new(3, 3, 0), t);
+        assert!((q - Vec3::new(2, 2, 0)).len() < 1e-12);
+        assert!((d - 2f64.sqrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn segment_segment_cases() {
+        let a = Segment3::new(Vec3::new(0, 0, 0), Vec3::new(1, 0, 0));
+        // parallel
+        let b = Segment3::new(Vec3::new(3, 1, 0), Vec3::new(4, 1, 0));
+        let (p, q, d) = segment_segment(a, b);
+        assert_eq!((p, q), (a.b, b.a));
+        assert!((d - 5f64.sqrt()).abs() < 1e-12);
+        // skew, closest points at endpoints
+        let c = Segment3::new(Vec3::new(2, -1, 1), Vec3::new(2, 1, 1));
+        let (p, q, _) = segment_segment(a, c);
+        assert_eq!((p, q), (a.b, Vec3::new(2, 0, 1)));
+        // degenerate
+        let point = Segment3::new(Vec3::new(0.5, 2.0, 0.0), Vec3::new(0.5, 2.0, 0.0));
+        assert_eq!(segment_segment(a, point), (Vec3::new(0.5, 0.0, 0.0), point.a, 2.0));
+        assert_eq!(segment_segment(point, a), (point.a, Vec3::new(0.5, 0.0, 0.0), 2.0));
+        assert_eq!(segment_segment(point, point).2, 0.0);
+    }
+
+    #[test]
+    fn line_line_cases() {
+        let a = Line3::through(Vec3::new(0, 0, 0), Vec3::new(1, 1, 0));
+        let b = Line3::through(Vec3::new(0, 2, 1), Vec3::new(1, 1, 1));
+        let (p, q, d) = line_line(a, b);
+        assert!((p - Vec3::new(1, 1, 0)).len() < 1e-12);
+        assert!((q - Vec3::new(1, 1, 1)).len() < 1e-12);
+        assert!((d - 1.0).abs() < 1e-12);
+        let parallel = Line3::new(Vec3::new(0, 0, 3), a.dir);
+        let (p, q, d) = line_line(a, parallel);
+        assert_eq!(p, a.point);
+        assert!((q - Vec3::new(0, 0, 3)).len() < 1e-12);
+        assert!((d - 3.0).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,185 @@
+//! Dual numbers for forward-mode automatic differentiation.
+use std::ops::{Add, Sub, Mul, Div, Neg};
+use std::ops::{AddAssign, SubAssign, MulAssign, DivAssign};
+use std::fmt;
+
+/// Dual number $a + b\varepsilon$, where $\varepsilon^2 = 0$
+///
+/// Evaluating function on `Dual::variable(x)` gives both value of function
+/// and its derivative at `x`.
+///
+/// # Example
+/// ```
+/// # use linal::Dual;
+/// let x = Dual::variable(2);
+/// let y = x * x * 3 + x.sin();
+/// assert_eq!(y.value, 12.0 + 2f64.sin());
+/// assert_eq!(y.deriv, 12.0 + 2f64.cos());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Dual {
+    /// real part (value)
+    pub value: f64,
+    /// dual part (derivative)
+    pub deriv: f64,
+}
+
+impl Dual {
+    /// Constructs a new `Dual` from value and derivative
+    pub fn new<I: Into<f64>>(value: I, deriv: I) -> Dual {
+        Dual { value: value.into(), deriv: deriv.into() }
+    }
+    /// Constant with zero derivative
+    pub fn constant<I: Into<f64>>(value: I) -> Dual {
+        Dual { value: value.into(), deriv: 0.0 }
+    }
+    /// Independent variable with unit derivative
+    pub fn variable<I: Into<f64>>(value: I) -> Dual {
+        Dual { value: value.into(), deriv: 1.0 }
+    }
+    // applies function with known derivative by chain rule
+    fn chain(self, value: f64, deriv: f64) -> Dual {
+        Dual { value, deriv: deriv * self.deriv }
+    }
+    /// Square root
+    pub fn sqrt(self) -> Dual {
+        let s = self.value.sqrt();
+        self.chain(s, 0.5 / s)
+    }
+    /// Sine
+    pub fn sin(self) -> Dual {
+        self.chain(self.value.sin(), self.value.cos())
+    }
+    /// Cosine
+    pub fn cos(self) -> Dual {
+        self.chain(self.value.cos(), -self.value.sin())
+    }
+    /// Exponent
+    pub fn exp(self) -> Dual {
+        let e = self.value.exp();
+        self.chain(e, e)
+    }
+    /// Natural logarithm
+    pub fn ln(self) -> Dual {
+        self.chain(self.value.ln(), 1.0 / self.value)
+    }
+    /// Integer power
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Dual;
+    /// let y = Dual::variable(2).powi(3);
+    /// assert_eq!((y.value, y.deriv), (8.0, 12.0));
+    /// ```
+    pub fn powi(self, n: i32) -> Dual {
+        self.chain(self.value.powi(n), n as f64 * self.value.powi(n - 1))
+    }
+    /// Absolute value
+    pub fn abs(self) -> Dual {
+        if self.value < 0.0 { -self } else { self }
+    }
+}
+
+impl From<f64> for Dual {
+    fn from(value: f64) -> Dual {
+        Dual::constant(value)
+    }
+}
+
+impl From<i32> for Dual {
+    fn from(value: i32) -> Dual {
+        Dual::constant(value)
+    }
+}
+
+impl<I: Into<Dual>> Add<I> for Dual {
+    type Output = Dual;
+    fn add(self, rhs: I) -> Dual {
+        let rhs = rhs.into();
+        Dual { value: self.value + rhs.value, deriv: self.deriv + rhs.deriv }
+    }
+}
+
+impl<I: Into<Dual>> Sub<I> for Dual {
+    type Output = Dual;
+    fn sub(self, rhs: I) -> Dual {
+        let rhs = rhs.into();
+        Dual { value: self.value - rhs.value, deriv: self.deriv - rhs.deriv }
+    }
+}
+
+impl<I: Into<Dual>> Mul<I> for Dual {
+    type Output = Dual;
+    fn mul(self, rhs: I) -> Dual {
+        let rhs = rhs.into();
+        Dual {
+            value: self.value * rhs.value,
+            deriv: self.deriv * rhs.value + self.value * rhs.deriv,
+        }
+    }
+}
+
+impl<I: Into<Dual>> Div<I> for Dual {
+    type Output = Dual;
+    fn div(self, rhs: I) -> Dual {
+        let rhs = rhs.into();
+        Dual {
+            value: self.value / rhs.value,
+            deriv: (self.deriv * rhs.value - self.value * rhs.deriv) / (rhs.value * rhs.value),
+        }
+    }
+}
+
+impl Neg for Dual {
+    type Output = Dual;
+    fn neg(self) -> Dual {
+        Dual { value: -self.value, deriv: -self.deriv }
+    }
+}
+
+impl<I: Into<Dual>> AddAssign<I> for Dual {
+    fn add_assign(&mut self, rhs: I) {
+        *self = *self + rhs;
+    }
+}
+
+impl<I: Into<Dual>> SubAssign<I> for Dual {
+    fn sub_assign(&mut self, rhs: I) {
+        *self = *self - rhs;
+    }
+}
+
+impl<I: Into<Dual>> MulAssign<I> for Dual {
+    fn mul_assign(&mut self, rhs: I) {
+        *self = *self * rhs;
+    }
+}
+
+impl<I: Into<Dual>> DivAssign<I> for Dual {
+    fn div_assign(&mut self, rhs: I) {
+        *self = *self / rhs;
+    }
+}
+
+impl fmt::Display for Dual {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} + {}ε", self.value, self.deriv)
+    }
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    #[test]
+    fn dual_matches_finite_difference() {
+        let f = |x: Dual| (x.exp() / (x * x + 1)).ln() + x.cos().powi(2) - x.sqrt();
+        let g = |x: f64| (x.exp() / (x * x + 1.0)).ln() + x.cos().powi(2) - x.sqrt();
+        let h = 1e-6;
+        for &x in &[0.3, 1.0, 2.5] {
+            let d = f(Dual::variable(x));
+            assert!((d.value - g(x)).abs() < 1e-12);
+            assert!((d.deriv - (g(x + h) - g(x - h)) / (2.0 * h)).abs() < 1e-6);
+        }
+    }
+}
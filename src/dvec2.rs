@@ -0,0 +1,148 @@
+//! Vectors on a plane with dual number coordinates.
+use std::ops::{Add, Sub, Mul, Div, Neg};
+use std::ops::{AddAssign, SubAssign, MulAssign, DivAssign};
+use std::ops::{Index, IndexMut};
+use dual::Dual;
+use vec2::Vec2;
+
+/// Vector on a plane with dual number coordinates
+///
+/// Carries vector value together with its derivative along some direction,
+/// so derivatives of vector expressions are computed automatically.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DVec2 {
+    /// x coordinate
+    pub x: Dual,
+    /// y coordinate
+    pub y: Dual,
+}
+
+impl DVec2 {
+    /// Constructs a new `DVec2` from dual coordinates
+    pub fn new(x: Dual, y: Dual) -> DVec2 {
+        DVec2 { x, y }
+    }
+    /// Constant vector with zero derivative
+    pub fn constant(v: Vec2) -> DVec2 {
+        DVec2::new(Dual::constant(v.x), Dual::constant(v.y))
+    }
+    /// Variable vector with derivative equal to `direction`
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{DVec2, Vec2};
+    /// // derivative of distance to origin along x axis
+    /// let p = DVec2::variable(Vec2::new(3, 4), Vec2::new(1, 0));
+    /// assert!((p.len().deriv - 0.6).abs() < 1e-12);
+    /// ```
+    pub fn variable(v: Vec2, direction: Vec2) -> DVec2 {
+        DVec2::new(Dual::new(v.x, direction.x), Dual::new(v.y, direction.y))
+    }
+    /// Value of vector
+    pub fn value(self) -> Vec2 {
+        Vec2::new(self.x.value, self.y.value)
+    }
+    /// Derivative of vector
+    pub fn deriv(self) -> Vec2 {
+        Vec2::new(self.x.deriv, self.y.deriv)
+    }
+    /// Scalar product
+    pub fn dot(self, rhs: DVec2) -> Dual {
+        self.x * rhs.x + self.y * rhs.y
+    }
+    /// Length of vector
+    pub fn len(self) -> Dual {
+        self.dot(self).sqrt()
+    }
+    /// Value and gradient of scalar function at point
+    ///
+    /// Function is evaluated once per coordinate.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{DVec2, Vec2};
+    /// let c = DVec2::constant(Vec2::new(1, 1));
+    /// let (value, grad) = DVec2::gradient(|p| (p - c).len(), Vec2::new(4, 5));
+    /// assert_eq!(value, 5.0);
+    /// assert!((grad - Vec2::new(0.6, 0.8)).len() < 1e-12);
+    /// ```
+    pub fn gradient<F: Fn(DVec2) -> Dual>(f: F, at: Vec2) -> (f64, Vec2) {
+        let dx = f(DVec2::variable(at, Vec2::new(1, 0)));
+        let dy = f(DVec2::variable(at, Vec2::new(0, 1)));
+        (dx.value, Vec2::new(dx.deriv, dy.deriv))
+    }
+    // need for op_default & op_assign
+    fn size(&self) -> usize { 2 }
+}
+
+op_default!(add, Add, +=, DVec2);
+op_default!(sub, Sub, -=, DVec2);
+op_default!(mul, Mul, *=, DVec2);
+op_default!(Dual, mul, Mul, *=, DVec2);
+op_default!(Dual, div, Div, /=, DVec2);
+op_assign!(add_assign, AddAssign, +=, DVec2);
+op_assign!(sub_assign, SubAssign, -=, DVec2);
+op_assign!(mul_assign, MulAssign, *=, DVec2);
+op_assign!(Dual, mul_assign, MulAssign, *=, DVec2);
+op_assign!(Dual, div_assign, DivAssign, /=, DVec2);
+
+impl Neg for DVec2 {
+    type Output = DVec2;
+
+    fn neg(self) -> DVec2 {
+        DVec2::new(-self.x, -self.y)
+    }
+}
+
+impl Index<usize> for DVec2 {
+    type Output = Dual;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            i => panic!("Index {} out of [0, 1] range", i)
+        }
+    }
+}
+
+impl IndexMut<usize> for DVec2 {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            i => panic!("Index {} out of [0, 1] range", i)
+        }
+    }
+}
+
+impl From<Vec2> for DVec2 {
+    fn from(v: Vec2) -> DVec2 {
+        DVec2::constant(v)
+    }
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    #[test]
+    fn dvec2_gradient_matches_finite_difference() {
+        let c = Vec2::new(0.5, -1.0);
+        let f = |p: DVec2| {
+            let d = p - DVec2::constant(c);
+            (d.len() * 2 - 1).powi(2) + p.x * p.y / (d.dot(d) + 1)
+        };
+        let g = |p: Vec2| {
+            let d = p - c;
+            (d.len() * 2.0 - 1.0).powi(2) + p.x * p.y / (d.dot(d) + 1.0)
+        };
+        let at = Vec2::new(1.3, 0.4);
+        let (value, grad) = DVec2::gradient(f, at);
+        assert!((value - g(at)).abs() < 1e-12);
+        let h = 1e-6;
+        let numeric = Vec2::new(g(at + Vec2::new(h, 0.0)) - g(at - Vec2::new(h, 0.0)),
+                                g(at + Vec2::new(0.0, h)) - g(at - Vec2::new(0.0, h))) / (2.0 * h);
+        assert!((grad - numeric).len() < 1e-6);
+    }
+}
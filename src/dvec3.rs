@@ -0,0 +1,160 @@
+//! Vectors in space with dual number coordinates.
+use std::ops::{Add, Sub, Mul, Div, Neg};
+use std::ops::{AddAssign, SubAssign, MulAssign, DivAssign};
+use std::ops::{Index, IndexMut};
+use dual::Dual;
+use vec3::Vec3;
+
+/// Vector in space with dual number coordinates
+///
+/// Carries vector value together with its derivative along some direction,
+/// so derivatives of vector expressions are computed automatically.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DVec3 {
+    /// x coordinate
+    pub x: Dual,
+    /// y coordinate
+    pub y: Dual,
+    /// z coordinate
+    pub z: Dual,
+}
+
+impl DVec3 {
+    /// Constructs a new `DVec3` from dual coordinates
+    pub fn new(x: Dual, y: Dual, z: Dual) -> DVec3 {
+        DVec3 { x, y, z }
+    }
+    /// Constant vector with zero derivative
+    pub fn constant(v: Vec3) -> DVec3 {
+        DVec3::new(Dual::constant(v.x), Dual::constant(v.y), Dual::constant(v.z))
+    }
+    /// Variable vector with derivative equal to `direction`
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{DVec3, Vec3};
+    /// // derivative of distance to origin along x axis
+    /// let p = DVec3::variable(Vec3::new(3, 4, 0), Vec3::new(1, 0, 0));
+    /// assert!((p.len().deriv - 0.6).abs() < 1e-12);
+    /// ```
+    pub fn variable(v: Vec3, direction: Vec3) -> DVec3 {
+        DVec3::new(Dual::new(v.x, direction.x), Dual::new(v.y, direction.y),
+                   Dual::new(v.z, direction.z))
+    }
+    /// Value of vector
+    pub fn value(self) -> Vec3 {
+        Vec3::new(self.x.value, self.y.value, self.z.value)
+    }
+    /// Derivative of vector
+    pub fn deriv(self) -> Vec3 {
+        Vec3::new(self.x.deriv, self.y.deriv, self.z.deriv)
+    }
+    /// Scalar product
+    pub fn dot(self, rhs: DVec3) -> Dual {
+        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z
+    }
+    /// Vector product
+    pub fn cross(self, rhs: DVec3) -> DVec3 {
+        DVec3::new(self.y * rhs.z - self.z * rhs.y,
+                   self.z * rhs.x - self.x * rhs.z,
+                   self.x * rhs.y - self.y * rhs.x)
+    }
+    /// Length of vector
+    pub fn len(self) -> Dual {
+        self.dot(self).sqrt()
+    }
+    /// Value and gradient of scalar function at point
+    ///
+    /// Function is evaluated once per coordinate.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{DVec3, Vec3};
+    /// let c = DVec3::constant(Vec3::new(1, 1, 2));
+    /// let (value, grad) = DVec3::gradient(|p| (p - c).len(), Vec3::new(4, 5, 2));
+    /// assert_eq!(value, 5.0);
+    /// assert!((grad - Vec3::new(0.6, 0.8, 0.0)).len() < 1e-12);
+    /// ```
+    pub fn gradient<F: Fn(DVec3) -> Dual>(f: F, at: Vec3) -> (f64, Vec3) {
+        let dx = f(DVec3::variable(at, Vec3::new(1, 0, 0)));
+        let dy = f(DVec3::variable(at, Vec3::new(0, 1, 0)));
+        let dz = f(DVec3::variable(at, Vec3::new(0, 0, 1)));
+        (dx.value, Vec3::new(dx.deriv, dy.deriv, dz.deriv))
+    }
+    // need for op_default & op_assign
+    fn size(&self) -> usize { 3 }
+}
+
+op_default!(add, Add, +=, DVec3);
+op_default!(sub, Sub, -=, DVec3);
+op_default!(mul, Mul, *=, DVec3);
+op_default!(Dual, mul, Mul, *=, DVec3);
+op_default!(Dual, div, Div, /=, DVec3);
+op_assign!(add_assign, AddAssign, +=, DVec3);
+op_assign!(sub_assign, SubAssign, -=, DVec3);
+op_assign!(mul_assign, MulAssign, *=, DVec3);
+op_assign!(Dual, mul_assign, MulAssign, *=, DVec3);
+op_assign!(Dual, div_assign, DivAssign, /=, DVec3);
+
+impl Neg for DVec3 {
+    type Output = DVec3;
+
+    fn neg(self) -> DVec3 {
+        DVec3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl Index<usize> for DVec3 {
+    type Output = Dual;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            i => panic!("Index {} out of [0, 2] range", i)
+        }
+    }
+}
+
+impl IndexMut<usize> for DVec3 {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            i => panic!("Index {} out of [0, 2] range", i)
+        }
+    }
+}
+
+impl From<Vec3> for DVec3 {
+    fn from(v: Vec3) -> DVec3 {
+        DVec3::constant(v)
+    }
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    #[test]
+    fn dvec3_gradient_matches_finite_difference() {
+        let c = Vec3::new(0.5, -1.0, 2.0);
+        let f = |p: DVec3| {
+            let d = p - DVec3::constant(c);
+            (d.len() * 2 - 1).powi(2) + p.x * p.z / (d.cross(p).dot(d) + d.dot(d) + 1)
+        };
+        let g = |p: Vec3| {
+            let d = p - c;
+            (d.len() * 2.0 - 1.0).powi(2) + p.x * p.z / (d.cross(p).dot(d) + d.dot(d) + 1.0)
+        };
+        let at = Vec3::new(1.3, 0.4, -0.7);
+        let (value, grad) = DVec3::gradient(f, at);
+        assert!((value - g(at)).abs() < 1e-12);
+        let h = 1e-6;
+        let diff = |e: Vec3| (g(at + e * h) - g(at - e * h)) / (2.0 * h);
+        let numeric = Vec3::new(diff(Vec3::new(1, 0, 0)), diff(Vec3::new(0, 1, 0)), diff(Vec3::new(0, 0, 1)));
+        assert!((grad - numeric).len() < 1e-6);
+    }
+}
@@ -0,0 +1,689 @@
+//! Regular grids of samples with interpolation.
+use std::ops::{Add, Index, IndexMut, Mul};
+use aabb3::Aabb3;
+use rect::Rect;
+use vec2::Vec2;
+use vec3::Vec3;
+
+/// Behaviour of field sampled outside of its bounds
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OutOfBounds<T> {
+    /// point is moved to nearest point of bounds
+    Clamp,
+    /// field is periodic with period equal to size of bounds
+    Wrap,
+    /// field takes constant value outside
+    Value(T),
+    /// sampling outside gives `None`
+    Reject,
+}
+
+// node index and fraction along one axis, `None` if outside of bounds
+fn locate<T>(x: f64, min: f64, max: f64, n: usize, policy: &OutOfBounds<T>) -> Option<(usize, f64)> {
+    if n == 1 {
+        return Some((0, 0.0));
+    }
+    let last = (n - 1) as f64;
+    let mut u = if max > min { (x - min) / (max - min) * last } else { 0.0 };
+    if !(0.0..=last).contains(&u) {
+        match *policy {
+            OutOfBounds::Clamp => u = u.clamp(0.0, last),
+            OutOfBounds::Wrap => u = u.rem_euclid(last),
+            _ => return None,
+        }
+    }
+    let i = (u.floor() as usize).min(n - 2);
+    Some((i, u - i as f64))
+}
+
+fn lerp<T: Copy + Add<Output = T> + Mul<f64, Output = T>>(a: T, b: T, t: f64) -> T {
+    a * (1.0 - t) + b * t
+}
+
+// first derivative at node `i` of `n` with spacing `h`, central differences
+// inside and one-sided at ends
+fn derivative<T, F>(n: usize, h: f64, i: usize, get: F) -> T
+where
+    T: Copy + Add<Output = T> + Mul<f64, Output = T>,
+    F: Fn(usize) -> T,
+{
+    if n < 2 {
+        return get(i) * 0.0;
+    }
+    let (a, b) = if i == 0 {
+        (0, 1)
+    } else if i == n - 1 {
+        (n - 2, n - 1)
+    } else {
+        (i - 1, i + 1)
+    };
+    (get(b) + get(a) * -1.0) * (1.0 / (h * (b - a) as f64))
+}
+
+// second derivative at node `i` of `n`, stencil is shifted inside at ends
+fn second_derivative<T, F>(n: usize, h: f64, i: usize, get: F) -> T
+where
+    T: Copy + Add<Output = T> + Mul<f64, Output = T>,
+    F: Fn(usize) -> T,
+{
+    if n < 3 {
+        return get(i) * 0.0;
+    }
+    let c = i.clamp(1, n - 2);
+    (get(c - 1) + get(c) * -2.0 + get(c + 1)) * (1.0 / (h * h))
+}
+
+// node spacing along axis
+fn spacing(min: f64, max: f64, n: usize) -> f64 {
+    if n > 1 { (max - min) / (n - 1) as f64 } else { 0.0 }
+}
+
+// policy for derived field, constant outside gives zero derivatives
+fn derived_policy<T, U>(policy: &OutOfBounds<T>, zero: U) -> OutOfBounds<U> {
+    match *policy {
+        OutOfBounds::Clamp => OutOfBounds::Clamp,
+        OutOfBounds::Wrap => OutOfBounds::Wrap,
+        OutOfBounds::Value(_) => OutOfBounds::Value(zero),
+        OutOfBounds::Reject => OutOfBounds::Reject,
+    }
+}
+
+/// Samples on regular grid over rectangle
+///
+/// Nodes are placed uniformly including borders of rectangle, so node
+/// `(0, 0)` is at `bounds.min` and node `(width - 1, height - 1)` is at
+/// `bounds.max`. Values are stored by rows.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Field2<T> {
+    bounds: Rect,
+    width: usize,
+    height: usize,
+    samples: Vec<T>,
+    out_of_bounds: OutOfBounds<T>,
+}
+
+impl<T: Copy + Add<Output = T> + Mul<f64, Output = T>> Field2<T> {
+    /// Constructs a new `Field2` with all nodes set to `value`
+    ///
+    /// Points outside of bounds are clamped by default.
+    ///
+    /// # Panics
+    /// Panics if `width` or `height` is zero.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Rect, Vec2};
+    /// # use linal::field::Field2;
+    /// let field = Field2::new(Rect::new(Vec2::zero(), Vec2::new(4, 2)), 5, 3, Vec2::new(1, 0));
+    /// assert_eq!(field.node(4, 2), Vec2::new(4, 2));
+    /// assert_eq!(field.sample(Vec2::new(1.5, 0.7)), Some(Vec2::new(1, 0)));
+    /// ```
+    pub fn new(bounds: Rect, width: usize, height: usize, value: T) -> Field2<T> {
+        assert!(width > 0 && height > 0, "field must have at least one node");
+        Field2 {
+            bounds,
+            width,
+            height,
+            samples: vec![value; width * height],
+            out_of_bounds: OutOfBounds::Clamp,
+        }
+    }
+    /// Constructs a new `Field2` with values of function at nodes
+    ///
+    /// # Panics
+    /// Panics if `width` or `height` is zero.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Rect, Vec2};
+    /// # use linal::field::Field2;
+    /// // rotational flow
+    /// let field = Field2::from_fn(Rect::new(Vec2::new(-1, -1), Vec2::new(1, 1)), 3, 3, |p| p.cross());
+    /// assert_eq!(field.sample(Vec2::new(0.5, 0.25)), Some(Vec2::new(0.25, -0.5)));
+    /// ```
+    pub fn from_fn<F: FnMut(Vec2) -> T>(bounds: Rect, width: usize, height: usize, mut f: F) -> Field2<T> {
+        assert!(width > 0 && height > 0, "field must have at least one node");
+        let mut field = Field2 {
+            bounds,
+            width,
+            height,
+            samples: Vec::with_capacity(width * height),
+            out_of_bounds: OutOfBounds::Clamp,
+        };
+        for j in 0..height {
+            for i in 0..width {
+                let p = field.node(i, j);
+                field.samples.push(f(p));
+            }
+        }
+        field
+    }
+    /// Sets behaviour outside of bounds
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Rect, Vec2};
+    /// # use linal::field::{Field2, OutOfBounds};
+    /// let bounds = Rect::new(Vec2::zero(), Vec2::new(1, 1));
+    /// let field = Field2::new(bounds, 2, 2, 1.0).with_out_of_bounds(OutOfBounds::Value(0.0));
+    /// assert_eq!(field.sample(Vec2::new(2, 0)), Some(0.0));
+    /// let field = field.with_out_of_bounds(OutOfBounds::Reject);
+    /// assert_eq!(field.sample(Vec2::new(2, 0)), None);
+    /// ```
+    pub fn with_out_of_bounds(mut self, out_of_bounds: OutOfBounds<T>) -> Field2<T> {
+        self.out_of_bounds = out_of_bounds;
+        self
+    }
+    /// Rectangle covered by field
+    pub fn bounds(&self) -> Rect {
+        self.bounds
+    }
+    /// Number of nodes along x and y axes
+    pub fn dims(&self) -> (usize, usize) {
+        (self.width, self.height)
+    }
+    /// Values at nodes stored by rows
+    pub fn samples(&self) -> &[T] {
+        &self.samples
+    }
+    /// Position of node
+    pub fn node(&self, i: usize, j: usize) -> Vec2 {
+        let t = |k: usize, n: usize| if n > 1 { k as f64 / (n - 1) as f64 } else { 0.0 };
+        let size = self.bounds.max - self.bounds.min;
+        self.bounds.min + Vec2::new(size.x * t(i, self.width), size.y * t(j, self.height))
+    }
+    /// Bilinear interpolation of field at point
+    ///
+    /// Outside of bounds behaviour is set by
+    /// [`with_out_of_bounds`](#method.with_out_of_bounds), only
+    /// `OutOfBounds::Reject` gives `None`.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Rect, Vec2};
+    /// # use linal::field::{Field2, OutOfBounds};
+    /// let bounds = Rect::new(Vec2::zero(), Vec2::new(2, 1));
+    /// let field = Field2::from_fn(bounds, 3, 2, |p| p.x + 10.0 * p.y);
+    /// assert_eq!(field.sample(Vec2::new(1.5, 0.5)), Some(6.5));
+    /// // clamped to (2, 1)
+    /// assert_eq!(field.sample(Vec2::new(5, 5)), Some(12.0));
+    /// let field = field.with_out_of_bounds(OutOfBounds::Wrap);
+    /// assert_eq!(field.sample(Vec2::new(2.5, 0.0)), Some(0.5));
+    /// ```
+    pub fn sample(&self, point: Vec2) -> Option<T> {
+        let (min, max) = (self.bounds.min, self.bounds.max);
+        let p = &self.out_of_bounds;
+        match (
+            locate(point.x, min.x, max.x, self.width, p),
+            locate(point.y, min.y, max.y, self.height, p),
+        ) {
+            (Some((i, u)), Some((j, v))) => {
+                let (i1, j1) = ((i + 1).min(self.width - 1), (j + 1).min(self.height - 1));
+                let bottom = lerp(self[(i, j)], self[(i1, j)], u);
+                let top = lerp(self[(i, j1)], self[(i1, j1)], u);
+                Some(lerp(bottom, top, v))
+            }
+            _ => match *p {
+                OutOfBounds::Value(value) => Some(value),
+                _ => None,
+            },
+        }
+    }
+    // field of same shape with values computed at nodes
+    fn map_nodes<U, F: FnMut(usize, usize) -> U>(&self, zero: U, mut f: F) -> Field2<U> {
+        let mut samples = Vec::with_capacity(self.samples.len());
+        for j in 0..self.height {
+            for i in 0..self.width {
+                samples.push(f(i, j));
+            }
+        }
+        Field2 {
+            bounds: self.bounds,
+            width: self.width,
+            height: self.height,
+            samples,
+            out_of_bounds: derived_policy(&self.out_of_bounds, zero),
+        }
+    }
+    // partial derivatives along x and y at node
+    fn partials(&self, i: usize, j: usize) -> (T, T) {
+        let (min, max) = (self.bounds.min, self.bounds.max);
+        (
+            derivative(self.width, spacing(min.x, max.x, self.width), i, |k| self[(k, j)]),
+            derivative(self.height, spacing(min.y, max.y, self.height), j, |k| self[(i, k)]),
+        )
+    }
+    /// Laplacian by finite differences
+    ///
+    /// Second derivatives use three point stencil, shifted inside at border
+    /// nodes. Axes with less than three nodes give zero contribution.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Rect, Vec2};
+    /// # use linal::field::Field2;
+    /// let bounds = Rect::new(Vec2::zero(), Vec2::new(1, 1));
+    /// let field = Field2::from_fn(bounds, 11, 11, |p| p.x * p.x + 2.0 * p.y * p.y);
+    /// let lap = field.laplacian();
+    /// assert!((lap[(0, 7)] - 6.0).abs() < 1e-9);
+    /// ```
+    pub fn laplacian(&self) -> Field2<T> {
+        let (min, max) = (self.bounds.min, self.bounds.max);
+        let (hx, hy) = (spacing(min.x, max.x, self.width), spacing(min.y, max.y, self.height));
+        let zero = self.samples[0] * 0.0;
+        self.map_nodes(zero, |i, j| {
+            second_derivative(self.width, hx, i, |k| self[(k, j)])
+                + second_derivative(self.height, hy, j, |k| self[(i, k)])
+        })
+    }
+}
+
+impl Field2<f64> {
+    /// Gradient of scalar field by finite differences
+    ///
+    /// Derivatives are central inside of grid and one-sided at border nodes.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Rect, Vec2};
+    /// # use linal::field::Field2;
+    /// let bounds = Rect::new(Vec2::zero(), Vec2::new(2, 1));
+    /// let field = Field2::from_fn(bounds, 5, 3, |p| 3.0 * p.x - p.y);
+    /// let grad = field.gradient();
+    /// assert!((grad[(4, 0)] - Vec2::new(3, -1)).len() < 1e-12);
+    /// assert!((grad.sample(Vec2::new(0.3, 0.6)).unwrap() - Vec2::new(3, -1)).len() < 1e-12);
+    /// ```
+    pub fn gradient(&self) -> Field2<Vec2> {
+        self.map_nodes(Vec2::zero(), |i, j| {
+            let (dx, dy) = self.partials(i, j);
+            Vec2::new(dx, dy)
+        })
+    }
+}
+
+impl Field2<Vec2> {
+    /// Divergence of vector field by finite differences
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Rect, Vec2};
+    /// # use linal::field::Field2;
+    /// let bounds = Rect::new(Vec2::new(-1, -1), Vec2::new(1, 1));
+    /// // source at origin
+    /// let field = Field2::from_fn(bounds, 5, 5, |p| p);
+    /// assert!((field.divergence()[(2, 3)] - 2.0).abs() < 1e-12);
+    /// ```
+    pub fn divergence(&self) -> Field2<f64> {
+        self.map_nodes(0.0, |i, j| {
+            let (dx, dy) = self.partials(i, j);
+            dx.x + dy.y
+        })
+    }
+    /// Scalar curl $\partial_x v_y - \partial_y v_x$ of vector field by
+    /// finite differences
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Rect, Vec2};
+    /// # use linal::field::Field2;
+    /// let bounds = Rect::new(Vec2::new(-1, -1), Vec2::new(1, 1));
+    /// // counter-clockwise rotation
+    /// let field = Field2::from_fn(bounds, 5, 5, |p| Vec2::new(-p.y, p.x));
+    /// assert!((field.curl()[(0, 4)] - 2.0).abs() < 1e-12);
+    /// ```
+    pub fn curl(&self) -> Field2<f64> {
+        self.map_nodes(0.0, |i, j| {
+            let (dx, dy) = self.partials(i, j);
+            dx.y - dy.x
+        })
+    }
+}
+
+impl<T> Index<(usize, usize)> for Field2<T> {
+    type Output = T;
+    /// Value at node `(i, j)`
+    fn index(&self, (i, j): (usize, usize)) -> &T {
+        assert!(i < self.width && j < self.height, "node index out of range");
+        &self.samples[j * self.width + i]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Field2<T> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut T {
+        assert!(i < self.width && j < self.height, "node index out of range");
+        &mut self.samples[j * self.width + i]
+    }
+}
+
+/// Samples on regular grid over box
+///
+/// Same as [`Field2`](struct.Field2.html) in space, values are stored by
+/// layers of rows, so node `(i, j, k)` is at index
+/// `(k * height + j) * width + i`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Field3<T> {
+    bounds: Aabb3,
+    dims: (usize, usize, usize),
+    samples: Vec<T>,
+    out_of_bounds: OutOfBounds<T>,
+}
+
+impl<T: Copy + Add<Output = T> + Mul<f64, Output = T>> Field3<T> {
+    /// Constructs a new `Field3` with all nodes set to `value`
+    ///
+    /// Points outside of bounds are clamped by default.
+    ///
+    /// # Panics
+    /// Panics if any dimension is zero.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Aabb3, Vec3};
+    /// # use linal::field::Field3;
+    /// let field = Field3::new(Aabb3::new(Vec3::zero(), Vec3::new(1, 1, 1)), (2, 2, 2), Vec3::new(0, 0, 1));
+    /// assert_eq!(field.sample(Vec3::new(0.3, 0.4, 0.5)), Some(Vec3::new(0, 0, 1)));
+    /// ```
+    pub fn new(bounds: Aabb3, dims: (usize, usize, usize), value: T) -> Field3<T> {
+        assert!(dims.0 > 0 && dims.1 > 0 && dims.2 > 0, "field must have at least one node");
+        Field3 {
+            bounds,
+            dims,
+            samples: vec![value; dims.0 * dims.1 * dims.2],
+            out_of_bounds: OutOfBounds::Clamp,
+        }
+    }
+    /// Constructs a new `Field3` with values of function at nodes
+    ///
+    /// # Panics
+    /// Panics if any dimension is zero.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Aabb3, Vec3};
+    /// # use linal::field::Field3;
+    /// let bounds = Aabb3::new(Vec3::zero(), Vec3::new(2, 2, 2));
+    /// let field = Field3::from_fn(bounds, (3, 3, 3), |p| p * 2.0);
+    /// assert_eq!(field.sample(Vec3::new(0.5, 1.25, 1.5)), Some(Vec3::new(1.0, 2.5, 3.0)));
+    /// ```
+    pub fn from_fn<F: FnMut(Vec3) -> T>(bounds: Aabb3, dims: (usize, usize, usize), mut f: F) -> Field3<T> {
+        assert!(dims.0 > 0 && dims.1 > 0 && dims.2 > 0, "field must have at least one node");
+        let mut field = Field3 {
+            bounds,
+            dims,
+            samples: Vec::with_capacity(dims.0 * dims.1 * dims.2),
+            out_of_bounds: OutOfBounds::Clamp,
+        };
+        for k in 0..dims.2 {
+            for j in 0..dims.1 {
+                for i in 0..dims.0 {
+                    let p = field.node(i, j, k);
+                    field.samples.push(f(p));
+                }
+            }
+        }
+        field
+    }
+    /// Sets behaviour outside of bounds
+    pub fn with_out_of_bounds(mut self, out_of_bounds: OutOfBounds<T>) -> Field3<T> {
+        self.out_of_bounds = out_of_bounds;
+        self
+    }
+    /// Box covered by field
+    pub fn bounds(&self) -> Aabb3 {
+        self.bounds
+    }
+    /// Number of nodes along x, y and z axes
+    pub fn dims(&self) -> (usize, usize, usize) {
+        self.dims
+    }
+    /// Values at nodes
+    pub fn samples(&self) -> &[T] {
+        &self.samples
+    }
+    /// Position of node
+    pub fn node(&self, i: usize, j: usize, k: usize) -> Vec3 {
+        let t = |m: usize, n: usize| if n > 1 { m as f64 / (n - 1) as f64 } else { 0.0 };
+        let size = self.bounds.max - self.bounds.min;
+        self.bounds.min + Vec3::new(size.x * t(i, self.dims.0), size.y * t(j, self.dims.1),
+                                    size.z * t(k, self.dims.2))
+    }
+    /// Trilinear interpolation of field at point
+    ///
+    /// Outside of bounds behaviour is set by
+    /// [`with_out_of_bounds`](#method.with_out_of_bounds), only
+    /// `OutOfBounds::Reject` gives `None`.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Aabb3, Vec3};
+    /// # use linal::field::{Field3, OutOfBounds};
+    /// let bounds = Aabb3::new(Vec3::zero(), Vec3::new(1, 1, 1));
+    /// let field = Field3::from_fn(bounds, (2, 2, 2), |p| p.x * p.y * p.z)
+    ///     .with_out_of_bounds(OutOfBounds::Reject);
+    /// assert_eq!(field.sample(Vec3::new(0.5, 0.5, 0.5)), Some(0.125));
+    /// assert_eq!(field.sample(Vec3::new(0.5, 0.5, 1.5)), None);
+    /// ```
+    pub fn sample(&self, point: Vec3) -> Option<T> {
+        let (min, max) = (self.bounds.min, self.bounds.max);
+        let (nx, ny, nz) = self.dims;
+        let p = &self.out_of_bounds;
+        match (
+            locate(point.x, min.x, max.x, nx, p),
+            locate(point.y, min.y, max.y, ny, p),
+            locate(point.z, min.z, max.z, nz, p),
+        ) {
+            (Some((i, u)), Some((j, v)), Some((k, w))) => {
+                let (i1, j1, k1) = ((i + 1).min(nx - 1), (j + 1).min(ny - 1), (k + 1).min(nz - 1));
+                let layer = |k| {
+                    let bottom = lerp(self[(i, j, k)], self[(i1, j, k)], u);
+                    let top = lerp(self[(i, j1, k)], self[(i1, j1, k)], u);
+                    lerp(bottom, top, v)
+                };
+                Some(lerp(layer(k), layer(k1), w))
+            }
+            _ => match *p {
+                OutOfBounds::Value(value) => Some(value),
+                _ => None,
+            },
+        }
+    }
+    // field of same shape with values computed at nodes
+    fn map_nodes<U, F: FnMut(usize, usize, usize) -> U>(&self, zero: U, mut f: F) -> Field3<U> {
+        let mut samples = Vec::with_capacity(self.samples.len());
+        for k in 0..self.dims.2 {
+            for j in 0..self.dims.1 {
+                for i in 0..self.dims.0 {
+                    samples.push(f(i, j, k));
+                }
+            }
+        }
+        Field3 {
+            bounds: self.bounds,
+            dims: self.dims,
+            samples,
+            out_of_bounds: derived_policy(&self.out_of_bounds, zero),
+        }
+    }
+    // node spacing along axes
+    fn spacings(&self) -> (f64, f64, f64) {
+        let (min, max) = (self.bounds.min, self.bounds.max);
+        (
+            spacing(min.x, max.x, self.dims.0),
+            spacing(min.y, max.y, self.dims.1),
+            spacing(min.z, max.z, self.dims.2),
+        )
+    }
+    // partial derivatives along x, y and z at node
+    fn partials(&self, i: usize, j: usize, k: usize) -> (T, T, T) {
+        let (nx, ny, nz) = self.dims;
+        let (hx, hy, hz) = self.spacings();
+        (
+            derivative(nx, hx, i, |m| self[(m, j, k)]),
+            derivative(ny, hy, j, |m| self[(i, m, k)]),
+            derivative(nz, hz, k, |m| self[(i, j, m)]),
+        )
+    }
+    /// Laplacian by finite differences
+    ///
+    /// See [`Field2::laplacian`](struct.Field2.html#method.laplacian).
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Aabb3, Vec3};
+    /// # use linal::field::Field3;
+    /// let bounds = Aabb3::new(Vec3::zero(), Vec3::new(1, 1, 1));
+    /// let field = Field3::from_fn(bounds, (5, 5, 5), |p| p.dot(p));
+    /// assert!((field.laplacian()[(1, 4, 2)] - 6.0).abs() < 1e-9);
+    /// ```
+    pub fn laplacian(&self) -> Field3<T> {
+        let (nx, ny, nz) = self.dims;
+        let (hx, hy, hz) = self.spacings();
+        let zero = self.samples[0] * 0.0;
+        self.map_nodes(zero, |i, j, k| {
+            second_derivative(nx, hx, i, |m| self[(m, j, k)])
+                + second_derivative(ny, hy, j, |m| self[(i, m, k)])
+                + second_derivative(nz, hz, k, |m| self[(i, j, m)])
+        })
+    }
+}
+
+impl Field3<f64> {
+    /// Gradient of scalar field by finite differences
+    ///
+    /// Derivatives are central inside of grid and one-sided at border nodes.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Aabb3, Vec3};
+    /// # use linal::field::Field3;
+    /// let bounds = Aabb3::new(Vec3::zero(), Vec3::new(1, 1, 1));
+    /// let field = Field3::from_fn(bounds, (3, 3, 3), |p| p.dot(Vec3::new(1, 2, 3)));
+    /// assert!((field.gradient()[(0, 1, 2)] - Vec3::new(1, 2, 3)).len() < 1e-12);
+    /// ```
+    pub fn gradient(&self) -> Field3<Vec3> {
+        self.map_nodes(Vec3::zero(), |i, j, k| {
+            let (dx, dy, dz) = self.partials(i, j, k);
+            Vec3::new(dx, dy, dz)
+        })
+    }
+}
+
+impl Field3<Vec3> {
+    /// Divergence of vector field by finite differences
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Aabb3, Vec3};
+    /// # use linal::field::Field3;
+    /// let bounds = Aabb3::new(Vec3::zero(), Vec3::new(1, 1, 1));
+    /// let field = Field3::from_fn(bounds, (3, 3, 3), |p| p * 2.0);
+    /// assert!((field.divergence()[(1, 1, 1)] - 6.0).abs() < 1e-12);
+    /// ```
+    pub fn divergence(&self) -> Field3<f64> {
+        self.map_nodes(0.0, |i, j, k| {
+            let (dx, dy, dz) = self.partials(i, j, k);
+            dx.x + dy.y + dz.z
+        })
+    }
+    /// Curl of vector field by finite differences
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Aabb3, Vec3};
+    /// # use linal::field::Field3;
+    /// let bounds = Aabb3::new(Vec3::new(-1, -1, -1), Vec3::new(1, 1, 1));
+    /// // rotation around z axis
+    /// let field = Field3::from_fn(bounds, (3, 3, 3), |p| Vec3::new(-p.y, p.x, 0.0));
+    /// assert!((field.curl()[(2, 0, 1)] - Vec3::new(0, 0, 2)).len() < 1e-12);
+    /// ```
+    pub fn curl(&self) -> Field3<Vec3> {
+        self.map_nodes(Vec3::zero(), |i, j, k| {
+            let (dx, dy, dz) = self.partials(i, j, k);
+            Vec3::new(dy.z - dz.y, dz.x - dx.z, dx.y - dy.x)
+        })
+    }
+}
+
+impl<T> Index<(usize, usize, usize)> for Field3<T> {
+    type Output = T;
+    /// Value at node `(i, j, k)`
+    fn index(&self, (i, j, k): (usize, usize, usize)) -> &T {
+        let (nx, ny, nz) = self.dims;
+        assert!(i < nx && j < ny && k < nz, "node index out of range");
+        &self.samples[(k * ny + j) * nx + i]
+    }
+}
+
+impl<T> IndexMut<(usize, usize, usize)> for Field3<T> {
+    fn index_mut(&mut self, (i, j, k): (usize, usize, usize)) -> &mut T {
+        let (nx, ny, nz) = self.dims;
+        assert!(i < nx && j < ny && k < nz, "node index out of range");
+        &mut self.samples[(k * ny + j) * nx + i]
+    }
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    #[test]
+    fn field2_reproduces_bilinear() {
+        let bounds = Rect::new(Vec2::new(-1, 2), Vec2::new(3, 5));
+        let f = |p: Vec2| Vec2::new(2.0 * p.x - p.y + p.x * p.y, 3.0 - p.y);
+        let field = Field2::from_fn(bounds, 5, 4, f);
+        for &p in &[Vec2::new(0.3, 2.7), Vec2::new(-1, 5), Vec2::new(2.9, 4.1)] {
+            assert!((field.sample(p).unwrap() - f(p)).len() < 1e-12);
+        }
+        assert_eq!(field[(4, 3)], f(Vec2::new(3, 5)));
+    }
+
+    #[test]
+    fn field2_index_mut_and_wrap() {
+        let bounds = Rect::new(Vec2::zero(), Vec2::new(2, 2));
+        let mut field = Field2::new(bounds, 3, 3, 0.0).with_out_of_bounds(OutOfBounds::Wrap);
+        field[(1, 1)] = 4.0;
+        assert_eq!(field.sample(Vec2::new(1, 1)), Some(4.0));
+        assert_eq!(field.sample(Vec2::new(3, -1)), Some(4.0));
+        assert_eq!(field.sample(Vec2::new(0.5, 1.0)), Some(2.0));
+    }
+
+    #[test]
+    fn field3_reproduces_trilinear() {
+        let bounds = Aabb3::new(Vec3::new(0, 0, 0), Vec3::new(1, 2, 3));
+        let f = |p: Vec3| p.x * p.y * p.z + p.z - 2.0 * p.x;
+        let field = Field3::from_fn(bounds, (2, 3, 4), f);
+        let p = Vec3::new(0.25, 1.5, 2.2);
+        assert!((field.sample(p).unwrap() - f(p)).abs() < 1e-12);
+        assert_eq!(field.sample(Vec3::new(-5, 0, 0)), Some(f(Vec3::zero())));
+    }
+
+    #[test]
+    fn field2_operators_converge() {
+        let bounds = Rect::new(Vec2::zero(), Vec2::new(1, 1));
+        let f = |p: Vec2| (p.x * 2.0).sin() * p.y.cos();
+        let grad = |p: Vec2| Vec2::new(2.0 * (p.x * 2.0).cos() * p.y.cos(), -(p.x * 2.0).sin() * p.y.sin());
+        let error = |n: usize| {
+            let g = Field2::from_fn(bounds, n, n, f).gradient();
+            let p = g.node(n / 2, n / 4);
+            (g[(n / 2, n / 4)] - grad(p)).len()
+        };
+        // second order inside
+        assert!(error(41) < error(21) / 3.5);
+        let v = Field2::from_fn(bounds, 21, 21, grad);
+        let div = v.divergence();
+        let lap = Field2::from_fn(bounds, 21, 21, f).laplacian();
+        assert!((div[(10, 10)] - lap[(10, 10)]).abs() < 1e-2);
+        // gradient field has no curl
+        assert!(v.curl()[(10, 10)].abs() < 1e-2);
+    }
+
+    #[test]
+    fn field3_curl_of_gradient() {
+        let bounds = Aabb3::new(Vec3::zero(), Vec3::new(1, 2, 1));
+        let field = Field3::from_fn(bounds, (6, 7, 8), |p| p.x * p.y * p.y + p.z * p.x);
+        let grad = field.gradient();
+        let curl = grad.curl();
+        assert!(curl[(3, 3, 3)].len() < 1e-9);
+        assert_eq!(grad.dims(), field.dims());
+    }
+}
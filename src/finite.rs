@@ -0,0 +1,164 @@
+//! Vectors validated to have finite coordinates.
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+use std::ops::{Deref, Index};
+use vec2::Vec2;
+use vec3::Vec3;
+use vec4::Vec4;
+
+/// Vector with indexable coordinates
+pub trait Coordinates: Copy + Index<usize, Output = f64> {
+    /// number of coordinates
+    const DIM: usize;
+    /// Checks that all coordinates are neither NaN nor infinite
+    fn is_finite(&self) -> bool {
+        (0..Self::DIM).all(|i| self[i].is_finite())
+    }
+}
+
+impl Coordinates for Vec2 {
+    const DIM: usize = 2;
+}
+
+impl Coordinates for Vec3 {
+    const DIM: usize = 3;
+}
+
+impl Coordinates for Vec4 {
+    const DIM: usize = 4;
+}
+
+/// Vector with finite coordinates
+///
+/// Since NaN can't appear inside, wrapper is totally ordered (lexicographically
+/// by coordinates) and can be hashed, so it can be used as key of `HashMap` or
+/// `BTreeMap`. Negative and positive zeros are treated as equal.
+///
+/// # Example
+/// ```
+/// # use linal::{Finite, Vec2};
+/// # use std::collections::HashSet;
+/// let mut seen = HashSet::new();
+/// seen.insert(Finite::new(Vec2::new(1, 2)).unwrap());
+/// assert!(seen.contains(&Finite::new(Vec2::new(1, 2)).unwrap()));
+/// assert!(Finite::new(Vec2::new(1.0, f64::NAN)).is_none());
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Finite<T>(T);
+
+impl<T: Coordinates> Finite<T> {
+    /// Wraps vector, returns `None` if any coordinate is NaN or infinite
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Finite, Vec3};
+    /// let v = Finite::new(Vec3::new(1, 2, 3)).unwrap();
+    /// assert_eq!(v.z, 3.0);
+    /// assert!(Finite::new(Vec3::new(1.0, f64::INFINITY, 0.0)).is_none());
+    /// ```
+    pub fn new(v: T) -> Option<Finite<T>> {
+        if v.is_finite() {
+            Some(Finite(v))
+        } else {
+            None
+        }
+    }
+    /// Underlying vector
+    pub fn get(self) -> T {
+        self.0
+    }
+    // coordinate with negative zero replaced by positive one
+    fn coord(&self, i: usize) -> f64 {
+        self.0[i] + 0.0
+    }
+}
+
+impl<T: Coordinates> Deref for Finite<T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: Coordinates> PartialEq for Finite<T> {
+    fn eq(&self, other: &Finite<T>) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: Coordinates> Eq for Finite<T> {}
+
+impl<T: Coordinates> PartialOrd for Finite<T> {
+    fn partial_cmp(&self, other: &Finite<T>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Coordinates> Ord for Finite<T> {
+    /// Lexicographic order of coordinates
+    fn cmp(&self, other: &Finite<T>) -> Ordering {
+        (0..T::DIM)
+            .map(|i| self.coord(i).total_cmp(&other.coord(i)))
+            .find(|&o| o != Ordering::Equal)
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+impl<T: Coordinates> Hash for Finite<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for i in 0..T::DIM {
+            self.coord(i).to_bits().hash(state);
+        }
+    }
+}
+
+impl Vec2 {
+    /// Constructs a new `Vec2`, returns `None` if any coordinate is NaN or infinite
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Vec2;
+    /// assert_eq!(Vec2::finite(1, 2), Some(Vec2::new(1, 2)));
+    /// assert_eq!(Vec2::finite(f64::NAN, 2.0), None);
+    /// ```
+    pub fn finite<I: Into<f64>>(x: I, y: I) -> Option<Vec2> {
+        Finite::new(Vec2::new(x, y)).map(Finite::get)
+    }
+}
+
+impl Vec3 {
+    /// Constructs a new `Vec3`, returns `None` if any coordinate is NaN or infinite
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Vec3;
+    /// assert_eq!(Vec3::finite(1, 2, 3), Some(Vec3::new(1, 2, 3)));
+    /// assert_eq!(Vec3::finite(1.0, f64::NEG_INFINITY, 3.0), None);
+    /// ```
+    pub fn finite<I: Into<f64>>(x: I, y: I, z: I) -> Option<Vec3> {
+        Finite::new(Vec3::new(x, y, z)).map(Finite::get)
+    }
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+    use std::collections::{BTreeSet, HashSet};
+
+    #[test]
+    fn finite_order() {
+        let points = [Vec2::new(1, 2), Vec2::new(0, 5), Vec2::new(1, -1), Vec2::new(0, 5)];
+        let set: BTreeSet<Finite<Vec2>> = points.iter().filter_map(|&p| Finite::new(p)).collect();
+        let sorted: Vec<Vec2> = set.into_iter().map(Finite::get).collect();
+        assert_eq!(sorted, vec![Vec2::new(0, 5), Vec2::new(1, -1), Vec2::new(1, 2)]);
+    }
+
+    #[test]
+    fn finite_signed_zero() {
+        let a = Finite::new(Vec3::new(0.0, -0.0, 1.0)).unwrap();
+        let b = Finite::new(Vec3::new(-0.0, 0.0, 1.0)).unwrap();
+        assert_eq!(a, b);
+        let set: HashSet<Finite<Vec3>> = vec![a, b].into_iter().collect();
+        assert_eq!(set.len(), 1);
+    }
+}
@@ -0,0 +1,241 @@
+//! Least-squares fitting of geometric primitives to point sets.
+use vec2::Vec2;
+use vec3::Vec3;
+use line2::Line2;
+use plane::Plane;
+
+// relative variance treated as zero
+const DEGENERATE_EPS: f64 = 1e-12;
+
+/// Line fitting with total least squares (orthogonal regression)
+///
+/// Minimizes the sum of squared orthogonal distances from points to the line.
+/// Returns fitted line, passing through the centroid of points, and RMS of
+/// orthogonal residuals. Returns `None` for fewer than two distinct points.
+///
+/// # Example
+/// ```
+/// # use linal::Vec2;
+/// # use linal::fit::line_fit;
+/// let points = [Vec2::new(0, 1), Vec2::new(1, 3), Vec2::new(2, 5), Vec2::new(3, 7)];
+/// let (line, rms) = line_fit(&points).unwrap();
+/// assert!(rms < 1e-12);
+/// assert!(line.dir.area(Vec2::new(1, 2)).abs() < 1e-12);
+/// ```
+pub fn line_fit(points: &[Vec2]) -> Option<(Line2, f64)> {
+    let center = Vec2::centroid(points);
+    let (variances, (major, _)) = Vec2::principal_axes(points);
+    let scale = points.iter().fold(0.0, |acc: f64, p| acc.max(p.dot(*p)));
+    if points.len() < 2 || variances.x <= DEGENERATE_EPS * DEGENERATE_EPS * scale {
+        return None;
+    }
+    // variance along the minor axis is the mean squared residual
+    Some((Line2::new(center, major), variances.y.max(0.0).sqrt()))
+}
+
+/// Plane fitting with total least squares
+///
+/// Minimizes the sum of squared distances from points to the plane. Fitted
+/// plane passes through the centroid of points and its normal is the
+/// direction of the smallest variance of points. Returns fitted plane and RMS
+/// of residuals, or `None` if all points are collinear.
+///
+/// # Example
+/// ```
+/// # use linal::Vec3;
+/// # use linal::fit::plane_fit;
+/// let points = [Vec3::new(0, 0, 1), Vec3::new(1, 0, 1), Vec3::new(0, 1, 1), Vec3::new(1, 1, 1)];
+/// let (plane, rms) = plane_fit(&points).unwrap();
+/// assert!(rms < 1e-12);
+/// assert!((plane.normal.z.abs() - 1.0).abs() < 1e-12);
+/// assert!((plane.dist.abs() - 1.0).abs() < 1e-12);
+/// ```
+pub fn plane_fit(points: &[Vec3]) -> Option<(Plane, f64)> {
+    let center = Vec3::centroid(points);
+    let (variances, (_, _, normal)) = Vec3::principal_axes(points);
+    if points.len() < 3 || variances.y <= DEGENERATE_EPS * variances.x {
+        return None;
+    }
+    // variance along the normal is the mean squared residual
+    Some((Plane::new(normal, normal.dot(center)), variances.z.max(0.0).sqrt()))
+}
+
+/// Algebraic circle fitting (Kåsa method)
+///
+/// Minimizes the sum of squared algebraic distances $|\vec{p}_i - \vec{c}|^2 - r^2$,
+/// which reduces to a linear problem. Result is exact for points lying on a
+/// circle and is a good approximation for small noise, but it is biased
+/// towards smaller circles for points covering a short arc. Returns center and
+/// radius of circle, or `None` if all points are collinear.
+///
+/// # Example
+/// ```
+/// # use linal::Vec2;
+/// # use linal::fit::circle_fit;
+/// let points = [Vec2::new(3, 0), Vec2::new(1, 2), Vec2::new(-1, 0), Vec2::new(1, -2)];
+/// let (center, radius) = circle_fit(&points).unwrap();
+/// assert!((center - Vec2::new(1, 0)).len() < 1e-12);
+/// assert!((radius - 2.0).abs() < 1e-12);
+/// ```
+pub fn circle_fit(points: &[Vec2]) -> Option<(Vec2, f64)> {
+    if points.len() < 3 {
+        return None;
+    }
+    let n = points.len() as f64;
+    let c = Vec2::centroid(points);
+    let (mut suu, mut suv, mut svv, mut ru, mut rv) = (0.0, 0.0, 0.0, 0.0, 0.0);
+    for &p in points {
+        let d = p - c;
+        let r2 = d.dot(d);
+        suu += d.x * d.x;
+        suv += d.x * d.y;
+        svv += d.y * d.y;
+        ru += 0.5 * d.x * r2;
+        rv += 0.5 * d.y * r2;
+    }
+    let det = suu * svv - suv * suv;
+    if det <= DEGENERATE_EPS * (suu + svv) * (suu + svv) {
+        return None;
+    }
+    let offset = Vec2::new(ru * svv - rv * suv, rv * suu - ru * suv) / det;
+    let radius = (offset.dot(offset) + (suu + svv) / n).sqrt();
+    Some((c + offset, radius))
+}
+
+/// Geometric circle fitting
+///
+/// Minimizes the sum of squared geometric distances $|\vec{p}_i - \vec{c}| - r$.
+/// Starts from result of algebraic fit [`circle_fit`](fn.circle_fit.html) and
+/// performs at most `iterations` steps of Späth fixed-point iteration, stopping
+/// early when center converges. Returns center and radius of circle, or `None`
+/// if all points are collinear.
+///
+/// # Example
+/// ```
+/// # use linal::Vec2;
+/// # use linal::fit::circle_fit_refined;
+/// let points = [Vec2::new(2.1, 0.0), Vec2::new(0.0, 1.9), Vec2::new(-2.0, 0.1),
+///               Vec2::new(0.1, -2.0), Vec2::new(1.4, 1.5)];
+/// let (center, radius) = circle_fit_refined(&points, 100).unwrap();
+/// assert!(center.len() < 0.1);
+/// assert!((radius - 2.0).abs() < 0.1);
+/// ```
+pub fn circle_fit_refined(points: &[Vec2], iterations: usize) -> Option<(Vec2, f64)> {
+    let (mut center, mut radius) = circle_fit(points)?;
+    let mean = Vec2::centroid(points);
+    let n = points.len() as f64;
+    for _ in 0..iterations {
+        let (mut dist, mut dir) = (0.0, Vec2::zero());
+        for &p in points {
+            let d = p - center;
+            let len = d.len();
+            dist += len;
+            if len > 0.0 {
+                dir += d / len;
+            }
+        }
+        radius = dist / n;
+        let next = mean - dir * (radius / n);
+        let step = (next - center).len();
+        center = next;
+        if step <= DEGENERATE_EPS * radius {
+            break;
+        }
+    }
+    Some((center, radius))
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    #[test]
+    fn line_fit_noisy() {
+        let dir = Vec2::new(1, -1).ort();
+        let normal = dir.cross();
+        let points: Vec<Vec2> = (0..10)
+            .map(|i| Vec2::new(2, 3) + dir * i + normal * if i % 2 == 0 { 0.1 } else { -0.1 })
+            .collect();
+        let (line, rms) = line_fit(&points).unwrap();
+        assert!(rms > 0.09 && rms < 0.1);
+        assert!(line.dir.dot(dir).abs() > 0.999);
+        assert!((line.point - Vec2::new(2, 3)).dot(normal).abs() < 1e-12);
+    }
+
+    #[test]
+    fn line_fit_vertical() {
+        let points = [Vec2::new(5, -1), Vec2::new(5, 0), Vec2::new(5, 4)];
+        let (line, rms) = line_fit(&points).unwrap();
+        assert!(rms < 1e-12);
+        assert!((line.dir.y.abs() - 1.0).abs() < 1e-12);
+        assert!((line.point.x - 5.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn line_fit_degenerate() {
+        assert!(line_fit(&[]).is_none());
+        assert!(line_fit(&[Vec2::new(1, 2)]).is_none());
+        assert!(line_fit(&[Vec2::new(1, 2), Vec2::new(1, 2)]).is_none());
+        assert!(line_fit(&[Vec2::new(0.1, 0.7); 3]).is_none());
+        assert!(line_fit(&[Vec2::new(1e6 + 0.1, -3e5 + 0.7); 5]).is_none());
+    }
+
+    #[test]
+    fn plane_fit_noisy() {
+        let normal = Vec3::new(2, -1, 2).ort();
+        let u = normal.cross(Vec3::new(0, 0, 1)).ort();
+        let v = normal.cross(u);
+        let points: Vec<Vec3> = (0..5).flat_map(|i| (0..5).map(move |j| (i, j)))
+            .map(|(i, j)| {
+                let noise = if (i + j) % 2 == 0 { 0.01 } else { -0.01 };
+                u * i + v * j + normal * (3.0 + noise)
+            })
+            .collect();
+        let (plane, rms) = plane_fit(&points).unwrap();
+        assert!(rms > 0.009 && rms < 0.01);
+        assert!((plane.normal.dot(normal).abs() - 1.0).abs() < 1e-4);
+        assert!((plane.dist.abs() - 3.0).abs() < 1e-2);
+    }
+
+    #[test]
+    fn plane_fit_degenerate() {
+        assert!(plane_fit(&[]).is_none());
+        assert!(plane_fit(&[Vec3::new(1, 2, 3), Vec3::new(3, 2, 1)]).is_none());
+        let line: Vec<Vec3> = (0..5).map(|i| Vec3::new(1, 2, 3) * i).collect();
+        assert!(plane_fit(&line).is_none());
+    }
+
+    #[test]
+    fn circle_fit_exact() {
+        let center = Vec2::new(-3, 7);
+        let points: Vec<Vec2> = (0..7).map(|i| center + Vec2::from_polar(5.0, i as f64)).collect();
+        let (c, r) = circle_fit(&points).unwrap();
+        assert!((c - center).len() < 1e-10);
+        assert!((r - 5.0).abs() < 1e-10);
+        let (c, r) = circle_fit_refined(&points, 10).unwrap();
+        assert!((c - center).len() < 1e-10);
+        assert!((r - 5.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn circle_fit_short_arc() {
+        // noisy points on a short arc, algebraic fit underestimates radius
+        let points: Vec<Vec2> = (0..9).map(|i| {
+            let noise = if i % 2 == 0 { 0.05 } else { -0.05 };
+            Vec2::from_polar(10.0 + noise, 0.1 * i as f64)
+        }).collect();
+        let (c_alg, r_alg) = circle_fit(&points).unwrap();
+        let (c, r) = circle_fit_refined(&points, 1000).unwrap();
+        let residual = |c: Vec2, r: f64| points.iter().map(|&p| ((p - c).len() - r).powi(2)).sum::<f64>();
+        assert!(residual(c, r) <= residual(c_alg, r_alg));
+        assert!((r - 10.0).abs() < 1.0);
+    }
+
+    #[test]
+    fn circle_fit_degenerate() {
+        assert!(circle_fit(&[Vec2::new(0, 0), Vec2::new(1, 1)]).is_none());
+        let line: Vec<Vec2> = (0..5).map(|i| Vec2::new(1, 2) * i).collect();
+        assert!(circle_fit(&line).is_none());
+        assert!(circle_fit_refined(&line, 10).is_none());
+    }
+}
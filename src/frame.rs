@@ -0,0 +1,239 @@
+//! Vectors tagged with coordinate frame.
+//!
+//! Frames are marker types, usually empty enums. Tags are checked at compile
+//! time and cost nothing at runtime: vectors of different frames can't be
+//! added, and only [`FrameTransform`](struct.FrameTransform.html) moves
+//! vector from one frame to another.
+//!
+//! # Example
+//! ```
+//! # use linal::{Isometry3, Vec3};
+//! # use linal::frame::{FrameTransform, Tagged};
+//! enum World {}
+//! enum Body {}
+//!
+//! let body_to_world: FrameTransform<Body, World> =
+//!     FrameTransform::new(Isometry3::translation(Vec3::new(0, 0, 10)));
+//! let sensor: Tagged<Body, Vec3> = Tagged::new(Vec3::new(1, 0, 0));
+//! let target: Tagged<World, Vec3> = Tagged::new(Vec3::new(1, 2, 10));
+//! let offset = target - body_to_world.transform_point(sensor);
+//! assert_eq!(offset.get(), Vec3::new(0, 2, 0));
+//! // doesn't compile: frames differ
+//! // let wrong = target - sensor;
+//! ```
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use isometry3::Isometry3;
+use vec3::Vec3;
+
+/// Vector in coordinate frame `F`
+///
+/// Arithmetic is allowed only between vectors of the same frame.
+pub struct Tagged<F, T> {
+    value: T,
+    frame: PhantomData<fn() -> F>,
+}
+
+impl<F, T> Tagged<F, T> {
+    /// Tags value with frame
+    pub fn new(value: T) -> Tagged<F, T> {
+        Tagged { value, frame: PhantomData }
+    }
+    /// Reinterprets value in other frame without any conversion
+    ///
+    /// Use it when frames are known to coincide.
+    pub fn retag<G>(self) -> Tagged<G, T> {
+        Tagged::new(self.value)
+    }
+}
+
+impl<F, T: Copy> Tagged<F, T> {
+    /// Underlying value
+    pub fn get(self) -> T {
+        self.value
+    }
+}
+
+impl<F> Tagged<F, Vec3> {
+    /// Scalar product, which requires both vectors in the same frame
+    pub fn dot(self, rhs: Tagged<F, Vec3>) -> f64 {
+        self.value.dot(rhs.value)
+    }
+    /// Vector product, which requires both vectors in the same frame
+    pub fn cross(self, rhs: Tagged<F, Vec3>) -> Tagged<F, Vec3> {
+        Tagged::new(self.value.cross(rhs.value))
+    }
+    /// Length of vector, it doesn't depend on frame
+    pub fn len(self) -> f64 {
+        self.value.len()
+    }
+}
+
+impl<F, T: Clone> Clone for Tagged<F, T> {
+    fn clone(&self) -> Tagged<F, T> {
+        Tagged::new(self.value.clone())
+    }
+}
+
+impl<F, T: Copy> Copy for Tagged<F, T> {}
+
+impl<F, T: PartialEq> PartialEq for Tagged<F, T> {
+    fn eq(&self, other: &Tagged<F, T>) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<F, T: fmt::Debug> fmt::Debug for Tagged<F, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+impl<F, T: Add<Output = T>> Add for Tagged<F, T> {
+    type Output = Tagged<F, T>;
+    fn add(self, rhs: Tagged<F, T>) -> Tagged<F, T> {
+        Tagged::new(self.value + rhs.value)
+    }
+}
+
+impl<F, T: Sub<Output = T>> Sub for Tagged<F, T> {
+    type Output = Tagged<F, T>;
+    fn sub(self, rhs: Tagged<F, T>) -> Tagged<F, T> {
+        Tagged::new(self.value - rhs.value)
+    }
+}
+
+impl<F, T: AddAssign> AddAssign for Tagged<F, T> {
+    fn add_assign(&mut self, rhs: Tagged<F, T>) {
+        self.value += rhs.value;
+    }
+}
+
+impl<F, T: SubAssign> SubAssign for Tagged<F, T> {
+    fn sub_assign(&mut self, rhs: Tagged<F, T>) {
+        self.value -= rhs.value;
+    }
+}
+
+impl<F, T: Neg<Output = T>> Neg for Tagged<F, T> {
+    type Output = Tagged<F, T>;
+    fn neg(self) -> Tagged<F, T> {
+        Tagged::new(-self.value)
+    }
+}
+
+impl<F, T: Mul<f64, Output = T>> Mul<f64> for Tagged<F, T> {
+    type Output = Tagged<F, T>;
+    fn mul(self, rhs: f64) -> Tagged<F, T> {
+        Tagged::new(self.value * rhs)
+    }
+}
+
+/// Rigid motion taking coordinates in frame `A` to coordinates in frame `B`
+///
+/// Composition with `*` applies right operand first, so frames must chain:
+/// `FrameTransform<B, C> * FrameTransform<A, B>` is `FrameTransform<A, C>`.
+///
+/// # Example
+/// ```
+/// # use linal::{Isometry3, Mat3, Vec3};
+/// # use linal::frame::{FrameTransform, Tagged};
+/// enum World {}
+/// enum Body {}
+/// enum Camera {}
+///
+/// let camera_to_body: FrameTransform<Camera, Body> =
+///     FrameTransform::new(Isometry3::translation(Vec3::new(0, 0, 1)));
+/// let body_to_world: FrameTransform<Body, World> =
+///     FrameTransform::new(Isometry3::translation(Vec3::new(5, 0, 0)));
+/// let camera_to_world = body_to_world * camera_to_body;
+/// let p = camera_to_world.transform_point(Tagged::<Camera, _>::new(Vec3::zero()));
+/// assert_eq!(p.get(), Vec3::new(5, 0, 1));
+/// let back = camera_to_world.inverse().transform_point(p);
+/// assert_eq!(back.get(), Vec3::zero());
+/// ```
+pub struct FrameTransform<A, B> {
+    motion: Isometry3,
+    frames: PhantomData<fn(A) -> B>,
+}
+
+impl<A, B> FrameTransform<A, B> {
+    /// Tags motion with source and target frames
+    pub fn new(motion: Isometry3) -> FrameTransform<A, B> {
+        FrameTransform { motion, frames: PhantomData }
+    }
+    /// Identity transform between coinciding frames
+    pub fn identity() -> FrameTransform<A, B> {
+        FrameTransform::new(Isometry3::identity())
+    }
+    /// Underlying motion
+    pub fn motion(self) -> Isometry3 {
+        self.motion
+    }
+    /// Transform from frame `B` back to frame `A`
+    pub fn inverse(self) -> FrameTransform<B, A> {
+        FrameTransform::new(self.motion.inverse())
+    }
+    /// Coordinates of point in frame `B`
+    pub fn transform_point(self, p: Tagged<A, Vec3>) -> Tagged<B, Vec3> {
+        Tagged::new(self.motion.transform_point(p.value))
+    }
+    /// Coordinates of vector in frame `B`, translation doesn't affect it
+    pub fn transform_vector(self, v: Tagged<A, Vec3>) -> Tagged<B, Vec3> {
+        Tagged::new(self.motion.transform_vector(v.value))
+    }
+}
+
+impl<A, B> Clone for FrameTransform<A, B> {
+    fn clone(&self) -> FrameTransform<A, B> {
+        *self
+    }
+}
+
+impl<A, B> Copy for FrameTransform<A, B> {}
+
+impl<A, B> PartialEq for FrameTransform<A, B> {
+    fn eq(&self, other: &FrameTransform<A, B>) -> bool {
+        self.motion == other.motion
+    }
+}
+
+impl<A, B> fmt::Debug for FrameTransform<A, B> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.motion.fmt(f)
+    }
+}
+
+impl<A, B, C> Mul<FrameTransform<A, B>> for FrameTransform<B, C> {
+    type Output = FrameTransform<A, C>;
+    fn mul(self, rhs: FrameTransform<A, B>) -> FrameTransform<A, C> {
+        FrameTransform::new(self.motion * rhs.motion)
+    }
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+    use mat3::Mat3;
+
+    enum World {}
+    enum Body {}
+
+    #[test]
+    fn frame_vectors_and_points() {
+        let rotation = Mat3::rotation(Vec3::new(0, 0, 1), std::f64::consts::FRAC_PI_2);
+        let body_to_world: FrameTransform<Body, World> =
+            FrameTransform::new(Isometry3::new(rotation, Vec3::new(1, 1, 1)));
+        let forward: Tagged<Body, Vec3> = Tagged::new(Vec3::new(1, 0, 0));
+        let dir = body_to_world.transform_vector(forward);
+        let point = body_to_world.transform_point(forward);
+        assert!((dir.get() - Vec3::new(0, 1, 0)).len() < 1e-12);
+        assert!(((point - dir).get() - Vec3::new(1, 1, 1)).len() < 1e-12);
+        let mut sum = dir;
+        sum += dir * 2.0;
+        assert!((sum.len() - 3.0).abs() < 1e-12);
+        assert_eq!(forward.cross(forward).get(), Vec3::zero());
+        assert_eq!(std::mem::size_of::<Tagged<World, Vec3>>(), std::mem::size_of::<Vec3>());
+    }
+}
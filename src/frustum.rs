@@ -0,0 +1,248 @@
+//! View frustums for visibility culling.
+use vec3::Vec3;
+use vec4::Vec4;
+use mat4::Mat4;
+use plane::Plane;
+use aabb3::Aabb3;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+// boxes culled by one task of parallel culling
+#[cfg(feature = "rayon")]
+const PAR_CHUNK: usize = 1024;
+
+/// Convex volume bounded by six planes with normals directed inside
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Frustum {
+    /// bounding planes in order: left, right, bottom, top, near, far
+    pub planes: [Plane; 6],
+}
+
+impl Frustum {
+    /// Mask of all six planes, see [`Frustum::cull_masked`]
+    pub const ALL_PLANES: u8 = 0b11_1111;
+
+    /// Constructs a new `Frustum` from projection-view matrix.
+    ///
+    /// Matrix maps world points in homogeneous coordinates to clip space,
+    /// where visible points satisfy $-w \le x, y, z \le w$ (OpenGL
+    /// convention).
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Frustum, Mat4, Vec3, Vec4};
+    /// // orthographic projection of box [-1, 1]^3
+    /// let frustum = Frustum::from_matrix(Mat4::identity());
+    /// assert!(frustum.contains_point(Vec3::new(0.5, -0.5, 0.9)));
+    /// assert!(!frustum.contains_point(Vec3::new(0.5, -0.5, 1.1)));
+    /// ```
+    pub fn from_matrix(m: Mat4) -> Frustum {
+        let plane = |v: Vec4| Plane::new(Vec3::new(v.x, v.y, v.z), -v.w);
+        Frustum {
+            planes: [plane(m.w + m.x), plane(m.w - m.x),
+                     plane(m.w + m.y), plane(m.w - m.y),
+                     plane(m.w + m.z), plane(m.w - m.z)],
+        }
+    }
+    /// Checks if point lies inside frustum or on its boundary
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Frustum, Mat4, Vec3};
+    /// let frustum = Frustum::from_matrix(Mat4::identity());
+    /// assert!(frustum.contains_point(Vec3::new(1, 1, 1)));
+    /// assert!(!frustum.contains_point(Vec3::new(2, 0, 0)));
+    /// ```
+    pub fn contains_point(&self, point: Vec3) -> bool {
+        self.planes.iter().all(|p| p.signed_distance(point) >= 0.0)
+    }
+    /// Checks if sphere intersects frustum
+    ///
+    /// Test is conservative: spheres near edges of frustum can be reported
+    /// as intersecting while lying outside.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Frustum, Mat4, Vec3};
+    /// let frustum = Frustum::from_matrix(Mat4::identity());
+    /// assert!(frustum.intersects_sphere(Vec3::new(2, 0, 0), 1.5));
+    /// assert!(!frustum.intersects_sphere(Vec3::new(3, 0, 0), 1.5));
+    /// ```
+    pub fn intersects_sphere(&self, center: Vec3, radius: f64) -> bool {
+        self.planes.iter().all(|p| p.signed_distance(center) >= -radius)
+    }
+    /// Checks if axis-aligned box intersects frustum
+    ///
+    /// Test is conservative: boxes near edges of frustum can be reported as
+    /// intersecting while lying outside.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Aabb3, Frustum, Mat4, Vec3};
+    /// let frustum = Frustum::from_matrix(Mat4::identity());
+    /// let inside = Aabb3::new(Vec3::new(0.5, 0.5, 0.5), Vec3::new(3, 3, 3));
+    /// let outside = Aabb3::new(Vec3::new(1.5, 0.0, 0.0), Vec3::new(3, 3, 3));
+    /// assert!(frustum.intersects_aabb(inside));
+    /// assert!(!frustum.intersects_aabb(outside));
+    /// ```
+    pub fn intersects_aabb(&self, aabb: Aabb3) -> bool {
+        self.planes.iter().all(|p| {
+            p.signed_distance(p.split_corners(aabb).0) >= 0.0
+        })
+    }
+    /// Tests box against planes selected by `mask`, bit $i$ for `planes[i]`
+    ///
+    /// Returns `None` if box is outside of any tested plane, otherwise mask
+    /// of tested planes which box still crosses. Box lying inside some plane
+    /// lies inside it together with all its sub-boxes, so in hierarchies
+    /// children are tested only against mask returned for their parent.
+    /// Test is conservative like [`Frustum::intersects_aabb`].
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Aabb3, Frustum, Mat4, Vec3};
+    /// let frustum = Frustum::from_matrix(Mat4::identity());
+    /// let parent = Aabb3::new(Vec3::new(0, 0, 0), Vec3::new(2.0, 0.5, 0.5));
+    /// // only the right plane crosses parent
+    /// assert_eq!(frustum.cull_masked(parent, Frustum::ALL_PLANES), Some(0b10));
+    /// let child = Aabb3::new(Vec3::new(1.5, 0.0, 0.0), Vec3::new(2.0, 0.5, 0.5));
+    /// assert_eq!(frustum.cull_masked(child, 0b10), None);
+    /// ```
+    pub fn cull_masked(&self, aabb: Aabb3, mask: u8) -> Option<u8> {
+        let mut crossing = 0;
+        for (i, p) in self.planes.iter().enumerate().filter(|&(i, _)| mask & 1 << i != 0) {
+            let (far, near) = p.split_corners(aabb);
+            if p.signed_distance(far) < 0.0 {
+                return None;
+            }
+            if p.signed_distance(near) < 0.0 {
+                crossing |= 1 << i;
+            }
+        }
+        Some(crossing)
+    }
+    /// Visibility of many boxes, `true` for boxes intersecting frustum
+    ///
+    /// Equivalent to [`Frustum::intersects_aabb`] for each box, but plane
+    /// which rejected previous box is tested first, so runs of nearby
+    /// invisible boxes are mostly rejected by a single plane.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Aabb3, Frustum, Mat4, Vec3};
+    /// let frustum = Frustum::from_matrix(Mat4::identity());
+    /// let boxes: Vec<Aabb3> = (0..4)
+    ///     .map(|i| Aabb3::from_center(Vec3::new(i as f64, 0.0, 0.0), Vec3::new(0.2, 0.2, 0.2)))
+    ///     .collect();
+    /// assert_eq!(frustum.cull(&boxes), vec![true, true, false, false]);
+    /// ```
+    pub fn cull(&self, boxes: &[Aabb3]) -> Vec<bool> {
+        let mut last = 0;
+        boxes.iter().map(|&aabb| {
+            if self.outside(aabb, last) {
+                return false;
+            }
+            match (0..6).find(|&i| i != last && self.outside(aabb, i)) {
+                Some(i) => {
+                    last = i;
+                    false
+                }
+                None => true,
+            }
+        }).collect()
+    }
+    /// Parallel version of [`Frustum::cull`]
+    #[cfg(feature = "rayon")]
+    pub fn cull_par(&self, boxes: &[Aabb3]) -> Vec<bool> {
+        boxes.par_chunks(PAR_CHUNK).flat_map_iter(|chunk| self.cull(chunk)).collect()
+    }
+    // checks if box lies outside of plane with given index
+    fn outside(&self, aabb: Aabb3, plane: usize) -> bool {
+        let p = self.planes[plane];
+        p.signed_distance(p.split_corners(aabb).0) < 0.0
+    }
+}
+
+impl Plane {
+    // corners of box farthest along normal and against it
+    fn split_corners(self, aabb: Aabb3) -> (Vec3, Vec3) {
+        let n = self.normal;
+        let pick = |positive: bool, min: f64, max: f64| if positive { max } else { min };
+        (Vec3::new(pick(n.x >= 0.0, aabb.min.x, aabb.max.x),
+                   pick(n.y >= 0.0, aabb.min.y, aabb.max.y),
+                   pick(n.z >= 0.0, aabb.min.z, aabb.max.z)),
+         Vec3::new(pick(n.x < 0.0, aabb.min.x, aabb.max.x),
+                   pick(n.y < 0.0, aabb.min.y, aabb.max.y),
+                   pick(n.z < 0.0, aabb.min.z, aabb.max.z)))
+    }
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    // perspective projection with 90 degrees field of view, camera at origin
+    // looking along negative z axis
+    fn perspective(near: f64, far: f64) -> Mat4 {
+        Mat4::new(Vec4::new(1, 0, 0, 0),
+                  Vec4::new(0, 1, 0, 0),
+                  Vec4::new(0.0, 0.0, (far + near) / (near - far), 2.0 * far * near / (near - far)),
+                  Vec4::new(0, 0, -1, 0))
+    }
+
+    #[test]
+    fn frustum_perspective_planes() {
+        let frustum = Frustum::from_matrix(perspective(1.0, 10.0));
+        let near = frustum.planes[4];
+        let far = frustum.planes[5];
+        assert!((near.normal - Vec3::new(0, 0, -1)).len() < 1e-12);
+        assert!((near.signed_distance(Vec3::zero()) + 1.0).abs() < 1e-12);
+        assert!((far.normal - Vec3::new(0, 0, 1)).len() < 1e-12);
+        assert!((far.signed_distance(Vec3::zero()) - 10.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn frustum_contains_point() {
+        let frustum = Frustum::from_matrix(perspective(1.0, 10.0));
+        assert!(frustum.contains_point(Vec3::new(0, 0, -5)));
+        assert!(frustum.contains_point(Vec3::new(4.9, -4.9, -5.0)));
+        assert!(!frustum.contains_point(Vec3::new(5.1, 0.0, -5.0)));
+        assert!(!frustum.contains_point(Vec3::new(0.0, 0.0, -0.5)));
+        assert!(!frustum.contains_point(Vec3::new(0, 0, -11)));
+        assert!(!frustum.contains_point(Vec3::new(0, 0, 5)));
+    }
+
+    #[test]
+    fn frustum_intersects() {
+        let frustum = Frustum::from_matrix(perspective(1.0, 10.0));
+        assert!(frustum.intersects_sphere(Vec3::new(0, 0, -11), 1.5));
+        assert!(!frustum.intersects_sphere(Vec3::new(0, 0, 2), 1.5));
+        let behind = Aabb3::new(Vec3::new(-1, -1, 1), Vec3::new(1, 1, 2));
+        let crossing = Aabb3::new(Vec3::new(-100, -1, -3), Vec3::new(100, 1, -2));
+        assert!(!frustum.intersects_aabb(behind));
+        assert!(frustum.intersects_aabb(crossing));
+    }
+
+    #[test]
+    fn frustum_cull() {
+        let frustum = Frustum::from_matrix(perspective(1.0, 10.0));
+        let boxes: Vec<Aabb3> = (0..400)
+            .map(|i| {
+                let c = Vec3::new((i * 37 % 101) as f64 / 4.0 - 12.0, (i * 53 % 97) as f64 / 8.0 - 6.0,
+                                  -((i * 11 % 13) as f64));
+                Aabb3::from_center(c, Vec3::new(0.5, 0.25, 0.75))
+            })
+            .collect();
+        let expected: Vec<bool> = boxes.iter().map(|&b| frustum.intersects_aabb(b)).collect();
+        assert!(expected.iter().any(|&v| v) && expected.iter().any(|&v| !v));
+        assert_eq!(frustum.cull(&boxes), expected);
+        #[cfg(feature = "rayon")]
+        assert_eq!(frustum.cull_par(&boxes), expected);
+        for (&b, &visible) in boxes.iter().zip(expected.iter()) {
+            let mask = frustum.cull_masked(b, Frustum::ALL_PLANES);
+            assert_eq!(mask.is_some(), visible);
+            // remaining planes decide visibility of the same box
+            assert_eq!(mask.and_then(|m| frustum.cull_masked(b, m)), mask);
+        }
+    }
+}
@@ -0,0 +1,426 @@
+//! Proximity queries for convex shapes on a plane.
+//!
+//! Gilbert–Johnson–Keerthi algorithm finds distance and closest points of
+//! separated shapes, expanding polytope algorithm (EPA) finds penetration of
+//! overlapping ones. Both work with Minkowski difference of shapes, which is
+//! sampled through [`Support`](trait.Support.html) mapping, so any convex
+//! shape can take part in queries.
+use circle::Circle;
+use obb::Obb;
+use polygon::Polygon;
+use rect::Rect;
+use segment2::Segment2;
+use triangle2::Triangle2;
+use vec2::Vec2;
+
+// limit of iterations for both algorithms, curved shapes converge gradually
+const MAX_ITERATIONS: usize = 100;
+// relative tolerance of distance
+const TOLERANCE: f64 = 1e-10;
+
+/// Convex shape given by support mapping
+pub trait Support {
+    /// Point of shape farthest along direction `dir`
+    ///
+    /// Direction doesn't have to be unit, but may be zero, in which case any
+    /// point of shape is returned.
+    fn support(&self, dir: Vec2) -> Vec2;
+}
+
+// farthest point of set along direction
+fn farthest(points: &[Vec2], dir: Vec2) -> Vec2 {
+    points
+        .iter()
+        .copied()
+        .max_by(|p, q| p.dot(dir).total_cmp(&q.dot(dir)))
+        .expect("support of empty point set")
+}
+
+impl Support for Vec2 {
+    fn support(&self, _dir: Vec2) -> Vec2 {
+        *self
+    }
+}
+
+/// Point cloud, which stands for its convex hull
+///
+/// # Panics
+/// Support of empty slice panics.
+impl Support for [Vec2] {
+    fn support(&self, dir: Vec2) -> Vec2 {
+        farthest(self, dir)
+    }
+}
+
+/// Convex polygon, non-convex one stands for its convex hull
+///
+/// # Panics
+/// Support of polygon without vertices panics.
+impl Support for Polygon {
+    fn support(&self, dir: Vec2) -> Vec2 {
+        farthest(&self.vertices, dir)
+    }
+}
+
+impl Support for Segment2 {
+    fn support(&self, dir: Vec2) -> Vec2 {
+        farthest(&[self.a, self.b], dir)
+    }
+}
+
+impl Support for Triangle2 {
+    fn support(&self, dir: Vec2) -> Vec2 {
+        farthest(&[self.a, self.b, self.c], dir)
+    }
+}
+
+impl Support for Circle {
+    fn support(&self, dir: Vec2) -> Vec2 {
+        let len = dir.len();
+        if len > 0.0 {
+            self.center + dir * (self.radius / len)
+        } else {
+            self.center
+        }
+    }
+}
+
+impl Support for Rect {
+    fn support(&self, dir: Vec2) -> Vec2 {
+        Vec2::new(
+            if dir.x < 0.0 { self.min.x } else { self.max.x },
+            if dir.y < 0.0 { self.min.y } else { self.max.y },
+        )
+    }
+}
+
+impl Support for Obb {
+    fn support(&self, dir: Vec2) -> Vec2 {
+        let (u, v) = self.axes();
+        let side = |axis: Vec2, half: f64| if axis.dot(dir) < 0.0 { -half } else { half };
+        self.center + u * side(u, self.half_extents.x) + v * side(v, self.half_extents.y)
+    }
+}
+
+/// Distance and closest points of separated shapes
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Proximity {
+    /// distance between shapes
+    pub distance: f64,
+    /// point of first shape closest to second one
+    pub point_a: Vec2,
+    /// point of second shape closest to first one
+    pub point_b: Vec2,
+}
+
+/// Penetration of overlapping shapes
+///
+/// Moving first shape by `-normal * depth` (or second one by
+/// `normal * depth`) makes shapes touch, no shorter translation does it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Penetration {
+    /// penetration depth
+    pub depth: f64,
+    /// unit direction from first shape to second one
+    pub normal: Vec2,
+    /// point of first shape deepest inside second one
+    pub point_a: Vec2,
+    /// point of second shape deepest inside first one
+    pub point_b: Vec2,
+}
+
+// point of Minkowski difference with points of shapes it came from
+#[derive(Debug, Clone, Copy)]
+struct Vertex {
+    w: Vec2,
+    a: Vec2,
+    b: Vec2,
+}
+
+fn support<A, B>(a: &A, b: &B, dir: Vec2) -> Vertex
+where
+    A: Support + ?Sized,
+    B: Support + ?Sized,
+{
+    let pa = a.support(dir);
+    let pb = b.support(-dir);
+    Vertex { w: pa - pb, a: pa, b: pb }
+}
+
+// parameter of point of segment closest to origin
+fn segment_param(p: Vec2, q: Vec2) -> f64 {
+    let d = q - p;
+    let len2 = d.dot(d);
+    if len2 == 0.0 {
+        0.0
+    } else {
+        (-p.dot(d) / len2).clamp(0.0, 1.0)
+    }
+}
+
+// reduces simplex to smallest subset containing point closest to origin,
+// returns barycentric weights of that point or `None` if origin is inside
+fn reduce(simplex: &mut Vec<Vertex>) -> Option<Vec<f64>> {
+    if simplex.len() == 3 {
+        let (p, q, r) = (simplex[0].w, simplex[1].w, simplex[2].w);
+        let area = (q - p).area(r - p);
+        if area != 0.0 {
+            let inside = [(p, q), (q, r), (r, p)]
+                .iter()
+                .all(|&(s, e)| area * (e - s).area(-s) >= 0.0);
+            if inside {
+                return None;
+            }
+        }
+        let edge = [(0, 1), (1, 2), (2, 0)]
+            .iter()
+            .map(|&(i, j)| {
+                let (s, e) = (simplex[i].w, simplex[j].w);
+                let c = s + (e - s) * segment_param(s, e);
+                (c.dot(c), i, j)
+            })
+            .min_by(|x, y| x.0.total_cmp(&y.0))
+            .unwrap();
+        *simplex = vec![simplex[edge.1], simplex[edge.2]];
+    }
+    if simplex.len() == 2 {
+        let t = segment_param(simplex[0].w, simplex[1].w);
+        if t <= 0.0 {
+            simplex.truncate(1);
+        } else if t >= 1.0 {
+            simplex.remove(0);
+        } else {
+            return Some(vec![1.0 - t, t]);
+        }
+    }
+    Some(vec![1.0])
+}
+
+// combination of simplex vertices with weights
+fn combine(simplex: &[Vertex], weights: &[f64]) -> Vertex {
+    simplex.iter().zip(weights).fold(
+        Vertex { w: Vec2::zero(), a: Vec2::zero(), b: Vec2::zero() },
+        |acc, (v, &k)| Vertex { w: acc.w + v.w * k, a: acc.a + v.a * k, b: acc.b + v.b * k },
+    )
+}
+
+// either closest points, or simplex containing origin with last direction
+fn gjk<A, B>(a: &A, b: &B) -> Result<Proximity, (Vec<Vertex>, Vec2)>
+where
+    A: Support + ?Sized,
+    B: Support + ?Sized,
+{
+    let mut dir = Vec2::new(1, 0);
+    let mut simplex = vec![support(a, b, dir)];
+    let mut weights = vec![1.0];
+    for _ in 0..MAX_ITERATIONS {
+        let v = combine(&simplex, &weights);
+        let vv = v.w.dot(v.w);
+        let scale = simplex.iter().map(|s| s.w.dot(s.w)).fold(0.0, f64::max);
+        if vv <= f64::EPSILON * scale {
+            return Err((simplex, dir));
+        }
+        dir = -v.w;
+        let w = support(a, b, dir);
+        if vv - v.w.dot(w.w) <= TOLERANCE * vv || simplex.iter().any(|s| s.w == w.w) {
+            break;
+        }
+        simplex.push(w);
+        match reduce(&mut simplex) {
+            Some(k) => weights = k,
+            None => return Err((simplex, dir)),
+        }
+    }
+    let v = combine(&simplex, &weights);
+    Ok(Proximity {
+        distance: v.w.len(),
+        point_a: v.a,
+        point_b: v.b,
+    })
+}
+
+/// Distance and closest points of convex shapes
+///
+/// Returns `None` if shapes overlap or touch.
+///
+/// # Example
+/// ```
+/// # use linal::{Circle, Rect, Vec2};
+/// # use linal::gjk::distance;
+/// let rect = Rect::new(Vec2::new(0, 0), Vec2::new(2, 2));
+/// let circle = Circle::new(Vec2::new(5, 1), 1);
+/// let p = distance(&rect, &circle).unwrap();
+/// assert!((p.distance - 2.0).abs() < 1e-9);
+/// assert!((p.point_a - Vec2::new(2, 1)).len() < 1e-6);
+/// assert!((p.point_b - Vec2::new(4, 1)).len() < 1e-6);
+/// assert!(distance(&rect, &Vec2::new(1, 1)).is_none());
+/// ```
+pub fn distance<A, B>(a: &A, b: &B) -> Option<Proximity>
+where
+    A: Support + ?Sized,
+    B: Support + ?Sized,
+{
+    gjk(a, b).ok()
+}
+
+/// Checks if convex shapes overlap or touch
+///
+/// # Example
+/// ```
+/// # use linal::{Segment2, Triangle2, Vec2};
+/// # use linal::gjk::intersects;
+/// let t = Triangle2::new(Vec2::new(0, 0), Vec2::new(4, 0), Vec2::new(0, 4));
+/// let s = Segment2::new(Vec2::new(1, 1), Vec2::new(5, 5));
+/// assert!(intersects(&t, &s));
+/// assert!(!intersects(&t, &Segment2::new(Vec2::new(3, 3), Vec2::new(5, 5))));
+/// ```
+pub fn intersects<A, B>(a: &A, b: &B) -> bool
+where
+    A: Support + ?Sized,
+    B: Support + ?Sized,
+{
+    gjk(a, b).is_err()
+}
+
+/// Penetration depth and direction of overlapping convex shapes
+///
+/// Returns `None` if shapes are separated, touching shapes give zero depth.
+///
+/// # Example
+/// ```
+/// # use linal::{Circle, Vec2};
+/// # use linal::gjk::penetration;
+/// let points = [Vec2::new(0, 0), Vec2::new(4, 0), Vec2::new(4, 4), Vec2::new(0, 4)];
+/// let circle = Circle::new(Vec2::new(5, 2), 2);
+/// let p = penetration(&points[..], &circle).unwrap();
+/// assert!((p.depth - 1.0).abs() < 1e-6);
+/// assert!((p.normal - Vec2::new(1, 0)).len() < 1e-6);
+/// assert!(penetration(&points[..], &Circle::new(Vec2::new(9, 2), 2)).is_none());
+/// ```
+pub fn penetration<A, B>(a: &A, b: &B) -> Option<Penetration>
+where
+    A: Support + ?Sized,
+    B: Support + ?Sized,
+{
+    let (mut poly, dir) = gjk(a, b).err()?;
+    let touching = |v: Vertex, normal: Vec2| Penetration {
+        depth: 0.0,
+        normal: normal.ort(),
+        point_a: v.a,
+        point_b: v.b,
+    };
+    // origin lies on boundary of Minkowski difference with outward normal `dir`
+    if poly.len() == 1 {
+        return Some(touching(poly[0], dir));
+    }
+    if poly.len() == 2 {
+        let (p, q) = (poly[0], poly[1]);
+        let edge = q.w - p.w;
+        let normal = if edge.cross().dot(dir) < 0.0 { -edge.cross() } else { edge.cross() };
+        let t = segment_param(p.w, q.w);
+        let closest = combine(&poly, &[1.0 - t, t]);
+        let w = [support(a, b, -normal), support(a, b, normal)]
+            .iter()
+            .copied()
+            .max_by(|x, y| edge.area(x.w - p.w).abs().total_cmp(&edge.area(y.w - p.w).abs()))
+            .unwrap();
+        if edge.area(w.w - p.w) == 0.0 {
+            // Minkowski difference is flat
+            return Some(touching(closest, normal));
+        }
+        poly.push(w);
+    }
+    // counter-clockwise polytope
+    if (poly[1].w - poly[0].w).area(poly[2].w - poly[0].w) < 0.0 {
+        poly.swap(1, 2);
+    }
+    let mut best = None;
+    for _ in 0..MAX_ITERATIONS {
+        let n = poly.len();
+        let (i, normal, depth) = (0..n)
+            .filter_map(|i| {
+                let e = poly[(i + 1) % n].w - poly[i].w;
+                let len = e.len();
+                if len > 0.0 {
+                    let normal = Vec2::new(e.y, -e.x) / len;
+                    Some((i, normal, normal.dot(poly[i].w)))
+                } else {
+                    None
+                }
+            })
+            .min_by(|x, y| x.2.total_cmp(&y.2))?;
+        best = Some((i, normal, depth));
+        let w = support(a, b, normal);
+        if w.w.dot(normal) - depth <= TOLERANCE * w.w.len().max(depth) {
+            break;
+        }
+        poly.insert(i + 1, w);
+    }
+    let (i, normal, depth) = best?;
+    let (p, q) = (poly[i], poly[(i + 1) % poly.len()]);
+    let t = segment_param(p.w, q.w);
+    let v = combine(&[p, q], &[1.0 - t, t]);
+    Some(Penetration {
+        depth: depth.max(0.0),
+        normal,
+        point_a: v.a,
+        point_b: v.b,
+    })
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+    use sat;
+
+    #[test]
+    fn gjk_polygons_distance() {
+        let a = Polygon::new(vec![Vec2::new(0, 0), Vec2::new(2, 0), Vec2::new(1, 2)]);
+        let b = Polygon::new(vec![Vec2::new(4, 1), Vec2::new(6, 1), Vec2::new(6, 3), Vec2::new(4, 3)]);
+        let p = distance(&a, &b).unwrap();
+        let expected = Segment2::new(Vec2::new(2, 0), Vec2::new(1, 2)).distance_to(Vec2::new(4, 1));
+        assert!((p.distance - expected).abs() < 1e-12);
+        assert!((p.point_b - Vec2::new(4, 1)).len() < 1e-12);
+        assert!(((p.point_b - p.point_a).len() - p.distance).abs() < 1e-12);
+        assert!(!intersects(&a, &b));
+    }
+
+    #[test]
+    fn gjk_circles() {
+        let a = Circle::new(Vec2::new(0, 0), 1);
+        let b = Circle::new(Vec2::new(3, 4), 2);
+        let p = distance(&a, &b).unwrap();
+        assert!((p.distance - 2.0).abs() < 1e-9);
+        assert!((p.point_a - Vec2::new(0.6, 0.8)).len() < 1e-4);
+        let c = Circle::new(Vec2::new(2, 0), 1.5);
+        let pen = penetration(&a, &c).unwrap();
+        assert!((pen.depth - 0.5).abs() < 1e-6);
+        assert!((pen.normal - Vec2::new(1, 0)).len() < 1e-3);
+    }
+
+    #[test]
+    fn epa_matches_sat() {
+        let a = Obb::new(Vec2::new(0.3, 0.1), Vec2::new(2, 1), Vec2::new(1.5, 0.5));
+        let b = Obb::new(Vec2::new(1.2, 1.0), Vec2::new(-1, 3), Vec2::new(1.0, 0.7));
+        let mtv = sat::overlap_obbs(a, b).unwrap();
+        let pen = penetration(&a, &b).unwrap();
+        assert!((pen.normal * -pen.depth - mtv).len() < 1e-9);
+        assert!((pen.point_a - pen.point_b - pen.normal * pen.depth).len() < 1e-9);
+        assert!(distance(&a, &b).is_none());
+    }
+
+    #[test]
+    fn gjk_touching() {
+        let a = Rect::new(Vec2::new(0, 0), Vec2::new(1, 1));
+        let b = Rect::new(Vec2::new(1, 0), Vec2::new(2, 1));
+        assert!(intersects(&a, &b));
+        let pen = penetration(&a, &b).unwrap();
+        assert_eq!(pen.depth, 0.0);
+        let corner = Vec2::new(1, 1);
+        let pen = penetration(&a, &corner).unwrap();
+        assert_eq!(pen.depth, 0.0);
+        let s = Segment2::new(Vec2::new(0, 2), Vec2::new(3, 2));
+        let p = distance(&s, &Vec2::new(1, 0)).unwrap();
+        assert_eq!((p.distance, p.point_a), (2.0, Vec2::new(1, 2)));
+    }
+}
@@ -0,0 +1,321 @@
+//! Regular grids: nodes of grids and cells crossed by segments.
+use aabb3::Aabb3;
+use ray3::Ray3;
+use rect::Rect;
+use vec2::Vec2;
+use vec3::Vec3;
+
+// coordinate of node `i` of `n` along axis
+fn node(min: f64, max: f64, i: usize, n: usize) -> f64 {
+    if n > 1 {
+        let t = i as f64 / (n - 1) as f64;
+        min * (1.0 - t) + max * t
+    } else {
+        min
+    }
+}
+
+/// Nodes of regular grid over rectangle
+///
+/// Yields `nx * ny` points by rows, i.e. x changes fastest. Nodes include
+/// borders of rectangle, axis with single node is placed at `rect.min`.
+///
+/// # Example
+/// ```
+/// # use linal::{Rect, Vec2};
+/// # use linal::grid::meshgrid;
+/// let rect = Rect::new(Vec2::new(0, 0), Vec2::new(2, 1));
+/// let points: Vec<Vec2> = meshgrid(rect, 3, 2).collect();
+/// assert_eq!(points.len(), 6);
+/// assert_eq!(points[1], Vec2::new(1, 0));
+/// assert_eq!(points[3], Vec2::new(0, 1));
+/// assert_eq!(points[5], Vec2::new(2, 1));
+/// ```
+pub fn meshgrid(rect: Rect, nx: usize, ny: usize) -> impl Iterator<Item = Vec2> {
+    (0..ny).flat_map(move |j| {
+        let y = node(rect.min.y, rect.max.y, j, ny);
+        (0..nx).map(move |i| Vec2::new(node(rect.min.x, rect.max.x, i, nx), y))
+    })
+}
+
+/// Nodes of regular grid over box
+///
+/// Yields `nx * ny * nz` points by layers of rows, i.e. x changes fastest
+/// and z slowest.
+///
+/// # Example
+/// ```
+/// # use linal::{Aabb3, Vec3};
+/// # use linal::grid::meshgrid3;
+/// let aabb = Aabb3::new(Vec3::zero(), Vec3::new(1, 1, 1));
+/// let points: Vec<Vec3> = meshgrid3(aabb, 2, 2, 2).collect();
+/// assert_eq!(points.len(), 8);
+/// assert_eq!(points[2], Vec3::new(0, 1, 0));
+/// assert_eq!(points[4], Vec3::new(0, 0, 1));
+/// ```
+pub fn meshgrid3(aabb: Aabb3, nx: usize, ny: usize, nz: usize) -> impl Iterator<Item = Vec3> {
+    (0..nz).flat_map(move |k| {
+        let z = node(aabb.min.z, aabb.max.z, k, nz);
+        (0..ny).flat_map(move |j| {
+            let y = node(aabb.min.y, aabb.max.y, j, ny);
+            (0..nx).map(move |i| Vec3::new(node(aabb.min.x, aabb.max.x, i, nx), y, z))
+        })
+    })
+}
+
+// cell of grid with given size containing point
+fn cell(p: Vec2, cell_size: f64) -> (i64, i64) {
+    p.to_cell(Vec2::new(cell_size, cell_size))
+}
+
+fn checked_size<I: Into<f64>>(cell_size: I) -> f64 {
+    let cell_size = cell_size.into();
+    assert!(cell_size > 0.0 && cell_size.is_finite(), "cell size must be positive");
+    cell_size
+}
+
+/// Cells of square grid touched by segment (supercover)
+///
+/// Cell $(i, j)$ covers $[i s, (i + 1) s) \times [j s, (j + 1) s)$, where
+/// $s$ is `cell_size`, same as [`SpatialHash`](../spatial/struct.SpatialHash.html).
+/// Cells are yielded in order along segment from `a` to `b`. When segment
+/// passes exactly through a corner of cells, both cells sharing that corner
+/// with the path are yielded before the diagonal one.
+///
+/// # Panics
+/// Panics if `cell_size` is not positive and finite.
+///
+/// # Example
+/// ```
+/// # use linal::Vec2;
+/// # use linal::grid::line_cells;
+/// let cells: Vec<(i64, i64)> = line_cells(Vec2::new(0.5, 0.5), Vec2::new(2.5, 1.5), 1).collect();
+/// assert_eq!(cells, vec![(0, 0), (1, 0), (1, 1), (2, 1)]);
+/// let diagonal: Vec<(i64, i64)> = line_cells(Vec2::new(0.5, 0.5), Vec2::new(1.5, 1.5), 1).collect();
+/// assert_eq!(diagonal, vec![(0, 0), (1, 0), (0, 1), (1, 1)]);
+/// ```
+pub fn line_cells<I: Into<f64>>(a: Vec2, b: Vec2, cell_size: I) -> impl Iterator<Item = (i64, i64)> {
+    let s = checked_size(cell_size);
+    let (mut x, mut y) = cell(a, s);
+    let (end_x, end_y) = cell(b, s);
+    let d = b - a;
+    // parameters of the next crossing of vertical and horizontal grid lines
+    let axis = |d: f64, a: f64, c: i64| -> (i64, f64, f64) {
+        if d > 0.0 {
+            (1, ((c + 1) as f64 * s - a) / d, s / d)
+        } else if d < 0.0 {
+            (-1, (c as f64 * s - a) / d, -s / d)
+        } else {
+            (0, f64::INFINITY, f64::INFINITY)
+        }
+    };
+    let (step_x, mut t_x, dt_x) = axis(d.x, a.x, x);
+    let (step_y, mut t_y, dt_y) = axis(d.y, a.y, y);
+    let (mut left_x, mut left_y) = ((end_x - x).abs(), (end_y - y).abs());
+    let mut pending: Vec<(i64, i64)> = vec![(x, y)];
+    ::std::iter::from_fn(move || {
+        if let Some(c) = pending.pop() {
+            return Some(c);
+        }
+        if left_x == 0 && left_y == 0 {
+            return None;
+        }
+        if left_y == 0 || (left_x > 0 && t_x < t_y) {
+            x += step_x;
+            t_x += dt_x;
+            left_x -= 1;
+        } else if left_x == 0 || t_y < t_x {
+            y += step_y;
+            t_y += dt_y;
+            left_y -= 1;
+        } else {
+            // through the corner, both side cells are touched at a point
+            pending.push((x + step_x, y + step_y));
+            pending.push((x, y + step_y));
+            x += step_x;
+            y += step_y;
+            t_x += dt_x;
+            t_y += dt_y;
+            left_x -= 1;
+            left_y -= 1;
+            return Some((x, y - step_y));
+        }
+        Some((x, y))
+    })
+}
+
+/// Cells of square grid along segment drawn by Bresenham's algorithm
+///
+/// Thin 8-connected line from cell containing `a` to cell containing `b`,
+/// with exactly one cell per column or row along the major axis. Unlike
+/// [`line_cells`] it can skip cells which segment touches slightly.
+///
+/// # Panics
+/// Panics if `cell_size` is not positive and finite.
+///
+/// # Example
+/// ```
+/// # use linal::Vec2;
+/// # use linal::grid::line_cells_bresenham;
+/// let cells: Vec<(i64, i64)> = line_cells_bresenham(Vec2::new(0.5, 0.5), Vec2::new(3.5, 1.5), 1).collect();
+/// assert_eq!(cells, vec![(0, 0), (1, 0), (2, 1), (3, 1)]);
+/// ```
+pub fn line_cells_bresenham<I: Into<f64>>(a: Vec2, b: Vec2, cell_size: I) -> impl Iterator<Item = (i64, i64)> {
+    let s = checked_size(cell_size);
+    let (mut x, mut y) = cell(a, s);
+    let (end_x, end_y) = cell(b, s);
+    let (dx, dy) = ((end_x - x).abs(), -(end_y - y).abs());
+    let (step_x, step_y) = ((end_x - x).signum(), (end_y - y).signum());
+    let mut err = dx + dy;
+    let mut done = false;
+    ::std::iter::from_fn(move || {
+        if done {
+            return None;
+        }
+        let current = (x, y);
+        if current == (end_x, end_y) {
+            done = true;
+            return Some(current);
+        }
+        let e2 = 2 * err;
+        if e2 >= dy {
+            err += dy;
+            x += step_x;
+        }
+        if e2 <= dx {
+            err += dx;
+            y += step_y;
+        }
+        Some(current)
+    })
+}
+
+/// Voxels of cubic grid pierced by ray, with distances at which ray enters them
+///
+/// Walks grid by Amanatides–Woo algorithm: voxel $(i, j, k)$ covers
+/// $[i s, (i + 1) s) \times [j s, (j + 1) s) \times [k s, (k + 1) s)$,
+/// where $s$ is `cell_size`. The first voxel contains ray origin and is
+/// entered at distance 0, walk stops before voxels entered farther than
+/// `max_t`, which can be infinite. When ray crosses an edge or a corner of
+/// voxels exactly, voxels sharing it are visited one by one at the same
+/// distance.
+///
+/// # Panics
+/// Panics if `cell_size` is not positive and finite.
+///
+/// # Example
+/// ```
+/// # use linal::{Ray3, Vec3};
+/// # use linal::grid::ray_voxels;
+/// let ray = Ray3::new(Vec3::new(0.5, 0.5, 0.5), Vec3::new(1, 0, 0));
+/// let voxels: Vec<((i64, i64, i64), f64)> = ray_voxels(ray, 1, 2.0).collect();
+/// assert_eq!(voxels, vec![((0, 0, 0), 0.0), ((1, 0, 0), 0.5), ((2, 0, 0), 1.5)]);
+/// ```
+pub fn ray_voxels<I: Into<f64>>(ray: Ray3, cell_size: I, max_t: f64) -> impl Iterator<Item = ((i64, i64, i64), f64)> {
+    let s = checked_size(cell_size);
+    let mut voxel = [0i64; 3];
+    let (mut step, mut t_next, mut dt) = ([0i64; 3], [f64::INFINITY; 3], [f64::INFINITY; 3]);
+    for k in 0..3 {
+        voxel[k] = (ray.origin[k] / s).floor() as i64;
+        let d = ray.dir[k];
+        if d > 0.0 {
+            step[k] = 1;
+            t_next[k] = ((voxel[k] + 1) as f64 * s - ray.origin[k]) / d;
+            dt[k] = s / d;
+        } else if d < 0.0 {
+            step[k] = -1;
+            t_next[k] = (voxel[k] as f64 * s - ray.origin[k]) / d;
+            dt[k] = -s / d;
+        }
+    }
+    let mut t = 0.0;
+    ::std::iter::from_fn(move || {
+        if t > max_t || t.is_infinite() {
+            return None;
+        }
+        let current = ((voxel[0], voxel[1], voxel[2]), t);
+        let k = (1..3).fold(0, |k, i| if t_next[i] < t_next[k] { i } else { k });
+        t = t_next[k];
+        voxel[k] += step[k];
+        t_next[k] += dt[k];
+        Some(current)
+    })
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+    use field::Field2;
+
+    #[test]
+    fn meshgrid_matches_field_nodes() {
+        let rect = Rect::new(Vec2::new(-1.5, 0.25), Vec2::new(3.0, 7.0));
+        let field = Field2::new(rect, 7, 4, 0.0);
+        let nodes: Vec<Vec2> = (0..4).flat_map(|j| (0..7).map(move |i| (i, j)))
+            .map(|(i, j)| field.node(i, j)).collect();
+        let points: Vec<Vec2> = meshgrid(rect, 7, 4).collect();
+        for (p, q) in points.iter().zip(&nodes) {
+            assert!((*p - *q).len() < 1e-12);
+        }
+        assert_eq!(points.len(), nodes.len());
+        assert_eq!(meshgrid(rect, 0, 3).count(), 0);
+        assert_eq!(meshgrid(rect, 1, 1).collect::<Vec<_>>(), vec![rect.min]);
+    }
+
+    #[test]
+    fn line_cells_supercover() {
+        let (a, b) = (Vec2::new(0.3, 4.9), Vec2::new(-7.2, -2.35));
+        let cells: Vec<(i64, i64)> = line_cells(a, b, 0.5).collect();
+        assert_eq!(cells[0], (0, 9));
+        assert_eq!(*cells.last().unwrap(), (-15, -5));
+        // 4-connected path touching every cell crossed by dense samples
+        for w in cells.windows(2) {
+            assert_eq!((w[0].0 - w[1].0).abs() + (w[0].1 - w[1].1).abs(), 1);
+        }
+        for i in 0..=1000 {
+            let p = a + (b - a) * (i as f64 / 1000.0);
+            assert!(cells.contains(&((p.x / 0.5).floor() as i64, (p.y / 0.5).floor() as i64)));
+        }
+        let single: Vec<(i64, i64)> = line_cells(a, a, 1).collect();
+        assert_eq!(single, vec![(0, 4)]);
+        let back: Vec<(i64, i64)> = line_cells(Vec2::new(2.0, 2.0), Vec2::new(0.0, 0.0), 1).collect();
+        assert_eq!(back, vec![(2, 2), (1, 2), (2, 1), (1, 1), (0, 1), (1, 0), (0, 0)]);
+    }
+
+    #[test]
+    fn line_cells_bresenham_octants() {
+        for &(b, n) in &[(Vec2::new(5.5, 2.5), 6), (Vec2::new(-2.5, -6.5), 8), (Vec2::new(0.5, -3.5), 5)] {
+            let cells: Vec<(i64, i64)> = line_cells_bresenham(Vec2::new(0.5, 0.5), b, 1).collect();
+            assert_eq!(cells.len(), n);
+            for w in cells.windows(2) {
+                assert!((w[0].0 - w[1].0).abs() <= 1 && (w[0].1 - w[1].1).abs() <= 1);
+            }
+            assert_eq!(*cells.last().unwrap(), ((b.x).floor() as i64, (b.y).floor() as i64));
+        }
+    }
+
+    #[test]
+    fn ray_voxels_walk() {
+        let ray = Ray3::new(Vec3::new(0.3, -1.7, 2.2), Vec3::new(-2.0, 1.5, 0.4));
+        let voxels: Vec<((i64, i64, i64), f64)> = ray_voxels(ray, 0.5, 10.0).collect();
+        assert_eq!(voxels[0], ((0, -4, 4), 0.0));
+        for w in voxels.windows(2) {
+            let ((a, ta), (b, tb)) = (w[0], w[1]);
+            assert_eq!((a.0 - b.0).abs() + (a.1 - b.1).abs() + (a.2 - b.2).abs(), 1);
+            assert!(ta < tb && tb <= 10.0);
+            // entry point lies on the face between voxels
+            let p = ray.at(tb) / 0.5;
+            let inside = |v: (i64, i64, i64)| {
+                (0..3).all(|k| {
+                    let c = [v.0, v.1, v.2][k] as f64;
+                    c - 1e-9 <= p[k] && p[k] <= c + 1.0 + 1e-9
+                })
+            };
+            assert!(inside(a) && inside(b));
+        }
+        let last = voxels.last().unwrap().1;
+        assert!(ray_voxels(ray, 0.5, f64::INFINITY).nth(voxels.len()).unwrap().1 > 10.0 && last <= 10.0);
+        let still = Ray3 { origin: Vec3::new(1.5, 0.5, 0.5), dir: Vec3::zero() };
+        assert_eq!(ray_voxels(still, 1, 100.0).count(), 1);
+    }
+}
@@ -0,0 +1,420 @@
+//! Bounding shapes for point sets.
+use vec2::Vec2;
+use circle::Circle;
+use obb::Obb;
+
+// relative tolerance for points lying on circle boundary
+const BOUNDARY_EPS: f64 = 1e-12;
+
+/// Smallest circle enclosing all points
+///
+/// Uses Welzl's algorithm over points taken in pseudo-random (but
+/// deterministic) order, which gives expected $O(n)$ time. Returns circle
+/// with zero radius at origin for empty slice.
+///
+/// # Example
+/// ```
+/// # use linal::{Circle, Vec2};
+/// # use linal::hull::min_enclosing_circle;
+/// let points = [Vec2::new(0, 0), Vec2::new(4, 0), Vec2::new(2, 1), Vec2::new(1, -1)];
+/// let c = min_enclosing_circle(&points);
+/// assert_eq!(c, Circle::new(Vec2::new(2, 0), 2));
+/// ```
+pub fn min_enclosing_circle(points: &[Vec2]) -> Circle {
+    let points = shuffled(points);
+    let mut circle = match points.first() {
+        Some(&p) => Circle::new(p, 0),
+        None => return Circle::new(Vec2::zero(), 0),
+    };
+    for i in 1..points.len() {
+        if encloses(circle, points[i]) {
+            continue;
+        }
+        circle = Circle::new(points[i], 0);
+        for j in 0..i {
+            if encloses(circle, points[j]) {
+                continue;
+            }
+            circle = Circle::from_diameter(points[i], points[j]);
+            for k in 0..j {
+                if encloses(circle, points[k]) {
+                    continue;
+                }
+                circle = Circle::through(points[i], points[j], points[k])
+                    .unwrap_or_else(|| widest_diameter(points[i], points[j], points[k]));
+            }
+        }
+    }
+    circle
+}
+
+/// Convex hull of points
+///
+/// Returns hull vertices in counterclockwise order, starting from the
+/// lowest-leftmost point. Collinear points on hull edges are dropped. Uses
+/// Andrew's monotone chain algorithm.
+///
+/// # Example
+/// ```
+/// # use linal::Vec2;
+/// # use linal::hull::convex_hull;
+/// let points = [Vec2::new(1, 1), Vec2::new(2, 2), Vec2::new(0, 2),
+///               Vec2::new(0, 0), Vec2::new(2, 0), Vec2::new(1, 0)];
+/// let hull = convex_hull(&points);
+/// assert_eq!(hull, vec![Vec2::new(0, 0), Vec2::new(2, 0), Vec2::new(2, 2), Vec2::new(0, 2)]);
+/// ```
+pub fn convex_hull(points: &[Vec2]) -> Vec<Vec2> {
+    let mut sorted = points.to_vec();
+    sorted.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+    sorted.dedup();
+    if sorted.len() < 3 {
+        return sorted;
+    }
+    let mut hull: Vec<Vec2> = Vec::with_capacity(2 * sorted.len());
+    let half = |hull: &mut Vec<Vec2>, p: Vec2, base: usize| {
+        while hull.len() >= base + 2 {
+            let (a, b) = (hull[hull.len() - 2], hull[hull.len() - 1]);
+            if (b - a).area(p - a) > 0.0 {
+                break;
+            }
+            hull.pop();
+        }
+        hull.push(p);
+    };
+    for &p in &sorted {
+        half(&mut hull, p, 0);
+    }
+    let base = hull.len() - 1;
+    for &p in sorted.iter().rev().skip(1) {
+        half(&mut hull, p, base);
+    }
+    hull.pop();
+    hull
+}
+
+/// Farthest pair of points of convex polygon (its diameter)
+///
+/// `hull` must be convex with vertices in counterclockwise order, as returned
+/// by [`convex_hull`](fn.convex_hull.html). Uses rotating calipers, so takes
+/// linear time. Returns `None` for empty polygon.
+///
+/// # Example
+/// ```
+/// # use linal::Vec2;
+/// # use linal::hull::{convex_hull, diameter};
+/// let hull = convex_hull(&[Vec2::new(0, 0), Vec2::new(3, 0), Vec2::new(3, 1),
+///                          Vec2::new(0, 1), Vec2::new(1.0, 0.5)]);
+/// let (a, b) = diameter(&hull).unwrap();
+/// assert_eq!((a - b).len(), 10f64.sqrt());
+/// ```
+pub fn diameter(hull: &[Vec2]) -> Option<(Vec2, Vec2)> {
+    match hull.len() {
+        0 => return None,
+        1 => return Some((hull[0], hull[0])),
+        _ => (),
+    }
+    let mut best = (hull[0], hull[1]);
+    calipers(hull, |i, c| {
+        let j = hull[c.top];
+        for &p in &[hull[i], hull[(i + 1) % hull.len()]] {
+            if (p - j).len() > (best.0 - best.1).len() {
+                best = (p, j);
+            }
+        }
+    });
+    Some(best)
+}
+
+/// Minimal width of convex polygon
+///
+/// Returns width of the narrowest strip containing polygon and unit normal
+/// of that strip. `hull` must be convex with vertices in counterclockwise
+/// order. Returns `None` for polygons with less than two vertices.
+///
+/// # Example
+/// ```
+/// # use linal::Vec2;
+/// # use linal::hull::width;
+/// let hull = [Vec2::new(0, 0), Vec2::new(4, 0), Vec2::new(4, 1), Vec2::new(0, 1)];
+/// let (w, normal) = width(&hull).unwrap();
+/// assert_eq!(w, 1.0);
+/// assert_eq!(normal.x, 0.0);
+/// ```
+pub fn width(hull: &[Vec2]) -> Option<(f64, Vec2)> {
+    if hull.len() < 2 {
+        return None;
+    }
+    let mut best: Option<(f64, Vec2)> = None;
+    calipers(hull, |i, c| {
+        let w = (hull[c.top] - hull[i]).dot(c.normal);
+        if best.map_or(true, |(b, _)| w < b) {
+            best = Some((w, c.normal));
+        }
+    });
+    best
+}
+
+/// Minimum-area rectangle enclosing convex polygon
+///
+/// One side of optimal rectangle always contains polygon edge, so all edges
+/// are checked with rotating calipers. `hull` must be convex with vertices in
+/// counterclockwise order. Returns `None` for polygons with less than two
+/// vertices.
+///
+/// # Example
+/// ```
+/// # use linal::Vec2;
+/// # use linal::hull::min_area_rect;
+/// // square rotated by 45 degrees
+/// let hull = [Vec2::new(1, 0), Vec2::new(2, 1), Vec2::new(1, 2), Vec2::new(0, 1)];
+/// let rect = min_area_rect(&hull).unwrap();
+/// assert!((rect.area() - 2.0).abs() < 1e-12);
+/// assert!((rect.center - Vec2::new(1, 1)).len() < 1e-12);
+/// ```
+pub fn min_area_rect(hull: &[Vec2]) -> Option<Obb> {
+    min_rect_by(hull, |obb| obb.area())
+}
+
+/// Minimum-perimeter rectangle enclosing convex polygon
+///
+/// Same as [`min_area_rect`](fn.min_area_rect.html), but minimizes
+/// perimeter.
+///
+/// # Example
+/// ```
+/// # use linal::Vec2;
+/// # use linal::hull::min_perimeter_rect;
+/// let hull = [Vec2::new(1, 0), Vec2::new(2, 1), Vec2::new(1, 2), Vec2::new(0, 1)];
+/// let rect = min_perimeter_rect(&hull).unwrap();
+/// assert!((rect.perimeter() - 4.0 * 2f64.sqrt()).abs() < 1e-12);
+/// ```
+pub fn min_perimeter_rect(hull: &[Vec2]) -> Option<Obb> {
+    min_rect_by(hull, |obb| obb.perimeter())
+}
+
+fn min_rect_by<F: Fn(Obb) -> f64>(hull: &[Vec2], measure: F) -> Option<Obb> {
+    if hull.len() < 2 {
+        return None;
+    }
+    let mut best: Option<(f64, Obb)> = None;
+    calipers(hull, |i, c| {
+        let origin = hull[i];
+        let dir = Vec2::new(c.normal.y, -c.normal.x);
+        let (lo, hi) = ((hull[c.left] - origin).dot(dir), (hull[c.right] - origin).dot(dir));
+        let height = (hull[c.top] - origin).dot(c.normal);
+        let center = origin + dir * ((lo + hi) * 0.5) + c.normal * (height * 0.5);
+        let obb = Obb::new(center, dir, Vec2::new((hi - lo) * 0.5, height * 0.5));
+        let value = measure(obb);
+        if best.map_or(true, |(b, _)| value < b) {
+            best = Some((value, obb));
+        }
+    });
+    best.map(|(_, obb)| obb)
+}
+
+// extreme vertices for one caliper position
+struct Caliper {
+    // inward unit normal of the current edge
+    normal: Vec2,
+    // vertex farthest from the edge
+    top: usize,
+    // vertices extreme along the edge direction
+    left: usize,
+    right: usize,
+}
+
+// Rotates calipers around convex polygon, calling `f` for every edge with
+// start index of the edge and extreme vertices in its frame.
+fn calipers<F: FnMut(usize, &Caliper)>(hull: &[Vec2], mut f: F) {
+    let n = hull.len();
+    let next = |i: usize| (i + 1) % n;
+    let (mut top, mut left, mut right) = (1, 1, 1);
+    for i in 0..n {
+        let dir = (hull[next(i)] - hull[i]).ort();
+        let normal = Vec2::new(-dir.y, dir.x);
+        // each pointer moves monotonically around the polygon
+        for _ in 0..n {
+            if (hull[next(right)] - hull[right]).dot(dir) <= 0.0 {
+                break;
+            }
+            right = next(right);
+        }
+        if i == 0 {
+            top = right;
+        }
+        for _ in 0..n {
+            if (hull[next(top)] - hull[top]).dot(normal) <= 0.0 {
+                break;
+            }
+            top = next(top);
+        }
+        if i == 0 {
+            left = top;
+        }
+        for _ in 0..n {
+            if (hull[next(left)] - hull[left]).dot(dir) >= 0.0 {
+                break;
+            }
+            left = next(left);
+        }
+        f(i, &Caliper { normal, top, left, right });
+    }
+}
+
+fn encloses(circle: Circle, point: Vec2) -> bool {
+    (point - circle.center).len() <= circle.radius * (1.0 + BOUNDARY_EPS) + BOUNDARY_EPS
+}
+
+// circle for (nearly) collinear triple: the one spanning the farthest pair
+fn widest_diameter(a: Vec2, b: Vec2, c: Vec2) -> Circle {
+    let pairs = [(a, b), (b, c), (a, c)];
+    let (p, q) = pairs.iter()
+        .cloned()
+        .fold((a, b), |best, pair| {
+            if (pair.0 - pair.1).len() > (best.0 - best.1).len() { pair } else { best }
+        });
+    Circle::from_diameter(p, q)
+}
+
+// Fisher-Yates shuffle driven by fixed-seed xorshift, keeps results reproducible
+fn shuffled(points: &[Vec2]) -> Vec<Vec2> {
+    let mut result = points.to_vec();
+    let mut state: u64 = 0x9e37_79b9_7f4a_7c15;
+    for i in (1..result.len()).rev() {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        result.swap(i, (state % (i as u64 + 1)) as usize);
+    }
+    result
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    fn cloud() -> Vec<Vec2> {
+        (0..100)
+            .map(|i| {
+                let i = i as f64;
+                Vec2::new((i * 1.7).sin() * 5.0, (i * 0.9).cos() * 2.0 + (i * 0.3).sin())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn convex_hull_is_convex() {
+        let points = cloud();
+        let hull = convex_hull(&points);
+        let n = hull.len();
+        for i in 0..n {
+            let (a, b, c) = (hull[i], hull[(i + 1) % n], hull[(i + 2) % n]);
+            assert!((b - a).area(c - b) > 0.0);
+        }
+        for &p in &points {
+            for i in 0..n {
+                let (a, b) = (hull[i], hull[(i + 1) % n]);
+                assert!((b - a).area(p - a) >= -1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn convex_hull_degenerate() {
+        assert!(convex_hull(&[]).is_empty());
+        let p = Vec2::new(1, 1);
+        assert_eq!(convex_hull(&[p, p]), vec![p]);
+        let line = [Vec2::new(0, 0), Vec2::new(2, 2), Vec2::new(1, 1)];
+        assert_eq!(convex_hull(&line), vec![Vec2::new(0, 0), Vec2::new(2, 2)]);
+        // NaN input gives garbage, but does not panic
+        convex_hull(&[Vec2::new(0, 0), Vec2::new(f64::NAN, 1.0), Vec2::new(1, 0), Vec2::new(0, 1)]);
+    }
+
+    #[test]
+    fn calipers_match_brute_force() {
+        let points = cloud();
+        let hull = convex_hull(&points);
+        let mut farthest: f64 = 0.0;
+        for &a in &points {
+            for &b in &points {
+                farthest = farthest.max((a - b).len());
+            }
+        }
+        let (a, b) = diameter(&hull).unwrap();
+        assert!(((a - b).len() - farthest).abs() < 1e-12);
+
+        let (w, normal) = width(&hull).unwrap();
+        let proj: Vec<f64> = points.iter().map(|p| p.dot(normal)).collect();
+        let spread = proj.iter().cloned().fold(f64::MIN, f64::max)
+                   - proj.iter().cloned().fold(f64::MAX, f64::min);
+        assert!((w - spread).abs() < 1e-9);
+
+        let n = hull.len();
+        let mut min_area = f64::MAX;
+        for i in 0..n {
+            let u = (hull[(i + 1) % n] - hull[i]).ort();
+            let v = Vec2::new(-u.y, u.x);
+            let extent = |axis: Vec2| {
+                let proj: Vec<f64> = hull.iter().map(|p| p.dot(axis)).collect();
+                proj.iter().cloned().fold(f64::MIN, f64::max)
+                    - proj.iter().cloned().fold(f64::MAX, f64::min)
+            };
+            min_area = min_area.min(extent(u) * extent(v));
+        }
+        let rect = min_area_rect(&hull).unwrap();
+        assert!((rect.area() - min_area).abs() < 1e-9);
+        let grown = Obb { half_extents: rect.half_extents + Vec2::new(1e-9, 1e-9), ..rect };
+        assert!(points.iter().all(|&p| grown.contains(p)));
+
+        let rect = min_perimeter_rect(&hull).unwrap();
+        let grown = Obb { half_extents: rect.half_extents + Vec2::new(1e-9, 1e-9), ..rect };
+        assert!(points.iter().all(|&p| grown.contains(p)));
+    }
+
+    #[test]
+    fn calipers_segment() {
+        let hull = [Vec2::new(0, 0), Vec2::new(3, 4)];
+        assert_eq!(diameter(&hull), Some((hull[0], hull[1])));
+        assert_eq!(width(&hull).unwrap().0, 0.0);
+        let rect = min_area_rect(&hull).unwrap();
+        assert!((rect.half_extents - Vec2::new(2.5, 0.0)).len() < 1e-12);
+        assert!(width(&hull[..1]).is_none());
+    }
+
+    #[test]
+    fn min_enclosing_circle_contains_all() {
+        let points: Vec<Vec2> = (0..200)
+            .map(|i| {
+                let i = i as f64;
+                Vec2::new((i * 0.7).sin() * 3.0 + i * 0.01, (i * 1.3).cos() * 2.0)
+            })
+            .collect();
+        let c = min_enclosing_circle(&points);
+        assert!(points.iter().all(|&p| (p - c.center).len() <= c.radius + 1e-9));
+        // at least two points lie on minimal circle
+        let on_boundary = points.iter()
+            .filter(|&&p| ((p - c.center).len() - c.radius).abs() < 1e-9)
+            .count();
+        assert!(on_boundary >= 2);
+    }
+
+    #[test]
+    fn min_enclosing_circle_triangle() {
+        // acute triangle: circumcircle
+        let points = [Vec2::new(0, 0), Vec2::new(4, 0), Vec2::new(2, 3)];
+        let c = min_enclosing_circle(&points);
+        let expected = Circle::through(points[0], points[1], points[2]).unwrap();
+        assert!((c.center - expected.center).len() < 1e-12);
+        assert!((c.radius - expected.radius).abs() < 1e-12);
+    }
+
+    #[test]
+    fn min_enclosing_circle_degenerate() {
+        assert_eq!(min_enclosing_circle(&[]), Circle::new(Vec2::zero(), 0));
+        let p = Vec2::new(1, 2);
+        assert_eq!(min_enclosing_circle(&[p, p, p]), Circle::new(p, 0));
+        let line = [Vec2::new(0, 0), Vec2::new(1, 1), Vec2::new(3, 3), Vec2::new(2, 2)];
+        let c = min_enclosing_circle(&line);
+        assert!((c.center - Vec2::new(1.5, 1.5)).len() < 1e-12);
+    }
+}
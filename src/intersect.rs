@@ -0,0 +1,223 @@
+//! Intersections of rays with geometric primitives.
+use vec3::Vec3;
+use ray3::Ray3;
+use plane::Plane;
+use triangle3::Triangle3;
+use aabb3::Aabb3;
+
+/// Intersection point of ray with surface
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hit {
+    /// distance from ray origin to intersection point
+    pub t: f64,
+    /// intersection point
+    pub point: Vec3,
+    /// unit normal of surface at intersection point
+    pub normal: Vec3,
+}
+
+/// Intersection of ray with sphere
+///
+/// Returns nearest intersection in front of ray origin with outward normal.
+/// For ray starting inside sphere it is the exit point.
+///
+/// # Example
+/// ```
+/// # use linal::{Ray3, Vec3};
+/// # use linal::intersect::ray_sphere;
+/// let ray = Ray3::new(Vec3::new(0, 0, -5), Vec3::new(0, 0, 1));
+/// let hit = ray_sphere(ray, Vec3::zero(), 2.0).unwrap();
+/// assert_eq!(hit.t, 3.0);
+/// assert_eq!(hit.point, Vec3::new(0, 0, -2));
+/// assert_eq!(hit.normal, Vec3::new(0, 0, -1));
+/// ```
+pub fn ray_sphere(ray: Ray3, center: Vec3, radius: f64) -> Option<Hit> {
+    let oc = ray.origin - center;
+    let b = oc.dot(ray.dir);
+    // distance from center to ray line, squared
+    let h = oc - ray.dir * b;
+    let disc = radius * radius - h.dot(h);
+    if disc < 0.0 {
+        return None;
+    }
+    let s = disc.sqrt();
+    let t = if -b - s >= 0.0 { -b - s } else { -b + s };
+    if t < 0.0 {
+        return None;
+    }
+    let point = ray.at(t);
+    Some(Hit { t, point, normal: (point - center) / radius })
+}
+
+/// Intersection of ray with plane
+///
+/// Returns intersection in front of ray origin with plane normal facing ray
+/// origin.
+///
+/// # Example
+/// ```
+/// # use linal::{Plane, Ray3, Vec3};
+/// # use linal::intersect::ray_plane;
+/// let plane = Plane::new(Vec3::new(0, 0, 1), 0);
+/// let ray = Ray3::new(Vec3::new(1, 1, -2), Vec3::new(0, 0, 1));
+/// let hit = ray_plane(ray, plane).unwrap();
+/// assert_eq!(hit.point, Vec3::new(1, 1, 0));
+/// assert_eq!(hit.normal, Vec3::new(0, 0, -1));
+/// ```
+pub fn ray_plane(ray: Ray3, plane: Plane) -> Option<Hit> {
+    let t = plane.intersect(ray)?;
+    let normal = if plane.normal.dot(ray.dir) > 0.0 { -plane.normal } else { plane.normal };
+    Some(Hit { t, point: ray.at(t), normal })
+}
+
+/// Intersection of ray with triangle (Möller–Trumbore algorithm)
+///
+/// Both sides of triangle are hit, returned normal faces ray origin.
+///
+/// # Example
+/// ```
+/// # use linal::{Ray3, Triangle3, Vec3};
+/// # use linal::intersect::ray_triangle;
+/// let tri = Triangle3::new(Vec3::new(0, 0, 0), Vec3::new(4, 0, 0), Vec3::new(0, 4, 0));
+/// let ray = Ray3::new(Vec3::new(1, 1, 3), Vec3::new(0, 0, -1));
+/// let hit = ray_triangle(ray, tri).unwrap();
+/// assert_eq!(hit.t, 3.0);
+/// assert_eq!(hit.normal, Vec3::new(0, 0, 1));
+/// let miss = Ray3::new(Vec3::new(3, 3, 3), Vec3::new(0, 0, -1));
+/// assert!(ray_triangle(miss, tri).is_none());
+/// ```
+pub fn ray_triangle(ray: Ray3, triangle: Triangle3) -> Option<Hit> {
+    let e1 = triangle.b - triangle.a;
+    let e2 = triangle.c - triangle.a;
+    let p = ray.dir.cross(e2);
+    let det = e1.dot(p);
+    if det == 0.0 {
+        return None;
+    }
+    let s = ray.origin - triangle.a;
+    let u = s.dot(p) / det;
+    if !(0.0..=1.0).contains(&u) {
+        return None;
+    }
+    let q = s.cross(e1);
+    let v = ray.dir.dot(q) / det;
+    if v < 0.0 || u + v > 1.0 {
+        return None;
+    }
+    let t = e2.dot(q) / det;
+    if t < 0.0 {
+        return None;
+    }
+    let normal = e1.cross(e2).ort();
+    let normal = if det < 0.0 { -normal } else { normal };
+    Some(Hit { t, point: ray.at(t), normal })
+}
+
+/// Intersection of ray with axis-aligned box (slab method)
+///
+/// Returns nearest intersection in front of ray origin with outward normal of
+/// the face. For ray starting inside box it is the exit point.
+///
+/// # Example
+/// ```
+/// # use linal::{Aabb3, Ray3, Vec3};
+/// # use linal::intersect::ray_aabb;
+/// let aabb = Aabb3::new(Vec3::new(-1, -1, -1), Vec3::new(1, 1, 1));
+/// let ray = Ray3::new(Vec3::new(-5, 0, 0), Vec3::new(1, 0, 0));
+/// let hit = ray_aabb(ray, aabb).unwrap();
+/// assert_eq!(hit.t, 4.0);
+/// assert_eq!(hit.normal, Vec3::new(-1, 0, 0));
+/// ```
+pub fn ray_aabb(ray: Ray3, aabb: Aabb3) -> Option<Hit> {
+    let (mut t_min, mut t_max) = (0.0, f64::INFINITY);
+    let (mut enter_axis, mut exit_axis) = (None, None);
+    for k in 0..3 {
+        let (o, d) = (ray.origin[k], ray.dir[k]);
+        if d == 0.0 {
+            if o < aabb.min[k] || o > aabb.max[k] {
+                return None;
+            }
+            continue;
+        }
+        let t1 = (aabb.min[k] - o) / d;
+        let t2 = (aabb.max[k] - o) / d;
+        let (near, far) = if t1 < t2 { (t1, t2) } else { (t2, t1) };
+        if near > t_min {
+            t_min = near;
+            enter_axis = Some(k);
+        }
+        if far < t_max {
+            t_max = far;
+            exit_axis = Some(k);
+        }
+        if t_min > t_max {
+            return None;
+        }
+    }
+    // ray origin inside box, report exit point
+    let (t, axis, sign) = match enter_axis {
+        Some(k) => (t_min, k, -ray.dir[k].signum()),
+        None => {
+            let k = exit_axis?;
+            (t_max, k, ray.dir[k].signum())
+        }
+    };
+    let mut normal = Vec3::zero();
+    normal[axis] = sign;
+    Some(Hit { t, point: ray.at(t), normal })
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    #[test]
+    fn ray_sphere_cases() {
+        let center = Vec3::new(0, 0, 0);
+        let inside = Ray3::new(Vec3::new(0, 0, 0), Vec3::new(1, 0, 0));
+        let hit = ray_sphere(inside, center, 2.0).unwrap();
+        assert_eq!(hit.t, 2.0);
+        assert_eq!(hit.normal, Vec3::new(1, 0, 0));
+        let behind = Ray3::new(Vec3::new(5, 0, 0), Vec3::new(1, 0, 0));
+        assert!(ray_sphere(behind, center, 2.0).is_none());
+        let miss = Ray3::new(Vec3::new(-5, 3, 0), Vec3::new(1, 0, 0));
+        assert!(ray_sphere(miss, center, 2.0).is_none());
+        let oblique = Ray3::new(Vec3::new(-5, 1, 0), Vec3::new(1, 0, 0));
+        let hit = ray_sphere(oblique, center, 2.0).unwrap();
+        assert!((hit.point.len() - 2.0).abs() < 1e-12);
+        assert!((hit.point.x + 3f64.sqrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn ray_triangle_back_side() {
+        let tri = Triangle3::new(Vec3::new(0, 0, 0), Vec3::new(4, 0, 0), Vec3::new(0, 4, 0));
+        let ray = Ray3::new(Vec3::new(1, 1, -3), Vec3::new(0, 0, 1));
+        let hit = ray_triangle(ray, tri).unwrap();
+        assert_eq!(hit.point, Vec3::new(1, 1, 0));
+        assert_eq!(hit.normal, Vec3::new(0, 0, -1));
+        let parallel = Ray3::new(Vec3::new(1, 1, 0), Vec3::new(1, 0, 0));
+        assert!(ray_triangle(parallel, tri).is_none());
+        let away = Ray3::new(Vec3::new(1, 1, 3), Vec3::new(0, 0, 1));
+        assert!(ray_triangle(away, tri).is_none());
+    }
+
+    #[test]
+    fn ray_aabb_cases() {
+        let aabb = Aabb3::new(Vec3::new(0, 0, 0), Vec3::new(2, 2, 2));
+        let inside = Ray3::new(Vec3::new(1, 1, 1), Vec3::new(0, 1, 0));
+        let hit = ray_aabb(inside, aabb).unwrap();
+        assert_eq!(hit.t, 1.0);
+        assert_eq!(hit.normal, Vec3::new(0, 1, 0));
+        let diagonal = Ray3::new(Vec3::new(-1, -1, -1), Vec3::new(1, 1, 1));
+        let hit = ray_aabb(diagonal, aabb).unwrap();
+        assert!((hit.point - Vec3::zero()).len() < 1e-12);
+        let miss = Ray3::new(Vec3::new(3, 0, 0), Vec3::new(0, 1, 0));
+        assert!(ray_aabb(miss, aabb).is_none());
+        let away = Ray3::new(Vec3::new(3, 1, 1), Vec3::new(1, 0, 0));
+        assert!(ray_aabb(away, aabb).is_none());
+        let top = Ray3::new(Vec3::new(1, 1, 5), Vec3::new(0, 0, -1));
+        let hit = ray_aabb(top, aabb).unwrap();
+        assert_eq!(hit.point, Vec3::new(1, 1, 2));
+        assert_eq!(hit.normal, Vec3::new(0, 0, 1));
+    }
+}
@@ -0,0 +1,237 @@
+//! Closed intervals of real numbers.
+use std::ops::{Add, Sub, Mul, Neg};
+use std::ops::{AddAssign, SubAssign, MulAssign};
+use std::fmt;
+
+// smallest float greater than `x`; `f64::next_up` is too recent for the crate
+fn next_up(x: f64) -> f64 {
+    if x.is_nan() || x == f64::INFINITY {
+        return x;
+    }
+    if x == 0.0 {
+        // both zeros step to the smallest positive subnormal
+        return f64::from_bits(1);
+    }
+    let bits = x.to_bits();
+    f64::from_bits(if x > 0.0 { bits + 1 } else { bits - 1 })
+}
+
+// largest float less than `x`
+fn next_down(x: f64) -> f64 {
+    -next_up(-x)
+}
+
+/// Closed interval $[lo, hi]$
+///
+/// Results of arithmetic operations are rounded outwards, so interval
+/// result always contains exact result of operation applied to any numbers
+/// from operand intervals.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Interval {
+    /// lower bound
+    pub lo: f64,
+    /// upper bound
+    pub hi: f64,
+}
+
+impl Interval {
+    /// Constructs a new `Interval` from bounds given in any order
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Interval;
+    /// let i = Interval::new(3, 1);
+    /// assert_eq!((i.lo, i.hi), (1.0, 3.0));
+    /// ```
+    pub fn new<I: Into<f64>>(a: I, b: I) -> Interval {
+        let (a, b) = (a.into(), b.into());
+        Interval { lo: a.min(b), hi: a.max(b) }
+    }
+    /// Degenerate interval containing single number
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Interval;
+    /// assert_eq!(Interval::point(2), Interval::new(2, 2));
+    /// ```
+    pub fn point<I: Into<f64>>(x: I) -> Interval {
+        let x = x.into();
+        Interval { lo: x, hi: x }
+    }
+    /// Width of interval
+    pub fn width(self) -> f64 {
+        self.hi - self.lo
+    }
+    /// Middle point of interval
+    pub fn mid(self) -> f64 {
+        0.5 * (self.lo + self.hi)
+    }
+    /// Checks if number lies inside interval
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Interval;
+    /// assert!(Interval::new(1, 3).contains(3));
+    /// assert!(!Interval::new(1, 3).contains(0));
+    /// ```
+    pub fn contains<I: Into<f64>>(self, x: I) -> bool {
+        let x = x.into();
+        self.lo <= x && x <= self.hi
+    }
+    /// Checks if intervals have common points
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Interval;
+    /// assert!(Interval::new(1, 3).intersects(Interval::new(3, 4)));
+    /// assert!(!Interval::new(1, 3).intersects(Interval::new(4, 5)));
+    /// ```
+    pub fn intersects(self, other: Interval) -> bool {
+        self.lo <= other.hi && other.lo <= self.hi
+    }
+    /// Square of interval
+    ///
+    /// Tighter than `self * self`, since both factors are the same number.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Interval;
+    /// let i = Interval::new(-1, 2).sqr();
+    /// assert!(i.contains(0) && i.contains(4) && !i.contains(-1));
+    /// ```
+    pub fn sqr(self) -> Interval {
+        let (a, b) = (self.lo * self.lo, self.hi * self.hi);
+        if self.contains(0) {
+            Interval::outward(0.0, a.max(b))
+        } else {
+            Interval::outward(a.min(b), a.max(b))
+        }
+    }
+    /// Square root of interval
+    ///
+    /// Negative part of interval is ignored.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Interval;
+    /// assert!(Interval::new(4, 9).sqrt().contains(3));
+    /// ```
+    pub fn sqrt(self) -> Interval {
+        Interval::outward(self.lo.max(0.0).sqrt(), self.hi.max(0.0).sqrt())
+    }
+    // interval from bounds rounded outwards by one ulp
+    fn outward(lo: f64, hi: f64) -> Interval {
+        Interval { lo: next_down(lo), hi: next_up(hi) }
+    }
+}
+
+impl From<f64> for Interval {
+    fn from(x: f64) -> Interval {
+        Interval::point(x)
+    }
+}
+
+impl From<i32> for Interval {
+    fn from(x: i32) -> Interval {
+        Interval::point(x)
+    }
+}
+
+impl Add for Interval {
+    type Output = Interval;
+    fn add(self, rhs: Interval) -> Interval {
+        Interval::outward(self.lo + rhs.lo, self.hi + rhs.hi)
+    }
+}
+
+impl Sub for Interval {
+    type Output = Interval;
+    fn sub(self, rhs: Interval) -> Interval {
+        Interval::outward(self.lo - rhs.hi, self.hi - rhs.lo)
+    }
+}
+
+impl Mul for Interval {
+    type Output = Interval;
+    fn mul(self, rhs: Interval) -> Interval {
+        let p = [self.lo * rhs.lo, self.lo * rhs.hi, self.hi * rhs.lo, self.hi * rhs.hi];
+        let lo = p.iter().cloned().fold(f64::INFINITY, f64::min);
+        let hi = p.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+        Interval::outward(lo, hi)
+    }
+}
+
+impl Neg for Interval {
+    type Output = Interval;
+    fn neg(self) -> Interval {
+        Interval { lo: -self.hi, hi: -self.lo }
+    }
+}
+
+impl AddAssign for Interval {
+    fn add_assign(&mut self, rhs: Interval) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Interval {
+    fn sub_assign(&mut self, rhs: Interval) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign for Interval {
+    fn mul_assign(&mut self, rhs: Interval) {
+        *self = *self * rhs;
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}, {}]", self.lo, self.hi)
+    }
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    #[test]
+    fn interval_encloses_exact() {
+        let (a, b) = (Interval::new(-1.5, 2.0), Interval::new(0.1, 0.3));
+        for i in 0..=10 {
+            for j in 0..=10 {
+                let x = (-1.5 + 0.35 * i as f64).min(2.0);
+                let y = (0.1 + 0.02 * j as f64).min(0.3);
+                assert!((a + b).contains(x + y));
+                assert!((a - b).contains(x - y));
+                assert!((a * b).contains(x * y));
+                assert!(a.sqr().contains(x * x));
+            }
+        }
+    }
+
+    #[test]
+    fn interval_rounds_outwards() {
+        // 0.1 + 0.2 is not representable exactly
+        let sum = Interval::point(0.1) + Interval::point(0.2);
+        assert!(sum.lo < 0.1 + 0.2 && 0.1 + 0.2 < sum.hi);
+        assert_eq!(-Interval::new(1, 2), Interval::new(-2, -1));
+    }
+
+    #[test]
+    fn interval_next_float() {
+        let tiny = f64::from_bits(1);
+        assert_eq!(next_up(0.0), tiny);
+        assert_eq!(next_up(-0.0), tiny);
+        assert_eq!(next_down(0.0), -tiny);
+        assert_eq!(next_up(1.0), 1.0 + f64::EPSILON);
+        assert_eq!(next_down(1.0), 1.0 - f64::EPSILON / 2.0);
+        assert_eq!(next_up(-tiny), -0.0);
+        assert_eq!(next_up(f64::MAX), f64::INFINITY);
+        assert_eq!(next_up(f64::INFINITY), f64::INFINITY);
+        assert_eq!(next_up(f64::NEG_INFINITY), f64::MIN);
+        assert_eq!(next_down(f64::NEG_INFINITY), f64::NEG_INFINITY);
+        assert!(next_up(f64::NAN).is_nan() && next_down(f64::NAN).is_nan());
+    }
+}
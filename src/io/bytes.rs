@@ -0,0 +1,195 @@
+//! Stable binary layout of vectors.
+//!
+//! Vector is encoded as its components in order `x`, `y`, `z`, each as
+//! IEEE 754 binary64 number in little or big endian byte order, without any
+//! padding or header. So `Vec2` takes 16 bytes, `Vec3` takes 24 bytes, and
+//! slice of vectors is concatenation of encoded vectors.
+use vec2::Vec2;
+use vec3::Vec3;
+
+fn read_f64(bytes: &[u8], le: bool) -> f64 {
+    let mut buf = [0; 8];
+    buf.copy_from_slice(&bytes[..8]);
+    if le { f64::from_le_bytes(buf) } else { f64::from_be_bytes(buf) }
+}
+
+fn write_f64(out: &mut [u8], value: f64, le: bool) {
+    let buf = if le { value.to_le_bytes() } else { value.to_be_bytes() };
+    out[..8].copy_from_slice(&buf);
+}
+
+// Encodes components of every vector one after another
+fn encode<T, F: Fn(&T) -> [u8; N], const N: usize>(items: &[T], f: F) -> Vec<u8> {
+    let mut out = Vec::with_capacity(items.len() * N);
+    for item in items {
+        out.extend_from_slice(&f(item));
+    }
+    out
+}
+
+// Decodes vectors, None if length is not multiple of encoded size
+fn decode<T, F: Fn([u8; N]) -> T, const N: usize>(bytes: &[u8], f: F) -> Option<Vec<T>> {
+    if bytes.len() % N != 0 {
+        return None;
+    }
+    Some(bytes.chunks(N)
+        .map(|chunk| {
+            let mut buf = [0; N];
+            buf.copy_from_slice(chunk);
+            f(buf)
+        })
+        .collect())
+}
+
+impl Vec2 {
+    fn to_bytes(self, le: bool) -> [u8; 16] {
+        let mut out = [0; 16];
+        write_f64(&mut out[0..], self.x, le);
+        write_f64(&mut out[8..], self.y, le);
+        out
+    }
+    fn from_bytes(bytes: [u8; 16], le: bool) -> Vec2 {
+        Vec2::new(read_f64(&bytes[0..], le), read_f64(&bytes[8..], le))
+    }
+    /// Encodes vector as 16 bytes in little endian order
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Vec2;
+    /// let bytes = Vec2::new(1, -2).to_le_bytes();
+    /// assert_eq!(bytes[..8], 1f64.to_le_bytes());
+    /// assert_eq!(Vec2::from_le_bytes(bytes), Vec2::new(1, -2));
+    /// ```
+    pub fn to_le_bytes(self) -> [u8; 16] {
+        self.to_bytes(true)
+    }
+    /// Encodes vector as 16 bytes in big endian order
+    pub fn to_be_bytes(self) -> [u8; 16] {
+        self.to_bytes(false)
+    }
+    /// Decodes vector from 16 bytes in little endian order
+    pub fn from_le_bytes(bytes: [u8; 16]) -> Vec2 {
+        Vec2::from_bytes(bytes, true)
+    }
+    /// Decodes vector from 16 bytes in big endian order
+    pub fn from_be_bytes(bytes: [u8; 16]) -> Vec2 {
+        Vec2::from_bytes(bytes, false)
+    }
+    /// Encodes slice of vectors in little endian order
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Vec2;
+    /// let points = [Vec2::new(1, 2), Vec2::new(3, 4)];
+    /// let bytes = Vec2::encode_le(&points);
+    /// assert_eq!(bytes.len(), 32);
+    /// assert_eq!(Vec2::decode_le(&bytes), Some(points.to_vec()));
+    /// assert_eq!(Vec2::decode_le(&bytes[1..]), None);
+    /// ```
+    pub fn encode_le(points: &[Vec2]) -> Vec<u8> {
+        encode(points, |p| p.to_le_bytes())
+    }
+    /// Encodes slice of vectors in big endian order
+    pub fn encode_be(points: &[Vec2]) -> Vec<u8> {
+        encode(points, |p| p.to_be_bytes())
+    }
+    /// Decodes vectors in little endian order
+    ///
+    /// Returns `None` if length of `bytes` is not multiple of 16.
+    pub fn decode_le(bytes: &[u8]) -> Option<Vec<Vec2>> {
+        decode(bytes, Vec2::from_le_bytes)
+    }
+    /// Decodes vectors in big endian order
+    ///
+    /// Returns `None` if length of `bytes` is not multiple of 16.
+    pub fn decode_be(bytes: &[u8]) -> Option<Vec<Vec2>> {
+        decode(bytes, Vec2::from_be_bytes)
+    }
+}
+
+impl Vec3 {
+    fn to_bytes(self, le: bool) -> [u8; 24] {
+        let mut out = [0; 24];
+        write_f64(&mut out[0..], self.x, le);
+        write_f64(&mut out[8..], self.y, le);
+        write_f64(&mut out[16..], self.z, le);
+        out
+    }
+    fn from_bytes(bytes: [u8; 24], le: bool) -> Vec3 {
+        Vec3::new(read_f64(&bytes[0..], le),
+                  read_f64(&bytes[8..], le),
+                  read_f64(&bytes[16..], le))
+    }
+    /// Encodes vector as 24 bytes in little endian order
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Vec3;
+    /// let bytes = Vec3::new(1, 2, 3).to_le_bytes();
+    /// assert_eq!(bytes[16..], 3f64.to_le_bytes());
+    /// assert_eq!(Vec3::from_le_bytes(bytes), Vec3::new(1, 2, 3));
+    /// ```
+    pub fn to_le_bytes(self) -> [u8; 24] {
+        self.to_bytes(true)
+    }
+    /// Encodes vector as 24 bytes in big endian order
+    pub fn to_be_bytes(self) -> [u8; 24] {
+        self.to_bytes(false)
+    }
+    /// Decodes vector from 24 bytes in little endian order
+    pub fn from_le_bytes(bytes: [u8; 24]) -> Vec3 {
+        Vec3::from_bytes(bytes, true)
+    }
+    /// Decodes vector from 24 bytes in big endian order
+    pub fn from_be_bytes(bytes: [u8; 24]) -> Vec3 {
+        Vec3::from_bytes(bytes, false)
+    }
+    /// Encodes slice of vectors in little endian order
+    pub fn encode_le(points: &[Vec3]) -> Vec<u8> {
+        encode(points, |p| p.to_le_bytes())
+    }
+    /// Encodes slice of vectors in big endian order
+    pub fn encode_be(points: &[Vec3]) -> Vec<u8> {
+        encode(points, |p| p.to_be_bytes())
+    }
+    /// Decodes vectors in little endian order
+    ///
+    /// Returns `None` if length of `bytes` is not multiple of 24.
+    pub fn decode_le(bytes: &[u8]) -> Option<Vec<Vec3>> {
+        decode(bytes, Vec3::from_le_bytes)
+    }
+    /// Decodes vectors in big endian order
+    ///
+    /// Returns `None` if length of `bytes` is not multiple of 24.
+    pub fn decode_be(bytes: &[u8]) -> Option<Vec<Vec3>> {
+        decode(bytes, Vec3::from_be_bytes)
+    }
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    #[test]
+    fn bytes_layout() {
+        let v = Vec3::new(1.5, -0.0, 1e300);
+        let be = v.to_be_bytes();
+        assert_eq!(be[..8], 1.5f64.to_be_bytes());
+        assert_eq!(be[8], 0x80);
+        assert_eq!(Vec3::from_be_bytes(be), v);
+        let mut le = v.to_le_bytes();
+        le.reverse();
+        assert_eq!(le[..8], 1e300f64.to_be_bytes());
+    }
+
+    #[test]
+    fn bytes_slices() {
+        let points = vec![Vec3::new(1, 2, 3), Vec3::new(-4.0, 5.5, 6.0)];
+        assert_eq!(Vec3::decode_be(&Vec3::encode_be(&points)), Some(points.clone()));
+        assert_eq!(Vec3::decode_le(&Vec3::encode_le(&points)), Some(points));
+        assert_eq!(Vec3::decode_le(&[0; 25]), None);
+        assert_eq!(Vec2::decode_be(&[]), Some(vec![]));
+        let nan = Vec2::from_le_bytes(Vec2::new(f64::NAN, 0.0).to_le_bytes());
+        assert!(nan.x.is_nan());
+    }
+}
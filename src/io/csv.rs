@@ -0,0 +1,244 @@
+//! Delimiter separated lists of points.
+use std::io::{self, BufRead, Write};
+use vec2::Vec2;
+use vec3::Vec3;
+
+/// Column of table holding vector component
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Column {
+    /// column with given zero-based index
+    Index(usize),
+    /// column with given header name, requires header line
+    Name(String),
+}
+
+/// Layout of delimiter separated table
+///
+/// By default fields are separated by commas, there is no header line and
+/// components are read from the leading columns.
+///
+/// # Example
+/// ```
+/// # use linal::io::{Column, CsvOptions};
+/// let options = CsvOptions::new()
+///     .with_delimiter(';')
+///     .with_columns(vec![Column::Name("lon".into()), Column::Name("lat".into())]);
+/// assert!(options.header);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsvOptions {
+    /// field separator
+    pub delimiter: char,
+    /// if first non-empty line contains column names
+    pub header: bool,
+    /// columns holding `x`, `y` and `z` components
+    pub columns: Vec<Column>,
+}
+
+impl CsvOptions {
+    /// Comma separated table without header
+    pub fn new() -> CsvOptions {
+        CsvOptions {
+            delimiter: ',',
+            header: false,
+            columns: vec![Column::Index(0), Column::Index(1), Column::Index(2)],
+        }
+    }
+    /// Sets field separator
+    pub fn with_delimiter(mut self, delimiter: char) -> CsvOptions {
+        self.delimiter = delimiter;
+        self
+    }
+    /// Sets if table starts with header line
+    pub fn with_header(mut self, header: bool) -> CsvOptions {
+        self.header = header;
+        self
+    }
+    /// Sets columns of components, in order `x`, `y`, `z`
+    ///
+    /// Named columns turn header on.
+    pub fn with_columns(mut self, columns: Vec<Column>) -> CsvOptions {
+        if columns.iter().any(|c| matches!(*c, Column::Name(_))) {
+            self.header = true;
+        }
+        self.columns = columns;
+        self
+    }
+}
+
+impl Default for CsvOptions {
+    fn default() -> CsvOptions {
+        CsvOptions::new()
+    }
+}
+
+fn invalid_data(line: usize, message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, format!("line {}: {}", line, message))
+}
+
+fn split(line: &str, delimiter: char) -> Vec<&str> {
+    line.split(delimiter)
+        .map(|field| field.trim().trim_matches('"'))
+        .collect()
+}
+
+// Reads rows of `n` components according to options
+fn read_rows<R: BufRead>(reader: R, options: &CsvOptions, n: usize) -> io::Result<Vec<Vec<f64>>> {
+    if options.columns.len() < n {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput,
+                                  format!("expected {} columns, {} given", n, options.columns.len())));
+    }
+    let mut indices: Option<Vec<usize>> = if options.header {
+        None
+    } else {
+        options.columns[..n].iter().map(|c| match *c {
+            Column::Index(i) => Ok(i),
+            Column::Name(ref name) => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("column `{}` given by name, but table has no header", name))),
+        }).collect::<io::Result<_>>().map(Some)?
+    };
+    let mut rows = Vec::new();
+    for (number, line) in reader.lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let fields = split(&line, options.delimiter);
+        let columns = match indices {
+            Some(ref columns) => columns,
+            None => {
+                let resolved = options.columns[..n].iter().map(|c| match *c {
+                    Column::Index(i) => Ok(i),
+                    Column::Name(ref name) => fields.iter()
+                        .position(|f| f == name)
+                        .ok_or_else(|| invalid_data(number + 1, format!("no column `{}` in header", name))),
+                }).collect::<io::Result<_>>()?;
+                indices = Some(resolved);
+                continue;
+            }
+        };
+        let row = columns.iter().map(|&i| {
+            let field = fields.get(i)
+                .ok_or_else(|| invalid_data(number + 1, format!("missing column {}", i)))?;
+            field.parse()
+                .map_err(|e| invalid_data(number + 1, format!("column {}: {}", i, e)))
+        }).collect::<io::Result<_>>()?;
+        rows.push(row);
+    }
+    Ok(rows)
+}
+
+// Writes header, if needed, and rows of components
+fn write_rows<W, I>(mut writer: W, options: &CsvOptions, n: usize, rows: I) -> io::Result<()>
+    where W: Write, I: Iterator<Item = Vec<f64>>
+{
+    let delimiter = options.delimiter.to_string();
+    if options.header {
+        let names: Vec<String> = ["x", "y", "z"][..n].iter().enumerate().map(|(i, &default)| {
+            match options.columns.get(i) {
+                Some(Column::Name(name)) => name.clone(),
+                _ => default.to_string(),
+            }
+        }).collect();
+        writeln!(writer, "{}", names.join(&delimiter))?;
+    }
+    for row in rows {
+        let fields: Vec<String> = row.iter().map(f64::to_string).collect();
+        writeln!(writer, "{}", fields.join(&delimiter))?;
+    }
+    Ok(())
+}
+
+/// Reads 2D points from delimiter separated table
+///
+/// Empty lines are skipped, fields are trimmed and may be quoted. Errors of
+/// kind `InvalidData` report line number of malformed row.
+///
+/// # Example
+/// ```
+/// # use linal::Vec2;
+/// # use linal::io::{Column, CsvOptions, read_points_csv};
+/// let data = "id,y,x\n1,2.5,1\n2,0,-3\n";
+/// let options = CsvOptions::new()
+///     .with_columns(vec![Column::Name("x".into()), Column::Name("y".into())]);
+/// let points = read_points_csv(data.as_bytes(), &options).unwrap();
+/// assert_eq!(points, vec![Vec2::new(1.0, 2.5), Vec2::new(-3.0, 0.0)]);
+/// ```
+pub fn read_points_csv<R: BufRead>(reader: R, options: &CsvOptions) -> io::Result<Vec<Vec2>> {
+    Ok(read_rows(reader, options, 2)?
+        .into_iter()
+        .map(|r| Vec2::new(r[0], r[1]))
+        .collect())
+}
+
+/// Reads 3D points from delimiter separated table
+///
+/// # Example
+/// ```
+/// # use linal::Vec3;
+/// # use linal::io::{CsvOptions, read_points3_csv};
+/// let data = "1 2 3\n4 5 6\n";
+/// let points = read_points3_csv(data.as_bytes(), &CsvOptions::new().with_delimiter(' ')).unwrap();
+/// assert_eq!(points[1], Vec3::new(4, 5, 6));
+/// ```
+pub fn read_points3_csv<R: BufRead>(reader: R, options: &CsvOptions) -> io::Result<Vec<Vec3>> {
+    Ok(read_rows(reader, options, 3)?
+        .into_iter()
+        .map(|r| Vec3::new(r[0], r[1], r[2]))
+        .collect())
+}
+
+/// Writes 2D points as delimiter separated table
+///
+/// Header uses names of named columns, `x` and `y` otherwise. Column indices
+/// are ignored, components are written in order.
+///
+/// # Example
+/// ```
+/// # use linal::Vec2;
+/// # use linal::io::{CsvOptions, write_points_csv};
+/// let mut out = Vec::new();
+/// let points = [Vec2::new(1.0, 2.5), Vec2::new(-3.0, 0.0)];
+/// write_points_csv(&mut out, &points, &CsvOptions::new().with_header(true)).unwrap();
+/// assert_eq!(String::from_utf8(out).unwrap(), "x,y\n1,2.5\n-3,0\n");
+/// ```
+pub fn write_points_csv<W: Write>(writer: W, points: &[Vec2], options: &CsvOptions) -> io::Result<()> {
+    write_rows(writer, options, 2, points.iter().map(|p| vec![p.x, p.y]))
+}
+
+/// Writes 3D points as delimiter separated table
+pub fn write_points3_csv<W: Write>(writer: W, points: &[Vec3], options: &CsvOptions) -> io::Result<()> {
+    write_rows(writer, options, 3, points.iter().map(|p| vec![p.x, p.y, p.z]))
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    #[test]
+    fn csv_round_trip() {
+        let points = vec![Vec3::new(0.1, -2.0, 3.5), Vec3::new(1e-20, 7.0, -0.0)];
+        let options = CsvOptions::new().with_delimiter('\t').with_header(true);
+        let mut out = Vec::new();
+        write_points3_csv(&mut out, &points, &options).unwrap();
+        let read = read_points3_csv(&out[..], &options).unwrap();
+        assert_eq!(read, points);
+    }
+
+    #[test]
+    fn csv_errors() {
+        let options = CsvOptions::new();
+        let err = read_points_csv("1,2\n\n3,x\n".as_bytes(), &options).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(err.to_string().starts_with("line 3"));
+        let err = read_points_csv("1\n".as_bytes(), &options).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        let named = options.with_columns(vec![Column::Name("a".into()), Column::Index(1)]);
+        let err = read_points_csv("b,c\n1,2\n".as_bytes(), &named).unwrap_err();
+        assert!(err.to_string().contains("`a`"));
+        let err = read_points3_csv("1,2,3\n".as_bytes(),
+                                   &CsvOptions::new().with_columns(vec![Column::Index(0)])).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    }
+}
@@ -0,0 +1,177 @@
+//! GeoJSON geometry objects, available with `geojson` feature.
+use std::error::Error;
+use std::fmt;
+use serde_json::{json, Value};
+use polygon::Polygon;
+use polyline::Polyline;
+use vec2::Vec2;
+
+/// Error returned when converting GeoJSON to geometry fails
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GeoJsonError {
+    /// geometry has other type than requested
+    WrongType {
+        /// requested geometry type
+        expected: &'static str,
+        /// type found in object, empty if missing
+        found: String,
+    },
+    /// `coordinates` member is missing or malformed
+    InvalidCoordinates,
+    /// polygon has interior rings, which are not supported
+    Holes,
+}
+
+impl fmt::Display for GeoJsonError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            GeoJsonError::WrongType { expected, ref found } =>
+                write!(f, "expected {} geometry, found `{}`", expected, found),
+            GeoJsonError::InvalidCoordinates => write!(f, "invalid coordinates"),
+            GeoJsonError::Holes => write!(f, "polygons with holes are not supported"),
+        }
+    }
+}
+
+impl Error for GeoJsonError {}
+
+/// Geometry convertible to and from GeoJSON geometry object
+///
+/// `Vec2` maps to `Point`, `Polyline` to `LineString` and `Polygon` without
+/// holes to `Polygon`. Conversion from GeoJSON also accepts `Feature` object
+/// and uses its geometry; altitude of positions is ignored.
+///
+/// # Example
+/// ```
+/// # extern crate linal;
+/// # extern crate serde_json;
+/// # use linal::{Polyline, Vec2};
+/// # use linal::io::GeoJson;
+/// # fn main() {
+/// let line = Polyline::new(vec![Vec2::new(0, 0), Vec2::new(1.5, 2.0)]);
+/// let value = line.to_geojson();
+/// assert_eq!(value.to_string(), r#"{"coordinates":[[0.0,0.0],[1.5,2.0]],"type":"LineString"}"#);
+/// assert_eq!(Polyline::from_geojson(&value), Ok(line));
+/// # }
+/// ```
+pub trait GeoJson: Sized {
+    /// Converts geometry to GeoJSON geometry object
+    fn to_geojson(&self) -> Value;
+    /// Converts GeoJSON geometry or feature object to geometry
+    fn from_geojson(value: &Value) -> Result<Self, GeoJsonError>;
+}
+
+// Coordinates of geometry object with given type, unwrapping feature
+fn coordinates<'a>(value: &'a Value, expected: &'static str) -> Result<&'a Value, GeoJsonError> {
+    let value = match value.get("type") {
+        Some(t) if t == "Feature" => value.get("geometry").unwrap_or(&Value::Null),
+        _ => value,
+    };
+    let found = value.get("type").and_then(Value::as_str).unwrap_or("");
+    if found != expected {
+        return Err(GeoJsonError::WrongType { expected, found: found.to_string() });
+    }
+    value.get("coordinates").ok_or(GeoJsonError::InvalidCoordinates)
+}
+
+fn position(value: &Value) -> Result<Vec2, GeoJsonError> {
+    let xy = value.as_array()
+        .filter(|a| a.len() >= 2)
+        .ok_or(GeoJsonError::InvalidCoordinates)?;
+    match (xy[0].as_f64(), xy[1].as_f64()) {
+        (Some(x), Some(y)) => Ok(Vec2::new(x, y)),
+        _ => Err(GeoJsonError::InvalidCoordinates),
+    }
+}
+
+fn positions(value: &Value) -> Result<Vec<Vec2>, GeoJsonError> {
+    value.as_array()
+        .ok_or(GeoJsonError::InvalidCoordinates)?
+        .iter()
+        .map(position)
+        .collect()
+}
+
+fn to_positions(points: &[Vec2]) -> Value {
+    Value::Array(points.iter().map(|p| json!([p.x, p.y])).collect())
+}
+
+impl GeoJson for Vec2 {
+    fn to_geojson(&self) -> Value {
+        json!({ "type": "Point", "coordinates": [self.x, self.y] })
+    }
+    fn from_geojson(value: &Value) -> Result<Vec2, GeoJsonError> {
+        position(coordinates(value, "Point")?)
+    }
+}
+
+impl GeoJson for Polyline {
+    fn to_geojson(&self) -> Value {
+        json!({ "type": "LineString", "coordinates": to_positions(&self.vertices) })
+    }
+    fn from_geojson(value: &Value) -> Result<Polyline, GeoJsonError> {
+        Ok(Polyline::new(positions(coordinates(value, "LineString")?)?))
+    }
+}
+
+impl GeoJson for Polygon {
+    /// Ring is closed by repeating the first vertex
+    fn to_geojson(&self) -> Value {
+        let mut ring = self.vertices.clone();
+        if let Some(&first) = self.vertices.first() {
+            ring.push(first);
+        }
+        json!({ "type": "Polygon", "coordinates": [to_positions(&ring)] })
+    }
+    /// Closing vertex of ring is dropped
+    fn from_geojson(value: &Value) -> Result<Polygon, GeoJsonError> {
+        let rings = coordinates(value, "Polygon")?
+            .as_array()
+            .ok_or(GeoJsonError::InvalidCoordinates)?;
+        if rings.len() > 1 {
+            return Err(GeoJsonError::Holes);
+        }
+        let mut vertices = match rings.first() {
+            Some(ring) => positions(ring)?,
+            None => Vec::new(),
+        };
+        if vertices.len() > 1 && vertices.first() == vertices.last() {
+            vertices.pop();
+        }
+        Ok(Polygon::new(vertices))
+    }
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    #[test]
+    fn geojson_round_trip() {
+        let polygon = Polygon::new(vec![Vec2::new(0.1, 0.2), Vec2::new(3, 0), Vec2::new(1e-7, 4.5)]);
+        let value = polygon.to_geojson();
+        assert_eq!(value["coordinates"][0].as_array().unwrap().len(), 4);
+        assert_eq!(Polygon::from_geojson(&value), Ok(polygon));
+        let p = Vec2::new(-1.5, 2.0);
+        assert_eq!(Vec2::from_geojson(&p.to_geojson()), Ok(p));
+    }
+
+    #[test]
+    fn geojson_parse() {
+        let feature: Value = serde_json::from_str(r#"{
+            "type": "Feature",
+            "properties": {},
+            "geometry": { "type": "Point", "coordinates": [30, 10, 120] }
+        }"#).unwrap();
+        assert_eq!(Vec2::from_geojson(&feature), Ok(Vec2::new(30, 10)));
+        assert_eq!(Polyline::from_geojson(&feature),
+                   Err(GeoJsonError::WrongType { expected: "LineString", found: "Point".into() }));
+        let bad = json!({ "type": "Point", "coordinates": [1] });
+        assert_eq!(Vec2::from_geojson(&bad), Err(GeoJsonError::InvalidCoordinates));
+        let holes = json!({ "type": "Polygon", "coordinates": [
+            [[0, 0], [4, 0], [0, 4], [0, 0]],
+            [[1, 1], [2, 1], [1, 2], [1, 1]],
+        ]});
+        assert_eq!(Polygon::from_geojson(&holes), Err(GeoJsonError::Holes));
+    }
+}
@@ -0,0 +1,251 @@
+//! Vertex positions and normals of OBJ and ASCII PLY meshes.
+//!
+//! Only vertex data is handled, faces and other elements are skipped on
+//! reading and not written.
+use std::io::{self, BufRead, Write};
+use vec3::Vec3;
+
+/// Vertex positions and normals of mesh
+///
+/// `normals` is either empty or has the same length as `positions`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Vertices {
+    /// vertex positions
+    pub positions: Vec<Vec3>,
+    /// vertex normals
+    pub normals: Vec<Vec3>,
+}
+
+fn invalid_data(line: usize, message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, format!("line {}: {}", line, message))
+}
+
+fn parse_xyz<'a, I: Iterator<Item = &'a str>>(words: I, line: usize) -> io::Result<Vec3> {
+    let v = words.take(3)
+        .map(|w| w.parse().map_err(|_| invalid_data(line, "invalid number")))
+        .collect::<io::Result<Vec<f64>>>()?;
+    if v.len() < 3 {
+        return Err(invalid_data(line, "expected 3 coordinates"));
+    }
+    Ok(Vec3::new(v[0], v[1], v[2]))
+}
+
+fn check_normals(vertices: &Vertices) -> io::Result<()> {
+    if vertices.normals.is_empty() || vertices.normals.len() == vertices.positions.len() {
+        Ok(())
+    } else {
+        Err(io::Error::new(io::ErrorKind::InvalidInput, "number of normals differs from number of positions"))
+    }
+}
+
+/// Reads `v` and `vn` records of Wavefront OBJ file
+///
+/// Homogeneous weight of positions is ignored.
+///
+/// # Example
+/// ```
+/// # use linal::Vec3;
+/// # use linal::io::read_obj;
+/// let obj = "# triangle\nv 0 0 0\nv 1 0 0\nv 0 1 0 1.0\nvn 0 0 1\nf 1 2 3\n";
+/// let vertices = read_obj(obj.as_bytes()).unwrap();
+/// assert_eq!(vertices.positions[2], Vec3::new(0, 1, 0));
+/// assert_eq!(vertices.normals, vec![Vec3::new(0, 0, 1)]);
+/// ```
+pub fn read_obj<R: BufRead>(reader: R) -> io::Result<Vertices> {
+    let mut vertices = Vertices::default();
+    for (number, line) in reader.lines().enumerate() {
+        let line = line?;
+        let mut words = line.split_whitespace();
+        match words.next() {
+            Some("v") => vertices.positions.push(parse_xyz(words, number + 1)?),
+            Some("vn") => vertices.normals.push(parse_xyz(words, number + 1)?),
+            _ => {}
+        }
+    }
+    Ok(vertices)
+}
+
+/// Writes vertices as `v` and `vn` records of Wavefront OBJ file
+///
+/// # Example
+/// ```
+/// # use linal::Vec3;
+/// # use linal::io::{Vertices, write_obj};
+/// let vertices = Vertices { positions: vec![Vec3::new(1, 2, 3)], normals: vec![] };
+/// let mut out = Vec::new();
+/// write_obj(&mut out, &vertices).unwrap();
+/// assert_eq!(String::from_utf8(out).unwrap(), "v 1 2 3\n");
+/// ```
+pub fn write_obj<W: Write>(mut writer: W, vertices: &Vertices) -> io::Result<()> {
+    check_normals(vertices)?;
+    for p in &vertices.positions {
+        writeln!(writer, "v {} {} {}", p.x, p.y, p.z)?;
+    }
+    for n in &vertices.normals {
+        writeln!(writer, "vn {} {} {}", n.x, n.y, n.z)?;
+    }
+    Ok(())
+}
+
+// Element declared in PLY header
+struct Element {
+    name: String,
+    count: usize,
+    properties: Vec<String>,
+}
+
+/// Reads vertex positions and normals of ASCII PLY file
+///
+/// Positions are taken from `x`, `y`, `z` properties of `vertex` element and
+/// normals from `nx`, `ny`, `nz`, if present. Binary PLY is not supported.
+///
+/// # Example
+/// ```
+/// # use linal::Vec3;
+/// # use linal::io::read_ply;
+/// let ply = "ply\nformat ascii 1.0\nelement vertex 2\n\
+///            property float x\nproperty float y\nproperty float z\n\
+///            property uchar red\nend_header\n1 2 3 255\n4 5 6 0\n";
+/// let vertices = read_ply(ply.as_bytes()).unwrap();
+/// assert_eq!(vertices.positions, vec![Vec3::new(1, 2, 3), Vec3::new(4, 5, 6)]);
+/// assert!(vertices.normals.is_empty());
+/// ```
+pub fn read_ply<R: BufRead>(reader: R) -> io::Result<Vertices> {
+    let mut lines = reader.lines().enumerate();
+    let mut next_line = move || -> io::Result<(usize, String)> {
+        match lines.next() {
+            Some((number, line)) => Ok((number + 1, line?)),
+            None => Err(io::Error::new(io::ErrorKind::UnexpectedEof, "unexpected end of PLY file")),
+        }
+    };
+    let (number, magic) = next_line()?;
+    if magic.trim() != "ply" {
+        return Err(invalid_data(number, "missing `ply` magic"));
+    }
+    let mut elements: Vec<Element> = Vec::new();
+    loop {
+        let (number, line) = next_line()?;
+        let words: Vec<&str> = line.split_whitespace().collect();
+        match words.first().cloned() {
+            Some("format") if words.get(1) != Some(&"ascii") =>
+                return Err(invalid_data(number, "only ASCII PLY is supported")),
+            Some("element") => {
+                let count = words.get(2)
+                    .and_then(|c| c.parse().ok())
+                    .ok_or_else(|| invalid_data(number, "invalid element count"))?;
+                elements.push(Element { name: words.get(1).unwrap_or(&"").to_string(), count, properties: vec![] });
+            }
+            Some("property") => {
+                let element = elements.last_mut()
+                    .ok_or_else(|| invalid_data(number, "property outside of element"))?;
+                element.properties.push(words.last().unwrap_or(&"").to_string());
+            }
+            Some("end_header") => break,
+            _ => {}
+        }
+    }
+    let mut vertices = Vertices::default();
+    for element in &elements {
+        if element.name != "vertex" {
+            for _ in 0..element.count {
+                next_line()?;
+            }
+            continue;
+        }
+        let find = |name: &str| element.properties.iter().position(|p| p == name);
+        let position = [find("x"), find("y"), find("z")];
+        let normal = [find("nx"), find("ny"), find("nz")];
+        let has_normals = normal.iter().all(Option::is_some);
+        if position.iter().any(Option::is_none) {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "vertex element has no x, y, z properties"));
+        }
+        for _ in 0..element.count {
+            let (number, line) = next_line()?;
+            let values = line.split_whitespace()
+                .map(|w| w.parse().map_err(|_| invalid_data(number, "invalid number")))
+                .collect::<io::Result<Vec<f64>>>()?;
+            let get = |indices: &[Option<usize>; 3]| -> io::Result<Vec3> {
+                let value = |i: Option<usize>| i.and_then(|i| values.get(i).cloned())
+                    .ok_or_else(|| invalid_data(number, "missing vertex property"));
+                Ok(Vec3::new(value(indices[0])?, value(indices[1])?, value(indices[2])?))
+            };
+            vertices.positions.push(get(&position)?);
+            if has_normals {
+                vertices.normals.push(get(&normal)?);
+            }
+        }
+        break;
+    }
+    Ok(vertices)
+}
+
+/// Writes vertices as ASCII PLY file with single `vertex` element
+///
+/// # Example
+/// ```
+/// # use linal::Vec3;
+/// # use linal::io::{Vertices, read_ply, write_ply};
+/// let vertices = Vertices {
+///     positions: vec![Vec3::new(1, 2, 3), Vec3::new(0, 0, 0)],
+///     normals: vec![Vec3::new(0, 0, 1), Vec3::new(0, 1, 0)],
+/// };
+/// let mut out = Vec::new();
+/// write_ply(&mut out, &vertices).unwrap();
+/// assert_eq!(read_ply(&out[..]).unwrap(), vertices);
+/// ```
+pub fn write_ply<W: Write>(mut writer: W, vertices: &Vertices) -> io::Result<()> {
+    check_normals(vertices)?;
+    let has_normals = !vertices.normals.is_empty();
+    writeln!(writer, "ply\nformat ascii 1.0\nelement vertex {}", vertices.positions.len())?;
+    writeln!(writer, "property double x\nproperty double y\nproperty double z")?;
+    if has_normals {
+        writeln!(writer, "property double nx\nproperty double ny\nproperty double nz")?;
+    }
+    writeln!(writer, "end_header")?;
+    for (i, p) in vertices.positions.iter().enumerate() {
+        write!(writer, "{} {} {}", p.x, p.y, p.z)?;
+        if has_normals {
+            let n = vertices.normals[i];
+            write!(writer, " {} {} {}", n.x, n.y, n.z)?;
+        }
+        writeln!(writer)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    #[test]
+    fn obj_round_trip() {
+        let vertices = Vertices {
+            positions: vec![Vec3::new(0.1, -2.0, 3e-5), Vec3::new(1, 1, 1)],
+            normals: vec![Vec3::new(0, 0, 1), Vec3::new(0, 1, 0)],
+        };
+        let mut out = Vec::new();
+        write_obj(&mut out, &vertices).unwrap();
+        assert_eq!(read_obj(&out[..]).unwrap(), vertices);
+        let err = read_obj("v 1 2\n".as_bytes()).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        let bad = Vertices { positions: vec![], normals: vec![Vec3::new(0, 0, 1)] };
+        assert_eq!(write_obj(Vec::new(), &bad).unwrap_err().kind(), io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn ply_skips_elements() {
+        let ply = "ply\nformat ascii 1.0\ncomment test\n\
+                   element face 1\nproperty list uchar int vertex_indices\n\
+                   element vertex 3\nproperty float nx\nproperty float ny\nproperty float nz\n\
+                   property float x\nproperty float y\nproperty float z\nend_header\n\
+                   3 0 1 2\n0 0 1 0 0 0\n0 0 1 1 0 0\n0 0 1 0 1 0\n";
+        let vertices = read_ply(ply.as_bytes()).unwrap();
+        assert_eq!(vertices.positions, vec![Vec3::new(0, 0, 0), Vec3::new(1, 0, 0), Vec3::new(0, 1, 0)]);
+        assert_eq!(vertices.normals, vec![Vec3::new(0, 0, 1); 3]);
+        let binary = "ply\nformat binary_little_endian 1.0\nend_header\n";
+        assert_eq!(read_ply(binary.as_bytes()).unwrap_err().kind(), io::ErrorKind::InvalidData);
+        let truncated = "ply\nformat ascii 1.0\nelement vertex 2\nproperty float x\n\
+                         property float y\nproperty float z\nend_header\n1 2 3\n";
+        assert_eq!(read_ply(truncated.as_bytes()).unwrap_err().kind(), io::ErrorKind::UnexpectedEof);
+    }
+}
@@ -0,0 +1,14 @@
+//! Reading and writing vectors in common exchange formats.
+pub mod csv;
+pub mod wkt;
+pub mod bytes;
+pub mod mesh;
+#[cfg(feature = "geojson")]
+pub mod geojson;
+
+pub use self::csv::{Column, CsvOptions, read_points_csv, read_points3_csv,
+                    write_points_csv, write_points3_csv};
+pub use self::wkt::{Wkt, ParseWktError};
+pub use self::mesh::{Vertices, read_obj, write_obj, read_ply, write_ply};
+#[cfg(feature = "geojson")]
+pub use self::geojson::{GeoJson, GeoJsonError};
@@ -0,0 +1,288 @@
+//! Well-Known Text representation of geometry.
+use std::error::Error;
+use std::fmt;
+use parse::{parse_components, ParseVecError};
+use polygon::Polygon;
+use polyline::Polyline;
+use vec2::Vec2;
+use vec3::Vec3;
+
+/// Error returned when parsing WKT fails
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseWktError {
+    /// geometry has other type than requested
+    WrongType {
+        /// requested geometry tag
+        expected: &'static str,
+        /// tag found in string
+        found: String,
+    },
+    /// missing or unbalanced parentheses
+    Syntax,
+    /// point is `EMPTY`, which has no `Vec2` or `Vec3` counterpart
+    Empty,
+    /// polygon has interior rings, which are not supported
+    Holes,
+    /// invalid coordinate tuple
+    Coordinate {
+        /// index of coordinate tuple
+        index: usize,
+        /// underlying error
+        error: ParseVecError,
+    },
+}
+
+impl fmt::Display for ParseWktError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ParseWktError::WrongType { expected, ref found } =>
+                write!(f, "expected {} geometry, found `{}`", expected, found),
+            ParseWktError::Syntax => write!(f, "malformed WKT"),
+            ParseWktError::Empty => write!(f, "empty geometry"),
+            ParseWktError::Holes => write!(f, "polygons with holes are not supported"),
+            ParseWktError::Coordinate { index, ref error } =>
+                write!(f, "invalid coordinate {}: {}", index, error),
+        }
+    }
+}
+
+impl Error for ParseWktError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            ParseWktError::Coordinate { ref error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// Geometry convertible to and from Well-Known Text
+///
+/// Only 2D `POINT`, `LINESTRING` and `POLYGON` without holes are supported,
+/// plus `POINT Z` for `Vec3`. Tags are case insensitive. `EMPTY` line
+/// strings and polygons have no vertices.
+///
+/// # Example
+/// ```
+/// # use linal::{Polyline, Vec2};
+/// # use linal::io::Wkt;
+/// let line = Polyline::from_wkt("LineString (0 0, 1 0.5, 2 2)").unwrap();
+/// assert_eq!(line.vertices[1], Vec2::new(1.0, 0.5));
+/// assert_eq!(line.to_wkt(), "LINESTRING (0 0, 1 0.5, 2 2)");
+/// ```
+pub trait Wkt: Sized {
+    /// Formats geometry as WKT
+    fn to_wkt(&self) -> String;
+    /// Parses geometry from WKT
+    fn from_wkt(s: &str) -> Result<Self, ParseWktError>;
+}
+
+// Splits string into upper case tag and body inside outer parentheses,
+// body is `None` for `EMPTY` geometry
+fn split_tag<'a>(s: &'a str, expected: &'static str) -> Result<Option<&'a str>, ParseWktError> {
+    let s = s.trim();
+    let (tag, body) = match s.find('(') {
+        Some(i) => (s[..i].trim(), &s[i..]),
+        None => (s, ""),
+    };
+    let tag = tag.split_whitespace().collect::<Vec<_>>().join(" ").to_uppercase();
+    if tag == format!("{} EMPTY", expected) {
+        return if body.is_empty() { Ok(None) } else { Err(ParseWktError::Syntax) };
+    }
+    if tag != expected {
+        return Err(ParseWktError::WrongType { expected, found: tag });
+    }
+    unwrap_parens(body).map(Some)
+}
+
+fn unwrap_parens(s: &str) -> Result<&str, ParseWktError> {
+    let s = s.trim();
+    if s.len() >= 2 && s.starts_with('(') && s.ends_with(')') {
+        Ok(&s[1..s.len() - 1])
+    } else {
+        Err(ParseWktError::Syntax)
+    }
+}
+
+// Splits comma separated list at top level of parentheses
+fn split_top_level(s: &str) -> Result<Vec<&str>, ParseWktError> {
+    let mut parts = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, c) in s.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' if depth == 0 => return Err(ParseWktError::Syntax),
+            ')' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(&s[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    if depth != 0 {
+        return Err(ParseWktError::Syntax);
+    }
+    parts.push(&s[start..]);
+    Ok(parts)
+}
+
+fn parse_coordinates(s: &str) -> Result<Vec<Vec2>, ParseWktError> {
+    if s.contains(['(', ')']) {
+        return Err(ParseWktError::Syntax);
+    }
+    s.split(',')
+        .enumerate()
+        .map(|(index, c)| parse_components(c, 2)
+            .map(|v| Vec2::new(v[0], v[1]))
+            .map_err(|error| ParseWktError::Coordinate { index, error }))
+        .collect()
+}
+
+fn format_coordinates(points: &[Vec2]) -> String {
+    points.iter()
+        .map(|p| format!("{} {}", p.x, p.y))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+impl Wkt for Vec2 {
+    /// # Example
+    /// ```
+    /// # use linal::Vec2;
+    /// # use linal::io::Wkt;
+    /// assert_eq!(Vec2::new(1.5, -2.0).to_wkt(), "POINT (1.5 -2)");
+    /// ```
+    fn to_wkt(&self) -> String {
+        format!("POINT ({} {})", self.x, self.y)
+    }
+    /// # Example
+    /// ```
+    /// # use linal::Vec2;
+    /// # use linal::io::Wkt;
+    /// assert_eq!(Vec2::from_wkt("point(1 2)"), Ok(Vec2::new(1, 2)));
+    /// ```
+    fn from_wkt(s: &str) -> Result<Vec2, ParseWktError> {
+        let body = split_tag(s, "POINT")?.ok_or(ParseWktError::Empty)?;
+        let points = parse_coordinates(body)?;
+        match points.len() {
+            1 => Ok(points[0]),
+            _ => Err(ParseWktError::Syntax),
+        }
+    }
+}
+
+impl Wkt for Vec3 {
+    /// # Example
+    /// ```
+    /// # use linal::Vec3;
+    /// # use linal::io::Wkt;
+    /// assert_eq!(Vec3::new(1, 2, 3).to_wkt(), "POINT Z (1 2 3)");
+    /// ```
+    fn to_wkt(&self) -> String {
+        format!("POINT Z ({} {} {})", self.x, self.y, self.z)
+    }
+    fn from_wkt(s: &str) -> Result<Vec3, ParseWktError> {
+        let body = split_tag(s, "POINT Z")?.ok_or(ParseWktError::Empty)?;
+        if body.contains(['(', ')', ',']) {
+            return Err(ParseWktError::Syntax);
+        }
+        parse_components(body, 3)
+            .map(|v| Vec3::new(v[0], v[1], v[2]))
+            .map_err(|error| ParseWktError::Coordinate { index: 0, error })
+    }
+}
+
+impl Wkt for Polyline {
+    fn to_wkt(&self) -> String {
+        if self.vertices.is_empty() {
+            return "LINESTRING EMPTY".to_string();
+        }
+        format!("LINESTRING ({})", format_coordinates(&self.vertices))
+    }
+    fn from_wkt(s: &str) -> Result<Polyline, ParseWktError> {
+        match split_tag(s, "LINESTRING")? {
+            Some(body) => Ok(Polyline::new(parse_coordinates(body)?)),
+            None => Ok(Polyline::new(Vec::new())),
+        }
+    }
+}
+
+impl Wkt for Polygon {
+    /// Ring is closed by repeating the first vertex
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Polygon, Vec2};
+    /// # use linal::io::Wkt;
+    /// let square = Polygon::new(vec![Vec2::new(0, 0), Vec2::new(1, 0), Vec2::new(1, 1)]);
+    /// assert_eq!(square.to_wkt(), "POLYGON ((0 0, 1 0, 1 1, 0 0))");
+    /// ```
+    fn to_wkt(&self) -> String {
+        match self.vertices.first() {
+            None => "POLYGON EMPTY".to_string(),
+            Some(&first) => {
+                let mut ring = self.vertices.clone();
+                ring.push(first);
+                format!("POLYGON (({}))", format_coordinates(&ring))
+            }
+        }
+    }
+    /// Closing vertex of ring is dropped
+    fn from_wkt(s: &str) -> Result<Polygon, ParseWktError> {
+        let body = match split_tag(s, "POLYGON")? {
+            Some(body) => body,
+            None => return Ok(Polygon::new(Vec::new())),
+        };
+        let rings = split_top_level(body)?
+            .into_iter()
+            .map(unwrap_parens)
+            .collect::<Result<Vec<_>, _>>()?;
+        if rings.len() > 1 {
+            return Err(ParseWktError::Holes);
+        }
+        let mut vertices = parse_coordinates(rings[0])?;
+        if vertices.len() > 1 && vertices.first() == vertices.last() {
+            vertices.pop();
+        }
+        Ok(Polygon::new(vertices))
+    }
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    #[test]
+    fn wkt_round_trip() {
+        let polygon = Polygon::new(vec![Vec2::new(0.1, 0.2), Vec2::new(3, 0), Vec2::new(1e-7, 4.5)]);
+        assert_eq!(Polygon::from_wkt(&polygon.to_wkt()), Ok(polygon));
+        let line = Polyline::new(vec![Vec2::new(-1, 2), Vec2::new(0.3, 1e10)]);
+        assert_eq!(Polyline::from_wkt(&line.to_wkt()), Ok(line));
+        let p = Vec3::new(1.25, -0.0, 7.0);
+        assert_eq!(Vec3::from_wkt(&p.to_wkt()), Ok(p));
+        assert_eq!(Vec3::from_wkt("point z(1 2 3)"), Ok(Vec3::new(1, 2, 3)));
+        let empty = Polyline::new(Vec::new());
+        assert_eq!(Polyline::from_wkt(&empty.to_wkt()), Ok(empty));
+        let empty = Polygon::new(Vec::new());
+        assert_eq!(Polygon::from_wkt(&empty.to_wkt()), Ok(empty));
+        assert_eq!(Polygon::from_wkt("polygon  empty"), Ok(Polygon::new(Vec::new())));
+    }
+
+    #[test]
+    fn wkt_errors() {
+        assert_eq!(Vec2::from_wkt("POINT EMPTY"), Err(ParseWktError::Empty));
+        assert_eq!(Vec3::from_wkt("POINT Z EMPTY"), Err(ParseWktError::Empty));
+        assert_eq!(Polyline::from_wkt("LINESTRING EMPTY (1 2)"), Err(ParseWktError::Syntax));
+        assert_eq!(Vec2::from_wkt("POINT (1 2"), Err(ParseWktError::Syntax));
+        assert_eq!(Vec2::from_wkt("LINESTRING (1 2, 3 4)"),
+                   Err(ParseWktError::WrongType { expected: "POINT", found: "LINESTRING".into() }));
+        assert_eq!(Polygon::from_wkt("POLYGON ((0 0, 4 0, 0 4, 0 0), (1 1, 2 1, 1 2, 1 1))"),
+                   Err(ParseWktError::Holes));
+        assert_eq!(Polygon::from_wkt("POLYGON (0 0, 4 0, 0 4, 0 0)"), Err(ParseWktError::Syntax));
+        match Polyline::from_wkt("LINESTRING (1 2, 3)") {
+            Err(ParseWktError::Coordinate { index: 1, .. }) => {}
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+}
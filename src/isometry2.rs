@@ -0,0 +1,104 @@
+//! Rigid motions of plane.
+use std::ops::{Mul, MulAssign};
+use mat2::Mat2;
+use mat3::Mat3;
+use vec2::Vec2;
+
+/// Rigid motion of plane: rotation followed by translation
+///
+/// Point $\vec{p}$ is mapped to $R \vec{p} + \vec{t}$. Composition with `*`
+/// applies right operand first.
+///
+/// # Example
+/// ```
+/// # use linal::{Isometry2, Vec2};
+/// # use std::f64::consts::FRAC_PI_2;
+/// let m = Isometry2::from_angle(FRAC_PI_2, Vec2::new(1, 0));
+/// let p = m.transform_point(Vec2::new(1, 0));
+/// assert!((p - Vec2::new(1, 1)).len() < 1e-12);
+/// assert!((m.inverse().transform_point(p) - Vec2::new(1, 0)).len() < 1e-12);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Isometry2 {
+    /// rotation matrix
+    pub rotation: Mat2,
+    /// translation vector
+    pub translation: Vec2,
+}
+
+impl Isometry2 {
+    /// Constructs a new `Isometry2` from rotation matrix and translation
+    pub fn new(rotation: Mat2, translation: Vec2) -> Isometry2 {
+        Isometry2 { rotation, translation }
+    }
+    /// Counterclockwise rotation by angle followed by translation
+    pub fn from_angle<I: Into<f64>>(angle: I, translation: Vec2) -> Isometry2 {
+        Isometry2::new(Mat2::rotation(angle), translation)
+    }
+    /// Identity motion
+    pub fn identity() -> Isometry2 {
+        Isometry2::new(Mat2::identity(), Vec2::zero())
+    }
+    /// Pure translation
+    pub fn translation(t: Vec2) -> Isometry2 {
+        Isometry2::new(Mat2::identity(), t)
+    }
+    /// Counterclockwise rotation angle in $(-\pi, \pi]$
+    pub fn angle(self) -> f64 {
+        self.rotation.y.x.atan2(self.rotation.x.x)
+    }
+    /// Image of point
+    pub fn transform_point(self, p: Vec2) -> Vec2 {
+        self.rotation * p + self.translation
+    }
+    /// Image of vector, translation doesn't affect it
+    pub fn transform_vector(self, v: Vec2) -> Vec2 {
+        self.rotation * v
+    }
+    /// Inverse motion
+    pub fn inverse(self) -> Isometry2 {
+        let r = self.rotation.transpose();
+        Isometry2::new(r, -(r * self.translation))
+    }
+    /// Homogeneous matrix of motion
+    pub fn to_mat3(self) -> Mat3 {
+        Mat3::affine2(self.rotation, self.translation)
+    }
+}
+
+impl Mul for Isometry2 {
+    type Output = Isometry2;
+
+    fn mul(self, rhs: Isometry2) -> Isometry2 {
+        Isometry2::new(self.rotation * rhs.rotation, self.transform_point(rhs.translation))
+    }
+}
+
+impl MulAssign for Isometry2 {
+    /// Accumulates transform in place, `a *= b` is the same as `a = a * b`
+    fn mul_assign(&mut self, rhs: Isometry2) {
+        *self = *self * rhs;
+    }
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    #[test]
+    fn isometry2_compose_inverse() {
+        let a = Isometry2::from_angle(0.8, Vec2::new(1, -1));
+        let b = Isometry2::from_angle(-2.1, Vec2::new(0, 3));
+        let p = Vec2::new(0.5, -4.0);
+        let ab = (a * b).transform_point(p);
+        assert!((ab - a.transform_point(b.transform_point(p))).len() < 1e-12);
+        assert!(((a * b).angle() - (0.8 - 2.1)).abs() < 1e-12);
+        assert!(((a * a.inverse()).transform_point(p) - p).len() < 1e-12);
+        let h = a.to_mat3() * p.to_homogeneous();
+        assert!((h - a.transform_point(p).to_homogeneous()).len() < 1e-12);
+        assert_eq!(h.z, 1.0);
+        let mut acc = a;
+        acc *= b;
+        assert!((acc.transform_point(p) - ab).len() < 1e-12);
+    }
+}
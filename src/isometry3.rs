@@ -0,0 +1,108 @@
+//! Rigid motions of space.
+use std::ops::{Mul, MulAssign};
+use mat3::Mat3;
+use mat4::Mat4;
+use vec3::Vec3;
+use vec4::Vec4;
+
+/// Rigid motion of space: rotation followed by translation
+///
+/// Point $\vec{p}$ is mapped to $R \vec{p} + \vec{t}$. Composition with `*`
+/// applies right operand first.
+///
+/// # Example
+/// ```
+/// # use linal::{Isometry3, Mat3, Vec3};
+/// # use std::f64::consts::FRAC_PI_2;
+/// let m = Isometry3::new(Mat3::rotation(Vec3::new(0, 0, 1), FRAC_PI_2), Vec3::new(1, 0, 0));
+/// let p = m.transform_point(Vec3::new(1, 0, 0));
+/// assert!((p - Vec3::new(1, 1, 0)).len() < 1e-12);
+/// assert!((m.inverse().transform_point(p) - Vec3::new(1, 0, 0)).len() < 1e-12);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Isometry3 {
+    /// rotation matrix
+    pub rotation: Mat3,
+    /// translation vector
+    pub translation: Vec3,
+}
+
+impl Isometry3 {
+    /// Constructs a new `Isometry3` from rotation matrix and translation
+    pub fn new(rotation: Mat3, translation: Vec3) -> Isometry3 {
+        Isometry3 { rotation, translation }
+    }
+    /// Identity motion
+    pub fn identity() -> Isometry3 {
+        Isometry3::new(Mat3::identity(), Vec3::zero())
+    }
+    /// Pure translation
+    pub fn translation(t: Vec3) -> Isometry3 {
+        Isometry3::new(Mat3::identity(), t)
+    }
+    /// Image of point
+    pub fn transform_point(self, p: Vec3) -> Vec3 {
+        self.rotation * p + self.translation
+    }
+    /// Image of vector, translation doesn't affect it
+    pub fn transform_vector(self, v: Vec3) -> Vec3 {
+        self.rotation * v
+    }
+    /// Inverse motion
+    pub fn inverse(self) -> Isometry3 {
+        let r = self.rotation.transpose();
+        Isometry3::new(r, -(r * self.translation))
+    }
+    /// Homogeneous matrix of motion
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Isometry3, Vec3, Vec4};
+    /// let m = Isometry3::translation(Vec3::new(1, 2, 3)).to_mat4();
+    /// assert_eq!(m * Vec4::new(1, 1, 1, 1), Vec4::new(2, 3, 4, 1));
+    /// ```
+    pub fn to_mat4(self) -> Mat4 {
+        let (r, t) = (self.rotation, self.translation);
+        Mat4::new(Vec4::new(r.x.x, r.x.y, r.x.z, t.x),
+                  Vec4::new(r.y.x, r.y.y, r.y.z, t.y),
+                  Vec4::new(r.z.x, r.z.y, r.z.z, t.z),
+                  Vec4::new(0, 0, 0, 1))
+    }
+}
+
+impl Mul for Isometry3 {
+    type Output = Isometry3;
+
+    fn mul(self, rhs: Isometry3) -> Isometry3 {
+        Isometry3::new(self.rotation * rhs.rotation, self.transform_point(rhs.translation))
+    }
+}
+
+impl MulAssign for Isometry3 {
+    /// Accumulates transform in place, `a *= b` is the same as `a = a * b`
+    fn mul_assign(&mut self, rhs: Isometry3) {
+        *self = *self * rhs;
+    }
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    #[test]
+    fn isometry3_compose_inverse() {
+        let a = Isometry3::new(Mat3::rotation(Vec3::new(1, 2, 3), 0.8), Vec3::new(1, -1, 2));
+        let b = Isometry3::new(Mat3::rotation(Vec3::new(-1, 0, 1), 2.1), Vec3::new(0, 3, 0));
+        let p = Vec3::new(0.5, 0.25, -4.0);
+        let ab = (a * b).transform_point(p);
+        assert!((ab - a.transform_point(b.transform_point(p))).len() < 1e-12);
+        let id = a * a.inverse();
+        assert!((id.transform_point(p) - p).len() < 1e-12);
+        let h = a.to_mat4() * Vec4::new(p.x, p.y, p.z, 1.0);
+        let q = a.transform_point(p);
+        assert!((Vec3::new(h.x, h.y, h.z) - q).len() < 1e-12);
+        let mut acc = a;
+        acc *= b;
+        assert!((acc.transform_point(p) - ab).len() < 1e-12);
+    }
+}
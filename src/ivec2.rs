@@ -0,0 +1,163 @@
+//! Vectors on a plane with interval coordinates.
+use std::ops::{Add, Sub, Mul, Neg};
+use std::ops::{AddAssign, SubAssign, MulAssign};
+use std::ops::{Index, IndexMut};
+use interval::Interval;
+use vec2::Vec2;
+
+/// Vector on a plane with interval coordinates
+///
+/// Represents every vector with coordinates from given intervals, results
+/// of operations enclose results for all such vectors.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IVec2 {
+    /// x coordinate interval
+    pub x: Interval,
+    /// y coordinate interval
+    pub y: Interval,
+}
+
+impl IVec2 {
+    /// Constructs a new `IVec2` from coordinate intervals
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Interval, IVec2};
+    /// let v = IVec2::new(Interval::new(0, 1), Interval::new(2, 3));
+    /// assert_eq!(v.y.hi, 3.0);
+    /// ```
+    pub fn new(x: Interval, y: Interval) -> IVec2 {
+        IVec2 { x, y }
+    }
+    /// Box of vectors between corners
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Interval, IVec2, Vec2};
+    /// let v = IVec2::from_bounds(Vec2::new(0, 3), Vec2::new(1, 2));
+    /// assert_eq!(v.y, Interval::new(2, 3));
+    /// ```
+    pub fn from_bounds(a: Vec2, b: Vec2) -> IVec2 {
+        IVec2::new(Interval::new(a.x, b.x), Interval::new(a.y, b.y))
+    }
+    /// Lower bounds of coordinates
+    pub fn lo(self) -> Vec2 {
+        Vec2::new(self.x.lo, self.y.lo)
+    }
+    /// Upper bounds of coordinates
+    pub fn hi(self) -> Vec2 {
+        Vec2::new(self.x.hi, self.y.hi)
+    }
+    /// Middle points of coordinate intervals
+    pub fn mid(self) -> Vec2 {
+        Vec2::new(self.x.mid(), self.y.mid())
+    }
+    /// Scalar product bounds
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Interval, IVec2, Vec2};
+    /// let a = IVec2::from_bounds(Vec2::new(1, 1), Vec2::new(2, 2));
+    /// let b = IVec2::from(Vec2::new(1, -1));
+    /// assert!(a.dot(b).contains(0) && a.dot(b).contains(1) && a.dot(b).contains(-1));
+    /// ```
+    pub fn dot(self, rhs: IVec2) -> Interval {
+        self.x * rhs.x + self.y * rhs.y
+    }
+    /// Length bounds
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{IVec2, Vec2};
+    /// let v = IVec2::from_bounds(Vec2::new(3, 4), Vec2::new(6, 8));
+    /// let len = v.len();
+    /// assert!(len.contains(5) && len.contains(10) && !len.contains(4.9));
+    /// ```
+    pub fn len(self) -> Interval {
+        (self.x.sqr() + self.y.sqr()).sqrt()
+    }
+    /// Checks if vector lies inside bounds
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{IVec2, Vec2};
+    /// let v = IVec2::from_bounds(Vec2::new(0, 0), Vec2::new(1, 1));
+    /// assert!(v.contains(Vec2::new(0.5, 1.0)));
+    /// assert!(!v.contains(Vec2::new(0.5, 1.5)));
+    /// ```
+    pub fn contains(self, v: Vec2) -> bool {
+        self.x.contains(v.x) && self.y.contains(v.y)
+    }
+    /// Checks if vector boxes have common points
+    pub fn intersects(self, other: IVec2) -> bool {
+        self.x.intersects(other.x) && self.y.intersects(other.y)
+    }
+    // need for op_default & op_assign
+    fn size(&self) -> usize { 2 }
+}
+
+op_default!(add, Add, +=, IVec2);
+op_default!(sub, Sub, -=, IVec2);
+op_default!(mul, Mul, *=, IVec2);
+op_default!(Interval, mul, Mul, *=, IVec2);
+op_assign!(add_assign, AddAssign, +=, IVec2);
+op_assign!(sub_assign, SubAssign, -=, IVec2);
+op_assign!(mul_assign, MulAssign, *=, IVec2);
+op_assign!(Interval, mul_assign, MulAssign, *=, IVec2);
+
+impl Neg for IVec2 {
+    type Output = IVec2;
+
+    fn neg(self) -> IVec2 {
+        IVec2::new(-self.x, -self.y)
+    }
+}
+
+impl Index<usize> for IVec2 {
+    type Output = Interval;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            i => panic!("Index {} out of [0, 1] range", i)
+        }
+    }
+}
+
+impl IndexMut<usize> for IVec2 {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            i => panic!("Index {} out of [0, 1] range", i)
+        }
+    }
+}
+
+impl From<Vec2> for IVec2 {
+    fn from(v: Vec2) -> IVec2 {
+        IVec2::new(Interval::point(v.x), Interval::point(v.y))
+    }
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    #[test]
+    fn ivec2_encloses_distance() {
+        // uncertain point against exact center
+        let p = IVec2::from_bounds(Vec2::new(0.9, 1.9), Vec2::new(1.1, 2.1));
+        let c = IVec2::from(Vec2::new(-0.3, 0.7));
+        let d = (p - c).len();
+        for i in 0..=4 {
+            for j in 0..=4 {
+                let q = Vec2::new(0.9 + 0.05 * i as f64, 1.9 + 0.05 * j as f64);
+                assert!(d.contains((q - Vec2::new(-0.3, 0.7)).len()));
+            }
+        }
+        let scaled = p * 2;
+        assert!(scaled.contains(Vec2::new(2, 4)));
+    }
+}
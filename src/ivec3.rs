@@ -0,0 +1,167 @@
+//! Vectors in space with interval coordinates.
+use std::ops::{Add, Sub, Mul, Neg};
+use std::ops::{AddAssign, SubAssign, MulAssign};
+use std::ops::{Index, IndexMut};
+use interval::Interval;
+use vec3::Vec3;
+
+/// Vector in space with interval coordinates
+///
+/// Represents every vector with coordinates from given intervals, results
+/// of operations enclose results for all such vectors.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IVec3 {
+    /// x coordinate interval
+    pub x: Interval,
+    /// y coordinate interval
+    pub y: Interval,
+    /// z coordinate interval
+    pub z: Interval,
+}
+
+impl IVec3 {
+    /// Constructs a new `IVec3` from coordinate intervals
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Interval, IVec3};
+    /// let v = IVec3::new(Interval::new(0, 1), Interval::new(2, 3), Interval::new(4, 5));
+    /// assert_eq!(v.y.hi, 3.0);
+    /// ```
+    pub fn new(x: Interval, y: Interval, z: Interval) -> IVec3 {
+        IVec3 { x, y, z }
+    }
+    /// Box of vectors between corners
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Interval, IVec3, Vec3};
+    /// let v = IVec3::from_bounds(Vec3::new(0, 3, 1), Vec3::new(1, 2, 1));
+    /// assert_eq!(v.y, Interval::new(2, 3));
+    /// ```
+    pub fn from_bounds(a: Vec3, b: Vec3) -> IVec3 {
+        IVec3::new(Interval::new(a.x, b.x), Interval::new(a.y, b.y), Interval::new(a.z, b.z))
+    }
+    /// Lower bounds of coordinates
+    pub fn lo(self) -> Vec3 {
+        Vec3::new(self.x.lo, self.y.lo, self.z.lo)
+    }
+    /// Upper bounds of coordinates
+    pub fn hi(self) -> Vec3 {
+        Vec3::new(self.x.hi, self.y.hi, self.z.hi)
+    }
+    /// Middle points of coordinate intervals
+    pub fn mid(self) -> Vec3 {
+        Vec3::new(self.x.mid(), self.y.mid(), self.z.mid())
+    }
+    /// Scalar product bounds
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Interval, IVec3, Vec3};
+    /// let a = IVec3::from_bounds(Vec3::new(1, 1, 0), Vec3::new(2, 2, 0));
+    /// let b = IVec3::from(Vec3::new(1, -1, 5));
+    /// assert!(a.dot(b).contains(0) && a.dot(b).contains(1) && a.dot(b).contains(-1));
+    /// ```
+    pub fn dot(self, rhs: IVec3) -> Interval {
+        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z
+    }
+    /// Length bounds
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{IVec3, Vec3};
+    /// let v = IVec3::from_bounds(Vec3::new(3, 4, 0), Vec3::new(6, 8, 0));
+    /// let len = v.len();
+    /// assert!(len.contains(5) && len.contains(10) && !len.contains(4.9));
+    /// ```
+    pub fn len(self) -> Interval {
+        (self.x.sqr() + self.y.sqr() + self.z.sqr()).sqrt()
+    }
+    /// Checks if vector lies inside bounds
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{IVec3, Vec3};
+    /// let v = IVec3::from_bounds(Vec3::new(0, 0, 0), Vec3::new(1, 1, 1));
+    /// assert!(v.contains(Vec3::new(0.5, 1.0, 0.0)));
+    /// assert!(!v.contains(Vec3::new(0.5, 1.5, 0.0)));
+    /// ```
+    pub fn contains(self, v: Vec3) -> bool {
+        self.x.contains(v.x) && self.y.contains(v.y) && self.z.contains(v.z)
+    }
+    /// Checks if vector boxes have common points
+    pub fn intersects(self, other: IVec3) -> bool {
+        self.x.intersects(other.x) && self.y.intersects(other.y) && self.z.intersects(other.z)
+    }
+    // need for op_default & op_assign
+    fn size(&self) -> usize { 3 }
+}
+
+op_default!(add, Add, +=, IVec3);
+op_default!(sub, Sub, -=, IVec3);
+op_default!(mul, Mul, *=, IVec3);
+op_default!(Interval, mul, Mul, *=, IVec3);
+op_assign!(add_assign, AddAssign, +=, IVec3);
+op_assign!(sub_assign, SubAssign, -=, IVec3);
+op_assign!(mul_assign, MulAssign, *=, IVec3);
+op_assign!(Interval, mul_assign, MulAssign, *=, IVec3);
+
+impl Neg for IVec3 {
+    type Output = IVec3;
+
+    fn neg(self) -> IVec3 {
+        IVec3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl Index<usize> for IVec3 {
+    type Output = Interval;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            i => panic!("Index {} out of [0, 2] range", i)
+        }
+    }
+}
+
+impl IndexMut<usize> for IVec3 {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            i => panic!("Index {} out of [0, 2] range", i)
+        }
+    }
+}
+
+impl From<Vec3> for IVec3 {
+    fn from(v: Vec3) -> IVec3 {
+        IVec3::new(Interval::point(v.x), Interval::point(v.y), Interval::point(v.z))
+    }
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    #[test]
+    fn ivec3_encloses_distance() {
+        // uncertain point against exact center
+        let p = IVec3::from_bounds(Vec3::new(0.9, 1.9, 3.0), Vec3::new(1.1, 2.1, 3.0));
+        let c = IVec3::from(Vec3::new(-0.3, 0.7, 1.0));
+        let d = (p - c).len();
+        for i in 0..=4 {
+            for j in 0..=4 {
+                let q = Vec3::new(0.9 + 0.05 * i as f64, 1.9 + 0.05 * j as f64, 3.0);
+                assert!(d.contains((q - Vec3::new(-0.3, 0.7, 1.0)).len()));
+            }
+        }
+        let scaled = p * 2;
+        assert!(scaled.contains(Vec3::new(2, 4, 6)));
+    }
+}
@@ -0,0 +1,91 @@
+//! LaTeX output of vectors.
+use std::fmt;
+use vec2::Vec2;
+use vec3::Vec3;
+
+/// Wrapper displaying vector as LaTeX column `pmatrix`
+///
+/// Precision, width and sign flags of formatter are applied to every
+/// component.
+///
+/// # Example
+/// ```
+/// # use linal::Vec2;
+/// let v = Vec2::new(1.0, 2.0 / 3.0);
+/// assert_eq!(format!("{:.3}", v.latex()),
+///            "\\begin{pmatrix} 1.000 \\\\ 0.667 \\end{pmatrix}");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Latex<T>(pub T);
+
+fn write_column(f: &mut fmt::Formatter, components: &[f64]) -> fmt::Result {
+    f.write_str("\\begin{pmatrix} ")?;
+    for (i, c) in components.iter().enumerate() {
+        if i > 0 {
+            f.write_str(" \\\\ ")?;
+        }
+        fmt::Display::fmt(c, f)?;
+    }
+    f.write_str(" \\end{pmatrix}")
+}
+
+impl fmt::Display for Latex<Vec2> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_column(f, &[self.0.x, self.0.y])
+    }
+}
+
+impl fmt::Display for Latex<Vec3> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_column(f, &[self.0.x, self.0.y, self.0.z])
+    }
+}
+
+impl Vec2 {
+    /// LaTeX representation, formatting flags are forwarded to components
+    pub fn latex(self) -> Latex<Vec2> {
+        Latex(self)
+    }
+    /// LaTeX column vector with given number of decimal digits
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Vec2;
+    /// assert_eq!(Vec2::new(1, -2).to_latex(1), "\\begin{pmatrix} 1.0 \\\\ -2.0 \\end{pmatrix}");
+    /// ```
+    pub fn to_latex(self, precision: usize) -> String {
+        format!("{:.*}", precision, self.latex())
+    }
+}
+
+impl Vec3 {
+    /// LaTeX representation, formatting flags are forwarded to components
+    pub fn latex(self) -> Latex<Vec3> {
+        Latex(self)
+    }
+    /// LaTeX column vector with given number of decimal digits
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Vec3;
+    /// assert_eq!(Vec3::new(1, 2, 3).to_latex(0),
+    ///            "\\begin{pmatrix} 1 \\\\ 2 \\\\ 3 \\end{pmatrix}");
+    /// ```
+    pub fn to_latex(self, precision: usize) -> String {
+        format!("{:.*}", precision, self.latex())
+    }
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    #[test]
+    fn latex_flags() {
+        let v = Vec3::new(1.5, -2.0, 0.25);
+        assert_eq!(v.latex().to_string(),
+                   "\\begin{pmatrix} 1.5 \\\\ -2 \\\\ 0.25 \\end{pmatrix}");
+        assert_eq!(format!("{:+.1}", Vec2::new(1, -2).latex()),
+                   "\\begin{pmatrix} +1.0 \\\\ -2.0 \\end{pmatrix}");
+    }
+}
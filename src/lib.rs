@@ -1,10 +1,157 @@
-//! Small and simple library to work with 2D and 3D vectors
+//! Small and simple library to work with 2D and 3D vectors and matrices
 #![warn(missing_docs)]
 
+#[cfg(feature = "rand")]
+extern crate rand;
+#[cfg(feature = "rational")]
+extern crate num_rational;
+#[cfg(feature = "rational")]
+extern crate num_traits;
+#[cfg(feature = "geojson")]
+extern crate serde_json;
+#[cfg(feature = "complex")]
+extern crate num_complex;
+#[cfg(feature = "uom")]
+extern crate uom;
+#[cfg(feature = "rayon")]
+extern crate rayon;
+#[cfg(feature = "proptest")]
+extern crate proptest;
+
 #[macro_use]
 mod macros;
+pub mod scalar;
 pub mod vec2;
 pub mod vec3;
+pub mod vec4;
+pub mod vecn;
+pub mod mat2;
+pub mod mat3;
+pub mod mat4;
+pub mod line2;
+pub mod line3;
+pub mod plane;
+pub mod ray2;
+pub mod ray3;
+pub mod segment2;
+pub mod segment3;
+pub mod triangle2;
+pub mod triangle3;
+pub mod rect;
+pub mod aabb3;
+pub mod frustum;
+pub mod quat;
+pub mod isometry2;
+pub mod isometry3;
+pub mod twist;
+pub mod pose;
+pub mod frame;
+pub mod ode;
+pub mod field;
+pub mod contour;
+pub mod grid;
+pub mod sampling;
+pub mod sfc;
+pub mod noise;
+pub mod sh;
+pub mod sphere;
+pub mod fit;
+pub mod normals;
+pub mod align;
+pub mod intersect;
+pub mod distances;
+pub mod containment;
+pub mod circle;
+pub mod arc;
+pub mod hull;
+pub mod dedup;
+pub mod obb;
+pub mod polygon;
+pub mod boolean;
+pub mod sat;
+pub mod gjk;
+pub mod polyline;
+pub mod bezier2;
+pub mod bezier3;
+pub mod spline2;
+pub mod spline3;
+pub mod curve;
+pub mod dir2;
+pub mod dir3;
+pub mod finite;
+pub mod map_math;
+pub mod interval;
+pub mod ivec2;
+pub mod ivec3;
+pub mod dual;
+pub mod dvec2;
+pub mod dvec3;
+pub mod vec2s;
+pub mod vec3s;
+pub mod batch;
+pub mod accurate;
+pub mod parse;
+pub mod latex;
+pub mod io;
+pub mod spatial;
+#[cfg(feature = "rand")]
+pub mod random;
+#[cfg(feature = "rand")]
+pub mod ransac;
+#[cfg(feature = "rational")]
+pub mod rational;
+#[cfg(feature = "complex")]
+pub mod complex;
+#[cfg(feature = "uom")]
+pub mod units;
+#[cfg(feature = "proptest")]
+pub mod arbitrary;
 // re-export
+pub use scalar::Scalar;
 pub use vec2::Vec2;
 pub use vec3::Vec3;
+pub use vec4::Vec4;
+pub use vecn::VecN;
+pub use mat2::Mat2;
+pub use mat3::Mat3;
+pub use mat4::Mat4;
+pub use line2::Line2;
+pub use line3::Line3;
+pub use plane::Plane;
+pub use ray2::Ray2;
+pub use ray3::Ray3;
+pub use segment2::Segment2;
+pub use segment3::Segment3;
+pub use triangle2::Triangle2;
+pub use triangle3::Triangle3;
+pub use rect::Rect;
+pub use aabb3::Aabb3;
+pub use frustum::Frustum;
+pub use quat::{Quat, Squad};
+pub use isometry2::Isometry2;
+pub use isometry3::Isometry3;
+pub use twist::{Twist, Screw};
+pub use pose::{Pose2, Pose3};
+pub use circle::Circle;
+pub use arc::{Arc, ArcDirection};
+pub use obb::Obb;
+pub use polygon::Polygon;
+pub use polyline::Polyline;
+pub use bezier2::{QuadBezier2, CubicBezier2};
+pub use bezier3::{QuadBezier3, CubicBezier3};
+pub use spline2::{Hermite2, CatmullRom2};
+pub use spline3::{Hermite3, CatmullRom3};
+pub use curve::{Curve, ArcLengthParam};
+pub use dir2::Dir2;
+pub use dir3::Dir3;
+pub use finite::Finite;
+pub use map_math::MapMath;
+pub use interval::Interval;
+pub use ivec2::IVec2;
+pub use ivec3::IVec3;
+pub use dual::Dual;
+pub use dvec2::DVec2;
+pub use dvec3::DVec3;
+pub use vec2s::Vec2s;
+pub use vec3s::Vec3s;
+pub use parse::ParseVecError;
@@ -0,0 +1,330 @@
+//! Infinite lines on a plane.
+use vec2::Vec2;
+use ray2::Ray2;
+use mat2::Mat2;
+use mat3::Mat3;
+
+// sine of angle between lines treated as parallel
+const PARALLEL_EPS: f64 = 1e-12;
+
+/// Position of point relative to oriented line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    /// point lies to the left of line direction
+    Left,
+    /// point lies to the right of line direction
+    Right,
+    /// point lies on the line
+    On,
+}
+
+/// Line on a plane, defined by a point and unit direction
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Line2 {
+    /// point on the line
+    pub point: Vec2,
+    /// unit direction of the line
+    pub dir: Vec2,
+}
+
+impl Line2 {
+    /// Constructs a new `Line2` through `point` along `dir`.
+    ///
+    /// Direction is normalized, so `dir` can have any non-zero length. Zero
+    /// `dir` gives line with NaN direction.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Line2, Vec2};
+    /// let line = Line2::new(Vec2::new(1, 1), Vec2::new(0, 3));
+    /// assert_eq!(line.dir, Vec2::new(0, 1));
+    /// ```
+    pub fn new(point: Vec2, dir: Vec2) -> Line2 {
+        Line2 {
+            point,
+            dir: dir.ort(),
+        }
+    }
+    /// Constructs a new `Line2` through two points, directed from `a` to `b`.
+    ///
+    /// Same as [`new`](#method.new), points must be distinct.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Line2, Vec2};
+    /// let line = Line2::through(Vec2::new(1, 1), Vec2::new(4, 5));
+    /// assert_eq!(line.point, Vec2::new(1, 1));
+    /// assert_eq!(line.dir, Vec2::new(0.6, 0.8));
+    /// ```
+    pub fn through(a: Vec2, b: Vec2) -> Line2 {
+        Line2::new(a, b - a)
+    }
+    /// Constructs a new `Line2` from implicit equation $ax + by + c = 0$.
+    ///
+    /// Line is directed so that $ax + by + c > 0$ on its left side. At least
+    /// one of `a` and `b` must be non-zero, otherwise line has NaN components.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Line2, Vec2};
+    /// // y = 2
+    /// let line = Line2::from_implicit(0, 1, -2);
+    /// assert_eq!(line.point, Vec2::new(0, 2));
+    /// assert_eq!(line.dir, Vec2::new(1, 0));
+    /// ```
+    pub fn from_implicit<I: Into<f64>>(a: I, b: I, c: I) -> Line2 {
+        let normal = Vec2::new(a, b);
+        let point = normal * (-c.into() / normal.dot(normal));
+        Line2::new(point, normal.cross())
+    }
+    /// Coefficients $(a, b, c)$ of implicit equation $ax + by + c = 0$.
+    ///
+    /// Coefficients are normalized, so $(a, b)$ is a unit normal pointing to
+    /// the left side of line and $ax + by + c$ is a signed distance to line.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Line2, Vec2};
+    /// let line = Line2::new(Vec2::new(0, 2), Vec2::new(-1, 0));
+    /// assert_eq!(line.to_implicit(), (0.0, -1.0, 2.0));
+    /// ```
+    pub fn to_implicit(self) -> (f64, f64, f64) {
+        let normal = -self.dir.cross();
+        (normal.x, normal.y, -normal.dot(self.point))
+    }
+    /// Signed distance from line to point, positive on the left side
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Line2, Vec2};
+    /// let line = Line2::new(Vec2::new(0, 1), Vec2::new(1, 0));
+    /// assert_eq!(line.signed_distance(Vec2::new(5, 3)), 2.0);
+    /// assert_eq!(line.signed_distance(Vec2::new(5, -1)), -2.0);
+    /// ```
+    pub fn signed_distance(self, point: Vec2) -> f64 {
+        self.dir.area(point - self.point)
+    }
+    /// Distance from line to point
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Line2, Vec2};
+    /// let line = Line2::through(Vec2::new(0, 0), Vec2::new(1, 1));
+    /// let d = line.distance_to_point(Vec2::new(2, 0));
+    /// assert!((d - 2f64.sqrt()).abs() < 1e-12);
+    /// ```
+    pub fn distance_to_point(self, point: Vec2) -> f64 {
+        self.signed_distance(point).abs()
+    }
+    /// Orthogonal projection of point onto line
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Line2, Vec2};
+    /// let line = Line2::new(Vec2::new(0, 1), Vec2::new(1, 0));
+    /// assert_eq!(line.project_point(Vec2::new(5, 3)), Vec2::new(5, 1));
+    /// ```
+    pub fn project_point(self, point: Vec2) -> Vec2 {
+        self.project_param(point).1
+    }
+    /// Parameter and point of orthogonal projection onto line
+    ///
+    /// Parameter is signed distance from `self.point` along `dir`, so
+    /// projection is `self.point + self.dir * t`.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Line2, Vec2};
+    /// let line = Line2::new(Vec2::new(1, 1), Vec2::new(1, 0));
+    /// assert_eq!(line.project_param(Vec2::new(-2, 5)), (-3.0, Vec2::new(-2, 1)));
+    /// ```
+    pub fn project_param(self, point: Vec2) -> (f64, Vec2) {
+        let t = self.dir.dot(point - self.point);
+        (t, self.point + self.dir * t)
+    }
+    /// Side of line where point lies
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Line2, Vec2};
+    /// # use linal::line2::Side;
+    /// let line = Line2::new(Vec2::new(0, 0), Vec2::new(1, 0));
+    /// assert_eq!(line.side_of(Vec2::new(3, 1)), Side::Left);
+    /// assert_eq!(line.side_of(Vec2::new(3, -1)), Side::Right);
+    /// assert_eq!(line.side_of(Vec2::new(3, 0)), Side::On);
+    /// ```
+    pub fn side_of(self, point: Vec2) -> Side {
+        let d = self.signed_distance(point);
+        if d > 0.0 {
+            Side::Left
+        } else if d < 0.0 {
+            Side::Right
+        } else {
+            Side::On
+        }
+    }
+    /// Parameters of intersection point along both lines
+    ///
+    /// Returns `(t, s)` such that intersection point is
+    /// `self.point + self.dir * t` and `other.point + other.dir * s`, so
+    /// parameters are signed distances from base points of lines. Lines with
+    /// sine of angle between them less than `1e-12` are treated as parallel
+    /// and give `None`.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Line2, Vec2};
+    /// let a = Line2::new(Vec2::new(0, 0), Vec2::new(1, 0));
+    /// let b = Line2::new(Vec2::new(3, 2), Vec2::new(0, -1));
+    /// assert_eq!(a.intersect_params(b), Some((3.0, 2.0)));
+    /// ```
+    pub fn intersect_params(self, other: Line2) -> Option<(f64, f64)> {
+        let det = self.dir.area(other.dir);
+        if det.abs() <= PARALLEL_EPS {
+            return None;
+        }
+        let d = other.point - self.point;
+        Some((d.area(other.dir) / det, d.area(self.dir) / det))
+    }
+    /// Intersection point of lines
+    ///
+    /// Returns `None` for parallel or nearly parallel lines, see
+    /// [`intersect_params`](#method.intersect_params).
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Line2, Vec2};
+    /// let a = Line2::through(Vec2::new(0, 0), Vec2::new(2, 2));
+    /// let b = Line2::through(Vec2::new(0, 2), Vec2::new(2, 0));
+    /// let p = a.intersect(b).unwrap();
+    /// assert!((p - Vec2::new(1, 1)).len() < 1e-12);
+    /// let c = Line2::through(Vec2::new(0, 1), Vec2::new(1, 2));
+    /// assert!(a.intersect(c).is_none());
+    /// ```
+    pub fn intersect(self, other: Line2) -> Option<Vec2> {
+        self.intersect_params(other).map(|(t, _)| self.point + self.dir * t)
+    }
+    /// Homogeneous matrix of reflection across line
+    ///
+    /// Points $(x, y, 1)$ are reflected across line, vectors $(x, y, 0)$
+    /// across its direction.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Line2, Vec2, Vec3};
+    /// let line = Line2::new(Vec2::new(0, 1), Vec2::new(1, 0));
+    /// let m = line.reflection();
+    /// assert_eq!(m * Vec3::new(3, 4, 1), Vec3::new(3, -2, 1));
+    /// assert_eq!(m * Vec3::new(3, 4, 0), Vec3::new(3, -4, 0));
+    /// ```
+    pub fn reflection(self) -> Mat3 {
+        let linear = Mat2::reflection(self.dir);
+        Mat3::affine2(linear, self.point - linear * self.point)
+    }
+}
+
+impl From<Ray2> for Line2 {
+    fn from(ray: Ray2) -> Line2 {
+        Line2 {
+            point: ray.origin,
+            dir: ray.dir,
+        }
+    }
+}
+
+impl Vec2 {
+    /// Mirror image of point across line
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Line2, Vec2};
+    /// let line = Line2::through(Vec2::new(0, 0), Vec2::new(1, 1));
+    /// let p = Vec2::new(3, 1).reflect_across(line);
+    /// assert!((p - Vec2::new(1, 3)).len() < 1e-12);
+    /// ```
+    pub fn reflect_across(self, line: Line2) -> Vec2 {
+        line.project_point(self) * 2.0 - self
+    }
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    #[test]
+    fn line2_implicit_round_trip() {
+        let line = Line2::through(Vec2::new(1, 2), Vec2::new(-3, 5));
+        let (a, b, c) = line.to_implicit();
+        let other = Line2::from_implicit(a, b, c);
+        assert!((other.dir - line.dir).len() < 1e-12);
+        assert!(line.distance_to_point(other.point) < 1e-12);
+        let p = Vec2::new(7, -2);
+        assert!((a * p.x + b * p.y + c - line.signed_distance(p)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn line2_from_implicit_scaled() {
+        let line = Line2::from_implicit(3, 4, -10);
+        assert!((line.point - Vec2::new(1.2, 1.6)).len() < 1e-12);
+        assert!(line.signed_distance(Vec2::new(3, 4)) > 0.0);
+    }
+
+    #[test]
+    fn line2_project_point() {
+        let line = Line2::through(Vec2::new(0, 0), Vec2::new(2, 2));
+        let p = Vec2::new(0, 2);
+        let q = line.project_point(p);
+        assert!((q - Vec2::new(1, 1)).len() < 1e-12);
+        assert!((p - q).dot(line.dir).abs() < 1e-12);
+    }
+
+    #[test]
+    fn line2_side_of() {
+        let line = Line2::through(Vec2::new(1, 1), Vec2::new(1, 5));
+        assert_eq!(line.side_of(Vec2::new(0, 0)), Side::Left);
+        assert_eq!(line.side_of(Vec2::new(2, 9)), Side::Right);
+        assert_eq!(line.side_of(Vec2::new(1, -7)), Side::On);
+    }
+
+    #[test]
+    fn line2_from_ray() {
+        let ray = Ray2::new(Vec2::new(1, 2), Vec2::new(0, 2));
+        let line = Line2::from(ray);
+        assert_eq!(line.point, ray.origin);
+        assert_eq!(line.dir, Vec2::new(0, 1));
+    }
+
+    #[test]
+    fn line2_intersect_params() {
+        let a = Line2::through(Vec2::new(1, 1), Vec2::new(4, 5));
+        let b = Line2::through(Vec2::new(-2, 7), Vec2::new(6, 1));
+        let (t, s) = a.intersect_params(b).unwrap();
+        let p = a.point + a.dir * t;
+        let q = b.point + b.dir * s;
+        assert!((p - q).len() < 1e-12);
+        assert!(a.distance_to_point(p) < 1e-12 && b.distance_to_point(p) < 1e-12);
+        assert_eq!(a.intersect(b), Some(p));
+    }
+
+    #[test]
+    fn line2_intersect_nearly_parallel() {
+        let a = Line2::new(Vec2::new(0, 0), Vec2::new(1, 0));
+        let b = Line2::new(Vec2::new(0, 1), Vec2::new(1.0, 1e-14));
+        assert!(a.intersect(b).is_none());
+        let c = Line2::new(Vec2::new(0, 1), Vec2::new(1.0, 1e-6));
+        let p = a.intersect(c).unwrap();
+        assert!((p.x + 1e6).abs() < 1e-3);
+        assert!(a.intersect(a).is_none());
+    }
+
+    #[test]
+    fn line2_reflection_matches_point() {
+        let line = Line2::through(Vec2::new(1, -2), Vec2::new(4, 3));
+        let m = line.reflection();
+        let p = Vec2::new(-2.5, 7.0);
+        let q = p.reflect_across(line);
+        assert!((Vec2::from_homogeneous(m * p.to_homogeneous()).unwrap() - q).len() < 1e-12);
+        assert!((line.signed_distance(p) + line.signed_distance(q)).abs() < 1e-12);
+        assert!((q.reflect_across(line) - p).len() < 1e-12);
+    }
+}
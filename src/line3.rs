@@ -0,0 +1,108 @@
+//! Infinite lines in 3-dimensional euclidian space.
+use vec3::Vec3;
+use ray3::Ray3;
+
+/// Line in space, defined by a point and unit direction
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Line3 {
+    /// point on the line
+    pub point: Vec3,
+    /// unit direction of the line
+    pub dir: Vec3,
+}
+
+impl Line3 {
+    /// Constructs a new `Line3` through `point` along `dir`.
+    ///
+    /// Direction is normalized, so `dir` can have any non-zero length. Zero
+    /// `dir` gives line with NaN direction.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Line3, Vec3};
+    /// let line = Line3::new(Vec3::new(1, 1, 1), Vec3::new(0, 3, 0));
+    /// assert_eq!(line.dir, Vec3::new(0, 1, 0));
+    /// ```
+    pub fn new(point: Vec3, dir: Vec3) -> Line3 {
+        Line3 {
+            point,
+            dir: dir.ort(),
+        }
+    }
+    /// Constructs a new `Line3` through two points, directed from `a` to `b`.
+    ///
+    /// Same as [`new`](#method.new), points must be distinct.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Line3, Vec3};
+    /// let line = Line3::through(Vec3::new(1, 1, 1), Vec3::new(1, 4, 5));
+    /// assert_eq!(line.point, Vec3::new(1, 1, 1));
+    /// assert_eq!(line.dir, Vec3::new(0.0, 0.6, 0.8));
+    /// ```
+    pub fn through(a: Vec3, b: Vec3) -> Line3 {
+        Line3::new(a, b - a)
+    }
+    /// Distance from line to point
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Line3, Vec3};
+    /// let line = Line3::new(Vec3::new(0, 0, 1), Vec3::new(1, 0, 0));
+    /// assert_eq!(line.distance_to_point(Vec3::new(5, 3, 5)), 5.0);
+    /// ```
+    pub fn distance_to_point(self, point: Vec3) -> f64 {
+        (point - self.point).cross(self.dir).len()
+    }
+    /// Orthogonal projection of point onto line
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Line3, Vec3};
+    /// let line = Line3::new(Vec3::new(0, 0, 1), Vec3::new(1, 0, 0));
+    /// assert_eq!(line.project_point(Vec3::new(5, 3, 5)), Vec3::new(5, 0, 1));
+    /// ```
+    pub fn project_point(self, point: Vec3) -> Vec3 {
+        self.point + self.dir * self.dir.dot(point - self.point)
+    }
+}
+
+impl From<Ray3> for Line3 {
+    fn from(ray: Ray3) -> Line3 {
+        Line3 {
+            point: ray.origin,
+            dir: ray.dir,
+        }
+    }
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    #[test]
+    fn line3_distance_to_point() {
+        let line = Line3::through(Vec3::new(1, 1, 1), Vec3::new(2, 2, 2));
+        assert!(line.distance_to_point(Vec3::new(5, 5, 5)) < 1e-12);
+        let d = line.distance_to_point(Vec3::new(1, -1, 0));
+        assert!((d - 2f64.sqrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn line3_project_point() {
+        let line = Line3::through(Vec3::new(0, 0, 0), Vec3::new(1, 1, 0));
+        let p = Vec3::new(0, 2, 3);
+        let q = line.project_point(p);
+        assert!((q - Vec3::new(1, 1, 0)).len() < 1e-12);
+        assert!((p - q).dot(line.dir).abs() < 1e-12);
+        assert!(((p - q).len() - line.distance_to_point(p)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn line3_from_ray() {
+        let ray = Ray3::new(Vec3::new(1, 2, 3), Vec3::new(0, 0, -2));
+        let line = Line3::from(ray);
+        assert_eq!(line.point, ray.origin);
+        assert_eq!(line.dir, Vec3::new(0, 0, -1));
+    }
+}
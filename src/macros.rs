@@ -1,6 +1,38 @@
 #[doc(hidden)]
 #[macro_export]
 macro_rules! op_default {
+    (f64, $func:ident, $bound:ident, $op:tt, $cls:ident<const $n:ident>) => {
+        impl<I: $crate::scalar::Scalar, const $n: usize> $bound<I> for $cls<$n> {
+            type Output = Self;
+
+            fn $func(mut self, _rhs: I) -> Self {
+                self $op _rhs.as_f64();
+                self
+            }
+        }
+    };
+    ($func:ident, $bound:ident, $op:tt, $cls:ident<const $n:ident>) => {
+        impl<const $n: usize> $bound for $cls<$n> {
+            type Output = Self;
+
+            fn $func(mut self, _rhs: Self) -> Self {
+                for i in 0..self.size() {
+                    self[i] $op _rhs[i];
+                }
+                self
+            }
+        }
+    };
+    (f64, $func:ident, $bound:ident, $op:tt, $cls:ident) => {
+        impl<I: $crate::scalar::Scalar> $bound<I> for $cls {
+            type Output = Self;
+
+            fn $func(mut self, _rhs: I) -> Self {
+                self $op _rhs.as_f64();
+                self
+            }
+        }
+    };
     ($func:ident, $bound:ident, $op:tt, $cls:ident) => {
         impl $bound for $cls {
             type Output = Self;
@@ -28,6 +60,35 @@ macro_rules! op_default {
 #[doc(hidden)]
 #[macro_export]
 macro_rules! op_assign {
+    (f64, $func:ident, $bound:ident, $op:tt, $cls:ident<const $n:ident>) => {
+        impl<I: $crate::scalar::Scalar, const $n: usize> $bound<I> for $cls<$n> {
+            fn $func(&mut self, _rhs: I) {
+                let k = $crate::scalar::Scalar::as_f64(_rhs);
+                for i in 0..self.size() {
+                    self[i] $op k;
+                }
+            }
+        }
+    };
+    ($func:ident, $bound:ident, $op:tt, $cls:ident<const $n:ident>) => {
+        impl<const $n: usize> $bound for $cls<$n> {
+            fn $func(&mut self, _rhs: Self) {
+                for i in 0..self.size() {
+                    self[i] $op _rhs[i];
+                }
+            }
+        }
+    };
+    (f64, $func:ident, $bound:ident, $op:tt, $cls:ident) => {
+        impl<I: $crate::scalar::Scalar> $bound<I> for $cls {
+            fn $func(&mut self, _rhs: I) {
+                let k = $crate::scalar::Scalar::as_f64(_rhs);
+                for i in 0..self.size() {
+                    self[i] $op k;
+                }
+            }
+        }
+    };
     ($func:ident, $bound:ident, $op:tt, $cls:ident) => {
         impl $bound for $cls {
             fn $func(&mut self, _rhs: Self) {
@@ -47,4 +108,118 @@ macro_rules! op_assign {
             }
         }
     };
-}
\ No newline at end of file
+}
+#[doc(hidden)]
+#[macro_export]
+macro_rules! op_scalar_left {
+    ($cls:ident<const $n:ident>) => {
+        op_scalar_left!($cls<const $n>; i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+    };
+    ($cls:ident<const $n:ident>; $($type:ident),*) => {
+        $(
+            impl<const $n: usize> Mul<$cls<$n>> for $type {
+                type Output = $cls<$n>;
+
+                fn mul(self, _rhs: $cls<$n>) -> $cls<$n> {
+                    _rhs * self
+                }
+            }
+        )*
+    };
+    ($cls:ident) => {
+        op_scalar_left!($cls; i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+    };
+    ($cls:ident; $($type:ident),*) => {
+        $(
+            impl Mul<$cls> for $type {
+                type Output = $cls;
+
+                fn mul(self, _rhs: $cls) -> $cls {
+                    _rhs * self
+                }
+            }
+        )*
+    };
+}
+
+/// Constructs `Vec2` from coordinates of any numeric types
+///
+/// Coordinates are converted with `as f64`, so integer and float literals
+/// can be mixed. Macro expands to struct literal and can be used in
+/// constants.
+///
+/// # Example
+/// ```
+/// # #[macro_use] extern crate linal;
+/// # use linal::Vec2;
+/// # fn main() {
+/// const ORIGIN: Vec2 = vec2!(0, 0);
+/// assert_eq!(vec2!(1, 2.5), Vec2::new(1.0, 2.5));
+/// assert_eq!(ORIGIN, Vec2::zero());
+/// # }
+/// ```
+#[macro_export]
+macro_rules! vec2 {
+    ($x:expr, $y:expr $(,)?) => {
+        $crate::Vec2 { x: ($x) as f64, y: ($y) as f64 }
+    };
+}
+
+/// Constructs `Vec3` from coordinates of any numeric types
+///
+/// Same as [`vec2!`](macro.vec2.html) in space.
+///
+/// # Example
+/// ```
+/// # #[macro_use] extern crate linal;
+/// # use linal::Vec3;
+/// # fn main() {
+/// const UP: Vec3 = vec3!(0, 0, 1);
+/// assert_eq!(vec3!(1, 0.5, -2), Vec3::new(1.0, 0.5, -2.0));
+/// assert_eq!(UP.z, 1.0);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! vec3 {
+    ($x:expr, $y:expr, $z:expr $(,)?) => {
+        $crate::Vec3 { x: ($x) as f64, y: ($y) as f64, z: ($z) as f64 }
+    };
+}
+
+/// Constructs point on a plane, which is `Vec2`
+///
+/// Same as [`vec2!`](macro.vec2.html), reads better for positions. Name is
+/// generic and may clash with macros of other crates, in that case use
+/// [`linal_point!`](macro.linal_point.html).
+///
+/// # Example
+/// ```
+/// # #[macro_use] extern crate linal;
+/// # use linal::Polygon;
+/// # fn main() {
+/// let square = Polygon::new(vec![point!(0, 0), point!(1, 0), point!(1, 1.5), point!(0, 1.5)]);
+/// assert_eq!(square.area(), 1.5);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! point {
+    ($x:expr, $y:expr $(,)?) => {
+        $crate::Vec2 { x: ($x) as f64, y: ($y) as f64 }
+    };
+}
+
+/// Alias of [`point!`](macro.point.html) prefixed with crate name
+///
+/// # Example
+/// ```
+/// # #[macro_use] extern crate linal;
+/// # fn main() {
+/// assert_eq!(linal_point!(1, 2.5), point!(1, 2.5));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! linal_point {
+    ($x:expr, $y:expr $(,)?) => {
+        $crate::Vec2 { x: ($x) as f64, y: ($y) as f64 }
+    };
+}
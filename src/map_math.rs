@@ -0,0 +1,78 @@
+//! Transcendental functions applied to every coordinate of vector.
+//!
+//! Kept in extension trait, so that methods like `sin` don't crowd vector
+//! types themselves; bring [`MapMath`] into scope to use them.
+use std::ops::IndexMut;
+use finite::Coordinates;
+
+/// Functions of `f64` applied to vector coordinates independently
+///
+/// Implemented for every vector with indexable coordinates.
+///
+/// # Example
+/// ```
+/// # use linal::{MapMath, Vec3};
+/// let v = Vec3::new(0, 1, 2).exp().ln();
+/// assert!((v - Vec3::new(0, 1, 2)).len() < 1e-12);
+/// assert_eq!(Vec3::new(1, 4, 9).powf(0.5), Vec3::new(1, 2, 3));
+/// ```
+pub trait MapMath: Coordinates + IndexMut<usize> {
+    /// Vector with function applied to every coordinate
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{MapMath, Vec2};
+    /// assert_eq!(Vec2::new(-1.5, 2.5).map(f64::floor), Vec2::new(-2, 2));
+    /// ```
+    fn map<F: Fn(f64) -> f64>(self, f: F) -> Self {
+        let mut v = self;
+        for i in 0..Self::DIM {
+            v[i] = f(v[i]);
+        }
+        v
+    }
+    /// Exponent of coordinates
+    fn exp(self) -> Self {
+        self.map(f64::exp)
+    }
+    /// Natural logarithm of coordinates, NaN for negative ones
+    fn ln(self) -> Self {
+        self.map(f64::ln)
+    }
+    /// Coordinates raised to power `p`
+    fn powf(self, p: f64) -> Self {
+        self.map(|x| x.powf(p))
+    }
+    /// Sine of coordinates
+    fn sin(self) -> Self {
+        self.map(f64::sin)
+    }
+    /// Cosine of coordinates
+    fn cos(self) -> Self {
+        self.map(f64::cos)
+    }
+    /// Hyperbolic tangent of coordinates
+    fn tanh(self) -> Self {
+        self.map(f64::tanh)
+    }
+}
+
+impl<T: Coordinates + IndexMut<usize>> MapMath for T {}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+    use vec2::Vec2;
+    use vec4::Vec4;
+
+    #[test]
+    fn map_math_componentwise() {
+        let v = Vec4::new(0.0, 0.5, -1.0, 20.0);
+        let t = v.tanh();
+        assert_eq!(t, Vec4::new(0.0, 0.5f64.tanh(), -(1f64.tanh()), 1.0));
+        let a = Vec2::new(0.3, -1.2);
+        let s = a.sin() * a.sin() + a.cos() * a.cos();
+        assert!((s - Vec2::new(1, 1)).len() < 1e-15);
+        assert!(Vec2::new(-1, 1).ln().x.is_nan());
+    }
+}
@@ -0,0 +1,535 @@
+//! Matrices 2x2.
+use std::ops::{Add, Sub, Mul, Neg};
+use std::ops::{AddAssign, SubAssign, MulAssign};
+use std::ops::{Index, IndexMut};
+use std::cmp::PartialEq;
+use vec2::Vec2;
+
+// relative length of column treated as linearly dependent
+const DEGENERATE_EPS: f64 = 1e-12;
+
+/// 2x2 matrix stored by rows
+#[derive(Debug, Clone, Copy)]
+pub struct Mat2 {
+    /// first row of matrix
+    pub x: Vec2,
+    /// second row of matrix
+    pub y: Vec2,
+}
+
+impl Mat2 {
+    /// Constructs a new `Mat2` from rows.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Mat2, Vec2};
+    /// let m = Mat2::new(Vec2::new(1, 2), Vec2::new(3, 4));
+    /// assert_eq!(m[0][1], 2.0);
+    /// assert_eq!(m[1][0], 3.0);
+    /// ```
+    pub fn new(x: Vec2, y: Vec2) -> Mat2 {
+        Mat2 { x, y }
+    }
+    /// Constructs a new `Mat2` from columns.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Mat2, Vec2};
+    /// let m = Mat2::from_cols(Vec2::new(1, 3), Vec2::new(2, 4));
+    /// assert_eq!(m, Mat2::new(Vec2::new(1, 2), Vec2::new(3, 4)));
+    /// ```
+    pub fn from_cols(a: Vec2, b: Vec2) -> Mat2 {
+        Mat2::new(a, b).transpose()
+    }
+    /// Create a zero `Mat2`
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Mat2, Vec2};
+    /// let zero = Mat2::zero();
+    /// assert_eq!(zero, Mat2::new(Vec2::zero(), Vec2::zero()));
+    /// ```
+    pub fn zero() -> Mat2 {
+        Mat2::new(Vec2::zero(), Vec2::zero())
+    }
+    /// Create an identity `Mat2`
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Mat2, Vec2};
+    /// let v = Vec2::new(3, 4);
+    /// assert_eq!(Mat2::identity() * v, v);
+    /// ```
+    pub fn identity() -> Mat2 {
+        Mat2::new(Vec2::new(1, 0), Vec2::new(0, 1))
+    }
+    /// Transposed matrix
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Mat2, Vec2};
+    /// let m = Mat2::new(Vec2::new(1, 2), Vec2::new(3, 4));
+    /// assert_eq!(m.transpose(), Mat2::new(Vec2::new(1, 3), Vec2::new(2, 4)));
+    /// ```
+    pub fn transpose(self) -> Mat2 {
+        Mat2::new(Vec2::new(self.x.x, self.y.x), Vec2::new(self.x.y, self.y.y))
+    }
+    /// Determinant of matrix
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Mat2, Vec2};
+    /// let m = Mat2::new(Vec2::new(1, 2), Vec2::new(3, 4));
+    /// assert_eq!(m.det(), -2.0);
+    /// ```
+    pub fn det(self) -> f64 {
+        self.x.area(self.y)
+    }
+    /// Trace of matrix
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Mat2, Vec2};
+    /// let m = Mat2::new(Vec2::new(1, 2), Vec2::new(3, 4));
+    /// assert_eq!(m.trace(), 5.0);
+    /// ```
+    pub fn trace(self) -> f64 {
+        self.x.x + self.y.y
+    }
+    /// Eigenvalues and eigenvectors of symmetric matrix
+    ///
+    /// Eigenvalues are returned in ascending order, eigenvectors have unit
+    /// length and follow the order of eigenvalues. Only the upper triangle of
+    /// matrix is used, so the result is meaningful only for symmetric matrices.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Mat2, Vec2};
+    /// let m = Mat2::new(Vec2::new(2, 1), Vec2::new(1, 2));
+    /// let (values, (a, b)) = m.symmetric_eigen();
+    /// assert!((values - Vec2::new(1, 3)).len() < 1e-12);
+    /// assert!((m * a - a * values.x).len() < 1e-12);
+    /// assert!((m * b - b * values.y).len() < 1e-12);
+    /// ```
+    pub fn symmetric_eigen(self) -> (Vec2, (Vec2, Vec2)) {
+        let (a, b, c) = (self.x.x, self.x.y, self.y.y);
+        let half_trace = 0.5 * (a + c);
+        let half_diff = 0.5 * (a - c);
+        let radius = half_diff.hypot(b);
+        let values = Vec2::new(half_trace - radius, half_trace + radius);
+        if radius == 0.0 {
+            return (values, (Vec2::new(1, 0), Vec2::new(0, 1)));
+        }
+        // angle of eigenvector for the largest eigenvalue
+        let theta = 0.5 * f64::atan2(b, half_diff);
+        let major = Vec2::new(theta.cos(), theta.sin());
+        (values, (-major.cross(), major))
+    }
+    /// QR decomposition
+    ///
+    /// Returns orthogonal matrix $Q$ and upper triangular matrix $R$ with
+    /// non-negative diagonal, such that $A = QR$. Columns of $Q$ are obtained
+    /// by Gram-Schmidt orthogonalization of columns of $A$; for degenerate
+    /// matrix missing columns are completed to orthonormal basis.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Mat2, Vec2};
+    /// let a = Mat2::new(Vec2::new(3, 1), Vec2::new(4, 2));
+    /// let (q, r) = a.qr();
+    /// assert!((q * q.transpose() - Mat2::identity()).x.len() < 1e-12);
+    /// assert!(r.y.x.abs() < 1e-12);
+    /// assert!((q * r - a).x.len() < 1e-12 && (q * r - a).y.len() < 1e-12);
+    /// ```
+    pub fn qr(self) -> (Mat2, Mat2) {
+        let t = self.transpose();
+        let (a, b) = (t.x, t.y);
+        let scale = a.len().max(b.len());
+        let e1 = if a.len() > DEGENERATE_EPS * scale { a.ort() } else { Vec2::new(1, 0) };
+        let mut e2 = -e1.cross();
+        if e2.dot(b) < 0.0 {
+            e2 = -e2;
+        }
+        let mut q = Mat2::from_cols(e1, e2);
+        let mut r = q.transpose() * self;
+        // tiny column replaced with basis vector may project negatively
+        if r.x.x < 0.0 {
+            r.x = -r.x;
+            q.x.x = -q.x.x;
+            q.y.x = -q.y.x;
+        }
+        (q, r)
+    }
+    /// Singular value decomposition
+    ///
+    /// Returns orthogonal matrices $U$, $V^T$ and singular values $\Sigma$ in
+    /// descending order, such that $A = U \Sigma V^T$. Decomposition is
+    /// computed in closed form.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Mat2, Vec2};
+    /// let a = Mat2::new(Vec2::new(3, 0), Vec2::new(4, 5));
+    /// let (u, s, vt) = a.svd();
+    /// assert!((s - Vec2::new(45f64.sqrt(), 5f64.sqrt())).len() < 1e-12);
+    /// let d = u * Mat2::diag(s) * vt - a;
+    /// assert!(d.x.len() < 1e-12 && d.y.len() < 1e-12);
+    /// ```
+    pub fn svd(self) -> (Mat2, Vec2, Mat2) {
+        let (a, b, c, d) = (self.x.x, self.x.y, self.y.x, self.y.y);
+        let (e, f) = (0.5 * (a + d), 0.5 * (a - d));
+        let (g, h) = (0.5 * (c + b), 0.5 * (c - b));
+        let (q, r) = (e.hypot(h), f.hypot(g));
+        let (a1, a2) = (g.atan2(f), h.atan2(e));
+        let (theta, phi) = (0.5 * (a2 - a1), 0.5 * (a2 + a1));
+        let mut u = Mat2::rotation(phi);
+        if q < r {
+            // negative second singular value, reflect second column of `u`
+            u.x.y = -u.x.y;
+            u.y.y = -u.y.y;
+        }
+        (u, Vec2::new(q + r, (q - r).abs()), Mat2::rotation(theta))
+    }
+    /// Diagonal matrix
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Mat2, Vec2};
+    /// let m = Mat2::diag(Vec2::new(2, 3));
+    /// assert_eq!(m, Mat2::new(Vec2::new(2, 0), Vec2::new(0, 3)));
+    /// ```
+    pub fn diag(d: Vec2) -> Mat2 {
+        Mat2::new(Vec2::new(d.x, 0.0), Vec2::new(0.0, d.y))
+    }
+    /// Counterclockwise rotation by angle
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Mat2, Vec2};
+    /// # use std::f64::consts::FRAC_PI_2;
+    /// let v = Mat2::rotation(FRAC_PI_2) * Vec2::new(1, 0);
+    /// assert!((v - Vec2::new(0, 1)).len() < 1e-12);
+    /// ```
+    pub fn rotation<I: Into<f64>>(angle: I) -> Mat2 {
+        let (sin, cos) = angle.into().sin_cos();
+        Mat2::new(Vec2::new(cos, -sin), Vec2::new(sin, cos))
+    }
+    /// Scaling along coordinate axes
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Mat2, Vec2};
+    /// assert_eq!(Mat2::scale(2, -1) * Vec2::new(3, 4), Vec2::new(6, -4));
+    /// ```
+    pub fn scale<I: Into<f64>>(sx: I, sy: I) -> Mat2 {
+        Mat2::diag(Vec2::new(sx, sy))
+    }
+    /// Shear, $x' = x + k_x y$ and $y' = y + k_y x$
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Mat2, Vec2};
+    /// assert_eq!(Mat2::shear(2, 0) * Vec2::new(1, 1), Vec2::new(3, 1));
+    /// assert_eq!(Mat2::shear(0, 2) * Vec2::new(1, 1), Vec2::new(1, 3));
+    /// ```
+    pub fn shear<I: Into<f64>>(kx: I, ky: I) -> Mat2 {
+        Mat2::new(Vec2::new(1.0, kx.into()), Vec2::new(ky.into(), 1.0))
+    }
+    /// Reflection across line through origin with direction `axis`
+    ///
+    /// Axis doesn't need to be normalized, but must be nonzero.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Mat2, Vec2};
+    /// let m = Mat2::reflection(Vec2::new(1, 1));
+    /// assert!((m * Vec2::new(2, 0) - Vec2::new(0, 2)).len() < 1e-12);
+    /// ```
+    pub fn reflection(axis: Vec2) -> Mat2 {
+        // 2 u u^T - I
+        let u = axis.ort();
+        Mat2::new(Vec2::new(2.0 * u.x * u.x - 1.0, 2.0 * u.x * u.y),
+                  Vec2::new(2.0 * u.x * u.y, 2.0 * u.y * u.y - 1.0))
+    }
+    /// Inverse matrix
+    ///
+    /// Returns `None` for degenerate matrices, i.e. when $|\det M|$ is
+    /// negligible compared to the product of row lengths, so a transform that
+    /// collapses the plane onto a line (zero scale) has no inverse.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Mat2, Vec2};
+    /// let m = Mat2::new(Vec2::new(2, 1), Vec2::new(1, 1));
+    /// assert_eq!(m * m.inverse().unwrap(), Mat2::identity());
+    /// assert!(Mat2::scale(1.0, 0.0).inverse().is_none());
+    /// ```
+    pub fn inverse(self) -> Option<Mat2> {
+        let det = self.det();
+        if det.abs() <= DEGENERATE_EPS * self.x.len() * self.y.len() || det == 0.0 {
+            return None;
+        }
+        Some(Mat2::new(Vec2::new(self.y.y, -self.x.y), Vec2::new(-self.y.x, self.x.x)) * (1.0 / det))
+    }
+    /// Jacobian of polar coordinates map $(r, \theta) \mapsto (x, y)$
+    ///
+    /// Columns are derivatives of point by $r$ and $\theta$, so the matrix
+    /// turns rates $(\dot r, \dot \theta)$ into Cartesian velocity; its
+    /// determinant is $r$. See [`Vec2::from_polar`].
+    ///
+    /// [`Vec2::from_polar`]: struct.Vec2.html#method.from_polar
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Mat2, Vec2};
+    /// let j = Mat2::polar_jacobian(2.0, 0.0);
+    /// // moving along circle of radius 2 at unit angular rate
+    /// assert_eq!(j * Vec2::new(0, 1), Vec2::new(0, 2));
+    /// assert_eq!(j.det(), 2.0);
+    /// ```
+    pub fn polar_jacobian<I: Into<f64>>(r: I, theta: I) -> Mat2 {
+        let (r, theta) = (r.into(), theta.into());
+        let (sin, cos) = theta.sin_cos();
+        Mat2::new(Vec2::new(cos, -r * sin), Vec2::new(sin, r * cos))
+    }
+    /// Jacobian of inverse map $(x, y) \mapsto (r, \theta)$ at polar point
+    ///
+    /// Turns Cartesian velocity into rates of polar coordinates; transposed,
+    /// turns gradient by polar coordinates into Cartesian one. Returns `None`
+    /// at origin, where angle is singular.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Mat2, Vec2};
+    /// # use std::f64::consts::FRAC_PI_2;
+    /// let inv = Mat2::polar_jacobian_inverse(2.0, FRAC_PI_2).unwrap();
+    /// let rates = inv * Vec2::new(-1, 0);
+    /// assert!((rates - Vec2::new(0.0, 0.5)).len() < 1e-12);
+    /// assert!(Mat2::polar_jacobian_inverse(0, 1).is_none());
+    /// ```
+    pub fn polar_jacobian_inverse<I: Into<f64>>(r: I, theta: I) -> Option<Mat2> {
+        let (r, theta) = (r.into(), theta.into());
+        if r == 0.0 {
+            return None;
+        }
+        let (sin, cos) = theta.sin_cos();
+        Some(Mat2::new(Vec2::new(cos, sin), Vec2::new(-sin / r, cos / r)))
+    }
+
+    // need for op_default & op_assign
+    fn size(&self) -> usize { 2 }
+}
+
+op_default!(add, Add, +=, Mat2);
+op_default!(sub, Sub, -=, Mat2);
+op_default!(f64, mul, Mul, *=, Mat2);
+op_scalar_left!(Mat2);
+op_assign!(add_assign, AddAssign, +=, Mat2);
+op_assign!(sub_assign, SubAssign, -=, Mat2);
+op_assign!(f64, mul_assign, MulAssign, *=, Mat2);
+
+impl Mul<Vec2> for Mat2 {
+    type Output = Vec2;
+
+    fn mul(self, rhs: Vec2) -> Vec2 {
+        Vec2::new(self.x.dot(rhs), self.y.dot(rhs))
+    }
+}
+
+impl Mul for Mat2 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        let t = rhs.transpose();
+        Mat2::from_cols(self * t.x, self * t.y)
+    }
+}
+
+impl MulAssign for Mat2 {
+    /// Accumulates transform in place, `a *= b` is the same as `a = a * b`
+    fn mul_assign(&mut self, rhs: Mat2) {
+        *self = *self * rhs;
+    }
+}
+
+impl Neg for Mat2 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl Index<usize> for Mat2 {
+    type Output = Vec2;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            i => panic!("Index {} out of [0, 1] range", i)
+        }
+    }
+}
+
+impl IndexMut<usize> for Mat2 {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            i => panic!("Index {} out of [0, 1] range", i)
+        }
+    }
+}
+
+impl PartialEq for Mat2 {
+    fn eq(&self, other: &Self) -> bool {
+        self.x == other.x && self.y == other.y
+    }
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    #[test]
+    fn mat2_mul() {
+        let a = Mat2::new(Vec2::new(1, 2), Vec2::new(3, 4));
+        let b = Mat2::new(Vec2::new(0, 1), Vec2::new(1, 0));
+        assert_eq!(a * b, Mat2::new(Vec2::new(2, 1), Vec2::new(4, 3)));
+        assert_eq!(b * a, Mat2::new(Vec2::new(3, 4), Vec2::new(1, 2)));
+        assert_eq!(a * Mat2::identity(), a);
+    }
+
+    #[test]
+    fn mat2_mul_vec() {
+        let a = Mat2::new(Vec2::new(1, 2), Vec2::new(3, 4));
+        assert_eq!(a * Vec2::new(1, 1), Vec2::new(3, 7));
+    }
+
+    #[test]
+    fn mat2_add_sub() {
+        let a = Mat2::new(Vec2::new(1, 2), Vec2::new(3, 4));
+        let mut z = a;
+        z += a;
+        assert_eq!(a + a, a * 2);
+        assert_eq!(z, a * 2);
+        z -= a;
+        assert_eq!(z, a);
+        assert_eq!(a - a, Mat2::zero());
+    }
+
+    #[test]
+    fn mat2_symmetric_eigen() {
+        let m = Mat2::new(Vec2::new(4, -2), Vec2::new(-2, 1));
+        let (values, (a, b)) = m.symmetric_eigen();
+        assert!(values.x.abs() < 1e-12);
+        assert!((values.y - 5.0).abs() < 1e-12);
+        assert!((m * a - a * values.x).len() < 1e-12);
+        assert!((m * b - b * values.y).len() < 1e-12);
+        assert!(a.dot(b).abs() < 1e-12);
+    }
+
+    #[test]
+    fn mat2_symmetric_eigen_diagonal() {
+        let m = Mat2::new(Vec2::new(3, 0), Vec2::new(0, -1));
+        let (values, (a, b)) = m.symmetric_eigen();
+        assert_eq!(values, Vec2::new(-1, 3));
+        assert!((a.x.abs() - 0.0).abs() < 1e-12 && (a.y.abs() - 1.0).abs() < 1e-12);
+        assert!((b.x.abs() - 1.0).abs() < 1e-12 && (b.y.abs() - 0.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn mat2_symmetric_eigen_scalar() {
+        let m = Mat2::identity() * 2;
+        let (values, (a, b)) = m.symmetric_eigen();
+        assert_eq!(values, Vec2::new(2, 2));
+        assert_eq!(a.dot(b), 0.0);
+    }
+
+    #[test]
+    fn mat2_qr() {
+        let a = Mat2::new(Vec2::new(1, -2), Vec2::new(3, 5));
+        let (q, r) = a.qr();
+        let i = q.transpose() * q - Mat2::identity();
+        let d = q * r - a;
+        assert!(i.x.len() < 1e-12 && i.y.len() < 1e-12);
+        assert!(d.x.len() < 1e-12 && d.y.len() < 1e-12);
+        assert_eq!(r.y.x, 0.0);
+        assert!(r.x.x >= 0.0 && r.y.y >= 0.0);
+    }
+
+    #[test]
+    fn mat2_qr_degenerate() {
+        let a = Mat2::new(Vec2::new(0, 2), Vec2::new(0, 4));
+        let (q, r) = a.qr();
+        let d = q * r - a;
+        assert!(d.x.len() < 1e-12 && d.y.len() < 1e-12);
+        assert!((q.det().abs() - 1.0).abs() < 1e-12);
+        let a = Mat2::new(Vec2::new(-1e-20, 0.0), Vec2::new(0, 1));
+        let (q, r) = a.qr();
+        let d = q * r - a;
+        assert!(d.x.len() < 1e-12 && d.y.len() < 1e-12);
+        assert!(r.x.x >= 0.0 && r.y.y >= 0.0);
+    }
+
+    fn check_svd(a: Mat2) {
+        let (u, s, vt) = a.svd();
+        let d = u * Mat2::diag(s) * vt - a;
+        let iu = u.transpose() * u - Mat2::identity();
+        let iv = vt.transpose() * vt - Mat2::identity();
+        assert!(d.x.len() + d.y.len() < 1e-12);
+        assert!(iu.x.len() + iu.y.len() + iv.x.len() + iv.y.len() < 1e-12);
+        assert!(s.x >= s.y && s.y >= 0.0);
+    }
+
+    #[test]
+    fn mat2_svd() {
+        check_svd(Mat2::new(Vec2::new(1, 2), Vec2::new(3, 4)));
+        check_svd(Mat2::new(Vec2::new(-1, 0), Vec2::new(0, 1)));
+        check_svd(Mat2::new(Vec2::new(0.5, 0.1), Vec2::new(-0.3, 0.2)));
+    }
+
+    #[test]
+    fn mat2_svd_degenerate() {
+        check_svd(Mat2::new(Vec2::new(1, 2), Vec2::new(2, 4)));
+        check_svd(Mat2::zero());
+        check_svd(Mat2::new(Vec2::new(-1e-20, 0.0), Vec2::new(0, 1)));
+        let (_, s, _) = Mat2::new(Vec2::new(1, 2), Vec2::new(2, 4)).svd();
+        assert!(s.y < 1e-12);
+    }
+
+    #[test]
+    #[should_panic]
+    fn mat2_index_out_of_range() {
+        let a = Mat2::identity();
+        let _ = a[2];
+    }
+
+    #[test]
+    fn mat2_transforms() {
+        let r = Mat2::rotation(0.7);
+        assert!((r * r.transpose() - Mat2::identity()).x.len() < 1e-12);
+        assert!((r.det() - 1.0).abs() < 1e-12);
+        let m = Mat2::reflection(Vec2::new(3, -4));
+        assert!((m * m - Mat2::identity()).x.len() < 1e-12);
+        assert!((m.det() + 1.0).abs() < 1e-12);
+        assert!((m * Vec2::new(3, -4) - Vec2::new(3, -4)).len() < 1e-12);
+        assert!((m * Vec2::new(4, 3) + Vec2::new(4, 3)).len() < 1e-12);
+        assert_eq!(Mat2::shear(1.5, 0.0).det(), 1.0);
+        assert_eq!(Mat2::scale(2, 3).det(), 6.0);
+    }
+
+    #[test]
+    fn mat2_inverse() {
+        let m = Mat2::rotation(0.3) * Mat2::scale(2.0, 0.5);
+        let inv = m.inverse().unwrap();
+        let e = m * inv - Mat2::identity();
+        assert!(e.x.len() + e.y.len() < 1e-12);
+        assert!(Mat2::new(Vec2::new(1.0, 2.0), Vec2::new(2.0, 4.0)).inverse().is_none());
+        let mut acc = Mat2::identity();
+        acc *= m;
+        acc *= inv;
+        assert!((acc * Vec2::new(1.0, 1.0) - Vec2::new(1.0, 1.0)).len() < 1e-12);
+    }
+}
@@ -0,0 +1,833 @@
+//! Matrices 3x3.
+use std::ops::{Add, Sub, Mul, Neg};
+use std::ops::{AddAssign, SubAssign, MulAssign};
+use std::ops::{Index, IndexMut};
+use std::cmp::PartialEq;
+use std::f64::consts::PI;
+use vec3::Vec3;
+use vec2::Vec2;
+use mat2::Mat2;
+
+// relative length of column treated as linearly dependent
+const DEGENERATE_EPS: f64 = 1e-12;
+// cosine between scaled axes treated as shear
+const SHEAR_EPS: f64 = 1e-9;
+
+/// 3x3 matrix stored by rows
+#[derive(Debug, Clone, Copy)]
+pub struct Mat3 {
+    /// first row of matrix
+    pub x: Vec3,
+    /// second row of matrix
+    pub y: Vec3,
+    /// third row of matrix
+    pub z: Vec3,
+}
+
+impl Mat3 {
+    /// Constructs a new `Mat3` from rows.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Mat3, Vec3};
+    /// let m = Mat3::new(Vec3::new(1, 2, 3), Vec3::new(4, 5, 6), Vec3::new(7, 8, 9));
+    /// assert_eq!(m[0][2], 3.0);
+    /// assert_eq!(m[2][0], 7.0);
+    /// ```
+    pub fn new(x: Vec3, y: Vec3, z: Vec3) -> Mat3 {
+        Mat3 { x, y, z }
+    }
+    /// Constructs a new `Mat3` from columns.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Mat3, Vec3};
+    /// let m = Mat3::from_cols(Vec3::new(1, 4, 7), Vec3::new(2, 5, 8), Vec3::new(3, 6, 9));
+    /// assert_eq!(m, Mat3::new(Vec3::new(1, 2, 3), Vec3::new(4, 5, 6), Vec3::new(7, 8, 9)));
+    /// ```
+    pub fn from_cols(a: Vec3, b: Vec3, c: Vec3) -> Mat3 {
+        Mat3::new(a, b, c).transpose()
+    }
+    /// Create a zero `Mat3`
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Mat3, Vec3};
+    /// let zero = Mat3::zero();
+    /// assert_eq!(zero, Mat3::new(Vec3::zero(), Vec3::zero(), Vec3::zero()));
+    /// ```
+    pub fn zero() -> Mat3 {
+        Mat3::new(Vec3::zero(), Vec3::zero(), Vec3::zero())
+    }
+    /// Create an identity `Mat3`
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Mat3, Vec3};
+    /// let v = Vec3::new(3, 4, 5);
+    /// assert_eq!(Mat3::identity() * v, v);
+    /// ```
+    pub fn identity() -> Mat3 {
+        Mat3::new(Vec3::new(1, 0, 0), Vec3::new(0, 1, 0), Vec3::new(0, 0, 1))
+    }
+    /// Transposed matrix
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Mat3, Vec3};
+    /// let m = Mat3::new(Vec3::new(1, 2, 3), Vec3::new(4, 5, 6), Vec3::new(7, 8, 9));
+    /// let t = Mat3::new(Vec3::new(1, 4, 7), Vec3::new(2, 5, 8), Vec3::new(3, 6, 9));
+    /// assert_eq!(m.transpose(), t);
+    /// ```
+    pub fn transpose(self) -> Mat3 {
+        Mat3::new(Vec3::new(self.x.x, self.y.x, self.z.x),
+                  Vec3::new(self.x.y, self.y.y, self.z.y),
+                  Vec3::new(self.x.z, self.y.z, self.z.z))
+    }
+    /// Determinant of matrix
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Mat3, Vec3};
+    /// let m = Mat3::new(Vec3::new(2, 0, 0), Vec3::new(3, 4, 0), Vec3::new(3, 4, 5));
+    /// assert_eq!(m.det(), 40.0);
+    /// ```
+    pub fn det(self) -> f64 {
+        self.x.cross(self.y).dot(self.z)
+    }
+    /// Trace of matrix
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Mat3, Vec3};
+    /// let m = Mat3::new(Vec3::new(1, 2, 3), Vec3::new(4, 5, 6), Vec3::new(7, 8, 9));
+    /// assert_eq!(m.trace(), 15.0);
+    /// ```
+    pub fn trace(self) -> f64 {
+        self.x.x + self.y.y + self.z.z
+    }
+    /// Eigenvalues and eigenvectors of symmetric matrix
+    ///
+    /// Eigenvalues are computed in closed form and returned in ascending
+    /// order, eigenvectors have unit length, follow the order of eigenvalues
+    /// and form a right-handed orthonormal basis. Only the upper triangle of
+    /// matrix is used, so the result is meaningful only for symmetric matrices.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Mat3, Vec3};
+    /// let m = Mat3::new(Vec3::new(2, 1, 0), Vec3::new(1, 2, 0), Vec3::new(0, 0, 5));
+    /// let (values, (a, b, c)) = m.symmetric_eigen();
+    /// assert!((values - Vec3::new(1, 3, 5)).len() < 1e-12);
+    /// assert!((m * a - a * values.x).len() < 1e-12);
+    /// assert!((m * b - b * values.y).len() < 1e-12);
+    /// assert!((m * c - c * values.z).len() < 1e-12);
+    /// ```
+    pub fn symmetric_eigen(self) -> (Vec3, (Vec3, Vec3, Vec3)) {
+        let m = Mat3::new(self.x,
+                          Vec3::new(self.x.y, self.y.y, self.y.z),
+                          Vec3::new(self.x.z, self.y.z, self.z.z));
+        // scale matrix to avoid overflow and loss of precision
+        let scale = (0..3).flat_map(|i| (0..3).map(move |j| (i, j)))
+                          .fold(0.0, |acc: f64, (i, j)| acc.max(m[i][j].abs()));
+        if scale == 0.0 {
+            return (Vec3::zero(), (Vec3::new(1, 0, 0), Vec3::new(0, 1, 0), Vec3::new(0, 0, 1)));
+        }
+        let m = m * (1.0 / scale);
+
+        let q = m.trace() / 3.0;
+        let off = m.x.y * m.x.y + m.x.z * m.x.z + m.y.z * m.y.z;
+        let diag = (m.x.x - q).powi(2) + (m.y.y - q).powi(2) + (m.z.z - q).powi(2);
+        let p = ((diag + 2.0 * off) / 6.0).sqrt();
+        if p == 0.0 {
+            let values = Vec3::new(q, q, q) * scale;
+            return (values, (Vec3::new(1, 0, 0), Vec3::new(0, 1, 0), Vec3::new(0, 0, 1)));
+        }
+        let b = (m - Mat3::identity() * q) * (1.0 / p);
+        let phi = (0.5 * b.det()).clamp(-1.0, 1.0).acos() / 3.0;
+        let max = q + 2.0 * p * phi.cos();
+        let min = q + 2.0 * p * (phi + 2.0 * PI / 3.0).cos();
+        let mid = (3.0 * q - max - min).max(min).min(max); // not clamp, it panics on NaN bounds
+
+        // start from the best separated eigenvalue
+        let (a, b, c) = if max - mid >= mid - min {
+            let c = eigenvector_simple(m, max);
+            let b = eigenvector_in_plane(m, c, mid);
+            (b.cross(c), b, c)
+        } else {
+            let a = eigenvector_simple(m, min);
+            let b = eigenvector_in_plane(m, a, mid);
+            (a, b, a.cross(b))
+        };
+        // refine eigenvalues with Rayleigh quotients, lost precision
+        // of closed form is noticeable for close eigenvalues
+        let mut pairs = [(a.dot(m * a), a), (b.dot(m * b), b), (c.dot(m * c), c)];
+        pairs.sort_by(|l, r| l.0.total_cmp(&r.0));
+        let [(l0, a), (l1, b), (l2, c)] = pairs;
+        let c = if a.cross(b).dot(c) < 0.0 { -c } else { c };
+        (Vec3::new(l0, l1, l2) * scale, (a, b, c))
+    }
+    /// QR decomposition
+    ///
+    /// Returns orthogonal matrix $Q$ and upper triangular matrix $R$ with
+    /// non-negative diagonal, such that $A = QR$. Columns of $Q$ are obtained
+    /// by Gram-Schmidt orthogonalization of columns of $A$ (with one step of
+    /// reorthogonalization); for degenerate matrix missing columns are
+    /// completed to orthonormal basis.
+    ///
+    /// Applied to rotation matrix with accumulated numerical drift it returns
+    /// orthonormalized rotation in $Q$.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Mat3, Vec3};
+    /// let a = Mat3::new(Vec3::new(2, 0, 1), Vec3::new(0, 1, 1), Vec3::new(1, 1, 3));
+    /// let (q, r) = a.qr();
+    /// let d = q * r - a;
+    /// assert!(d.x.len() + d.y.len() + d.z.len() < 1e-12);
+    /// assert!(r.y.x == 0.0 && r.z.x == 0.0 && r.z.y == 0.0);
+    /// ```
+    pub fn qr(self) -> (Mat3, Mat3) {
+        let t = self.transpose();
+        let (a, b, c) = (t.x, t.y, t.z);
+        let scale = a.len().max(b.len()).max(c.len());
+        let eps = DEGENERATE_EPS * scale;
+
+        let e1 = if a.len() > eps { a.ort() } else { Vec3::new(1, 0, 0) };
+        let mut e2 = b - e1 * e1.dot(b);
+        e2 -= e1 * e1.dot(e2);
+        let e2 = if e2.len() > eps { e2.ort() } else { orthogonal_complement(e1).0 };
+        let mut e3 = e1.cross(e2);
+        if e3.dot(c) < 0.0 {
+            e3 = -e3;
+        }
+        let mut q = Mat3::from_cols(e1, e2, e3);
+        let mut r = q.transpose() * self;
+        // exact zeros below diagonal
+        r.y.x = 0.0;
+        r.z.x = 0.0;
+        r.z.y = 0.0;
+        // tiny columns replaced with completion vectors may project negatively
+        for i in 0..3 {
+            if r[i][i] < 0.0 {
+                r[i] = -r[i];
+                for j in 0..3 {
+                    q[j][i] = -q[j][i];
+                }
+            }
+        }
+        (q, r)
+    }
+    /// Singular value decomposition
+    ///
+    /// Returns orthogonal matrices $U$, $V^T$ and singular values $\Sigma$ in
+    /// descending order, such that $A = U \Sigma V^T$. Right singular vectors
+    /// are eigenvectors of $A^T A$, left ones are obtained by QR decomposition
+    /// of $A V$, which keeps small singular values accurate. Non-negative
+    /// diagonal of $R$ makes singular values non-negative for singular matrix
+    /// too.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Mat3, Vec3};
+    /// let a = Mat3::new(Vec3::new(2, 0, 0), Vec3::new(0, -3, 0), Vec3::new(0, 0, 1));
+    /// let (u, s, vt) = a.svd();
+    /// assert!((s - Vec3::new(3, 2, 1)).len() < 1e-12);
+    /// let d = u * Mat3::diag(s) * vt - a;
+    /// assert!(d.x.len() + d.y.len() + d.z.len() < 1e-12);
+    /// ```
+    pub fn svd(self) -> (Mat3, Vec3, Mat3) {
+        let (_, (a, b, c)) = (self.transpose() * self).symmetric_eigen();
+        // descending order of singular values
+        let v = Mat3::from_cols(c, b, a);
+        let (u, r) = (self * v).qr();
+        (u, Vec3::new(r.x.x, r.y.y, r.z.z), v.transpose())
+    }
+    /// Diagonal matrix
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Mat3, Vec3};
+    /// let m = Mat3::diag(Vec3::new(1, 2, 3));
+    /// assert_eq!(m * Vec3::new(1, 1, 1), Vec3::new(1, 2, 3));
+    /// ```
+    pub fn diag(d: Vec3) -> Mat3 {
+        Mat3::new(Vec3::new(d.x, 0.0, 0.0), Vec3::new(0.0, d.y, 0.0), Vec3::new(0.0, 0.0, d.z))
+    }
+    /// Homogeneous 2D affine transform from linear part and translation
+    ///
+    /// Point $(x, y)$ is represented as $(x, y, 1)$, so transformed point is
+    /// $A \vec{p} + \vec{t}$.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Mat2, Mat3, Vec2, Vec3};
+    /// let m = Mat3::affine2(Mat2::scale(2, 2), Vec2::new(1, 0));
+    /// assert_eq!(m * Vec3::new(1, 1, 1), Vec3::new(3, 2, 1));
+    /// ```
+    pub fn affine2(linear: Mat2, translation: Vec2) -> Mat3 {
+        Mat3::new(Vec3::new(linear.x.x, linear.x.y, translation.x),
+                  Vec3::new(linear.y.x, linear.y.y, translation.y),
+                  Vec3::new(0, 0, 1))
+    }
+    /// Decompose homogeneous 2D transform into translation, rotation and scale
+    ///
+    /// Returns `(translation, angle, scale)` such that matrix equals
+    /// `translation2(t) * rotation2(angle) * scale2(s.x, s.y)`. Reflection is
+    /// encoded as negative `s.y`. Returns `None` for projective, degenerate
+    /// or sheared transforms, which can't be represented by these components.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Mat3, Vec2};
+    /// let m = Mat3::translation2(Vec2::new(1.0, 2.0)) * Mat3::rotation2(0.5) * Mat3::scale2(2.0, 3.0);
+    /// let (t, angle, s) = m.decompose2().unwrap();
+    /// assert!((t - Vec2::new(1.0, 2.0)).len() < 1e-12);
+    /// assert!((angle - 0.5).abs() < 1e-12);
+    /// assert!((s - Vec2::new(2.0, 3.0)).len() < 1e-12);
+    /// assert!(Mat3::shear2(1.0, 0.0).decompose2().is_none());
+    /// ```
+    pub fn decompose2(self) -> Option<(Vec2, f64, Vec2)> {
+        if self.z != Vec3::new(0, 0, 1) {
+            return None;
+        }
+        let (a, b) = (Vec2::new(self.x.x, self.y.x), Vec2::new(self.x.y, self.y.y));
+        let (sx, sy) = (a.len(), b.len());
+        if sx == 0.0 || sy == 0.0 || a.dot(b).abs() > SHEAR_EPS * sx * sy {
+            return None;
+        }
+        let sy = if a.area(b) < 0.0 { -sy } else { sy };
+        Some((Vec2::new(self.x.z, self.y.z), a.y.atan2(a.x), Vec2::new(sx, sy)))
+    }
+    /// Homogeneous 2D translation
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Mat3, Vec2, Vec3};
+    /// let m = Mat3::translation2(Vec2::new(1, -2));
+    /// assert_eq!(m * Vec3::new(1, 1, 1), Vec3::new(2, -1, 1));
+    /// // directions are not translated
+    /// assert_eq!(m * Vec3::new(1, 1, 0), Vec3::new(1, 1, 0));
+    /// ```
+    pub fn translation2(t: Vec2) -> Mat3 {
+        Mat3::affine2(Mat2::identity(), t)
+    }
+    /// Homogeneous 2D counterclockwise rotation around origin
+    pub fn rotation2<I: Into<f64>>(angle: I) -> Mat3 {
+        Mat3::affine2(Mat2::rotation(angle), Vec2::zero())
+    }
+    /// Homogeneous 2D scaling along coordinate axes
+    pub fn scale2<I: Into<f64>>(sx: I, sy: I) -> Mat3 {
+        Mat3::affine2(Mat2::scale(sx, sy), Vec2::zero())
+    }
+    /// Homogeneous 2D shear, see [`Mat2::shear`]
+    pub fn shear2<I: Into<f64>>(kx: I, ky: I) -> Mat3 {
+        Mat3::affine2(Mat2::shear(kx, ky), Vec2::zero())
+    }
+    /// Homogeneous 2D reflection across line through origin, see [`Mat2::reflection`]
+    pub fn reflection2(axis: Vec2) -> Mat3 {
+        Mat3::affine2(Mat2::reflection(axis), Vec2::zero())
+    }
+    /// Cross product matrix, $[\vec{v}]_\times \vec{u} = \vec{v} \times \vec{u}$
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Mat3, Vec3};
+    /// let (v, u) = (Vec3::new(1, 2, 3), Vec3::new(-2, 0, 5));
+    /// assert_eq!(Mat3::skew(v) * u, v.cross(u));
+    /// ```
+    pub fn skew(v: Vec3) -> Mat3 {
+        Mat3::new(Vec3::new(0.0, -v.z, v.y),
+                  Vec3::new(v.z, 0.0, -v.x),
+                  Vec3::new(-v.y, v.x, 0.0))
+    }
+    /// Rotation around axis by angle
+    ///
+    /// Rotation is counterclockwise when looking against `axis`, which
+    /// doesn't need to be normalized, but must be nonzero.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Mat3, Vec3};
+    /// # use std::f64::consts::FRAC_PI_2;
+    /// let m = Mat3::rotation(Vec3::new(0, 0, 2), FRAC_PI_2);
+    /// assert!((m * Vec3::new(1, 0, 0) - Vec3::new(0, 1, 0)).len() < 1e-12);
+    /// ```
+    pub fn rotation<I: Into<f64>>(axis: Vec3, angle: I) -> Mat3 {
+        let (sin, cos) = angle.into().sin_cos();
+        let k = Mat3::skew(axis.ort());
+        // Rodrigues' rotation formula
+        Mat3::identity() + k * sin + k * k * (1.0 - cos)
+    }
+    /// Householder reflection across plane through origin with given normal
+    ///
+    /// Matrix $I - 2 \hat{n} \hat{n}^T$ reflects vectors (directions),
+    /// normal doesn't need to be normalized, but must be nonzero.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Mat3, Vec3};
+    /// let m = Mat3::householder(Vec3::new(0, 0, 2));
+    /// assert_eq!(m * Vec3::new(1, 2, 3), Vec3::new(1, 2, -3));
+    /// assert_eq!(m * m, Mat3::identity());
+    /// ```
+    pub fn householder(normal: Vec3) -> Mat3 {
+        let n = normal.ort();
+        let row = |k: f64, e: Vec3| e - n * (2.0 * k);
+        Mat3::new(row(n.x, Vec3::new(1, 0, 0)), row(n.y, Vec3::new(0, 1, 0)),
+                  row(n.z, Vec3::new(0, 0, 1)))
+    }
+    /// Homogeneous 2D transform of point
+    ///
+    /// Point is extended to $(x, y, 1)$ and result is divided by its last
+    /// coordinate, so projective transforms work too. Same as `self * p`.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Mat3, Vec2};
+    /// let m = Mat3::translation2(Vec2::new(1, 2)) * Mat3::scale2(2, 3);
+    /// assert_eq!(m.transform_point2(Vec2::new(1, 1)), Vec2::new(3, 5));
+    /// assert_eq!(m * Vec2::new(1, 1), Vec2::new(3, 5));
+    /// ```
+    pub fn transform_point2(self, p: Vec2) -> Vec2 {
+        let h = self * p.to_homogeneous();
+        Vec2::new(h.x / h.z, h.y / h.z)
+    }
+    /// Homogeneous 2D transform of vector
+    ///
+    /// Vector is extended to $(x, y, 0)$, so translation doesn't affect it.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Mat3, Vec2};
+    /// let m = Mat3::translation2(Vec2::new(1, 2)) * Mat3::scale2(2, 3);
+    /// assert_eq!(m.transform_vector2(Vec2::new(1, 1)), Vec2::new(2, 3));
+    /// ```
+    pub fn transform_vector2(self, v: Vec2) -> Vec2 {
+        let h = self * v.to_homogeneous_direction();
+        Vec2::new(h.x, h.y)
+    }
+    /// Inverse matrix
+    ///
+    /// Returns `None` for degenerate matrices, i.e. when $|\det M|$ is
+    /// negligible compared to the product of row lengths, so a transform with
+    /// zero scale along some direction has no inverse.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Mat3, Vec2};
+    /// let m = Mat3::translation2(Vec2::new(1.0, 2.0)) * Mat3::rotation2(0.5);
+    /// let p = Vec2::new(3.0, -1.0);
+    /// assert!((m.inverse().unwrap() * (m * p) - p).len() < 1e-12);
+    /// assert!(Mat3::scale2(0.0, 2.0).inverse().is_none());
+    /// ```
+    pub fn inverse(self) -> Option<Mat3> {
+        let det = self.det();
+        if det.abs() <= DEGENERATE_EPS * self.x.len() * self.y.len() * self.z.len() || det == 0.0 {
+            return None;
+        }
+        let adj = Mat3::from_cols(self.y.cross(self.z), self.z.cross(self.x), self.x.cross(self.y));
+        Some(adj * (1.0 / det))
+    }
+    /// Jacobian of spherical coordinates map $(r, \theta, \phi) \mapsto (x, y, z)$
+    ///
+    /// Columns are derivatives of point by $r$, polar angle $\theta$ and
+    /// azimuth $\phi$, so the matrix turns rates of spherical coordinates
+    /// into Cartesian velocity; its determinant is $r^2 \sin \theta$. See
+    /// [`Vec3::from_spherical`].
+    ///
+    /// [`Vec3::from_spherical`]: struct.Vec3.html#method.from_spherical
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Mat3, Vec3};
+    /// # use std::f64::consts::FRAC_PI_2;
+    /// let j = Mat3::spherical_jacobian(2.0, FRAC_PI_2, 0.0);
+    /// let v = j * Vec3::new(0, 0, 1);
+    /// assert!((v - Vec3::new(0, 2, 0)).len() < 1e-12);
+    /// assert!((j.det() - 4.0).abs() < 1e-12);
+    /// ```
+    pub fn spherical_jacobian<I: Into<f64>>(r: I, theta: I, phi: I) -> Mat3 {
+        let (r, theta, phi) = (r.into(), theta.into(), phi.into());
+        let (st, ct) = theta.sin_cos();
+        let (sp, cp) = phi.sin_cos();
+        Mat3::new(Vec3::new(st * cp, r * ct * cp, -r * st * sp),
+                  Vec3::new(st * sp, r * ct * sp, r * st * cp),
+                  Vec3::new(ct, -r * st, 0.0))
+    }
+    /// Jacobian of inverse map $(x, y, z) \mapsto (r, \theta, \phi)$ at
+    /// spherical point
+    ///
+    /// Turns Cartesian velocity into rates of spherical coordinates;
+    /// transposed, turns gradient by spherical coordinates into Cartesian
+    /// one. Returns `None` on $z$ axis, where azimuth is singular.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Mat3, Vec3};
+    /// let (r, theta, phi) = (2.0, 0.7, -1.2);
+    /// let j = Mat3::spherical_jacobian(r, theta, phi);
+    /// let inv = Mat3::spherical_jacobian_inverse(r, theta, phi).unwrap();
+    /// let rates = Vec3::new(0.5, -1.0, 3.0);
+    /// assert!((inv * (j * rates) - rates).len() < 1e-12);
+    /// assert!(Mat3::spherical_jacobian_inverse(1, 0, 0).is_none());
+    /// ```
+    pub fn spherical_jacobian_inverse<I: Into<f64>>(r: I, theta: I, phi: I) -> Option<Mat3> {
+        let (r, theta, phi) = (r.into(), theta.into(), phi.into());
+        let (st, ct) = theta.sin_cos();
+        if r == 0.0 || st == 0.0 {
+            return None;
+        }
+        let (sp, cp) = phi.sin_cos();
+        Some(Mat3::new(Vec3::new(st * cp, st * sp, ct),
+                       Vec3::new(ct * cp / r, ct * sp / r, -st / r),
+                       Vec3::new(-sp / (r * st), cp / (r * st), 0.0)))
+    }
+
+    // need for op_default & op_assign
+    fn size(&self) -> usize { 3 }
+}
+
+// eigenvector for eigenvalue of multiplicity one
+fn eigenvector_simple(m: Mat3, value: f64) -> Vec3 {
+    let r = m - Mat3::identity() * value;
+    let candidates = [r.x.cross(r.y), r.x.cross(r.z), r.y.cross(r.z)];
+    let best = candidates.iter()
+                         .fold(Vec3::zero(), |acc, &v| if v.dot(v) > acc.dot(acc) { v } else { acc });
+    if best.dot(best) == 0.0 {
+        Vec3::new(1, 0, 0)
+    } else {
+        best.ort()
+    }
+}
+
+// eigenvector orthogonal to known eigenvector `e`
+fn eigenvector_in_plane(m: Mat3, e: Vec3, value: f64) -> Vec3 {
+    let (u, v) = orthogonal_complement(e);
+    let (mu, mv) = (m * u, m * v);
+    // restriction of `m - value * I` to the plane spanned by `u` and `v`
+    let (m00, m01, m11) = (u.dot(mu) - value, u.dot(mv), v.dot(mv) - value);
+    let (a00, a01, a11) = (m00.abs(), m01.abs(), m11.abs());
+    if a00.max(a01) >= a11 {
+        if a00.max(a01) == 0.0 {
+            return u;
+        }
+        if a00 >= a01 {
+            let t = m01 / m00;
+            let c = 1.0 / (1.0 + t * t).sqrt();
+            (u * (t * c) - v * c).ort()
+        } else {
+            let t = m00 / m01;
+            let s = 1.0 / (1.0 + t * t).sqrt();
+            (u * s - v * (t * s)).ort()
+        }
+    } else if a11 >= a01 {
+        let t = m01 / m11;
+        let c = 1.0 / (1.0 + t * t).sqrt();
+        (u * c - v * (t * c)).ort()
+    } else {
+        let t = m11 / m01;
+        let s = 1.0 / (1.0 + t * t).sqrt();
+        (u * (t * s) - v * s).ort()
+    }
+}
+
+// orthonormal pair of vectors orthogonal to unit vector `e`
+pub(crate) fn orthogonal_complement(e: Vec3) -> (Vec3, Vec3) {
+    let u = if e.x.abs() > e.y.abs() {
+        Vec3::new(-e.z, 0.0, e.x).ort()
+    } else {
+        Vec3::new(0.0, e.z, -e.y).ort()
+    };
+    (u, e.cross(u))
+}
+
+op_default!(add, Add, +=, Mat3);
+op_default!(sub, Sub, -=, Mat3);
+op_default!(f64, mul, Mul, *=, Mat3);
+op_scalar_left!(Mat3);
+op_assign!(add_assign, AddAssign, +=, Mat3);
+op_assign!(sub_assign, SubAssign, -=, Mat3);
+op_assign!(f64, mul_assign, MulAssign, *=, Mat3);
+
+impl Mul<Vec3> for Mat3 {
+    type Output = Vec3;
+
+    fn mul(self, rhs: Vec3) -> Vec3 {
+        Vec3::new(self.x.dot(rhs), self.y.dot(rhs), self.z.dot(rhs))
+    }
+}
+
+impl Mul<Vec2> for Mat3 {
+    type Output = Vec2;
+
+    /// Homogeneous transform of point, see [`transform_point2`](#method.transform_point2)
+    fn mul(self, p: Vec2) -> Vec2 {
+        self.transform_point2(p)
+    }
+}
+
+impl Mul for Mat3 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        let t = rhs.transpose();
+        Mat3::from_cols(self * t.x, self * t.y, self * t.z)
+    }
+}
+
+impl MulAssign for Mat3 {
+    /// Accumulates transform in place, `a *= b` is the same as `a = a * b`
+    fn mul_assign(&mut self, rhs: Mat3) {
+        *self = *self * rhs;
+    }
+}
+
+impl Neg for Mat3 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl Index<usize> for Mat3 {
+    type Output = Vec3;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            i => panic!("Index {} out of [0, 2] range", i)
+        }
+    }
+}
+
+impl IndexMut<usize> for Mat3 {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            i => panic!("Index {} out of [0, 2] range", i)
+        }
+    }
+}
+
+impl PartialEq for Mat3 {
+    fn eq(&self, other: &Self) -> bool {
+        self.x == other.x && self.y == other.y && self.z == other.z
+    }
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    fn check_eigen(m: Mat3) {
+        let (values, (a, b, c)) = m.symmetric_eigen();
+        let eps = 1e-10 * (1.0 + values.x.abs().max(values.z.abs()));
+        assert!(values.x <= values.y && values.y <= values.z);
+        for &(v, l) in [(a, values.x), (b, values.y), (c, values.z)].iter() {
+            assert!((v.len() - 1.0).abs() < 1e-12);
+            assert!((m * v - v * l).len() < eps);
+        }
+        assert!(a.dot(b).abs() < 1e-10 && a.dot(c).abs() < 1e-10 && b.dot(c).abs() < 1e-10);
+        assert!((a.cross(b) - c).len() < 1e-10);
+    }
+
+    #[test]
+    fn mat3_mul() {
+        let a = Mat3::new(Vec3::new(1, 2, 3), Vec3::new(4, 5, 6), Vec3::new(7, 8, 9));
+        let p = Mat3::new(Vec3::new(0, 1, 0), Vec3::new(0, 0, 1), Vec3::new(1, 0, 0));
+        assert_eq!(p * a, Mat3::new(a.y, a.z, a.x));
+        assert_eq!(a * Mat3::identity(), a);
+        assert_eq!(Mat3::identity() * a, a);
+    }
+
+    #[test]
+    fn mat3_mul_vec() {
+        let a = Mat3::new(Vec3::new(1, 2, 3), Vec3::new(4, 5, 6), Vec3::new(7, 8, 9));
+        assert_eq!(a * Vec3::new(1, 0, -1), Vec3::new(-2, -2, -2));
+    }
+
+    #[test]
+    fn mat3_add_sub() {
+        let a = Mat3::new(Vec3::new(1, 2, 3), Vec3::new(4, 5, 6), Vec3::new(7, 8, 9));
+        let mut z = a;
+        z += a;
+        assert_eq!(a + a, a * 2);
+        assert_eq!(z, a * 2);
+        z -= a;
+        assert_eq!(z, a);
+        assert_eq!(-a + a, Mat3::zero());
+    }
+
+    #[test]
+    fn mat3_symmetric_eigen() {
+        check_eigen(Mat3::new(Vec3::new(2, -1, 0), Vec3::new(-1, 2, -1), Vec3::new(0, -1, 2)));
+        check_eigen(Mat3::new(Vec3::new(1e6, 3.0, -2.0), Vec3::new(3.0, 0.5, 7.0), Vec3::new(-2.0, 7.0, -4.0)));
+        check_eigen(Mat3::new(Vec3::new(0, 0, 1), Vec3::new(0, 0, 0), Vec3::new(1, 0, 0)));
+    }
+
+    #[test]
+    fn mat3_symmetric_eigen_repeated() {
+        // eigenvalues 1, 1, 4
+        check_eigen(Mat3::new(Vec3::new(2, 1, 1), Vec3::new(1, 2, 1), Vec3::new(1, 1, 2)));
+        // eigenvalues 0, 3, 3
+        check_eigen(Mat3::new(Vec3::new(2, -1, -1), Vec3::new(-1, 2, -1), Vec3::new(-1, -1, 2)));
+        check_eigen(Mat3::new(Vec3::new(5, 0, 0), Vec3::new(0, 5, 0), Vec3::new(0, 0, 1)));
+    }
+
+    #[test]
+    fn mat3_symmetric_eigen_scalar() {
+        let (values, _) = (Mat3::identity() * 3).symmetric_eigen();
+        assert_eq!(values, Vec3::new(3, 3, 3));
+        let (values, _) = Mat3::zero().symmetric_eigen();
+        assert_eq!(values, Vec3::zero());
+        // NaN entry propagates instead of panicking
+        let m = Mat3::new(Vec3::new(1.0, f64::NAN, 0.0), Vec3::new(f64::NAN, 2.0, 0.0), Vec3::new(0, 0, 3));
+        let (values, _) = m.symmetric_eigen();
+        assert!(values.x.is_nan());
+    }
+
+    fn check_qr(a: Mat3) {
+        let (q, r) = a.qr();
+        let i = q.transpose() * q - Mat3::identity();
+        let d = q * r - a;
+        assert!(i.x.len() + i.y.len() + i.z.len() < 1e-12);
+        assert!(d.x.len() + d.y.len() + d.z.len() < 1e-12);
+        assert!(r.x.x >= 0.0 && r.y.y >= 0.0 && r.z.z >= 0.0);
+    }
+
+    #[test]
+    fn mat3_qr() {
+        check_qr(Mat3::new(Vec3::new(12, -51, 4), Vec3::new(6, 167, -68), Vec3::new(-4, 24, -41)) * 0.01);
+        check_qr(Mat3::new(Vec3::new(0, 1, 0), Vec3::new(1, 0, 0), Vec3::new(0, 0, 1)));
+    }
+
+    #[test]
+    fn mat3_qr_degenerate() {
+        check_qr(Mat3::new(Vec3::new(1, 2, 3), Vec3::new(2, 4, 6), Vec3::new(0, 0, 0)));
+        check_qr(Mat3::new(Vec3::new(1, 1, 2), Vec3::new(1, 1, 2), Vec3::new(1, 1, 2)));
+        check_qr(Mat3::zero());
+        check_qr(Mat3::new(Vec3::new(-1e-20, 0.0, 0.0), Vec3::new(0.0, -1e-20, 0.0), Vec3::new(0, 0, 1)));
+        check_qr(Mat3::new(Vec3::new(-1e-20, 2.0, 0.0), Vec3::new(0.0, 0.0, 0.0), Vec3::new(0, 1, 0)));
+    }
+
+    #[test]
+    fn mat3_qr_orthonormalize() {
+        let r = Mat3::new(Vec3::new(0.6, -0.8, 0.0), Vec3::new(0.8, 0.6, 0.0), Vec3::new(0, 0, 1));
+        let mut drifted = r;
+        drifted.x.x += 1e-6;
+        drifted.z.y -= 1e-6;
+        let (q, _) = drifted.qr();
+        let i = q.transpose() * q - Mat3::identity();
+        assert!(i.x.len() + i.y.len() + i.z.len() < 1e-12);
+        assert!((q.det() - 1.0).abs() < 1e-12);
+        let d = q - r;
+        assert!(d.x.len() + d.y.len() + d.z.len() < 1e-5);
+    }
+
+    fn check_svd(a: Mat3) {
+        let (u, s, vt) = a.svd();
+        let d = u * Mat3::diag(s) * vt - a;
+        let iu = u.transpose() * u - Mat3::identity();
+        let iv = vt * vt.transpose() - Mat3::identity();
+        assert!(d.x.len() + d.y.len() + d.z.len() < 1e-10);
+        assert!(iu.x.len() + iu.y.len() + iu.z.len() < 1e-12);
+        assert!(iv.x.len() + iv.y.len() + iv.z.len() < 1e-12);
+        assert!(s.x >= s.y && s.y >= s.z && s.z >= 0.0);
+    }
+
+    #[test]
+    fn mat3_svd() {
+        check_svd(Mat3::new(Vec3::new(1, 2, 3), Vec3::new(4, 5, 6), Vec3::new(7, 8, 10)));
+        check_svd(Mat3::new(Vec3::new(0, 1, 0), Vec3::new(1, 0, 0), Vec3::new(0, 0, 1)));
+        check_svd(Mat3::new(Vec3::new(1e-3, 2.0, 0.0), Vec3::new(0.0, 1e-3, 5.0), Vec3::new(3.0, 0.0, -1e-3)));
+    }
+
+    #[test]
+    fn mat3_svd_degenerate() {
+        check_svd(Mat3::new(Vec3::new(1, 2, 3), Vec3::new(4, 5, 6), Vec3::new(7, 8, 9)));
+        check_svd(Mat3::new(Vec3::new(1, 1, 1), Vec3::new(2, 2, 2), Vec3::new(3, 3, 3)));
+        check_svd(Mat3::zero());
+        check_svd(Mat3::new(Vec3::new(-1e-20, 0.0, 0.0), Vec3::new(0.0, -1e-20, 0.0), Vec3::new(0, 0, 1)));
+        check_svd(Mat3::new(Vec3::new(0.0, 0.0, -1e-20), Vec3::new(0, 2, 0), Vec3::new(0, 0, 0)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn mat3_index_out_of_range() {
+        let a = Mat3::identity();
+        let _ = a[3];
+    }
+
+    #[test]
+    fn mat3_affine2_composition() {
+        // scale around point (1, 1): translate to origin, scale, translate back
+        let c = Vec2::new(1, 1);
+        let m = Mat3::translation2(c) * Mat3::scale2(2, 3) * Mat3::translation2(-c);
+        assert_eq!(m * Vec3::new(1, 1, 1), Vec3::new(1, 1, 1));
+        assert_eq!(m * Vec3::new(2, 2, 1), Vec3::new(3, 4, 1));
+        let r = Mat3::rotation2(1.0) * Mat3::reflection2(Vec2::new(1, 0)) * Mat3::shear2(0.5, 0.0);
+        assert!((r.z - Vec3::new(0, 0, 1)).len() < 1e-12);
+        assert!((r.det() + 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn mat3_transform_point_and_vector2() {
+        let m = Mat3::affine2(Mat2::rotation(std::f64::consts::FRAC_PI_2), Vec2::new(5, 0));
+        let (a, b) = (Vec2::new(1, 0), Vec2::new(3, 2));
+        assert!((m * a - Vec2::new(5, 1)).len() < 1e-12);
+        // vector between points transforms as vector
+        assert!((m * b - m * a - m.transform_vector2(b - a)).len() < 1e-12);
+        // projective transform divides by w
+        let p = Mat3::new(Vec3::new(1, 0, 0), Vec3::new(0, 1, 0), Vec3::new(0, 1, 1));
+        assert_eq!(p * Vec2::new(4, 1), Vec2::new(2.0, 0.5));
+    }
+
+    #[test]
+    fn mat3_inverse() {
+        let m = Mat3::rotation(Vec3::new(1.0, 2.0, 3.0), 0.7) * Mat3::diag(Vec3::new(1e3, 1.0, 1e-3));
+        let inv = m.inverse().unwrap();
+        let v = Vec3::new(1.0, -2.0, 0.5);
+        assert!((inv * (m * v) - v).len() < 1e-9);
+        let flat = Mat3::new(Vec3::new(1.0, 0.0, 0.0), Vec3::new(0.0, 1.0, 0.0), Vec3::new(1.0, 1.0, 1e-14));
+        assert!(flat.inverse().is_none());
+        let mut acc = Mat3::identity();
+        acc *= Mat3::translation2(Vec2::new(1.0, 0.0));
+        acc *= Mat3::rotation2(::std::f64::consts::FRAC_PI_2);
+        assert!((acc * Vec2::new(1.0, 0.0) - Vec2::new(1.0, 1.0)).len() < 1e-12);
+    }
+
+    #[test]
+    fn mat3_decompose2() {
+        let m = Mat3::translation2(Vec2::new(-1.0, 4.0)) * Mat3::rotation2(-2.5) * Mat3::scale2(0.5, -2.0);
+        let (t, angle, s) = m.decompose2().unwrap();
+        let back = Mat3::translation2(t) * Mat3::rotation2(angle) * Mat3::scale2(s.x, s.y);
+        let p = Vec2::new(3.0, 7.0);
+        assert!((back * p - m * p).len() < 1e-12);
+        assert!(s.y < 0.0 && s.x > 0.0);
+        assert!(Mat3::scale2(0.0, 1.0).decompose2().is_none());
+        assert!((Mat3::rotation2(0.3) * Mat3::shear2(0.0, 0.1)).decompose2().is_none());
+    }
+
+    #[test]
+    fn mat3_spherical_jacobian() {
+        let (r, theta, phi) = (1.5, 2.2f64, 0.4);
+        let j = Mat3::spherical_jacobian(r, theta, phi);
+        // columns match finite differences of spherical map
+        let h = 1e-6;
+        let p = Vec3::from_spherical(r, theta, phi);
+        let dr = (Vec3::from_spherical(r + h, theta, phi) - p) / h;
+        let dphi = (Vec3::from_spherical(r, theta, phi + h) - p) / h;
+        assert!((j * Vec3::new(1, 0, 0) - dr).len() < 1e-5);
+        assert!((j * Vec3::new(0, 0, 1) - dphi).len() < 1e-5);
+        assert!((j.det() - r * r * theta.sin()).abs() < 1e-12);
+        let inv = Mat3::spherical_jacobian_inverse(r, theta, phi).unwrap();
+        let d = j * inv - Mat3::identity();
+        assert!(d.x.len() + d.y.len() + d.z.len() < 1e-12);
+    }
+}
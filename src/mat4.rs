@@ -0,0 +1,315 @@
+//! Matrices 4x4, mostly transformations in homogeneous coordinates.
+use std::ops::{Add, Sub, Mul, Neg};
+use std::ops::{AddAssign, SubAssign, MulAssign};
+use std::ops::{Index, IndexMut};
+use std::cmp::PartialEq;
+use vec4::Vec4;
+use vec3::Vec3;
+use mat3::Mat3;
+
+// relative threshold of degenerate matrices
+const DEGENERATE_EPS: f64 = 1e-12;
+// cosine between scaled axes treated as shear
+const SHEAR_EPS: f64 = 1e-9;
+
+/// 4x4 matrix stored by rows
+#[derive(Debug, Clone, Copy)]
+pub struct Mat4 {
+    /// first row of matrix
+    pub x: Vec4,
+    /// second row of matrix
+    pub y: Vec4,
+    /// third row of matrix
+    pub z: Vec4,
+    /// fourth row of matrix
+    pub w: Vec4,
+}
+
+impl Mat4 {
+    /// Constructs a new `Mat4` from rows.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Mat4, Vec4};
+    /// let m = Mat4::new(Vec4::new(1, 0, 0, 5), Vec4::new(0, 1, 0, 6),
+    ///                   Vec4::new(0, 0, 1, 7), Vec4::new(0, 0, 0, 1));
+    /// assert_eq!(m[1][3], 6.0);
+    /// ```
+    pub fn new(x: Vec4, y: Vec4, z: Vec4, w: Vec4) -> Mat4 {
+        Mat4 { x, y, z, w }
+    }
+    /// Create a zero `Mat4`
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Mat4, Vec4};
+    /// assert_eq!(Mat4::zero() * Vec4::new(1, 2, 3, 4), Vec4::zero());
+    /// ```
+    pub fn zero() -> Mat4 {
+        Mat4::new(Vec4::zero(), Vec4::zero(), Vec4::zero(), Vec4::zero())
+    }
+    /// Create an identity `Mat4`
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Mat4, Vec4};
+    /// let v = Vec4::new(1, 2, 3, 4);
+    /// assert_eq!(Mat4::identity() * v, v);
+    /// ```
+    pub fn identity() -> Mat4 {
+        Mat4::new(Vec4::new(1, 0, 0, 0), Vec4::new(0, 1, 0, 0),
+                  Vec4::new(0, 0, 1, 0), Vec4::new(0, 0, 0, 1))
+    }
+    /// Transposed matrix
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Mat4, Vec4};
+    /// let m = Mat4::new(Vec4::new(1, 0, 0, 5), Vec4::new(0, 1, 0, 6),
+    ///                   Vec4::new(0, 0, 1, 7), Vec4::new(0, 0, 0, 1));
+    /// assert_eq!(m.transpose().w, Vec4::new(5, 6, 7, 1));
+    /// ```
+    pub fn transpose(self) -> Mat4 {
+        let mut t = Mat4::zero();
+        for i in 0..4 {
+            for j in 0..4 {
+                t[i][j] = self[j][i];
+            }
+        }
+        t
+    }
+    /// Inverse matrix
+    ///
+    /// Computed by Gauss-Jordan elimination with partial pivoting. Returns
+    /// `None` for degenerate matrices, i.e. when $|\det M|$ is negligible
+    /// compared to the product of row lengths, so a transform with zero scale
+    /// along some direction has no inverse.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Mat4, Vec4};
+    /// let m = Mat4::new(Vec4::new(2, 0, 0, 5), Vec4::new(0, 4, 0, 6),
+    ///                   Vec4::new(0, 0, 1, 7), Vec4::new(0, 0, 0, 1));
+    /// let p = Vec4::new(1, 2, 3, 1);
+    /// assert_eq!(m.inverse().unwrap() * (m * p), p);
+    /// assert!(Mat4::zero().inverse().is_none());
+    /// ```
+    pub fn inverse(self) -> Option<Mat4> {
+        let scale = self.x.len() * self.y.len() * self.z.len() * self.w.len();
+        let mut a = self;
+        let mut inv = Mat4::identity();
+        let mut det = 1.0;
+        for i in 0..4 {
+            let pivot = (i..4).fold(i, |p, k| if a[k][i].abs() > a[p][i].abs() { k } else { p });
+            if pivot != i {
+                let (row, inv_row) = (a[i], inv[i]);
+                a[i] = a[pivot];
+                a[pivot] = row;
+                inv[i] = inv[pivot];
+                inv[pivot] = inv_row;
+                det = -det;
+            }
+            let p = a[i][i];
+            if p == 0.0 {
+                return None;
+            }
+            det *= p;
+            a[i] *= 1.0 / p;
+            inv[i] *= 1.0 / p;
+            let (row, inv_row) = (a[i], inv[i]);
+            for k in (0..4).filter(|&k| k != i) {
+                let f = a[k][i];
+                a[k] -= row * f;
+                inv[k] -= inv_row * f;
+            }
+        }
+        if det.abs() <= DEGENERATE_EPS * scale {
+            return None;
+        }
+        Some(inv)
+    }
+    /// Homogeneous 3D affine transform
+    ///
+    /// Point $\vec{p}$ is represented as $(p_x, p_y, p_z, 1)$, so transformed
+    /// point is $A \vec{p} + \vec{t}$.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Mat3, Mat4, Vec3, Vec4};
+    /// let m = Mat4::affine(Mat3::diag(Vec3::new(2, 2, 2)), Vec3::new(1, 0, 0));
+    /// assert_eq!(m * Vec4::new(1, 1, 1, 1), Vec4::new(3, 2, 2, 1));
+    /// ```
+    pub fn affine(linear: Mat3, translation: Vec3) -> Mat4 {
+        Mat4::new(Vec4::new(linear.x.x, linear.x.y, linear.x.z, translation.x),
+                  Vec4::new(linear.y.x, linear.y.y, linear.y.z, translation.y),
+                  Vec4::new(linear.z.x, linear.z.y, linear.z.z, translation.z),
+                  Vec4::new(0, 0, 0, 1))
+    }
+    /// Decompose homogeneous 3D transform into translation, rotation and scale
+    ///
+    /// Returns `(translation, rotation, scale)` such that matrix equals
+    /// `affine(rotation * Mat3::diag(scale), translation)`, where `rotation`
+    /// is orthonormal with positive determinant. Reflection is encoded as
+    /// negative `scale.z`. Returns `None` for projective, degenerate or
+    /// sheared transforms, which can't be represented by these components.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Mat3, Mat4, Vec3};
+    /// let r = Mat3::rotation(Vec3::new(1.0, 1.0, 0.0), 0.3);
+    /// let m = Mat4::affine(r * Mat3::diag(Vec3::new(1.0, 2.0, 3.0)), Vec3::new(4.0, 5.0, 6.0));
+    /// let (t, rotation, s) = m.decompose_trs().unwrap();
+    /// assert!((t - Vec3::new(4.0, 5.0, 6.0)).len() < 1e-12);
+    /// assert!((rotation.x - r.x).len() < 1e-12);
+    /// assert!((s - Vec3::new(1.0, 2.0, 3.0)).len() < 1e-12);
+    /// ```
+    pub fn decompose_trs(self) -> Option<(Vec3, Mat3, Vec3)> {
+        if self.w != Vec4::new(0, 0, 0, 1) {
+            return None;
+        }
+        let a = Vec3::new(self.x.x, self.y.x, self.z.x);
+        let b = Vec3::new(self.x.y, self.y.y, self.z.y);
+        let c = Vec3::new(self.x.z, self.y.z, self.z.z);
+        let (sx, sy, sz) = (a.len(), b.len(), c.len());
+        if sx == 0.0 || sy == 0.0 || sz == 0.0 {
+            return None;
+        }
+        let sheared = |u: Vec3, v: Vec3, su: f64, sv: f64| u.dot(v).abs() > SHEAR_EPS * su * sv;
+        if sheared(a, b, sx, sy) || sheared(b, c, sy, sz) || sheared(a, c, sx, sz) {
+            return None;
+        }
+        let sz = if a.cross(b).dot(c) < 0.0 { -sz } else { sz };
+        let rotation = Mat3::from_cols(a / sx, b / sy, c / sz);
+        Some((Vec3::new(self.x.w, self.y.w, self.z.w), rotation, Vec3::new(sx, sy, sz)))
+    }
+
+    // need for op_default & op_assign
+    fn size(&self) -> usize { 4 }
+}
+
+op_default!(add, Add, +=, Mat4);
+op_default!(sub, Sub, -=, Mat4);
+op_default!(f64, mul, Mul, *=, Mat4);
+op_scalar_left!(Mat4);
+op_assign!(add_assign, AddAssign, +=, Mat4);
+op_assign!(sub_assign, SubAssign, -=, Mat4);
+op_assign!(f64, mul_assign, MulAssign, *=, Mat4);
+
+impl Mul<Vec4> for Mat4 {
+    type Output = Vec4;
+
+    fn mul(self, rhs: Vec4) -> Vec4 {
+        Vec4::new(self.x.dot(rhs), self.y.dot(rhs), self.z.dot(rhs), self.w.dot(rhs))
+    }
+}
+
+impl Mul for Mat4 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        let t = rhs.transpose();
+        Mat4::new(t * self.x, t * self.y, t * self.z, t * self.w)
+    }
+}
+
+impl MulAssign for Mat4 {
+    /// Accumulates transform in place, `a *= b` is the same as `a = a * b`
+    fn mul_assign(&mut self, rhs: Mat4) {
+        *self = *self * rhs;
+    }
+}
+
+impl Neg for Mat4 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y, -self.z, -self.w)
+    }
+}
+
+impl Index<usize> for Mat4 {
+    type Output = Vec4;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            3 => &self.w,
+            i => panic!("Index {} out of [0, 3] range", i)
+        }
+    }
+}
+
+impl IndexMut<usize> for Mat4 {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            3 => &mut self.w,
+            i => panic!("Index {} out of [0, 3] range", i)
+        }
+    }
+}
+
+impl PartialEq for Mat4 {
+    fn eq(&self, other: &Self) -> bool {
+        self.x == other.x && self.y == other.y && self.z == other.z && self.w == other.w
+    }
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    #[test]
+    fn mat4_mul() {
+        let t = Mat4::new(Vec4::new(1, 0, 0, 5), Vec4::new(0, 1, 0, 6),
+                          Vec4::new(0, 0, 1, 7), Vec4::new(0, 0, 0, 1));
+        let s = Mat4::new(Vec4::new(2, 0, 0, 0), Vec4::new(0, 2, 0, 0),
+                          Vec4::new(0, 0, 2, 0), Vec4::new(0, 0, 0, 1));
+        let p = Vec4::new(1, 1, 1, 1);
+        assert_eq!((t * s) * p, t * (s * p));
+        assert_eq!((t * s) * p, Vec4::new(7, 8, 9, 1));
+        assert_eq!((s * t) * p, Vec4::new(12, 14, 16, 1));
+        assert_eq!(t * Mat4::identity(), t);
+    }
+
+    #[test]
+    fn mat4_transpose() {
+        let t = Mat4::new(Vec4::new(1, 2, 3, 4), Vec4::new(5, 6, 7, 8),
+                          Vec4::new(9, 10, 11, 12), Vec4::new(13, 14, 15, 16));
+        assert_eq!(t.transpose().transpose(), t);
+        assert_eq!(t.transpose().x, Vec4::new(1, 5, 9, 13));
+    }
+
+    #[test]
+    fn mat4_inverse() {
+        let m = Mat4::new(Vec4::new(0, 2, 0, 1), Vec4::new(3, 0, 0, 2),
+                          Vec4::new(0, 0, 4, 3), Vec4::new(0, 0, 0, 1));
+        let inv = m.inverse().unwrap();
+        let e = m * inv - Mat4::identity();
+        assert!(e.x.len() + e.y.len() + e.z.len() + e.w.len() < 1e-12);
+        let flat = Mat4::new(Vec4::new(1, 0, 0, 0), Vec4::new(0, 1, 0, 0),
+                             Vec4::new(0, 0, 0, 0), Vec4::new(0, 0, 0, 1));
+        assert!(flat.inverse().is_none());
+        let mut acc = Mat4::identity();
+        acc *= m;
+        assert_eq!(acc, m);
+    }
+
+    #[test]
+    fn mat4_decompose_trs() {
+        let r = Mat3::rotation(Vec3::new(-1.0, 2.0, 0.5), 2.0);
+        let m = Mat4::affine(r * Mat3::diag(Vec3::new(2.0, 0.5, -1.0)), Vec3::new(1.0, 2.0, 3.0));
+        let (t, rotation, s) = m.decompose_trs().unwrap();
+        assert!((rotation.det() - 1.0).abs() < 1e-12);
+        let p = Vec4::new(0.3, -1.0, 2.0, 1.0);
+        let e = Mat4::affine(rotation * Mat3::diag(s), t) * p - m * p;
+        assert!(e.len() < 1e-12);
+        let shear = Mat3::new(Vec3::new(1.0, 0.2, 0.0), Vec3::new(0.0, 1.0, 0.0), Vec3::new(0.0, 0.0, 1.0));
+        assert!(Mat4::affine(shear, t).decompose_trs().is_none());
+        assert!(Mat4::affine(Mat3::zero(), t).decompose_trs().is_none());
+    }
+}
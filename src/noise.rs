@@ -0,0 +1,219 @@
+//! Gradient noise with analytic derivatives.
+//!
+//! Noise is Perlin's improved gradient noise: pseudo-random gradients at
+//! integer lattice points blended with quintic fade curve, so both value and
+//! gradient are continuous. Value vanishes at lattice points and lies
+//! roughly in $[-1, 1]$.
+use std::f64::consts::FRAC_1_SQRT_2;
+use vec2::Vec2;
+use vec3::Vec3;
+
+// gradients of planar noise
+const GRADIENTS2: [(f64, f64); 8] = [
+    (1.0, 0.0), (-1.0, 0.0), (0.0, 1.0), (0.0, -1.0),
+    (FRAC_1_SQRT_2, FRAC_1_SQRT_2), (-FRAC_1_SQRT_2, FRAC_1_SQRT_2),
+    (FRAC_1_SQRT_2, -FRAC_1_SQRT_2), (-FRAC_1_SQRT_2, -FRAC_1_SQRT_2),
+];
+
+// gradients of spatial noise, directions to middles of cube edges
+const GRADIENTS3: [(f64, f64, f64); 12] = [
+    (1.0, 1.0, 0.0), (-1.0, 1.0, 0.0), (1.0, -1.0, 0.0), (-1.0, -1.0, 0.0),
+    (1.0, 0.0, 1.0), (-1.0, 0.0, 1.0), (1.0, 0.0, -1.0), (-1.0, 0.0, -1.0),
+    (0.0, 1.0, 1.0), (0.0, -1.0, 1.0), (0.0, 1.0, -1.0), (0.0, -1.0, -1.0),
+];
+
+// pseudo-random hash of lattice point
+fn hash(seed: u64, coords: &[i64]) -> u64 {
+    // splitmix64 finalizer applied per coordinate
+    coords.iter().fold(seed ^ 0x9e37_79b9_7f4a_7c15, |h, &c| {
+        let mut z = h.wrapping_add(c as u64).wrapping_add(0x9e37_79b9_7f4a_7c15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    })
+}
+
+// fade curve 6t^5 - 15t^4 + 10t^3 and its derivative
+fn fade(t: f64) -> (f64, f64) {
+    (t * t * t * (t * (t * 6.0 - 15.0) + 10.0), 30.0 * t * t * (t - 1.0) * (t - 1.0))
+}
+
+/// Perlin noise on a plane with its gradient
+///
+/// Same as [`perlin2_seeded`](fn.perlin2_seeded.html) with zero seed.
+///
+/// # Example
+/// ```
+/// # use linal::Vec2;
+/// # use linal::noise::perlin2;
+/// let (value, _) = perlin2(Vec2::new(3, -7));
+/// assert_eq!(value, 0.0);
+/// let (value, _) = perlin2(Vec2::new(3.4, -7.2));
+/// assert!(value.abs() <= 1.0);
+/// ```
+pub fn perlin2(point: Vec2) -> (f64, Vec2) {
+    perlin2_seeded(point, 0)
+}
+
+/// Perlin noise on a plane with its gradient for given seed
+///
+/// # Example
+/// ```
+/// # use linal::Vec2;
+/// # use linal::noise::perlin2_seeded;
+/// let p = Vec2::new(0.3, 0.6);
+/// let (value, gradient) = perlin2_seeded(p, 42);
+/// let h = 1e-6;
+/// let (dx, _) = perlin2_seeded(p + Vec2::new(h, 0.0), 42);
+/// assert!(((dx - value) / h - gradient.x).abs() < 1e-4);
+/// ```
+pub fn perlin2_seeded(point: Vec2, seed: u64) -> (f64, Vec2) {
+    let (x0, y0) = (point.x.floor(), point.y.floor());
+    let (fx, fy) = (point.x - x0, point.y - y0);
+    let (i, j) = (x0 as i64, y0 as i64);
+    let (u, du) = fade(fx);
+    let (v, dv) = fade(fy);
+    // gradients and their dot products with offsets at corners
+    let corner = |di: i64, dj: i64| {
+        // lattice wraps around for coordinates saturated at bounds of i64
+        let lattice = [i.wrapping_add(di), j.wrapping_add(dj)];
+        let (gx, gy) = GRADIENTS2[(hash(seed, &lattice) % 8) as usize];
+        let g = Vec2::new(gx, gy);
+        (g, g.dot(Vec2::new(fx - di as f64, fy - dj as f64)))
+    };
+    let (g00, n00) = corner(0, 0);
+    let (g10, n10) = corner(1, 0);
+    let (g01, n01) = corner(0, 1);
+    let (g11, n11) = corner(1, 1);
+    let (k1, k2, k3) = (n10 - n00, n01 - n00, n00 - n10 - n01 + n11);
+    let value = n00 + u * k1 + v * k2 + u * v * k3;
+    let gradient = g00 + (g10 - g00) * u + (g01 - g00) * v + (g00 - g10 - g01 + g11) * (u * v)
+        + Vec2::new(du * (k1 + v * k3), dv * (k2 + u * k3));
+    (value, gradient)
+}
+
+/// Perlin noise in space with its gradient
+///
+/// Same as [`perlin3_seeded`](fn.perlin3_seeded.html) with zero seed.
+///
+/// # Example
+/// ```
+/// # use linal::Vec3;
+/// # use linal::noise::perlin3;
+/// let (value, _) = perlin3(Vec3::new(1, 2, 3));
+/// assert_eq!(value, 0.0);
+/// ```
+pub fn perlin3(point: Vec3) -> (f64, Vec3) {
+    perlin3_seeded(point, 0)
+}
+
+/// Perlin noise in space with its gradient for given seed
+///
+/// # Example
+/// ```
+/// # use linal::Vec3;
+/// # use linal::noise::perlin3_seeded;
+/// let p = Vec3::new(0.3, 0.6, -1.2);
+/// let (value, gradient) = perlin3_seeded(p, 7);
+/// let h = 1e-6;
+/// let (dz, _) = perlin3_seeded(p + Vec3::new(0.0, 0.0, h), 7);
+/// assert!(((dz - value) / h - gradient.z).abs() < 1e-4);
+/// ```
+pub fn perlin3_seeded(point: Vec3, seed: u64) -> (f64, Vec3) {
+    let (x0, y0, z0) = (point.x.floor(), point.y.floor(), point.z.floor());
+    let f = point - Vec3::new(x0, y0, z0);
+    let (i, j, k) = (x0 as i64, y0 as i64, z0 as i64);
+    let (u, du) = fade(f.x);
+    let (v, dv) = fade(f.y);
+    let (w, dw) = fade(f.z);
+    let corner = |di: i64, dj: i64, dk: i64| {
+        let lattice = [i.wrapping_add(di), j.wrapping_add(dj), k.wrapping_add(dk)];
+        let (gx, gy, gz) = GRADIENTS3[(hash(seed, &lattice) % 12) as usize];
+        let g = Vec3::new(gx, gy, gz);
+        (g, g.dot(f - Vec3::new(di as f64, dj as f64, dk as f64)))
+    };
+    let (g000, n000) = corner(0, 0, 0);
+    let (g100, n100) = corner(1, 0, 0);
+    let (g010, n010) = corner(0, 1, 0);
+    let (g110, n110) = corner(1, 1, 0);
+    let (g001, n001) = corner(0, 0, 1);
+    let (g101, n101) = corner(1, 0, 1);
+    let (g011, n011) = corner(0, 1, 1);
+    let (g111, n111) = corner(1, 1, 1);
+    // coefficients of trilinear blend
+    let k1 = n100 - n000;
+    let k2 = n010 - n000;
+    let k3 = n001 - n000;
+    let k4 = n000 - n100 - n010 + n110;
+    let k5 = n000 - n010 - n001 + n011;
+    let k6 = n000 - n100 - n001 + n101;
+    let k7 = -n000 + n100 + n010 - n110 + n001 - n101 - n011 + n111;
+    let value = n000 + u * k1 + v * k2 + w * k3 + u * v * k4 + v * w * k5 + w * u * k6
+        + u * v * w * k7;
+    let blend = g000
+        + (g100 - g000) * u
+        + (g010 - g000) * v
+        + (g001 - g000) * w
+        + (g000 - g100 - g010 + g110) * (u * v)
+        + (g000 - g010 - g001 + g011) * (v * w)
+        + (g000 - g100 - g001 + g101) * (w * u)
+        + (-g000 + g100 + g010 - g110 + g001 - g101 - g011 + g111) * (u * v * w);
+    let gradient = blend + Vec3::new(
+        du * (k1 + v * k4 + w * k6 + v * w * k7),
+        dv * (k2 + w * k5 + u * k4 + u * w * k7),
+        dw * (k3 + u * k6 + v * k5 + u * v * k7),
+    );
+    (value, gradient)
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    #[test]
+    fn perlin2_gradient_matches_differences() {
+        let h = 1e-6;
+        for s in 0..20 {
+            let p = Vec2::new(s as f64 * 0.37 - 3.0, s as f64 * 0.71 + 0.2);
+            let (value, gradient) = perlin2_seeded(p, s);
+            assert!(value.abs() <= 1.0);
+            let (vx, _) = perlin2_seeded(p + Vec2::new(h, 0.0), s);
+            let (vy, _) = perlin2_seeded(p + Vec2::new(0.0, h), s);
+            let numeric = Vec2::new(vx - value, vy - value) / h;
+            assert!((numeric - gradient).len() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn perlin3_gradient_matches_differences() {
+        let h = 1e-6;
+        for s in 0..20 {
+            let p = Vec3::new(s as f64 * 0.37 - 3.0, s as f64 * 0.71 + 0.2, -(s as f64) * 0.53);
+            let (value, gradient) = perlin3_seeded(p, s);
+            let (vx, _) = perlin3_seeded(p + Vec3::new(h, 0.0, 0.0), s);
+            let (vy, _) = perlin3_seeded(p + Vec3::new(0.0, h, 0.0), s);
+            let (vz, _) = perlin3_seeded(p + Vec3::new(0.0, 0.0, h), s);
+            let numeric = Vec3::new(vx - value, vy - value, vz - value) / h;
+            assert!((numeric - gradient).len() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn perlin_continuous_across_cells() {
+        let e = 1e-9;
+        let (a, ga) = perlin2(Vec2::new(2.0 - e, 0.5));
+        let (b, gb) = perlin2(Vec2::new(2.0 + e, 0.5));
+        assert!((a - b).abs() < 1e-8 && (ga - gb).len() < 1e-6);
+        assert_ne!(perlin2_seeded(Vec2::new(0.5, 0.5), 1), perlin2_seeded(Vec2::new(0.5, 0.5), 2));
+    }
+
+    #[test]
+    fn perlin_far_from_origin() {
+        for &x in &[1e19, -1e19, 9.3e18] {
+            let (value, gradient) = perlin2(Vec2::new(x, x));
+            assert!(value.is_finite() && gradient.x.is_finite());
+            let (value, _) = perlin3(Vec3::new(x, -x, x));
+            assert!(value.is_finite());
+        }
+    }
+}
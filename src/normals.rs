@@ -0,0 +1,171 @@
+//! Normals of point clouds.
+//!
+//! Normal of every point is estimated by fitting plane to its neighborhood:
+//! it is eigenvector of covariance of neighbors with the smallest
+//! eigenvalue. Such normals have arbitrary sign, [`orient`] makes them
+//! consistent across the surface.
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+use spatial::KdTree;
+use vec3::Vec3;
+
+/// Neighbors of point used for fitting plane, including point itself
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Neighborhood {
+    /// given number of nearest points
+    Nearest(usize),
+    /// points within given distance
+    Radius(f64),
+}
+
+// edge of neighborhood graph, ordered by weight
+struct Candidate {
+    weight: f64,
+    from: usize,
+    to: usize,
+}
+
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Candidate) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Candidate {}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Candidate) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Candidate) -> Ordering {
+        self.weight.total_cmp(&other.weight)
+            .then(self.to.cmp(&other.to))
+            .then(self.from.cmp(&other.from))
+    }
+}
+
+/// Unit normals of points estimated by plane fitting
+///
+/// Signs of normals are arbitrary. Points with less than 3 points in
+/// neighborhood get zero normal.
+///
+/// # Example
+/// ```
+/// # use linal::Vec3;
+/// # use linal::normals::{estimate, Neighborhood};
+/// let points: Vec<Vec3> = (0..25).map(|i| Vec3::new(i % 5, i / 5, 2)).collect();
+/// let normals = estimate(&points, Neighborhood::Nearest(6));
+/// assert!(normals.iter().all(|n| (n.z.abs() - 1.0).abs() < 1e-12));
+/// ```
+pub fn estimate(points: &[Vec3], neighborhood: Neighborhood) -> Vec<Vec3> {
+    let tree = KdTree::new(points);
+    let mut neighbors = Vec::new();
+    points.iter().map(|&p| {
+        neighbors.clear();
+        match neighborhood {
+            Neighborhood::Nearest(k) => {
+                neighbors.extend(tree.k_nearest(p, k).into_iter().map(|(i, _)| points[i]))
+            }
+            Neighborhood::Radius(r) => {
+                neighbors.extend(tree.within_radius(p, r).into_iter().map(|i| points[i]))
+            }
+        }
+        if neighbors.len() < 3 {
+            return Vec3::zero();
+        }
+        (Vec3::principal_axes(&neighbors).1).2
+    }).collect()
+}
+
+/// Flips normals to consistent orientation over the surface
+///
+/// Orientation is propagated along minimum spanning tree of graph of `k`
+/// nearest neighbors, weighted by $1 - |n_i \cdot n_j|$, so that it passes
+/// between nearly parallel normals first (Hoppe et al.). In every connected
+/// part of graph the point with the highest $z$ gets normal pointing up.
+///
+/// # Panics
+/// Panics if `points` and `normals` have different lengths.
+///
+/// # Example
+/// ```
+/// # use linal::Vec3;
+/// # use linal::normals::{estimate, orient, Neighborhood};
+/// let points: Vec<Vec3> = (0..100)
+///     .map(|i| Vec3::from_spherical(1.0, 0.3 + 0.025 * i as f64, 0.6 * i as f64))
+///     .collect();
+/// let mut normals = estimate(&points, Neighborhood::Nearest(8));
+/// orient(&points, &mut normals, 8);
+/// assert!(points.iter().zip(&normals).all(|(p, n)| p.dot(*n) > 0.9));
+/// ```
+pub fn orient(points: &[Vec3], normals: &mut [Vec3], k: usize) {
+    assert_eq!(points.len(), normals.len(), "every point requires a normal");
+    let tree = KdTree::new(points);
+    // neighborhood graph made symmetric
+    let mut graph = vec![Vec::new(); points.len()];
+    for (i, &p) in points.iter().enumerate() {
+        for (j, _) in tree.k_nearest(p, k + 1) {
+            if i != j {
+                graph[i].push(j);
+                graph[j].push(i);
+            }
+        }
+    }
+    let mut seeds: Vec<usize> = (0..points.len()).collect();
+    seeds.sort_by(|&a, &b| points[b].z.total_cmp(&points[a].z).then(a.cmp(&b)));
+    let mut visited = vec![false; points.len()];
+    let mut heap = BinaryHeap::new();
+    for seed in seeds {
+        if visited[seed] {
+            continue;
+        }
+        if normals[seed].z < 0.0 {
+            normals[seed] = -normals[seed];
+        }
+        heap.push(Reverse(Candidate { weight: 0.0, from: seed, to: seed }));
+        while let Some(Reverse(Candidate { from, to, .. })) = heap.pop() {
+            if visited[to] {
+                continue;
+            }
+            visited[to] = true;
+            if normals[from].dot(normals[to]) < 0.0 {
+                normals[to] = -normals[to];
+            }
+            for &next in &graph[to] {
+                if !visited[next] {
+                    let weight = 1.0 - normals[to].dot(normals[next]).abs();
+                    heap.push(Reverse(Candidate { weight, from: to, to: next }));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    #[test]
+    fn normals_of_cylinder() {
+        // grid on cylinder around z axis, true normals are radial
+        let points: Vec<Vec3> = (0..600)
+            .map(|i| {
+                let angle = 0.1 * (i % 60) as f64;
+                Vec3::new(angle.cos(), angle.sin(), 0.1 * (i / 60) as f64)
+            })
+            .collect();
+        let mut normals = estimate(&points, Neighborhood::Radius(0.25));
+        for (p, n) in points.iter().zip(&normals) {
+            let radial = Vec3::new(p.x, p.y, 0.0).ort();
+            assert!((n.dot(radial).abs() - 1.0).abs() < 1e-2);
+        }
+        orient(&points, &mut normals, 10);
+        let outward = points.iter().zip(&normals).filter(|&(p, n)| p.dot(*n) > 0.0).count();
+        assert!(outward == 0 || outward == points.len());
+        let sparse = estimate(&points[..2], Neighborhood::Nearest(5));
+        assert_eq!(sparse, vec![Vec3::zero(); 2]);
+    }
+}
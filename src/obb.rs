@@ -0,0 +1,97 @@
+//! Oriented rectangles on a plane.
+use vec2::Vec2;
+
+/// Oriented bounding rectangle
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Obb {
+    /// center of rectangle
+    pub center: Vec2,
+    /// unit direction of the first rectangle side
+    pub axis: Vec2,
+    /// half-lengths of rectangle along `axis` and its perpendicular
+    pub half_extents: Vec2,
+}
+
+impl Obb {
+    /// Constructs a new `Obb`
+    ///
+    /// `axis` is normalized, second axis is rotated counterclockwise from it.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Obb, Vec2};
+    /// let b = Obb::new(Vec2::new(1, 1), Vec2::new(0, 2), Vec2::new(2, 1));
+    /// assert_eq!(b.axes(), (Vec2::new(0, 1), Vec2::new(-1, 0)));
+    /// ```
+    pub fn new(center: Vec2, axis: Vec2, half_extents: Vec2) -> Obb {
+        Obb { center, axis: axis.ort(), half_extents }
+    }
+    /// Pair of unit rectangle axes, second is rotated counterclockwise from first
+    pub fn axes(self) -> (Vec2, Vec2) {
+        (self.axis, Vec2::new(-self.axis.y, self.axis.x))
+    }
+    /// Area of rectangle
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Obb, Vec2};
+    /// let b = Obb::new(Vec2::zero(), Vec2::new(1, 1), Vec2::new(2, 1));
+    /// assert_eq!(b.area(), 8.0);
+    /// ```
+    pub fn area(self) -> f64 {
+        4.0 * self.half_extents.x * self.half_extents.y
+    }
+    /// Perimeter of rectangle
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Obb, Vec2};
+    /// let b = Obb::new(Vec2::zero(), Vec2::new(1, 1), Vec2::new(2, 1));
+    /// assert_eq!(b.perimeter(), 12.0);
+    /// ```
+    pub fn perimeter(self) -> f64 {
+        4.0 * (self.half_extents.x + self.half_extents.y)
+    }
+    /// Checks if point lies inside rectangle or on its boundary
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Obb, Vec2};
+    /// let b = Obb::new(Vec2::zero(), Vec2::new(1, 1), Vec2::new(2.0, 0.5));
+    /// assert!(b.contains(Vec2::new(1, 1)));
+    /// assert!(!b.contains(Vec2::new(1, -1)));
+    /// ```
+    pub fn contains(self, point: Vec2) -> bool {
+        let (u, v) = self.axes();
+        let d = point - self.center;
+        d.dot(u).abs() <= self.half_extents.x && d.dot(v).abs() <= self.half_extents.y
+    }
+    /// Corners of rectangle in counterclockwise order
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Obb, Vec2};
+    /// let b = Obb::new(Vec2::new(1, 1), Vec2::new(1, 0), Vec2::new(1, 1));
+    /// assert_eq!(b.corners(), [Vec2::new(0, 0), Vec2::new(2, 0), Vec2::new(2, 2), Vec2::new(0, 2)]);
+    /// ```
+    pub fn corners(self) -> [Vec2; 4] {
+        let (u, v) = self.axes();
+        let (u, v) = (u * self.half_extents.x, v * self.half_extents.y);
+        [self.center - u - v, self.center + u - v, self.center + u + v, self.center - u + v]
+    }
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    #[test]
+    fn obb_corners() {
+        let b = Obb::new(Vec2::new(1, 2), Vec2::new(3, 4), Vec2::new(2, 1));
+        let corners = b.corners();
+        assert!(((corners[0] - corners[1]).len() - 4.0).abs() < 1e-12);
+        assert!(((corners[1] - corners[2]).len() - 2.0).abs() < 1e-12);
+        assert!((corners[1] - corners[0]).area(corners[2] - corners[1]) > 0.0);
+        assert!(corners.iter().all(|&c| Obb { half_extents: b.half_extents * 1.000001, ..b }.contains(c)));
+    }
+}
@@ -0,0 +1,169 @@
+//! Steppers for equations of motion $\ddot x = a(t, x, \dot x)$.
+//!
+//! Each stepper advances position and velocity over one time step `dt`,
+//! calling acceleration closure as `accel(t, x, v)`.
+use std::ops::{Add, Mul};
+
+/// State of integrated system, e.g. `Vec2` or `Vec3`
+///
+/// Implemented for all types closed under addition and scaling.
+pub trait State: Copy + Add<Output = Self> + Mul<f64, Output = Self> {}
+
+impl<T: Copy + Add<Output = T> + Mul<f64, Output = T>> State for T {}
+
+/// Explicit Euler step
+///
+/// First order method, both position and velocity use values from start of
+/// step. Energy of oscillating systems grows with it.
+///
+/// # Example
+/// ```
+/// # use linal::Vec2;
+/// # use linal::ode::euler;
+/// let gravity = |_t: f64, _x: Vec2, _v: Vec2| Vec2::new(0, -10);
+/// let (x, v) = euler(Vec2::zero(), Vec2::new(1, 0), 0.0, 0.5, gravity);
+/// assert_eq!(x, Vec2::new(0.5, 0.0));
+/// assert_eq!(v, Vec2::new(1, -5));
+/// ```
+pub fn euler<T, F>(x: T, v: T, t: f64, dt: f64, mut accel: F) -> (T, T)
+where
+    T: State,
+    F: FnMut(f64, T, T) -> T,
+{
+    let a = accel(t, x, v);
+    (x + v * dt, v + a * dt)
+}
+
+/// Semi-implicit (symplectic) Euler step
+///
+/// Velocity is updated first and then used to move position. It's still
+/// first order, but keeps energy of oscillating systems bounded.
+///
+/// # Example
+/// ```
+/// # use linal::Vec2;
+/// # use linal::ode::semi_implicit_euler;
+/// let gravity = |_t: f64, _x: Vec2, _v: Vec2| Vec2::new(0, -10);
+/// let (x, v) = semi_implicit_euler(Vec2::zero(), Vec2::new(1, 0), 0.0, 0.5, gravity);
+/// assert_eq!(x, Vec2::new(0.5, -2.5));
+/// assert_eq!(v, Vec2::new(1, -5));
+/// ```
+pub fn semi_implicit_euler<T, F>(x: T, v: T, t: f64, dt: f64, mut accel: F) -> (T, T)
+where
+    T: State,
+    F: FnMut(f64, T, T) -> T,
+{
+    let v = v + accel(t, x, v) * dt;
+    (x + v * dt, v)
+}
+
+/// Velocity Verlet step
+///
+/// Second order symplectic method. It's exact for velocity independent
+/// forces only, for others acceleration at end of step is evaluated with
+/// velocity predicted by Euler step.
+///
+/// # Example
+/// ```
+/// # use linal::Vec2;
+/// # use linal::ode::velocity_verlet;
+/// // constant acceleration is integrated exactly
+/// let gravity = |_t: f64, _x: Vec2, _v: Vec2| Vec2::new(0, -10);
+/// let (x, v) = velocity_verlet(Vec2::zero(), Vec2::new(1, 0), 0.0, 0.5, gravity);
+/// assert_eq!(x, Vec2::new(0.5, -1.25));
+/// assert_eq!(v, Vec2::new(1, -5));
+/// ```
+pub fn velocity_verlet<T, F>(x: T, v: T, t: f64, dt: f64, mut accel: F) -> (T, T)
+where
+    T: State,
+    F: FnMut(f64, T, T) -> T,
+{
+    let a0 = accel(t, x, v);
+    let x1 = x + v * dt + a0 * (0.5 * dt * dt);
+    let a1 = accel(t + dt, x1, v + a0 * dt);
+    (x1, v + (a0 + a1) * (0.5 * dt))
+}
+
+/// Classic fourth order Runge-Kutta step
+///
+/// Most accurate of steppers, but needs four evaluations of acceleration
+/// and isn't symplectic.
+///
+/// # Example
+/// ```
+/// # use linal::Vec3;
+/// # use linal::ode::rk4;
+/// // drag force: v' = -v, so v(t) = v0 * exp(-t)
+/// let drag = |_t: f64, _x: Vec3, v: Vec3| -v;
+/// let (_, v) = rk4(Vec3::zero(), Vec3::new(1, 0, 0), 0.0, 0.1, drag);
+/// assert!((v.x - (-0.1f64).exp()).abs() < 1e-7);
+/// ```
+pub fn rk4<T, F>(x: T, v: T, t: f64, dt: f64, mut accel: F) -> (T, T)
+where
+    T: State,
+    F: FnMut(f64, T, T) -> T,
+{
+    let h = 0.5 * dt;
+    let (k1x, k1v) = (v, accel(t, x, v));
+    let (k2x, k2v) = {
+        let v2 = v + k1v * h;
+        (v2, accel(t + h, x + k1x * h, v2))
+    };
+    let (k3x, k3v) = {
+        let v3 = v + k2v * h;
+        (v3, accel(t + h, x + k2x * h, v3))
+    };
+    let (k4x, k4v) = {
+        let v4 = v + k3v * dt;
+        (v4, accel(t + dt, x + k3x * dt, v4))
+    };
+    let sixth = dt / 6.0;
+    (
+        x + (k1x + k2x * 2.0 + k3x * 2.0 + k4x) * sixth,
+        v + (k1v + k2v * 2.0 + k3v * 2.0 + k4v) * sixth,
+    )
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+    use vec2::Vec2;
+
+    // circular orbit around origin with unit radius and period 2pi
+    fn orbit<S>(step: S, steps: usize) -> (Vec2, Vec2)
+    where
+        S: Fn(Vec2, Vec2, f64, f64, &dyn Fn(f64, Vec2, Vec2) -> Vec2) -> (Vec2, Vec2),
+    {
+        let gravity = |_t: f64, x: Vec2, _v: Vec2| -x * x.dot(x).powf(-1.5);
+        let dt = 2.0 * std::f64::consts::PI / steps as f64;
+        let (mut x, mut v) = (Vec2::new(1, 0), Vec2::new(0, 1));
+        for i in 0..steps {
+            let r = step(x, v, i as f64 * dt, dt, &gravity);
+            x = r.0;
+            v = r.1;
+        }
+        (x, v)
+    }
+
+    #[test]
+    fn ode_orbit_accuracy() {
+        let start = Vec2::new(1, 0);
+        let e = (orbit(|x, v, t, dt, a| euler(x, v, t, dt, a), 1000).0 - start).len();
+        let s = (orbit(|x, v, t, dt, a| semi_implicit_euler(x, v, t, dt, a), 1000).0 - start).len();
+        let vv = (orbit(|x, v, t, dt, a| velocity_verlet(x, v, t, dt, a), 1000).0 - start).len();
+        let r = (orbit(|x, v, t, dt, a| rk4(x, v, t, dt, a), 1000).0 - start).len();
+        assert!(e > s && s > vv && vv > r);
+        assert!(vv < 1e-4);
+        assert!(r < 1e-8);
+    }
+
+    #[test]
+    fn ode_semi_implicit_energy_bounded() {
+        let (x, v) = orbit(|x, v, t, dt, a| semi_implicit_euler(x, v, t, dt, a), 100);
+        let energy = 0.5 * v.dot(v) - 1.0 / x.len();
+        assert!((energy + 0.5).abs() < 0.05);
+        let (x, v) = orbit(|x, v, t, dt, a| euler(x, v, t, dt, a), 100);
+        let energy = 0.5 * v.dot(v) - 1.0 / x.len();
+        assert!(energy + 0.5 > 0.1);
+    }
+}
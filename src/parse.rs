@@ -0,0 +1,111 @@
+//! Parsing vectors from strings.
+use std::error::Error;
+use std::fmt;
+use std::num::ParseFloatError;
+
+/// Error returned when parsing vector from string fails
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseVecError {
+    /// string has fewer components than vector, `index` of the first missing one
+    MissingComponent {
+        /// index of missing component
+        index: usize,
+    },
+    /// string has more tokens than vector components
+    ExtraTokens {
+        /// number of vector components
+        expected: usize,
+        /// number of tokens in string
+        found: usize,
+    },
+    /// component is not a valid number
+    InvalidFloat {
+        /// index of invalid component
+        index: usize,
+        /// underlying error
+        error: ParseFloatError,
+    },
+}
+
+impl fmt::Display for ParseVecError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ParseVecError::MissingComponent { index } =>
+                write!(f, "missing vector component {}", index),
+            ParseVecError::ExtraTokens { expected, found } =>
+                write!(f, "expected {} vector components, found {}", expected, found),
+            ParseVecError::InvalidFloat { index, ref error } =>
+                write!(f, "invalid vector component {}: {}", index, error),
+        }
+    }
+}
+
+impl Error for ParseVecError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            ParseVecError::InvalidFloat { ref error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+// strips one pair of matching brackets around string
+fn strip_brackets(s: &str) -> &str {
+    let s = s.trim();
+    for &(open, close) in &[('(', ')'), ('[', ']')] {
+        if s.starts_with(open) && s.ends_with(close) && s.len() >= 2 {
+            return &s[1..s.len() - 1];
+        }
+    }
+    s
+}
+
+// Parses exactly `n` numbers separated by commas or whitespace, optionally
+// wrapped in parentheses or square brackets.
+pub(crate) fn parse_components(s: &str, n: usize) -> Result<Vec<f64>, ParseVecError> {
+    let s = strip_brackets(s);
+    let words: Vec<&str> = if s.contains(',') {
+        s.split(',').map(str::trim).collect()
+    } else {
+        s.split_whitespace().collect()
+    };
+    if words.len() < n {
+        return Err(ParseVecError::MissingComponent { index: words.len() });
+    }
+    if words.len() > n {
+        return Err(ParseVecError::ExtraTokens { expected: n, found: words.len() });
+    }
+    words.iter()
+        .enumerate()
+        .map(|(index, w)| w.parse().map_err(|error| ParseVecError::InvalidFloat { index, error }))
+        .collect()
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    #[test]
+    fn parse_components_errors() {
+        assert_eq!(parse_components("1 2", 2), Ok(vec![1.0, 2.0]));
+        assert_eq!(parse_components("1", 2), Err(ParseVecError::MissingComponent { index: 1 }));
+        assert_eq!(parse_components("", 3), Err(ParseVecError::MissingComponent { index: 0 }));
+        assert_eq!(parse_components("1 2 3", 2),
+                   Err(ParseVecError::ExtraTokens { expected: 2, found: 3 }));
+        match parse_components("1 x", 2) {
+            Err(ParseVecError::InvalidFloat { index: 1, .. }) => (),
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn parse_components_formats() {
+        for s in &["1 2", "1,2", " 1 , 2 ", "(1, 2)", "[1 2]", "[1,2]", "1e0 2E0", "(+1.0,\t0.2e1)"] {
+            assert_eq!(parse_components(s, 2), Ok(vec![1.0, 2.0]), "{:?}", s);
+        }
+        assert_eq!(parse_components("(1, 2, 3)", 2),
+                   Err(ParseVecError::ExtraTokens { expected: 2, found: 3 }));
+        assert!(parse_components("(1 2]", 2).is_err());
+        assert!(parse_components("1,,2", 2).is_err());
+    }
+}
@@ -0,0 +1,234 @@
+//! Planes in 3-dimensional euclidian space.
+use vec3::Vec3;
+use vec4::Vec4;
+use mat3::Mat3;
+use mat4::Mat4;
+use ray3::Ray3;
+
+/// Plane $\vec{n} \cdot \vec{r} = d$ with unit normal $\vec{n}$
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Plane {
+    /// unit normal of the plane
+    pub normal: Vec3,
+    /// signed distance from origin to the plane along normal
+    pub dist: f64,
+}
+
+impl Plane {
+    /// Constructs a new `Plane` from equation $\vec{n} \cdot \vec{r} = d$.
+    ///
+    /// Equation is normalized, so `normal` can have any non-zero length. Zero
+    /// `normal` gives plane with NaN components.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Plane, Vec3};
+    /// let plane = Plane::new(Vec3::new(0, 0, 2), 4.0);
+    /// assert_eq!(plane.normal, Vec3::new(0, 0, 1));
+    /// assert_eq!(plane.dist, 2.0);
+    /// ```
+    pub fn new<I: Into<f64>>(normal: Vec3, dist: I) -> Plane {
+        let len = normal.len();
+        Plane {
+            normal: normal / len,
+            dist: dist.into() / len,
+        }
+    }
+    /// Constructs a new `Plane` through `point` with given `normal`.
+    ///
+    /// Same as [`new`](#method.new), `normal` must be non-zero.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Plane, Vec3};
+    /// let plane = Plane::from_point_normal(Vec3::new(1, 2, 3), Vec3::new(0, 0, -1));
+    /// assert_eq!(plane.normal, Vec3::new(0, 0, -1));
+    /// assert_eq!(plane.dist, -3.0);
+    /// ```
+    pub fn from_point_normal(point: Vec3, normal: Vec3) -> Plane {
+        Plane::new(normal, normal.dot(point))
+    }
+    /// Constructs a new `Plane` through three points.
+    ///
+    /// Normal is directed so that points `a`, `b`, `c` go counterclockwise
+    /// when viewed from its end. Returns `None` for collinear points.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Plane, Vec3};
+    /// let a = Vec3::new(1, 0, 2);
+    /// let b = Vec3::new(0, 1, 2);
+    /// let c = Vec3::new(0, 0, 2);
+    /// let plane = Plane::from_points(a, b, c).unwrap();
+    /// assert_eq!(plane.normal, Vec3::new(0, 0, 1));
+    /// assert_eq!(plane.dist, 2.0);
+    /// ```
+    pub fn from_points(a: Vec3, b: Vec3, c: Vec3) -> Option<Plane> {
+        let normal = (b - a).cross(c - a);
+        if normal.dot(normal) == 0.0 {
+            return None;
+        }
+        Some(Plane::from_point_normal(a, normal))
+    }
+    /// Signed distance from plane to point, positive on the side of normal
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Plane, Vec3};
+    /// let plane = Plane::new(Vec3::new(0, 0, 1), 2);
+    /// assert_eq!(plane.signed_distance(Vec3::new(5, 5, 7)), 5.0);
+    /// assert_eq!(plane.signed_distance(Vec3::new(5, 5, 0)), -2.0);
+    /// ```
+    pub fn signed_distance(self, point: Vec3) -> f64 {
+        self.normal.dot(point) - self.dist
+    }
+    /// Orthogonal projection of point onto plane
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Plane, Vec3};
+    /// let plane = Plane::new(Vec3::new(0, 0, 1), 2);
+    /// assert_eq!(plane.project(Vec3::new(5, 5, 7)), Vec3::new(5, 5, 2));
+    /// ```
+    pub fn project(self, point: Vec3) -> Vec3 {
+        point - self.normal * self.signed_distance(point)
+    }
+    /// Intersection of ray with plane
+    ///
+    /// Returns distance `t` from ray origin to the intersection point
+    /// `ray.at(t)`, or `None` if ray is parallel to plane or points away from
+    /// it.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Plane, Ray3, Vec3};
+    /// let plane = Plane::new(Vec3::new(0, 0, 1), 2);
+    /// let ray = Ray3::new(Vec3::new(1, 1, 5), Vec3::new(0, 0, -1));
+    /// let t = plane.intersect(ray).unwrap();
+    /// assert_eq!(t, 3.0);
+    /// assert_eq!(ray.at(t), Vec3::new(1, 1, 2));
+    /// ```
+    pub fn intersect(self, ray: Ray3) -> Option<f64> {
+        let speed = self.normal.dot(ray.dir);
+        if speed == 0.0 {
+            return None;
+        }
+        let t = -self.signed_distance(ray.origin) / speed;
+        if t < 0.0 {
+            None
+        } else {
+            Some(t)
+        }
+    }
+    /// Homogeneous matrix of reflection across plane
+    ///
+    /// Points $(x, y, z, 1)$ are reflected across plane, vectors
+    /// $(x, y, z, 0)$ by [`Mat3::householder`](struct.Mat3.html#method.householder)
+    /// of its normal.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Plane, Vec3, Vec4};
+    /// let plane = Plane::new(Vec3::new(1, 0, 0), 1);
+    /// let m = plane.reflection();
+    /// assert_eq!(m * Vec4::new(3, 1, 1, 1), Vec4::new(-1, 1, 1, 1));
+    /// assert_eq!(m * Vec4::new(3, 1, 1, 0), Vec4::new(-3, 1, 1, 0));
+    /// ```
+    pub fn reflection(self) -> Mat4 {
+        let r = Mat3::householder(self.normal);
+        let t = self.normal * (2.0 * self.dist);
+        Mat4::new(Vec4::new(r.x.x, r.x.y, r.x.z, t.x), Vec4::new(r.y.x, r.y.y, r.y.z, t.y),
+                  Vec4::new(r.z.x, r.z.y, r.z.z, t.z), Vec4::new(0, 0, 0, 1))
+    }
+}
+
+impl Vec3 {
+    /// Mirror image of point across plane
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Plane, Vec3};
+    /// let plane = Plane::new(Vec3::new(0, 0, 1), 2);
+    /// assert_eq!(Vec3::new(1, 2, 5).reflect_across(plane), Vec3::new(1, 2, -1));
+    /// ```
+    pub fn reflect_across(self, plane: Plane) -> Vec3 {
+        self - plane.normal * (2.0 * plane.signed_distance(self))
+    }
+    /// Orthogonal projection of point onto plane
+    ///
+    /// Same as [`Plane::project`](struct.Plane.html#method.project).
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Plane, Vec3};
+    /// let plane = Plane::from_point_normal(Vec3::new(0, 0, 1), Vec3::new(0, 1, 1));
+    /// let p = Vec3::new(3, 2, 3).project_onto(plane);
+    /// assert!((p - Vec3::new(3, 0, 1)).len() < 1e-12);
+    /// ```
+    pub fn project_onto(self, plane: Plane) -> Vec3 {
+        plane.project(self)
+    }
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    #[test]
+    fn plane_from_points() {
+        let a = Vec3::new(1, 0, 0);
+        let b = Vec3::new(0, 1, 0);
+        let c = Vec3::new(0, 0, 1);
+        let plane = Plane::from_points(a, b, c).unwrap();
+        assert!((plane.normal - Vec3::new(1, 1, 1).ort()).len() < 1e-12);
+        for &p in [a, b, c].iter() {
+            assert!(plane.signed_distance(p).abs() < 1e-12);
+        }
+        assert!(Plane::from_points(a, b, a * 2 - b).is_none());
+    }
+
+    #[test]
+    fn plane_project() {
+        let plane = Plane::from_point_normal(Vec3::new(1, 1, 1), Vec3::new(1, 1, 0));
+        let p = Vec3::new(3, 2, 7);
+        let q = plane.project(p);
+        assert!(plane.signed_distance(q).abs() < 1e-12);
+        assert!((p - q).cross(plane.normal).len() < 1e-12);
+    }
+
+    #[test]
+    fn plane_intersect() {
+        let plane = Plane::new(Vec3::new(1, 0, 0), 1);
+        let ray = Ray3::new(Vec3::new(-1, 0, 0), Vec3::new(1, 1, 0));
+        let t = plane.intersect(ray).unwrap();
+        assert!((ray.at(t) - Vec3::new(1, 2, 0)).len() < 1e-12);
+        let parallel = Ray3::new(Vec3::new(-1, 0, 0), Vec3::new(0, 1, 0));
+        assert!(plane.intersect(parallel).is_none());
+        let away = Ray3::new(Vec3::new(-1, 0, 0), Vec3::new(-1, 0, 0));
+        assert!(plane.intersect(away).is_none());
+        let from_behind = Ray3::new(Vec3::new(5, 0, 0), Vec3::new(-1, 0, 0));
+        assert_eq!(plane.intersect(from_behind), Some(4.0));
+    }
+
+    #[test]
+    fn plane_project_onto_matches_vector_projection() {
+        let plane = Plane::new(Vec3::new(1, -2, 2), 6);
+        let origin = plane.normal * plane.dist;
+        let p = Vec3::new(4, 1, -3);
+        let q = p.project_onto(plane);
+        assert!(plane.signed_distance(q).abs() < 1e-12);
+        let expected = origin + (p - origin).project_onto_plane(Vec3::new(1, -2, 2));
+        assert!((q - expected).len() < 1e-12);
+    }
+
+    #[test]
+    fn plane_reflection_matches_point() {
+        let plane = Plane::from_point_normal(Vec3::new(1, 2, 3), Vec3::new(1, -1, 2));
+        let p = Vec3::new(-4.0, 0.5, 2.0);
+        let q = p.reflect_across(plane);
+        let h = plane.reflection() * p.to_homogeneous();
+        assert!((Vec3::from_homogeneous(h).unwrap() - q).len() < 1e-12);
+        assert!((plane.signed_distance(p) + plane.signed_distance(q)).abs() < 1e-12);
+        assert!((plane.project(p) - plane.project(q)).len() < 1e-12);
+    }
+}
@@ -0,0 +1,334 @@
+//! Simple polygons on a plane.
+use vec2::Vec2;
+use containment::point_in_polygon;
+
+/// Polygon on a plane, defined by closed chain of vertices
+///
+/// Last vertex is implicitly connected with the first one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polygon {
+    /// vertices of polygon
+    pub vertices: Vec<Vec2>,
+}
+
+impl Polygon {
+    /// Constructs a new `Polygon` from vertices.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Polygon, Vec2};
+    /// let p = Polygon::new(vec![Vec2::new(0, 0), Vec2::new(1, 0), Vec2::new(0, 1)]);
+    /// assert_eq!(p.vertices.len(), 3);
+    /// ```
+    pub fn new(vertices: Vec<Vec2>) -> Polygon {
+        Polygon { vertices }
+    }
+    /// Iterator over polygon edges as pairs of vertices
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Polygon, Vec2};
+    /// let p = Polygon::new(vec![Vec2::new(0, 0), Vec2::new(1, 0), Vec2::new(0, 1)]);
+    /// let last = p.edges().last().unwrap();
+    /// assert_eq!(last, (Vec2::new(0, 1), Vec2::new(0, 0)));
+    /// ```
+    pub fn edges<'a>(&'a self) -> impl Iterator<Item = (Vec2, Vec2)> + 'a {
+        let n = self.vertices.len();
+        (0..n).map(move |i| (self.vertices[i], self.vertices[(i + 1) % n]))
+    }
+    /// Signed area, positive for counterclockwise order of vertices
+    ///
+    /// Calculated with shoelace formula.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Polygon, Vec2};
+    /// let p = Polygon::new(vec![Vec2::new(0, 0), Vec2::new(2, 0),
+    ///                           Vec2::new(2, 2), Vec2::new(0, 2)]);
+    /// assert_eq!(p.signed_area(), 4.0);
+    /// let q = Polygon::new(p.vertices.iter().rev().cloned().collect());
+    /// assert_eq!(q.signed_area(), -4.0);
+    /// ```
+    pub fn signed_area(&self) -> f64 {
+        0.5 * self.edges().map(|(a, b)| a.area(b)).sum::<f64>()
+    }
+    /// Area of polygon
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Polygon, Vec2};
+    /// let p = Polygon::new(vec![Vec2::new(0, 0), Vec2::new(0, 3), Vec2::new(4, 0)]);
+    /// assert_eq!(p.area(), 6.0);
+    /// ```
+    pub fn area(&self) -> f64 {
+        self.signed_area().abs()
+    }
+    /// Length of polygon boundary
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Polygon, Vec2};
+    /// let p = Polygon::new(vec![Vec2::new(0, 0), Vec2::new(4, 0), Vec2::new(0, 3)]);
+    /// assert_eq!(p.perimeter(), 12.0);
+    /// ```
+    pub fn perimeter(&self) -> f64 {
+        self.edges().map(|(a, b)| (b - a).len()).sum()
+    }
+    /// Centroid (center of mass) of polygon area
+    ///
+    /// Returns `None` for polygons with zero area.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Polygon, Vec2};
+    /// // "L" shape made of three unit squares
+    /// let p = Polygon::new(vec![Vec2::new(0, 0), Vec2::new(2, 0), Vec2::new(2, 1),
+    ///                           Vec2::new(1, 1), Vec2::new(1, 2), Vec2::new(0, 2)]);
+    /// let c = p.centroid().unwrap();
+    /// assert!((c - Vec2::new(5.0 / 6.0, 5.0 / 6.0)).len() < 1e-12);
+    /// ```
+    pub fn centroid(&self) -> Option<Vec2> {
+        let area = self.signed_area();
+        if area == 0.0 {
+            return None;
+        }
+        let sum = self.edges().fold(Vec2::zero(), |acc, (a, b)| acc + (a + b) * a.area(b));
+        Some(sum / (6.0 * area))
+    }
+    /// Second moments of area about origin
+    ///
+    /// Returns $(I_{xx}, I_{yy}, I_{xy})$, where
+    /// $I_{xx} = \int y^2 dA$, $I_{yy} = \int x^2 dA$ and $I_{xy} = \int xy\,dA$.
+    /// Result doesn't depend on vertices orientation.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Polygon, Vec2};
+    /// let p = Polygon::new(vec![Vec2::new(0, 0), Vec2::new(1, 0),
+    ///                           Vec2::new(1, 1), Vec2::new(0, 1)]);
+    /// let (ixx, iyy, ixy) = p.second_moments();
+    /// assert!((ixx - 1.0 / 3.0).abs() < 1e-12);
+    /// assert!((iyy - 1.0 / 3.0).abs() < 1e-12);
+    /// assert!((ixy - 0.25).abs() < 1e-12);
+    /// ```
+    pub fn second_moments(&self) -> (f64, f64, f64) {
+        let (mut ixx, mut iyy, mut ixy) = (0.0, 0.0, 0.0);
+        for (a, b) in self.edges() {
+            let cross = a.area(b);
+            ixx += cross * (a.y * a.y + a.y * b.y + b.y * b.y);
+            iyy += cross * (a.x * a.x + a.x * b.x + b.x * b.x);
+            ixy += cross * (a.x * b.y + 2.0 * a.x * a.y + 2.0 * b.x * b.y + b.x * a.y);
+        }
+        let sign = self.signed_area().signum();
+        (sign * ixx / 12.0, sign * iyy / 12.0, sign * ixy / 24.0)
+    }
+    /// Second moments of area about centroid
+    ///
+    /// Same as [`second_moments`](#method.second_moments), but shifted to
+    /// centroid by parallel axis theorem. Returns `None` for polygons with zero
+    /// area.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Polygon, Vec2};
+    /// let p = Polygon::new(vec![Vec2::new(1, 1), Vec2::new(4, 1),
+    ///                           Vec2::new(4, 3), Vec2::new(1, 3)]);
+    /// let (ixx, iyy, ixy) = p.centroidal_second_moments().unwrap();
+    /// // b * h^3 / 12 for rectangle
+    /// assert!((ixx - 3.0 * 8.0 / 12.0).abs() < 1e-12);
+    /// assert!((iyy - 27.0 * 2.0 / 12.0).abs() < 1e-12);
+    /// assert!(ixy.abs() < 1e-12);
+    /// ```
+    pub fn centroidal_second_moments(&self) -> Option<(f64, f64, f64)> {
+        let c = self.centroid()?;
+        let area = self.area();
+        let (ixx, iyy, ixy) = self.second_moments();
+        Some((ixx - area * c.y * c.y, iyy - area * c.x * c.x, ixy - area * c.x * c.y))
+    }
+    /// Mass, center of mass and moment of inertia of uniform plate
+    ///
+    /// Returns `(mass, centroid, inertia)`, where moment of inertia is taken
+    /// about axis through centroid perpendicular to plane, i.e.
+    /// $\rho (I_{xx} + I_{yy})$ of
+    /// [`centroidal_second_moments`](#method.centroidal_second_moments).
+    /// Returns `None` for polygons with zero area.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Polygon, Vec2};
+    /// let p = Polygon::new(vec![Vec2::new(0, 0), Vec2::new(4, 0),
+    ///                           Vec2::new(4, 2), Vec2::new(0, 2)]);
+    /// let (mass, center, inertia) = p.mass_properties(0.5).unwrap();
+    /// assert_eq!(mass, 4.0);
+    /// assert_eq!(center, Vec2::new(2, 1));
+    /// // m * (w^2 + h^2) / 12 for rectangle
+    /// assert!((inertia - 4.0 * 20.0 / 12.0).abs() < 1e-12);
+    /// ```
+    pub fn mass_properties<I: Into<f64>>(&self, density: I) -> Option<(f64, Vec2, f64)> {
+        let density = density.into();
+        let c = self.centroid()?;
+        let (ixx, iyy, _) = self.centroidal_second_moments()?;
+        Some((density * self.area(), c, density * (ixx + iyy)))
+    }
+    /// Checks if point lies inside polygon or on its boundary
+    ///
+    /// See [`point_in_polygon`](../containment/fn.point_in_polygon.html).
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Polygon, Vec2};
+    /// let p = Polygon::new(vec![Vec2::new(0, 0), Vec2::new(4, 0), Vec2::new(0, 3)]);
+    /// assert!(p.contains(Vec2::new(1, 1)));
+    /// assert!(!p.contains(Vec2::new(3, 3)));
+    /// ```
+    pub fn contains(&self, point: Vec2) -> bool {
+        point_in_polygon(point, &self.vertices)
+    }
+    /// Clips polygon by convex window
+    ///
+    /// Implements Sutherland–Hodgman algorithm: polygon is clipped by every
+    /// edge of `window` in turn. Window may have any orientation. Clipped
+    /// polygon of non-convex subject may contain degenerate edges along window
+    /// boundary; empty polygon is returned if there is no overlap.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Polygon, Vec2};
+    /// let subject = Polygon::new(vec![Vec2::new(-1, -1), Vec2::new(1, -1),
+    ///                                 Vec2::new(1, 1), Vec2::new(-1, 1)]);
+    /// let window = Polygon::new(vec![Vec2::new(0, 0), Vec2::new(2, 0),
+    ///                                Vec2::new(2, 2), Vec2::new(0, 2)]);
+    /// let clipped = subject.clip_to_convex(&window);
+    /// assert_eq!(clipped.area(), 1.0);
+    /// assert_eq!(clipped.centroid(), Some(Vec2::new(0.5, 0.5)));
+    /// ```
+    pub fn clip_to_convex(&self, window: &Polygon) -> Polygon {
+        let orientation = window.signed_area().signum();
+        let mut output = self.vertices.clone();
+        for (a, b) in window.edges() {
+            if output.is_empty() {
+                break;
+            }
+            let edge = b - a;
+            // positive for points inside of the window
+            let side = |p: Vec2| orientation * edge.area(p - a);
+            let input = Polygon::new(output);
+            output = Vec::with_capacity(input.vertices.len() + 1);
+            for (p, q) in input.edges() {
+                let (sp, sq) = (side(p), side(q));
+                if sp >= 0.0 {
+                    output.push(p);
+                }
+                if (sp >= 0.0) != (sq >= 0.0) {
+                    output.push(p + (q - p) * (sp / (sp - sq)));
+                }
+            }
+        }
+        Polygon::new(output)
+    }
+    /// Polygon smoothed by Chaikin's corner cutting
+    ///
+    /// Every iteration replaces each edge by points at its quarter and three
+    /// quarters, so number of vertices doubles. Result converges to closed
+    /// quadratic B-spline and stays inside of convex hull of polygon.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Polygon, Vec2};
+    /// let square = Polygon::new(vec![Vec2::new(0, 0), Vec2::new(4, 0), Vec2::new(4, 4), Vec2::new(0, 4)]);
+    /// let s = square.smooth_chaikin(1);
+    /// assert_eq!(s.vertices.len(), 8);
+    /// assert_eq!(s.vertices[0], Vec2::new(1, 0));
+    /// assert_eq!(s.area(), 14.0);
+    /// ```
+    pub fn smooth_chaikin(&self, iterations: usize) -> Polygon {
+        let mut polygon = self.clone();
+        for _ in 0..iterations {
+            if polygon.vertices.len() < 3 {
+                break;
+            }
+            let smooth = polygon.edges()
+                .flat_map(|(p, q)| vec![p * 0.75 + q * 0.25, p * 0.25 + q * 0.75])
+                .collect();
+            polygon = Polygon::new(smooth);
+        }
+        polygon
+    }
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    #[test]
+    fn polygon_clip() {
+        let triangle = Polygon::new(vec![Vec2::new(0, 0), Vec2::new(4, 0), Vec2::new(0, 4)]);
+        let window = Polygon::new(vec![Vec2::new(1, 3), Vec2::new(3, 3),
+                                       Vec2::new(3, 1), Vec2::new(1, 1)]);
+        // half of window is cut by hypotenuse, window is clockwise
+        let clipped = triangle.clip_to_convex(&window);
+        assert!((clipped.area() - 2.0).abs() < 1e-12);
+        // subject inside window is left unchanged
+        let big = Polygon::new(vec![Vec2::new(-9, -9), Vec2::new(9, -9), Vec2::new(0, 9)]);
+        assert_eq!(triangle.clip_to_convex(&big), triangle);
+        // no overlap
+        let far = Polygon::new(vec![Vec2::new(5, 5), Vec2::new(6, 5), Vec2::new(6, 6)]);
+        assert!(triangle.clip_to_convex(&far).vertices.is_empty());
+    }
+
+    #[test]
+    fn polygon_degenerate() {
+        let empty = Polygon::new(vec![]);
+        assert_eq!(empty.signed_area(), 0.0);
+        assert_eq!(empty.perimeter(), 0.0);
+        assert!(empty.centroid().is_none());
+        let flat = Polygon::new(vec![Vec2::new(0, 0), Vec2::new(1, 1), Vec2::new(2, 2)]);
+        assert!(flat.centroid().is_none());
+        assert!(flat.centroidal_second_moments().is_none());
+    }
+
+    #[test]
+    fn polygon_moments_orientation() {
+        let ccw = Polygon::new(vec![Vec2::new(0, 0), Vec2::new(3, 0), Vec2::new(1, 2)]);
+        let cw = Polygon::new(ccw.vertices.iter().rev().cloned().collect());
+        assert_eq!(ccw.centroid(), cw.centroid());
+        let (a, b) = (ccw.second_moments(), cw.second_moments());
+        assert!((a.0 - b.0).abs() < 1e-12 && (a.1 - b.1).abs() < 1e-12 && (a.2 - b.2).abs() < 1e-12);
+        assert!(a.0 > 0.0 && a.1 > 0.0);
+    }
+
+    #[test]
+    fn polygon_moments_translation_invariant() {
+        let square = |o: Vec2| Polygon::new(vec![o, o + Vec2::new(2, 0),
+                                                o + Vec2::new(2, 2), o + Vec2::new(0, 2)]);
+        let a = square(Vec2::zero()).centroidal_second_moments().unwrap();
+        let b = square(Vec2::new(-7, 3)).centroidal_second_moments().unwrap();
+        assert!((a.0 - 16.0 / 12.0).abs() < 1e-12);
+        assert!((a.0 - b.0).abs() < 1e-9 && (a.1 - b.1).abs() < 1e-9 && (a.2 - b.2).abs() < 1e-9);
+    }
+
+    #[test]
+    fn polygon_mass_properties() {
+        // right triangle with legs 3 and 6, clockwise
+        let p = Polygon::new(vec![Vec2::new(1, 1), Vec2::new(1, 7), Vec2::new(4, 1)]);
+        let (mass, c, inertia) = p.mass_properties(2).unwrap();
+        assert!((mass - 18.0).abs() < 1e-12);
+        assert!((c - Vec2::new(2, 3)).len() < 1e-12);
+        // m * (a^2 + b^2) / 18 for right triangle
+        assert!((inertia - 18.0 * 45.0 / 18.0).abs() < 1e-9);
+        let flat = Polygon::new(vec![Vec2::new(0, 0), Vec2::new(1, 1), Vec2::new(2, 2)]);
+        assert!(flat.mass_properties(1).is_none());
+    }
+
+    #[test]
+    fn polygon_smooth_chaikin() {
+        let triangle = Polygon::new(vec![Vec2::new(0, 0), Vec2::new(6, 0), Vec2::new(0, 6)]);
+        let s = triangle.smooth_chaikin(5);
+        assert_eq!(s.vertices.len(), 3 << 5);
+        assert!(s.vertices.iter().all(|&v| triangle.contains(v)));
+        // centroid of vertices is preserved by corner cutting
+        let mean = |p: &Polygon| p.vertices.iter().fold(Vec2::zero(), |a, &v| a + v) / p.vertices.len() as f64;
+        assert!((mean(&s) - mean(&triangle)).len() < 1e-12);
+        assert!(s.area() < triangle.area() && s.signed_area() > 0.0);
+    }
+}
@@ -0,0 +1,442 @@
+//! Open polygonal chains on a plane.
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+use segment2::Segment2;
+use vec2::Vec2;
+
+// vertex of Visvalingam simplification, ordered by triangle area
+struct Candidate {
+    area: f64,
+    index: usize,
+}
+
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Candidate) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Candidate {}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Candidate) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Candidate) -> Ordering {
+        self.area.total_cmp(&other.area).then(self.index.cmp(&other.index))
+    }
+}
+
+/// Polyline on a plane, defined by chain of vertices
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polyline {
+    /// vertices of polyline
+    pub vertices: Vec<Vec2>,
+}
+
+impl Polyline {
+    /// Constructs a new `Polyline` from vertices.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Polyline, Vec2};
+    /// let p = Polyline::new(vec![Vec2::new(0, 0), Vec2::new(1, 0), Vec2::new(1, 1)]);
+    /// assert_eq!(p.vertices.len(), 3);
+    /// ```
+    pub fn new(vertices: Vec<Vec2>) -> Polyline {
+        Polyline { vertices }
+    }
+    /// Total length of polyline
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Polyline, Vec2};
+    /// let p = Polyline::new(vec![Vec2::new(0, 0), Vec2::new(3, 4), Vec2::new(3, 0)]);
+    /// assert_eq!(p.length(), 9.0);
+    /// ```
+    pub fn length(&self) -> f64 {
+        self.vertices.windows(2).map(|w| (w[1] - w[0]).len()).sum()
+    }
+    /// Point at given distance along polyline from its start
+    ///
+    /// Distance is clamped to polyline length. Returns `None` for empty
+    /// polyline.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Polyline, Vec2};
+    /// let p = Polyline::new(vec![Vec2::new(0, 0), Vec2::new(2, 0), Vec2::new(2, 2)]);
+    /// assert_eq!(p.point_at_distance(1), Some(Vec2::new(1, 0)));
+    /// assert_eq!(p.point_at_distance(3), Some(Vec2::new(2, 1)));
+    /// assert_eq!(p.point_at_distance(10), Some(Vec2::new(2, 2)));
+    /// ```
+    pub fn point_at_distance<I: Into<f64>>(&self, distance: I) -> Option<Vec2> {
+        let mut rest = distance.into().max(0.0);
+        for w in self.vertices.windows(2) {
+            let len = (w[1] - w[0]).len();
+            if rest <= len && len > 0.0 {
+                return Some(w[0] + (w[1] - w[0]) * (rest / len));
+            }
+            rest -= len;
+        }
+        self.vertices.last().cloned()
+    }
+    /// Resamples polyline into `n` points evenly spaced along its length
+    ///
+    /// First and last points of result coincide with polyline ends.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Polyline, Vec2};
+    /// let p = Polyline::new(vec![Vec2::new(0, 0), Vec2::new(3, 0), Vec2::new(3, 1)]);
+    /// let r = p.resample(5);
+    /// assert_eq!(r.vertices, vec![Vec2::new(0, 0), Vec2::new(1, 0), Vec2::new(2, 0),
+    ///                             Vec2::new(3, 0), Vec2::new(3, 1)]);
+    /// ```
+    pub fn resample(&self, n: usize) -> Polyline {
+        if n < 2 {
+            return Polyline::new(self.vertices.iter().take(n).cloned().collect());
+        }
+        let step = self.length() / (n - 1) as f64;
+        self.sample_at((0..n).map(|i| i as f64 * step))
+    }
+    /// Resamples polyline into points with given spacing along its length
+    ///
+    /// Points are placed at distances $0, ds, 2ds, \ldots$ from the start;
+    /// the end of polyline is always included, so the last interval may be
+    /// shorter than `spacing`.
+    ///
+    /// # Panics
+    /// Panics if `spacing` is not positive.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Polyline, Vec2};
+    /// let p = Polyline::new(vec![Vec2::new(0, 0), Vec2::new(2.5, 0.0)]);
+    /// let r = p.resample_by_spacing(1);
+    /// assert_eq!(r.vertices, vec![Vec2::new(0, 0), Vec2::new(1, 0), Vec2::new(2, 0),
+    ///                             Vec2::new(2.5, 0.0)]);
+    /// ```
+    pub fn resample_by_spacing<I: Into<f64>>(&self, spacing: I) -> Polyline {
+        let spacing = spacing.into();
+        assert!(spacing > 0.0, "spacing must be positive");
+        if self.vertices.is_empty() {
+            return Polyline::new(vec![]);
+        }
+        let length = self.length();
+        let count = (length / spacing).floor() as usize;
+        let mut distances: Vec<f64> = (0..=count).map(|i| i as f64 * spacing).collect();
+        if length - count as f64 * spacing > 0.0 {
+            distances.push(length);
+        }
+        self.sample_at(distances.into_iter())
+    }
+    /// Signed discrete curvature at vertices
+    ///
+    /// At interior vertex it's turning angle between adjacent segments
+    /// divided by half of their total length, positive for counterclockwise
+    /// turns. It's zero at end vertices and at vertices adjacent to
+    /// zero-length segments, where direction is undefined.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Polyline, Vec2};
+    /// # use std::f64::consts::FRAC_PI_2;
+    /// let p = Polyline::new(vec![Vec2::new(0, 0), Vec2::new(1, 0), Vec2::new(1, 1)]);
+    /// assert_eq!(p.curvature(), vec![0.0, FRAC_PI_2, 0.0]);
+    /// ```
+    pub fn curvature(&self) -> Vec<f64> {
+        let n = self.vertices.len();
+        (0..n).map(|i| {
+            if i == 0 || i + 1 == n {
+                return 0.0;
+            }
+            let a = self.vertices[i] - self.vertices[i - 1];
+            let b = self.vertices[i + 1] - self.vertices[i];
+            if a.len() == 0.0 || b.len() == 0.0 {
+                return 0.0;
+            }
+            a.area(b).atan2(a.dot(b)) / (0.5 * (a.len() + b.len()))
+        }).collect()
+    }
+    /// Maximal absolute discrete curvature, see [`curvature`](#method.curvature)
+    ///
+    /// Returns zero for polylines with less than three vertices.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Polyline, Vec2};
+    /// let p = Polyline::new(vec![Vec2::new(0, 0), Vec2::new(2, 0), Vec2::new(2, 2), Vec2::new(4, 2)]);
+    /// assert_eq!(p.max_curvature(), std::f64::consts::FRAC_PI_4);
+    /// ```
+    pub fn max_curvature(&self) -> f64 {
+        self.curvature().into_iter().fold(0.0, |m, k| m.max(k.abs()))
+    }
+    /// Simplified polyline by Ramer–Douglas–Peucker algorithm
+    ///
+    /// Keeps end vertices and the least number of others, so that every
+    /// removed vertex lies within `tolerance` from the simplified chain
+    /// between its kept neighbors.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Polyline, Vec2};
+    /// let p = Polyline::new(vec![Vec2::new(0.0, 0.0), Vec2::new(1.0, 0.05), Vec2::new(2.0, -0.05),
+    ///                            Vec2::new(3.0, 0.0), Vec2::new(3.0, 2.0)]);
+    /// let s = p.simplify(0.1);
+    /// assert_eq!(s.vertices, vec![Vec2::new(0, 0), Vec2::new(3, 0), Vec2::new(3, 2)]);
+    /// ```
+    pub fn simplify<I: Into<f64>>(&self, tolerance: I) -> Polyline {
+        let tolerance = tolerance.into();
+        let n = self.vertices.len();
+        if n < 3 {
+            return self.clone();
+        }
+        let mut keep = vec![false; n];
+        keep[0] = true;
+        keep[n - 1] = true;
+        let mut stack = vec![(0, n - 1)];
+        while let Some((first, last)) = stack.pop() {
+            let chord = Segment2::new(self.vertices[first], self.vertices[last]);
+            let farthest = (first + 1..last)
+                .map(|i| (i, chord.distance_to(self.vertices[i])))
+                .fold(None, |best: Option<(usize, f64)>, c| match best {
+                    Some(b) if b.1 >= c.1 => Some(b),
+                    _ => Some(c),
+                });
+            if let Some((i, d)) = farthest {
+                if d > tolerance {
+                    keep[i] = true;
+                    stack.push((first, i));
+                    stack.push((i, last));
+                }
+            }
+        }
+        self.filter_vertices(&keep)
+    }
+    /// Simplified polyline by Visvalingam–Whyatt algorithm
+    ///
+    /// Repeatedly removes interior vertex forming the smallest triangle with
+    /// its current neighbors while its area is less than `min_area`. Unlike
+    /// [`simplify`](#method.simplify) it tends to keep overall shape of
+    /// curves rather than extreme points.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Polyline, Vec2};
+    /// let p = Polyline::new(vec![Vec2::new(0.0, 0.0), Vec2::new(1.0, 0.1), Vec2::new(2.0, 0.0),
+    ///                            Vec2::new(2.0, 2.0)]);
+    /// let s = p.simplify_visvalingam(0.5);
+    /// assert_eq!(s.vertices, vec![Vec2::new(0, 0), Vec2::new(2, 0), Vec2::new(2, 2)]);
+    /// ```
+    pub fn simplify_visvalingam<I: Into<f64>>(&self, min_area: I) -> Polyline {
+        let min_area = min_area.into();
+        let n = self.vertices.len();
+        if n < 3 {
+            return self.clone();
+        }
+        let v = &self.vertices;
+        let area = |a: usize, b: usize, c: usize| 0.5 * (v[b] - v[a]).area(v[c] - v[a]).abs();
+        // neighbors of vertices in simplified chain, ends are never removed
+        let mut prev: Vec<usize> = (0..n).map(|i| i.saturating_sub(1)).collect();
+        let mut next: Vec<usize> = (0..n).map(|i| (i + 1).min(n - 1)).collect();
+        let mut current: Vec<f64> = (0..n)
+            .map(|i| if i == 0 || i == n - 1 { f64::INFINITY } else { area(i - 1, i, i + 1) })
+            .collect();
+        let mut keep = vec![true; n];
+        let mut heap: BinaryHeap<Reverse<Candidate>> = (1..n - 1)
+            .map(|index| Reverse(Candidate { area: current[index], index }))
+            .collect();
+        while let Some(Reverse(Candidate { area: a, index })) = heap.pop() {
+            if !keep[index] || a != current[index] {
+                // stale entry, area changed after removal of neighbor
+                continue;
+            }
+            if a >= min_area {
+                break;
+            }
+            keep[index] = false;
+            let (p, q) = (prev[index], next[index]);
+            next[p] = q;
+            prev[q] = p;
+            for &i in &[p, q] {
+                if i != 0 && i != n - 1 {
+                    current[i] = area(prev[i], i, next[i]);
+                    heap.push(Reverse(Candidate { area: current[i], index: i }));
+                }
+            }
+        }
+        self.filter_vertices(&keep)
+    }
+    /// Polyline smoothed by Chaikin's corner cutting
+    ///
+    /// Every iteration replaces each segment by points at its quarter and
+    /// three quarters, except that end vertices are kept in place. Result
+    /// converges to quadratic B-spline, number of vertices roughly doubles
+    /// with every iteration. See [`Polygon::smooth_chaikin`] for closed
+    /// outlines.
+    ///
+    /// [`Polygon::smooth_chaikin`]: struct.Polygon.html#method.smooth_chaikin
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Polyline, Vec2};
+    /// let p = Polyline::new(vec![Vec2::new(0, 0), Vec2::new(4, 0), Vec2::new(4, 4)]);
+    /// let s = p.smooth_chaikin(1);
+    /// assert_eq!(s.vertices, vec![Vec2::new(0, 0), Vec2::new(3, 0), Vec2::new(4, 1), Vec2::new(4, 4)]);
+    /// ```
+    pub fn smooth_chaikin(&self, iterations: usize) -> Polyline {
+        let mut vertices = self.vertices.clone();
+        for _ in 0..iterations {
+            let n = vertices.len();
+            if n < 3 {
+                break;
+            }
+            let mut smooth = Vec::with_capacity(2 * n);
+            smooth.push(vertices[0]);
+            for i in 0..n - 1 {
+                let (p, q) = (vertices[i], vertices[i + 1]);
+                if i > 0 {
+                    smooth.push(p * 0.75 + q * 0.25);
+                }
+                if i + 2 < n {
+                    smooth.push(p * 0.25 + q * 0.75);
+                }
+            }
+            smooth.push(vertices[n - 1]);
+            vertices = smooth;
+        }
+        Polyline::new(vertices)
+    }
+    // polyline of vertices with set flags
+    fn filter_vertices(&self, keep: &[bool]) -> Polyline {
+        Polyline::new(self.vertices.iter().zip(keep).filter(|&(_, &k)| k).map(|(&v, _)| v).collect())
+    }
+    // points at increasing distances, walking the polyline once
+    fn sample_at<D: Iterator<Item = f64>>(&self, distances: D) -> Polyline {
+        let mut result = Vec::new();
+        let (mut segment, mut start) = (0, 0.0);
+        for d in distances {
+            while segment + 2 < self.vertices.len() {
+                let len = (self.vertices[segment + 1] - self.vertices[segment]).len();
+                if start + len >= d {
+                    break;
+                }
+                start += len;
+                segment += 1;
+            }
+            let point = match self.vertices.get(segment + 1) {
+                Some(&b) => {
+                    let a = self.vertices[segment];
+                    let len = (b - a).len();
+                    if len > 0.0 {
+                        a + (b - a) * ((d - start) / len).clamp(0.0, 1.0)
+                    } else {
+                        b
+                    }
+                }
+                None => match self.vertices.first() {
+                    Some(&p) => p,
+                    None => break,
+                },
+            };
+            result.push(point);
+        }
+        Polyline::new(result)
+    }
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    #[test]
+    fn polyline_resample_uniform() {
+        let p = Polyline::new((0..20).map(|i| Vec2::from_polar(1.0, i as f64 * 0.3)).collect());
+        let r = p.resample(50);
+        assert_eq!(r.vertices.len(), 50);
+        assert_eq!(r.vertices[0], p.vertices[0]);
+        assert!((r.vertices[49] - p.vertices[19]).len() < 1e-12);
+        let step = p.length() / 49.0;
+        for w in r.vertices.windows(2) {
+            // chords are no longer than arc steps
+            assert!((w[1] - w[0]).len() <= step + 1e-12);
+            assert!((w[1] - w[0]).len() > 0.9 * step);
+        }
+    }
+
+    #[test]
+    fn polyline_degenerate() {
+        let empty = Polyline::new(vec![]);
+        assert_eq!(empty.length(), 0.0);
+        assert_eq!(empty.point_at_distance(1), None);
+        assert!(empty.resample(3).vertices.is_empty());
+        assert!(empty.resample_by_spacing(1).vertices.is_empty());
+        let p = Vec2::new(1, 2);
+        let single = Polyline::new(vec![p]);
+        assert_eq!(single.point_at_distance(1), Some(p));
+        assert_eq!(single.resample(3).vertices, vec![p, p, p]);
+        assert_eq!(single.resample_by_spacing(1).vertices, vec![p]);
+        // repeated vertices don't produce NaN
+        let repeated = Polyline::new(vec![p, p, Vec2::new(2, 2)]);
+        assert_eq!(repeated.point_at_distance(0.5), Some(Vec2::new(1.5, 2.0)));
+        assert_eq!(repeated.resample(2).vertices, vec![p, Vec2::new(2, 2)]);
+    }
+
+    #[test]
+    fn polyline_curvature_of_circle() {
+        let r = 2.5;
+        let arc = Polyline::new((0..100).map(|i| Vec2::from_polar(r, i as f64 * 0.02)).collect());
+        let k = arc.curvature();
+        assert_eq!((k[0], k[99]), (0.0, 0.0));
+        assert!(k[1..99].iter().all(|&k| (k - 1.0 / r).abs() < 1e-4));
+        let reversed = Polyline::new(arc.vertices.iter().rev().cloned().collect());
+        assert!((reversed.max_curvature() - 1.0 / r).abs() < 1e-4);
+        assert!(reversed.curvature()[50] < 0.0);
+        let p = Vec2::new(1, 1);
+        assert_eq!(Polyline::new(vec![p, p, Vec2::new(2, 1)]).max_curvature(), 0.0);
+        assert_eq!(Polyline::new(vec![]).max_curvature(), 0.0);
+    }
+
+    #[test]
+    fn polyline_simplify() {
+        let p = Polyline::new((0..200).map(|i| {
+            let t = i as f64 * 0.05;
+            Vec2::new(t, t.sin() + 0.001 * (i as f64 * 7.0).sin())
+        }).collect());
+        for &tolerance in &[0.001, 0.01, 0.1] {
+            let s = p.simplify(tolerance);
+            assert!(s.vertices.len() < p.vertices.len());
+            assert_eq!(s.vertices.first(), p.vertices.first());
+            assert_eq!(s.vertices.last(), p.vertices.last());
+            // every original vertex is close to simplified chain
+            for &v in &p.vertices {
+                let d = s.vertices.windows(2)
+                    .map(|w| Segment2::new(w[0], w[1]).distance_to(v))
+                    .fold(f64::INFINITY, f64::min);
+                assert!(d <= tolerance + 1e-12);
+            }
+        }
+        let s = p.simplify_visvalingam(0.01);
+        assert!(s.vertices.len() < p.vertices.len() && s.vertices.len() > 2);
+        assert_eq!(s.vertices.last(), p.vertices.last());
+        let tiny = Polyline::new(vec![Vec2::new(0, 0), Vec2::new(1, 1)]);
+        assert_eq!(tiny.simplify(10), tiny);
+        assert_eq!(tiny.simplify_visvalingam(10), tiny);
+    }
+
+    #[test]
+    fn polyline_smooth_chaikin() {
+        let p = Polyline::new(vec![Vec2::new(0, 0), Vec2::new(2, 3), Vec2::new(4, -1), Vec2::new(7, 2)]);
+        let s = p.smooth_chaikin(4);
+        assert_eq!(s.vertices.first(), p.vertices.first());
+        assert_eq!(s.vertices.last(), p.vertices.last());
+        assert!(s.length() < p.length());
+        assert_eq!(s.vertices.len(), 34);
+        let segment = Polyline::new(vec![Vec2::new(0, 0), Vec2::new(1, 0)]);
+        assert_eq!(segment.smooth_chaikin(3), segment);
+    }
+}
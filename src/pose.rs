@@ -0,0 +1,201 @@
+//! Poses of rigid bodies: position with orientation.
+use std::ops::{Mul, MulAssign};
+use isometry2::Isometry2;
+use isometry3::Isometry3;
+use mat2::Mat2;
+use mat3::Mat3;
+use quat::Quat;
+use vec2::Vec2;
+use vec3::Vec3;
+
+/// Pose of rigid body in plane
+///
+/// Local frame of body is rotated counterclockwise by `angle` and has origin
+/// at `position`. Composition `parent * child` turns pose of `child` given in
+/// frame of `parent` into world pose.
+///
+/// # Example
+/// ```
+/// # use linal::{Pose2, Vec2};
+/// # use std::f64::consts::FRAC_PI_2;
+/// let body = Pose2::new(Vec2::new(1.0, 0.0), FRAC_PI_2);
+/// let p = body.to_world_point(Vec2::new(1.0, 0.0));
+/// assert!((p - Vec2::new(1.0, 1.0)).len() < 1e-12);
+/// assert!((body.to_local_point(p) - Vec2::new(1.0, 0.0)).len() < 1e-12);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Pose2 {
+    /// origin of local frame
+    pub position: Vec2,
+    /// counterclockwise rotation of local frame
+    pub angle: f64,
+}
+
+/// Pose of rigid body in space
+///
+/// Local frame of body is rotated by unit quaternion `orientation` and has
+/// origin at `position`. Composition `parent * child` turns pose of `child`
+/// given in frame of `parent` into world pose.
+///
+/// # Example
+/// ```
+/// # use linal::{Pose3, Quat, Vec3};
+/// # use std::f64::consts::FRAC_PI_2;
+/// let body = Pose3::new(Vec3::new(1, 0, 0), Quat::from_axis_angle(Vec3::new(0, 0, 1), FRAC_PI_2));
+/// let p = body.to_world_point(Vec3::new(1, 0, 0));
+/// assert!((p - Vec3::new(1, 1, 0)).len() < 1e-12);
+/// assert!((body.to_local_point(p) - Vec3::new(1, 0, 0)).len() < 1e-12);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Pose3 {
+    /// origin of local frame
+    pub position: Vec3,
+    /// rotation of local frame, unit quaternion
+    pub orientation: Quat,
+}
+
+impl Pose2 {
+    /// Constructs a new `Pose2` from position and angle
+    pub fn new<I: Into<f64>>(position: Vec2, angle: I) -> Pose2 {
+        Pose2 { position, angle: angle.into() }
+    }
+    /// Pose at origin aligned with world axes
+    pub fn identity() -> Pose2 {
+        Pose2::new(Vec2::zero(), 0.0)
+    }
+    /// World coordinates of point given in local frame
+    pub fn to_world_point(self, p: Vec2) -> Vec2 {
+        Mat2::rotation(self.angle) * p + self.position
+    }
+    /// World components of vector given in local frame, position doesn't affect it
+    pub fn to_world_vector(self, v: Vec2) -> Vec2 {
+        Mat2::rotation(self.angle) * v
+    }
+    /// Local coordinates of point given in world frame
+    pub fn to_local_point(self, p: Vec2) -> Vec2 {
+        Mat2::rotation(-self.angle) * (p - self.position)
+    }
+    /// Local components of vector given in world frame, position doesn't affect it
+    pub fn to_local_vector(self, v: Vec2) -> Vec2 {
+        Mat2::rotation(-self.angle) * v
+    }
+    /// Pose of world frame relative to this pose
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Pose2, Vec2};
+    /// let a = Pose2::new(Vec2::new(1.0, 2.0), 0.5);
+    /// let id = a * a.inverse();
+    /// assert!(id.position.len() < 1e-12 && id.angle.abs() < 1e-12);
+    /// ```
+    pub fn inverse(self) -> Pose2 {
+        Pose2::from_isometry(self.to_isometry().inverse())
+    }
+    /// Pose of body moved from origin by rigid motion, angle is in $(-\pi, \pi]$
+    pub fn from_isometry(m: Isometry2) -> Pose2 {
+        Pose2::new(m.translation, m.angle())
+    }
+    /// Rigid motion mapping local coordinates to world ones
+    pub fn to_isometry(self) -> Isometry2 {
+        Isometry2::from_angle(self.angle, self.position)
+    }
+}
+
+impl Pose3 {
+    /// Constructs a new `Pose3` from position and orientation
+    pub fn new(position: Vec3, orientation: Quat) -> Pose3 {
+        Pose3 { position, orientation }
+    }
+    /// Pose at origin aligned with world axes
+    pub fn identity() -> Pose3 {
+        Pose3::new(Vec3::zero(), Quat::identity())
+    }
+    /// World coordinates of point given in local frame
+    pub fn to_world_point(self, p: Vec3) -> Vec3 {
+        self.orientation.rotate(p) + self.position
+    }
+    /// World components of vector given in local frame, position doesn't affect it
+    pub fn to_world_vector(self, v: Vec3) -> Vec3 {
+        self.orientation.rotate(v)
+    }
+    /// Local coordinates of point given in world frame
+    pub fn to_local_point(self, p: Vec3) -> Vec3 {
+        self.orientation.conjugate().rotate(p - self.position)
+    }
+    /// Local components of vector given in world frame, position doesn't affect it
+    pub fn to_local_vector(self, v: Vec3) -> Vec3 {
+        self.orientation.conjugate().rotate(v)
+    }
+    /// Pose of world frame relative to this pose
+    pub fn inverse(self) -> Pose3 {
+        let q = self.orientation.conjugate();
+        Pose3::new(q.rotate(-self.position), q)
+    }
+    /// Rigid motion mapping local coordinates to world ones
+    pub fn to_isometry(self) -> Isometry3 {
+        Isometry3::new(Mat3::from_quat(self.orientation), self.position)
+    }
+}
+
+impl Mul for Pose2 {
+    type Output = Pose2;
+
+    fn mul(self, rhs: Pose2) -> Pose2 {
+        Pose2::from_isometry(self.to_isometry() * rhs.to_isometry())
+    }
+}
+
+impl MulAssign for Pose2 {
+    fn mul_assign(&mut self, rhs: Pose2) {
+        *self = *self * rhs;
+    }
+}
+
+impl Mul for Pose3 {
+    type Output = Pose3;
+
+    fn mul(self, rhs: Pose3) -> Pose3 {
+        // renormalize to keep long chains of composition from drifting
+        let orientation = (self.orientation * rhs.orientation).normalize();
+        Pose3::new(self.to_world_point(rhs.position), orientation)
+    }
+}
+
+impl MulAssign for Pose3 {
+    fn mul_assign(&mut self, rhs: Pose3) {
+        *self = *self * rhs;
+    }
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+    use std::f64::consts::PI;
+
+    #[test]
+    fn pose2_compose() {
+        let a = Pose2::new(Vec2::new(1.0, -1.0), 3.0);
+        let b = Pose2::new(Vec2::new(0.0, 3.0), 1.0);
+        let p = Vec2::new(0.5, -4.0);
+        let ab = a * b;
+        assert!((ab.to_world_point(p) - a.to_world_point(b.to_world_point(p))).len() < 1e-12);
+        assert!((ab.angle - (4.0 - 2.0 * PI)).abs() < 1e-12);
+        assert!((ab.to_local_point(ab.to_world_point(p)) - p).len() < 1e-12);
+        assert!(((a.inverse() * ab).position - b.position).len() < 1e-12);
+        assert!((a.to_isometry().transform_vector(p) - a.to_world_vector(p)).len() < 1e-12);
+    }
+
+    #[test]
+    fn pose3_compose() {
+        let a = Pose3::new(Vec3::new(1, -1, 2), Quat::from_axis_angle(Vec3::new(1, 2, 3), 0.8));
+        let b = Pose3::new(Vec3::new(0, 3, 0), Quat::from_axis_angle(Vec3::new(-1, 0, 1), 2.1));
+        let p = Vec3::new(0.5, 0.25, -4.0);
+        let mut ab = a;
+        ab *= b;
+        assert!((ab.to_world_point(p) - a.to_world_point(b.to_world_point(p))).len() < 1e-12);
+        assert!((ab.to_local_vector(ab.to_world_vector(p)) - p).len() < 1e-12);
+        let id = a * a.inverse();
+        assert!(id.position.len() < 1e-12 && (id.orientation.w.abs() - 1.0).abs() < 1e-12);
+        assert!((a.to_isometry().transform_point(p) - a.to_world_point(p)).len() < 1e-12);
+    }
+}
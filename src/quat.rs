@@ -0,0 +1,401 @@
+//! Quaternions, mostly rotations of space.
+use std::ops::{Add, Sub, Mul, Neg};
+use vec3::Vec3;
+use mat3::Mat3;
+
+// cosine of angle between quaternions above which slerp falls back to lerp
+const SLERP_LINEAR: f64 = 1.0 - 1e-9;
+
+/// Quaternion $w + x i + y j + z k$
+///
+/// Unit quaternions represent rotations of space, with $q$ and $-q$ being
+/// the same rotation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quat {
+    /// scalar part
+    pub w: f64,
+    /// component of vector part
+    pub x: f64,
+    /// component of vector part
+    pub y: f64,
+    /// component of vector part
+    pub z: f64,
+}
+
+impl Quat {
+    /// Constructs a new `Quat` from scalar and vector components.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Quat, Vec3};
+    /// let q = Quat::new(1, 2, 3, 4);
+    /// assert_eq!(q.w, 1.0);
+    /// assert_eq!(q.vector(), Vec3::new(2, 3, 4));
+    /// ```
+    pub fn new<I: Into<f64>>(w: I, x: I, y: I, z: I) -> Quat {
+        Quat { w: w.into(), x: x.into(), y: y.into(), z: z.into() }
+    }
+    /// Quaternion from scalar and vector parts
+    pub fn from_parts(w: f64, v: Vec3) -> Quat {
+        Quat::new(w, v.x, v.y, v.z)
+    }
+    /// Identity rotation
+    pub fn identity() -> Quat {
+        Quat::new(1, 0, 0, 0)
+    }
+    /// Rotation around axis by angle
+    ///
+    /// Rotation is counterclockwise when looking against `axis`, which
+    /// doesn't need to be normalized, but must be nonzero.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Quat, Vec3};
+    /// # use std::f64::consts::FRAC_PI_2;
+    /// let q = Quat::from_axis_angle(Vec3::new(0, 0, 1), FRAC_PI_2);
+    /// assert!((q.rotate(Vec3::new(1, 0, 0)) - Vec3::new(0, 1, 0)).len() < 1e-12);
+    /// ```
+    pub fn from_axis_angle<I: Into<f64>>(axis: Vec3, angle: I) -> Quat {
+        let (sin, cos) = (0.5 * angle.into()).sin_cos();
+        Quat::from_parts(cos, axis.ort() * sin)
+    }
+    /// Unit quaternion of rotation matrix
+    ///
+    /// Uses Shepperd's method: the largest of $|w|, |x|, |y|, |z|$ is
+    /// recovered first from trace or diagonal, so the result stays accurate
+    /// for rotations near 180°, where the trace-based formula loses precision.
+    /// Matrix must be orthonormal with positive determinant.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Mat3, Quat, Vec3};
+    /// let m = Mat3::rotation(Vec3::new(1, 2, 3), 0.7);
+    /// let q = Quat::from_mat3(m);
+    /// let v = Vec3::new(1, -1, 2);
+    /// assert!((q.rotate(v) - m * v).len() < 1e-12);
+    /// ```
+    pub fn from_mat3(m: Mat3) -> Quat {
+        let trace = m.trace();
+        let q = if trace >= m.x.x && trace >= m.y.y && trace >= m.z.z {
+            let s = 2.0 * (1.0 + trace).sqrt();
+            Quat::new(0.25 * s, (m.z.y - m.y.z) / s, (m.x.z - m.z.x) / s, (m.y.x - m.x.y) / s)
+        } else if m.x.x >= m.y.y && m.x.x >= m.z.z {
+            let s = 2.0 * (1.0 + m.x.x - m.y.y - m.z.z).sqrt();
+            Quat::new((m.z.y - m.y.z) / s, 0.25 * s, (m.x.y + m.y.x) / s, (m.x.z + m.z.x) / s)
+        } else if m.y.y >= m.z.z {
+            let s = 2.0 * (1.0 + m.y.y - m.x.x - m.z.z).sqrt();
+            Quat::new((m.x.z - m.z.x) / s, (m.x.y + m.y.x) / s, 0.25 * s, (m.y.z + m.z.y) / s)
+        } else {
+            let s = 2.0 * (1.0 + m.z.z - m.x.x - m.y.y).sqrt();
+            Quat::new((m.y.x - m.x.y) / s, (m.x.z + m.z.x) / s, (m.y.z + m.z.y) / s, 0.25 * s)
+        };
+        q.normalize()
+    }
+    /// Vector part
+    pub fn vector(self) -> Vec3 {
+        Vec3::new(self.x, self.y, self.z)
+    }
+    /// Dot product of quaternions as 4D vectors
+    pub fn dot(self, rhs: Quat) -> f64 {
+        self.w * rhs.w + self.x * rhs.x + self.y * rhs.y + self.z * rhs.z
+    }
+    /// Norm of quaternion
+    pub fn norm(self) -> f64 {
+        self.dot(self).sqrt()
+    }
+    /// Unit quaternion of the same direction
+    pub fn normalize(self) -> Quat {
+        self * (1.0 / self.norm())
+    }
+    /// Conjugate quaternion, $w - x i - y j - z k$
+    pub fn conjugate(self) -> Quat {
+        Quat::from_parts(self.w, -self.vector())
+    }
+    /// Multiplicative inverse
+    ///
+    /// Equals conjugate for unit quaternions.
+    pub fn inverse(self) -> Quat {
+        self.conjugate() * (1.0 / self.dot(self))
+    }
+    /// Rotates vector by unit quaternion
+    pub fn rotate(self, v: Vec3) -> Vec3 {
+        let u = self.vector();
+        let t = u.cross(v) * 2.0;
+        v + t * self.w + u.cross(t)
+    }
+    /// Exponent of quaternion
+    pub fn exp(self) -> Quat {
+        let v = self.vector();
+        let theta = v.len();
+        let (sin, cos) = theta.sin_cos();
+        // sin(theta) / theta tends to 1
+        let k = if theta < 1e-12 { 1.0 } else { sin / theta };
+        Quat::from_parts(cos, v * k) * self.w.exp()
+    }
+    /// Natural logarithm of quaternion
+    ///
+    /// For unit quaternion scalar part is zero and vector part is rotation
+    /// axis scaled by half of angle.
+    pub fn ln(self) -> Quat {
+        let norm = self.norm();
+        let v = self.vector();
+        let len = v.len();
+        let theta = len.atan2(self.w);
+        let k = if len < 1e-12 { 1.0 / norm } else { theta / len };
+        Quat::from_parts(norm.ln(), v * k)
+    }
+    /// Spherical linear interpolation between unit quaternions
+    ///
+    /// Interpolation goes along the shortest arc, so `other` is negated if it
+    /// lies in the opposite hemisphere. Angular velocity is constant.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Quat, Vec3};
+    /// let axis = Vec3::new(1, 2, 3);
+    /// let a = Quat::from_axis_angle(axis, 0.2);
+    /// let b = Quat::from_axis_angle(axis, 1.0);
+    /// let m = a.slerp(b, 0.5);
+    /// assert!((m.dot(Quat::from_axis_angle(axis, 0.6)) - 1.0).abs() < 1e-12);
+    /// // -b is the same rotation, result is the same
+    /// assert!((a.slerp(-b, 0.5).dot(m) - 1.0).abs() < 1e-12);
+    /// ```
+    pub fn slerp<I: Into<f64>>(self, other: Quat, t: I) -> Quat {
+        if self.dot(other) < 0.0 {
+            slerp(self, -other, t.into())
+        } else {
+            slerp(self, other, t.into())
+        }
+    }
+}
+
+// slerp along arc from `a` to `b`, without hemisphere check
+fn slerp(a: Quat, b: Quat, t: f64) -> Quat {
+    let cos = a.dot(b).clamp(-1.0, 1.0);
+    if cos > SLERP_LINEAR {
+        return (a + (b - a) * t).normalize();
+    }
+    let theta = cos.acos();
+    let sin = theta.sin();
+    (a * ((1.0 - t) * theta).sin() + b * (t * theta).sin()) * (1.0 / sin)
+}
+
+/// Spherical cubic interpolation of rotations (squad)
+///
+/// Interpolates sequence of unit quaternions with $C^1$ continuous rotation,
+/// parameter `t` runs from `0` to `keys.len() - 1` with `t = i` at key `i`.
+///
+/// # Example
+/// ```
+/// # use linal::{Quat, Squad, Vec3};
+/// let keys = vec![
+///     Quat::identity(),
+///     Quat::from_axis_angle(Vec3::new(0, 0, 1), 1.0),
+///     Quat::from_axis_angle(Vec3::new(1, 0, 0), 1.0),
+/// ];
+/// let squad = Squad::new(keys.clone());
+/// assert!((squad.eval(1.0).dot(keys[1]).abs() - 1.0).abs() < 1e-12);
+/// assert!((squad.eval(1.5).norm() - 1.0).abs() < 1e-12);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Squad {
+    /// interpolated rotations, in common hemisphere with neighbours
+    pub keys: Vec<Quat>,
+    /// inner control points
+    pub controls: Vec<Quat>,
+}
+
+impl Squad {
+    /// Constructs squad through keys
+    ///
+    /// Keys are negated where needed, so neighbours are interpolated along
+    /// the shortest arc. End controls are keys themselves.
+    ///
+    /// # Panics
+    /// Panics if `keys` is empty.
+    pub fn new(mut keys: Vec<Quat>) -> Squad {
+        assert!(!keys.is_empty(), "squad needs at least one key");
+        for i in 1..keys.len() {
+            if keys[i].dot(keys[i - 1]) < 0.0 {
+                keys[i] = -keys[i];
+            }
+        }
+        let n = keys.len();
+        let controls = (0..n).map(|i| {
+            if i == 0 || i + 1 == n {
+                return keys[i];
+            }
+            let inv = keys[i].conjugate();
+            let next = (inv * keys[i + 1]).ln();
+            let prev = (inv * keys[i - 1]).ln();
+            keys[i] * ((next + prev) * -0.25).exp()
+        }).collect();
+        Squad { keys, controls }
+    }
+    /// Interpolated rotation at parameter `t`, clamped to `[0, keys.len() - 1]`
+    pub fn eval<I: Into<f64>>(&self, t: I) -> Quat {
+        let n = self.keys.len();
+        if n == 1 {
+            return self.keys[0];
+        }
+        let t = t.into().clamp(0.0, (n - 1) as f64);
+        let i = (t.floor() as usize).min(n - 2);
+        let h = t - i as f64;
+        let (q0, q1) = (self.keys[i], self.keys[i + 1]);
+        let (s0, s1) = (self.controls[i], self.controls[i + 1]);
+        slerp(slerp(q0, q1, h), slerp(s0, s1, h), 2.0 * h * (1.0 - h))
+    }
+}
+
+impl Add for Quat {
+    type Output = Quat;
+
+    fn add(self, rhs: Quat) -> Quat {
+        Quat::new(self.w + rhs.w, self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl Sub for Quat {
+    type Output = Quat;
+
+    fn sub(self, rhs: Quat) -> Quat {
+        self + -rhs
+    }
+}
+
+impl Neg for Quat {
+    type Output = Quat;
+
+    fn neg(self) -> Quat {
+        Quat::new(-self.w, -self.x, -self.y, -self.z)
+    }
+}
+
+impl Mul<f64> for Quat {
+    type Output = Quat;
+
+    fn mul(self, rhs: f64) -> Quat {
+        Quat::new(self.w * rhs, self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl Mul for Quat {
+    type Output = Quat;
+
+    /// Hamilton product, rotation `rhs` followed by `self`
+    fn mul(self, rhs: Quat) -> Quat {
+        let (a, b) = (self.vector(), rhs.vector());
+        Quat::from_parts(self.w * rhs.w - a.dot(b), b * self.w + a * rhs.w + a.cross(b))
+    }
+}
+
+impl Mat3 {
+    /// Rotation matrix of unit quaternion
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Mat3, Quat, Vec3};
+    /// let q = Quat::from_axis_angle(Vec3::new(1, 2, 3), 0.7);
+    /// let v = Vec3::new(1, -1, 2);
+    /// assert!((Mat3::from_quat(q) * v - q.rotate(v)).len() < 1e-12);
+    /// ```
+    pub fn from_quat(q: Quat) -> Mat3 {
+        let (w, x, y, z) = (q.w, q.x, q.y, q.z);
+        Mat3::new(Vec3::new(1.0 - 2.0 * (y * y + z * z), 2.0 * (x * y - w * z), 2.0 * (x * z + w * y)),
+                  Vec3::new(2.0 * (x * y + w * z), 1.0 - 2.0 * (x * x + z * z), 2.0 * (y * z - w * x)),
+                  Vec3::new(2.0 * (x * z - w * y), 2.0 * (y * z + w * x), 1.0 - 2.0 * (x * x + y * y)))
+    }
+}
+
+impl Vec3 {
+    /// Point rotated by unit quaternion around `pivot`
+    ///
+    /// Same as [`rotate_around`](struct.Vec3.html#method.rotate_around) with
+    /// rotation given by quaternion.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Quat, Vec3};
+    /// # use std::f64::consts::PI;
+    /// let q = Quat::from_axis_angle(Vec3::new(1, 0, 0), PI);
+    /// let p = Vec3::new(0, 3, 1).rotate_around_quat(Vec3::new(0, 2, 0), q);
+    /// assert!((p - Vec3::new(0, 1, -1)).len() < 1e-12);
+    /// ```
+    pub fn rotate_around_quat(self, pivot: Vec3, rotation: Quat) -> Vec3 {
+        rotation.rotate(self - pivot) + pivot
+    }
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+    use std::f64::consts::PI;
+
+    #[test]
+    fn quat_rotation() {
+        let a = Quat::from_axis_angle(Vec3::new(1, 2, 3), 0.7);
+        let b = Quat::from_axis_angle(Vec3::new(-2, 0, 1), 2.0);
+        let v = Vec3::new(0.5, -1.0, 4.0);
+        assert!(((a * b).rotate(v) - a.rotate(b.rotate(v))).len() < 1e-12);
+        assert!((a.inverse().rotate(a.rotate(v)) - v).len() < 1e-12);
+        assert!(((a.ln() * 2.0).exp().dot(a * a) - 1.0).abs() < 1e-12);
+        assert!((Quat::new(2, 0, 0, 0).ln().w - 2f64.ln()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn quat_slerp() {
+        let axis = Vec3::new(0, 1, 0);
+        let a = Quat::from_axis_angle(axis, -0.1);
+        let b = Quat::from_axis_angle(axis, 2.0 * PI - 0.3);
+        // shortest arc from -0.1 to -0.3
+        let m = a.slerp(b, 0.5);
+        assert!((m.dot(Quat::from_axis_angle(axis, -0.2)).abs() - 1.0).abs() < 1e-12);
+        assert_eq!(a.slerp(b, 0.0), a);
+        assert!((a.slerp(a, 0.3).dot(a) - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn squad_smooth() {
+        let keys: Vec<Quat> = (0..5)
+            .map(|i| Quat::from_axis_angle(Vec3::new(1.0, i as f64, 2.0), 0.5 * i as f64))
+            .collect();
+        let squad = Squad::new(keys);
+        // angular velocity is continuous across key
+        let dt = 1e-5;
+        let before = squad.eval(2.0 - dt).conjugate() * squad.eval(2.0);
+        let after = squad.eval(2.0).conjugate() * squad.eval(2.0 + dt);
+        assert!((before.vector() - after.vector()).len() < 1e-8);
+        for i in 0..5 {
+            assert!((squad.eval(i as f64).dot(squad.keys[i]) - 1.0).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn quat_rotate_around_matches_matrix() {
+        let (pivot, axis, angle) = (Vec3::new(1, -2, 3), Vec3::new(1, 2, 2), 0.7);
+        let q = Quat::from_axis_angle(axis, angle);
+        let p = Vec3::new(4, 0, -1);
+        let a = p.rotate_around(pivot, axis, angle);
+        let b = p.rotate_around_quat(pivot, q);
+        assert!((a - b).len() < 1e-12);
+        assert!(((a - pivot).len() - (p - pivot).len()).abs() < 1e-12);
+        assert!((pivot.rotate_around_quat(pivot, q) - pivot).len() < 1e-12);
+    }
+
+    #[test]
+    fn quat_mat3_round_trip() {
+        let axes = [Vec3::new(1, 0, 0), Vec3::new(0, 1, 0), Vec3::new(0, 0, 1), Vec3::new(1, -2, 3)];
+        let angles = [0.0, 0.3, PI - 1e-9, PI, -PI + 1e-7];
+        let v = Vec3::new(0.5, -1.0, 4.0);
+        for &axis in axes.iter() {
+            for &angle in angles.iter() {
+                let q = Quat::from_axis_angle(axis, angle);
+                let m = Mat3::from_quat(q);
+                assert!((m * v - q.rotate(v)).len() < 1e-12);
+                let r = Quat::from_mat3(m);
+                assert!((r.dot(q).abs() - 1.0).abs() < 1e-12);
+                assert!((r.rotate(v) - m * v).len() < 1e-12);
+            }
+        }
+    }
+}
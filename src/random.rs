@@ -0,0 +1,251 @@
+//! Random sampling of vectors, available with `rand` feature.
+use std::f64::consts::PI;
+use rand::Rng;
+use rand::distributions::{Distribution, Standard};
+use vec2::Vec2;
+use vec3::Vec3;
+
+impl Vec2 {
+    /// Random unit vector, uniformly distributed on a circle
+    ///
+    /// # Example
+    /// ```
+    /// # extern crate rand;
+    /// # extern crate linal;
+    /// # use linal::Vec2;
+    /// # fn main() {
+    /// let v = Vec2::random_unit(&mut rand::thread_rng());
+    /// assert!((v.len() - 1.0).abs() < 1e-12);
+    /// # }
+    /// ```
+    pub fn random_unit<R: Rng + ?Sized>(rng: &mut R) -> Vec2 {
+        Vec2::from_polar(1.0, rng.gen_range(0.0..2.0 * PI))
+    }
+    /// Random vector, uniformly distributed in a unit disk
+    ///
+    /// # Example
+    /// ```
+    /// # extern crate rand;
+    /// # extern crate linal;
+    /// # use linal::Vec2;
+    /// # fn main() {
+    /// let v = Vec2::random_in_disk(&mut rand::thread_rng());
+    /// assert!(v.len() <= 1.0);
+    /// # }
+    /// ```
+    pub fn random_in_disk<R: Rng + ?Sized>(rng: &mut R) -> Vec2 {
+        // radius density grows linearly, so it is sampled as sqrt of uniform
+        Vec2::random_unit(rng) * rng.gen::<f64>().sqrt()
+    }
+}
+
+impl Vec3 {
+    /// Random unit vector, uniformly distributed on a sphere
+    ///
+    /// # Example
+    /// ```
+    /// # extern crate rand;
+    /// # extern crate linal;
+    /// # use linal::Vec3;
+    /// # fn main() {
+    /// let v = Vec3::random_unit(&mut rand::thread_rng());
+    /// assert!((v.len() - 1.0).abs() < 1e-12);
+    /// # }
+    /// ```
+    pub fn random_unit<R: Rng + ?Sized>(rng: &mut R) -> Vec3 {
+        // by Archimedes' hat-box theorem z is uniform on a sphere
+        let z: f64 = rng.gen_range(-1.0..=1.0);
+        let phi = rng.gen_range(0.0..2.0 * PI);
+        let r = (1.0 - z * z).sqrt();
+        Vec3::new(r * phi.cos(), r * phi.sin(), z)
+    }
+    /// Random vector, uniformly distributed in a unit ball
+    ///
+    /// # Example
+    /// ```
+    /// # extern crate rand;
+    /// # extern crate linal;
+    /// # use linal::Vec3;
+    /// # fn main() {
+    /// let v = Vec3::random_in_ball(&mut rand::thread_rng());
+    /// assert!(v.len() <= 1.0);
+    /// # }
+    /// ```
+    pub fn random_in_ball<R: Rng + ?Sized>(rng: &mut R) -> Vec3 {
+        Vec3::random_unit(rng) * rng.gen::<f64>().cbrt()
+    }
+}
+
+impl Distribution<Vec2> for Standard {
+    /// Vector with components uniformly distributed in $[0, 1)$
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec2 {
+        Vec2::new(rng.gen::<f64>(), rng.gen::<f64>())
+    }
+}
+
+impl Distribution<Vec3> for Standard {
+    /// Vector with components uniformly distributed in $[0, 1)$
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3 {
+        Vec3::new(rng.gen::<f64>(), rng.gen::<f64>(), rng.gen::<f64>())
+    }
+}
+
+/// Uniform distribution on a unit circle
+///
+/// # Example
+/// ```
+/// # extern crate rand;
+/// # extern crate linal;
+/// # use linal::Vec2;
+/// # use linal::random::UnitVec2;
+/// # use rand::Rng;
+/// # fn main() {
+/// let v: Vec2 = rand::thread_rng().sample(UnitVec2);
+/// assert!((v.len() - 1.0).abs() < 1e-12);
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct UnitVec2;
+
+impl Distribution<Vec2> for UnitVec2 {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec2 {
+        Vec2::random_unit(rng)
+    }
+}
+
+/// Uniform distribution on a unit sphere
+///
+/// # Example
+/// ```
+/// # extern crate rand;
+/// # extern crate linal;
+/// # use linal::Vec3;
+/// # use linal::random::UnitVec3;
+/// # use rand::Rng;
+/// # fn main() {
+/// let points: Vec<Vec3> = rand::thread_rng().sample_iter(UnitVec3).take(10).collect();
+/// assert!(points.iter().all(|v| (v.len() - 1.0).abs() < 1e-12));
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct UnitVec3;
+
+impl Distribution<Vec3> for UnitVec3 {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3 {
+        Vec3::random_unit(rng)
+    }
+}
+
+/// Normal distribution with independent components
+///
+/// Every component has given mean and standard deviation.
+///
+/// # Example
+/// ```
+/// # extern crate rand;
+/// # extern crate linal;
+/// # use linal::Vec3;
+/// # use linal::random::Gaussian;
+/// # use rand::Rng;
+/// # fn main() {
+/// let noise: Vec3 = rand::thread_rng().sample(Gaussian::new(0, 1));
+/// # let _ = noise;
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Gaussian {
+    /// mean of every component
+    pub mean: f64,
+    /// standard deviation of every component
+    pub std_dev: f64,
+}
+
+impl Gaussian {
+    /// Constructs a new `Gaussian` distribution
+    pub fn new<I: Into<f64>>(mean: I, std_dev: I) -> Gaussian {
+        Gaussian { mean: mean.into(), std_dev: std_dev.into() }
+    }
+    // pair of independent standard normal values by Box-Muller transform
+    fn standard_pair<R: Rng + ?Sized>(rng: &mut R) -> (f64, f64) {
+        // shift to (0, 1] to avoid logarithm of zero
+        let u = 1.0 - rng.gen::<f64>();
+        let r = (-2.0 * u.ln()).sqrt();
+        let v = Vec2::from_polar(r, rng.gen_range(0.0..2.0 * PI));
+        (v.x, v.y)
+    }
+}
+
+impl Distribution<Vec2> for Gaussian {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec2 {
+        let (x, y) = Gaussian::standard_pair(rng);
+        Vec2::new(x, y) * self.std_dev + Vec2::new(self.mean, self.mean)
+    }
+}
+
+impl Distribution<Vec3> for Gaussian {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3 {
+        let (x, y) = Gaussian::standard_pair(rng);
+        let (z, _) = Gaussian::standard_pair(rng);
+        Vec3::new(x, y, z) * self.std_dev + Vec3::new(self.mean, self.mean, self.mean)
+    }
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    #[test]
+    fn random_in_disk_uniform() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let n = 20000;
+        // fraction inside of half radius equals ratio of areas
+        let inner = (0..n).filter(|_| Vec2::random_in_disk(&mut rng).len() < 0.5).count();
+        assert!((inner as f64 / n as f64 - 0.25).abs() < 0.02);
+    }
+
+    #[test]
+    fn random_unit_sphere_uniform() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let n = 20000;
+        let mut mean = Vec3::zero();
+        let mut upper = 0;
+        for _ in 0..n {
+            let v = Vec3::random_unit(&mut rng);
+            assert!((v.len() - 1.0).abs() < 1e-12);
+            mean += v;
+            if v.z > 0.5 {
+                upper += 1;
+            }
+        }
+        assert!((mean / n as f64).len() < 0.03);
+        // spherical cap above z = 0.5 covers quarter of sphere
+        assert!((upper as f64 / n as f64 - 0.25).abs() < 0.02);
+        let inner = (0..n).filter(|_| Vec3::random_in_ball(&mut rng).len() < 0.5).count();
+        assert!((inner as f64 / n as f64 - 0.125).abs() < 0.02);
+    }
+
+    #[test]
+    fn gaussian_moments() {
+        let rng = StdRng::seed_from_u64(11);
+        let n = 20000;
+        let samples: Vec<Vec3> = rng.sample_iter(Gaussian::new(2.0, 0.5)).take(n).collect();
+        let mean = Vec3::centroid(&samples);
+        assert!((mean - Vec3::new(2, 2, 2)).len() < 0.02);
+        let cov = Vec3::covariance(&samples);
+        for i in 0..3 {
+            assert!((cov[i][i] - 0.25).abs() < 0.01);
+        }
+        assert!(cov[0][1].abs() < 0.01);
+    }
+
+    #[test]
+    fn standard_range() {
+        let mut rng = StdRng::seed_from_u64(3);
+        for _ in 0..1000 {
+            let v: Vec2 = rng.gen();
+            assert!((0.0..1.0).contains(&v.x) && (0.0..1.0).contains(&v.y));
+        }
+    }
+}
@@ -0,0 +1,170 @@
+//! Robust model fitting with RANSAC, available with `rand` feature.
+//!
+//! Models are repeatedly fitted to random minimal samples of points, the one
+//! with most points within threshold distance (inliers) wins and is refitted
+//! to all its inliers by least squares. Unlike plain least squares in
+//! [`fit`](../fit/index.html), outliers don't affect result as long as there
+//! are enough iterations to draw sample of inliers only.
+use rand::Rng;
+use rand::seq::index;
+use circle::Circle;
+use fit::{circle_fit, line_fit, plane_fit};
+use line2::Line2;
+use plane::Plane;
+use vec2::Vec2;
+use vec3::Vec3;
+
+/// Model that can be fitted to points by RANSAC
+pub trait Model: Sized {
+    /// type of points
+    type Point: Copy;
+    /// Number of points determining model
+    const SAMPLE_SIZE: usize;
+    /// Least-squares fit to at least `SAMPLE_SIZE` points, `None` if points
+    /// are degenerate
+    fn fit(points: &[Self::Point]) -> Option<Self>;
+    /// Distance from model to point
+    fn distance(&self, point: Self::Point) -> f64;
+}
+
+impl Model for Line2 {
+    type Point = Vec2;
+    const SAMPLE_SIZE: usize = 2;
+    fn fit(points: &[Vec2]) -> Option<Line2> {
+        line_fit(points).map(|(line, _)| line)
+    }
+    fn distance(&self, point: Vec2) -> f64 {
+        self.distance_to_point(point)
+    }
+}
+
+impl Model for Circle {
+    type Point = Vec2;
+    const SAMPLE_SIZE: usize = 3;
+    fn fit(points: &[Vec2]) -> Option<Circle> {
+        circle_fit(points).map(|(center, radius)| Circle::new(center, radius))
+    }
+    fn distance(&self, point: Vec2) -> f64 {
+        ((point - self.center).len() - self.radius).abs()
+    }
+}
+
+impl Model for Plane {
+    type Point = Vec3;
+    const SAMPLE_SIZE: usize = 3;
+    fn fit(points: &[Vec3]) -> Option<Plane> {
+        plane_fit(points).map(|(plane, _)| plane)
+    }
+    fn distance(&self, point: Vec3) -> f64 {
+        self.signed_distance(point).abs()
+    }
+}
+
+// indices of points within threshold and sum of their distances
+fn inliers<M: Model>(model: &M, points: &[M::Point], threshold: f64) -> (Vec<usize>, f64) {
+    let mut total = 0.0;
+    let ids = points.iter().enumerate()
+        .filter_map(|(i, &p)| {
+            let d = model.distance(p);
+            if d <= threshold {
+                total += d;
+                Some(i)
+            } else {
+                None
+            }
+        })
+        .collect();
+    (ids, total)
+}
+
+/// Model fitted to points robustly against outliers, with indices of its
+/// inliers in ascending order
+///
+/// Runs `iterations` rounds of fitting model to random minimal sample;
+/// candidate with more inliers (points within `threshold` from model) wins,
+/// ties go to smaller sum of inlier distances. Winner is refitted to its
+/// inliers. Returns `None` if there are fewer points than model needs or
+/// all samples are degenerate.
+///
+/// # Example
+/// ```
+/// # extern crate rand;
+/// # extern crate linal;
+/// # use linal::{Line2, Vec2};
+/// # use linal::ransac::ransac;
+/// # use rand::SeedableRng;
+/// let mut points: Vec<Vec2> = (0..20).map(|i| Vec2::new(i as f64, 2.0 * i as f64 + 1.0)).collect();
+/// points.push(Vec2::new(5, -30));
+/// points.push(Vec2::new(-7, 40));
+/// let mut rng = rand::rngs::StdRng::seed_from_u64(1);
+/// let (line, inliers): (Line2, _) = ransac(&points, 0.01, 50, &mut rng).unwrap();
+/// assert_eq!(inliers, (0..20).collect::<Vec<_>>());
+/// assert!(line.dir.area(Vec2::new(1, 2)).abs() < 1e-9);
+/// ```
+pub fn ransac<M, R>(points: &[M::Point], threshold: f64, iterations: usize, rng: &mut R) -> Option<(M, Vec<usize>)>
+    where M: Model, R: Rng + ?Sized
+{
+    if points.len() < M::SAMPLE_SIZE {
+        return None;
+    }
+    let mut best: Option<(M, Vec<usize>, f64)> = None;
+    let mut sample = Vec::with_capacity(M::SAMPLE_SIZE);
+    for _ in 0..iterations {
+        sample.clear();
+        sample.extend(index::sample(rng, points.len(), M::SAMPLE_SIZE).iter().map(|i| points[i]));
+        let model = match M::fit(&sample) {
+            Some(model) => model,
+            None => continue,
+        };
+        let (ids, total) = inliers(&model, points, threshold);
+        let better = best.as_ref().map_or(true, |&(_, ref best_ids, best_total)| {
+            ids.len() > best_ids.len() || (ids.len() == best_ids.len() && total < best_total)
+        });
+        if better {
+            best = Some((model, ids, total));
+        }
+    }
+    let (model, ids, _) = best?;
+    let support: Vec<M::Point> = ids.iter().map(|&i| points[i]).collect();
+    match M::fit(&support) {
+        Some(refined) => {
+            let (refined_ids, _) = inliers(&refined, points, threshold);
+            // refit may drift away when inliers are barely within threshold
+            if refined_ids.len() >= ids.len() {
+                return Some((refined, refined_ids));
+            }
+            Some((model, ids))
+        }
+        None => Some((model, ids)),
+    }
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    #[test]
+    fn ransac_circle_and_plane() {
+        let mut rng = StdRng::seed_from_u64(7);
+        // noisy circle with 30% outliers
+        let mut points: Vec<Vec2> = (0..70)
+            .map(|i| Vec2::new(3, -1) + Vec2::from_polar(2.0 + 0.01 * (i % 3) as f64, 0.3 * i as f64))
+            .collect();
+        points.extend((0..30).map(|_| Vec2::new(rng.gen_range(-5.0..10.0), rng.gen_range(-8.0..6.0))));
+        let (circle, inliers): (Circle, _) = ransac(&points, 0.05, 200, &mut rng).unwrap();
+        assert!((circle.center - Vec2::new(3, -1)).len() < 0.02);
+        assert!((circle.radius - 2.01).abs() < 0.02);
+        assert!((0..70).all(|i| inliers.contains(&i)));
+        assert!(circle_fit(&points).map_or(true, |(c, _)| (c - Vec2::new(3, -1)).len() > 0.02));
+        let mut points3: Vec<Vec3> = (0..50)
+            .map(|i| Vec3::new((i % 7) as f64, (i / 7) as f64, 1.0 + 0.5 * (i % 7) as f64))
+            .collect();
+        points3.extend((0..20).map(|i| Vec3::new(i as f64 * 0.3, 2.0, 20.0 - i as f64)));
+        let (plane, inliers): (Plane, _) = ransac(&points3, 1e-6, 100, &mut rng).unwrap();
+        assert_eq!(inliers.len(), 50);
+        assert!(plane.signed_distance(Vec3::new(10.0, -3.0, 6.0)).abs() < 1e-9);
+        assert!(ransac::<Plane, _>(&points3[..2], 1.0, 10, &mut rng).is_none());
+    }
+}
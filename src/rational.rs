@@ -0,0 +1,296 @@
+//! Exact vectors over rational numbers, available with `rational` feature.
+//!
+//! Every finite `f64` is a rational number, so plain vectors convert to
+//! rational ones without loss. Constructions are then carried out exactly
+//! and results can be rounded back to `f64`.
+use std::cmp::Ordering;
+use std::ops::{Add, Sub, Mul, Neg};
+use num_rational::BigRational;
+use num_traits::{Signed, ToPrimitive, Zero};
+use vec2::Vec2;
+use vec3::Vec3;
+
+fn exact(x: f64) -> BigRational {
+    BigRational::from_float(x).expect("coordinate must be finite")
+}
+
+fn round(x: &BigRational) -> f64 {
+    x.to_f64().unwrap_or(f64::NAN)
+}
+
+/// Vector on a plane with exact rational coordinates
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RVec2 {
+    /// x coordinate
+    pub x: BigRational,
+    /// y coordinate
+    pub y: BigRational,
+}
+
+impl RVec2 {
+    /// Constructs a new `RVec2`
+    pub fn new(x: BigRational, y: BigRational) -> RVec2 {
+        RVec2 { x, y }
+    }
+    /// Zero vector
+    pub fn zero() -> RVec2 {
+        RVec2::new(BigRational::zero(), BigRational::zero())
+    }
+    /// Vector rounded to nearest floating point coordinates
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Vec2;
+    /// # use linal::rational::RVec2;
+    /// let v = RVec2::from(Vec2::new(0.1, 3.0));
+    /// assert_eq!(v.to_vec2(), Vec2::new(0.1, 3.0));
+    /// ```
+    pub fn to_vec2(&self) -> Vec2 {
+        Vec2::new(round(&self.x), round(&self.y))
+    }
+    /// Scalar product
+    pub fn dot(&self, rhs: &RVec2) -> BigRational {
+        &self.x * &rhs.x + &self.y * &rhs.y
+    }
+    /// Oriented area of parallelogram built on vectors
+    pub fn area(&self, rhs: &RVec2) -> BigRational {
+        &self.x * &rhs.y - &self.y * &rhs.x
+    }
+}
+
+impl From<Vec2> for RVec2 {
+    /// Exact conversion
+    ///
+    /// # Panics
+    /// Panics if any coordinate is NaN or infinite.
+    fn from(v: Vec2) -> RVec2 {
+        RVec2::new(exact(v.x), exact(v.y))
+    }
+}
+
+impl Add for &RVec2 {
+    type Output = RVec2;
+    fn add(self, rhs: &RVec2) -> RVec2 {
+        RVec2::new(&self.x + &rhs.x, &self.y + &rhs.y)
+    }
+}
+
+impl Sub for &RVec2 {
+    type Output = RVec2;
+    fn sub(self, rhs: &RVec2) -> RVec2 {
+        RVec2::new(&self.x - &rhs.x, &self.y - &rhs.y)
+    }
+}
+
+impl<'a> Mul<&'a BigRational> for &'a RVec2 {
+    type Output = RVec2;
+    fn mul(self, rhs: &BigRational) -> RVec2 {
+        RVec2::new(&self.x * rhs, &self.y * rhs)
+    }
+}
+
+impl Neg for &RVec2 {
+    type Output = RVec2;
+    fn neg(self) -> RVec2 {
+        RVec2::new(-&self.x, -&self.y)
+    }
+}
+
+/// Vector in space with exact rational coordinates
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RVec3 {
+    /// x coordinate
+    pub x: BigRational,
+    /// y coordinate
+    pub y: BigRational,
+    /// z coordinate
+    pub z: BigRational,
+}
+
+impl RVec3 {
+    /// Constructs a new `RVec3`
+    pub fn new(x: BigRational, y: BigRational, z: BigRational) -> RVec3 {
+        RVec3 { x, y, z }
+    }
+    /// Zero vector
+    pub fn zero() -> RVec3 {
+        RVec3::new(BigRational::zero(), BigRational::zero(), BigRational::zero())
+    }
+    /// Vector rounded to nearest floating point coordinates
+    pub fn to_vec3(&self) -> Vec3 {
+        Vec3::new(round(&self.x), round(&self.y), round(&self.z))
+    }
+    /// Scalar product
+    pub fn dot(&self, rhs: &RVec3) -> BigRational {
+        &self.x * &rhs.x + &self.y * &rhs.y + &self.z * &rhs.z
+    }
+    /// Vector product
+    pub fn cross(&self, rhs: &RVec3) -> RVec3 {
+        RVec3::new(&self.y * &rhs.z - &self.z * &rhs.y,
+                   &self.z * &rhs.x - &self.x * &rhs.z,
+                   &self.x * &rhs.y - &self.y * &rhs.x)
+    }
+}
+
+impl From<Vec3> for RVec3 {
+    /// Exact conversion
+    ///
+    /// # Panics
+    /// Panics if any coordinate is NaN or infinite.
+    fn from(v: Vec3) -> RVec3 {
+        RVec3::new(exact(v.x), exact(v.y), exact(v.z))
+    }
+}
+
+impl Add for &RVec3 {
+    type Output = RVec3;
+    fn add(self, rhs: &RVec3) -> RVec3 {
+        RVec3::new(&self.x + &rhs.x, &self.y + &rhs.y, &self.z + &rhs.z)
+    }
+}
+
+impl Sub for &RVec3 {
+    type Output = RVec3;
+    fn sub(self, rhs: &RVec3) -> RVec3 {
+        RVec3::new(&self.x - &rhs.x, &self.y - &rhs.y, &self.z - &rhs.z)
+    }
+}
+
+impl<'a> Mul<&'a BigRational> for &'a RVec3 {
+    type Output = RVec3;
+    fn mul(self, rhs: &BigRational) -> RVec3 {
+        RVec3::new(&self.x * rhs, &self.y * rhs, &self.z * rhs)
+    }
+}
+
+impl Neg for &RVec3 {
+    type Output = RVec3;
+    fn neg(self) -> RVec3 {
+        RVec3::new(-&self.x, -&self.y, -&self.z)
+    }
+}
+
+/// Exact orientation of triangle `a`, `b`, `c`
+///
+/// Returns `Greater` for counterclockwise order, `Less` for clockwise and
+/// `Equal` for collinear points. Unlike floating point test, result is never
+/// spoiled by rounding.
+///
+/// # Example
+/// ```
+/// # use linal::Vec2;
+/// # use linal::rational::orientation;
+/// # use std::cmp::Ordering;
+/// let (a, b) = (Vec2::new(0.1, 0.1), Vec2::new(0.3, 0.3));
+/// assert_eq!(orientation(a, b, Vec2::new(0.7, 0.7)), Ordering::Equal);
+/// assert_eq!(orientation(a, b, Vec2::new(0.0, 1.0)), Ordering::Greater);
+/// ```
+pub fn orientation(a: Vec2, b: Vec2, c: Vec2) -> Ordering {
+    let (a, b, c) = (RVec2::from(a), RVec2::from(b), RVec2::from(c));
+    let area = (&b - &a).area(&(&c - &a));
+    if area.is_positive() {
+        Ordering::Greater
+    } else if area.is_negative() {
+        Ordering::Less
+    } else {
+        Ordering::Equal
+    }
+}
+
+/// Exact intersection point of lines through `a`, `b` and through `c`, `d`
+///
+/// Returns `None` for parallel or degenerate lines.
+///
+/// # Example
+/// ```
+/// # use linal::Vec2;
+/// # use linal::rational::line_intersection;
+/// let p = line_intersection(Vec2::new(0, 0), Vec2::new(3, 1),
+///                           Vec2::new(0, 1), Vec2::new(3, 0)).unwrap();
+/// assert_eq!(p.to_vec2(), Vec2::new(1.5, 0.5));
+/// ```
+pub fn line_intersection(a: Vec2, b: Vec2, c: Vec2, d: Vec2) -> Option<RVec2> {
+    let (a, b, c, d) = (RVec2::from(a), RVec2::from(b), RVec2::from(c), RVec2::from(d));
+    let (r, s) = (&b - &a, &d - &c);
+    let denom = r.area(&s);
+    if denom.is_zero() {
+        return None;
+    }
+    let t = (&c - &a).area(&s) / denom;
+    Some(&a + &(&r * &t))
+}
+
+/// Exact convex hull of points
+///
+/// Same as [`convex_hull`](../hull/fn.convex_hull.html), but orientation
+/// tests are exact, so nearly collinear points are classified correctly.
+///
+/// # Example
+/// ```
+/// # use linal::Vec2;
+/// # use linal::rational::convex_hull;
+/// // tiny bulge of the bottom edge is kept
+/// let points = [Vec2::new(0.0, 0.0), Vec2::new(1.0, 0.0), Vec2::new(0.5, -1e-300), Vec2::new(0.0, 1.0)];
+/// assert_eq!(convex_hull(&points).len(), 4);
+/// ```
+pub fn convex_hull(points: &[Vec2]) -> Vec<Vec2> {
+    let mut sorted = points.to_vec();
+    sorted.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+    sorted.dedup();
+    if sorted.len() < 3 {
+        return sorted;
+    }
+    let mut hull: Vec<Vec2> = Vec::with_capacity(2 * sorted.len());
+    let half = |hull: &mut Vec<Vec2>, p: Vec2, base: usize| {
+        while hull.len() >= base + 2 &&
+              orientation(hull[hull.len() - 2], hull[hull.len() - 1], p) != Ordering::Greater {
+            hull.pop();
+        }
+        hull.push(p);
+    };
+    for &p in &sorted {
+        half(&mut hull, p, 0);
+    }
+    let base = hull.len() - 1;
+    for &p in sorted.iter().rev().skip(1) {
+        half(&mut hull, p, base);
+    }
+    hull.pop();
+    hull
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    #[test]
+    fn rational_orientation_robust() {
+        // classic failure of floating point predicate: points near a line
+        let (q, r) = (Vec2::new(12.0, 12.0), Vec2::new(24.0, 24.0));
+        let mut found_disagreement = false;
+        for i in 0..32 {
+            for j in 0..32 {
+                let p = Vec2::new(0.5 + i as f64 * f64::EPSILON, 0.5 + j as f64 * f64::EPSILON);
+                let exact = orientation(p, q, r);
+                // points above diagonal are counterclockwise
+                assert_eq!(exact, p.y.partial_cmp(&p.x).unwrap());
+                let float = (q - p).area(r - p);
+                if float.partial_cmp(&0.0).unwrap() != exact {
+                    found_disagreement = true;
+                }
+            }
+        }
+        assert!(found_disagreement);
+    }
+
+    #[test]
+    fn rational_round_trip() {
+        let v = Vec3::new(0.1, -1e300, 3.5e-310);
+        assert_eq!(RVec3::from(v).to_vec3(), v);
+        let a = RVec3::from(Vec3::new(1, 0, 0));
+        let b = RVec3::from(Vec3::new(0, 1, 0));
+        assert_eq!(a.cross(&b).to_vec3(), Vec3::new(0, 0, 1));
+        assert!(line_intersection(Vec2::new(0, 0), Vec2::new(1, 1),
+                                  Vec2::new(0, 1), Vec2::new(1, 2)).is_none());
+    }
+}
@@ -0,0 +1,62 @@
+//! Rays on a plane.
+use vec2::Vec2;
+
+/// Ray on a plane, defined by origin and unit direction
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ray2 {
+    /// origin of the ray
+    pub origin: Vec2,
+    /// unit direction of the ray
+    pub dir: Vec2,
+}
+
+impl Ray2 {
+    /// Constructs a new `Ray2` from `origin` along `dir`.
+    ///
+    /// Direction is normalized, so parameter of [`at`](#method.at) is a
+    /// distance from origin.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Ray2, Vec2};
+    /// let ray = Ray2::new(Vec2::new(1, 2), Vec2::new(3, 4));
+    /// assert_eq!(ray.dir, Vec2::new(0.6, 0.8));
+    /// ```
+    pub fn new(origin: Vec2, dir: Vec2) -> Ray2 {
+        Ray2 {
+            origin,
+            dir: dir.ort(),
+        }
+    }
+    /// Point of the ray at distance `t` from origin
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Ray2, Vec2};
+    /// let ray = Ray2::new(Vec2::new(1, 2), Vec2::new(3, 4));
+    /// assert_eq!(ray.at(5), Vec2::new(4, 6));
+    /// ```
+    pub fn at<I: Into<f64>>(self, t: I) -> Vec2 {
+        self.origin + self.dir * t.into()
+    }
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    #[test]
+    fn ray2_at() {
+        let ray = Ray2::new(Vec2::new(-1, 1), Vec2::new(2, 0));
+        assert_eq!(ray.at(0), ray.origin);
+        assert_eq!(ray.at(3), Vec2::new(2, 1));
+        assert!(((ray.at(2.5) - ray.origin).len() - 2.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn ray2_normalized() {
+        let ray = Ray2::new(Vec2::new(3, -4), Vec2::new(3, -4) * 7);
+        assert!((ray.dir.len() - 1.0).abs() < 1e-12);
+        assert!((ray.dir - Vec2::new(3, -4).ort()).len() < 1e-12);
+    }
+}
@@ -0,0 +1,62 @@
+//! Rays in 3-dimensional euclidian space.
+use vec3::Vec3;
+
+/// Ray in 3-dimensional euclidian space, defined by origin and unit direction
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ray3 {
+    /// origin of the ray
+    pub origin: Vec3,
+    /// unit direction of the ray
+    pub dir: Vec3,
+}
+
+impl Ray3 {
+    /// Constructs a new `Ray3` from `origin` along `dir`.
+    ///
+    /// Direction is normalized, so parameter of [`at`](#method.at) is a
+    /// distance from origin.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Ray3, Vec3};
+    /// let ray = Ray3::new(Vec3::new(1, 2, 3), Vec3::new(0, 0, -2));
+    /// assert_eq!(ray.dir, Vec3::new(0, 0, -1));
+    /// ```
+    pub fn new(origin: Vec3, dir: Vec3) -> Ray3 {
+        Ray3 {
+            origin,
+            dir: dir.ort(),
+        }
+    }
+    /// Point of the ray at distance `t` from origin
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Ray3, Vec3};
+    /// let ray = Ray3::new(Vec3::new(1, 2, 3), Vec3::new(0, 0, -2));
+    /// assert_eq!(ray.at(5), Vec3::new(1, 2, -2));
+    /// ```
+    pub fn at<I: Into<f64>>(self, t: I) -> Vec3 {
+        self.origin + self.dir * t.into()
+    }
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    #[test]
+    fn ray3_at() {
+        let ray = Ray3::new(Vec3::new(-1, 1, 0), Vec3::new(0, 0, 5));
+        assert_eq!(ray.at(0), ray.origin);
+        assert_eq!(ray.at(3), Vec3::new(-1, 1, 3));
+        assert!(((ray.at(2.5) - ray.origin).len() - 2.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn ray3_normalized() {
+        let ray = Ray3::new(Vec3::new(2, -3, 6), Vec3::new(2, -3, 6) * 7);
+        assert!((ray.dir.len() - 1.0).abs() < 1e-12);
+        assert!((ray.dir - Vec3::new(2, -3, 6).ort()).len() < 1e-12);
+    }
+}
@@ -0,0 +1,303 @@
+//! Axis-aligned rectangles on a plane.
+use vec2::Vec2;
+
+/// Axis-aligned rectangle, defined by minimal and maximal corners
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rect {
+    /// corner with minimal coordinates
+    pub min: Vec2,
+    /// corner with maximal coordinates
+    pub max: Vec2,
+}
+
+impl Rect {
+    /// Constructs a new `Rect` from two opposite corners.
+    ///
+    /// Corners can be given in any order.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Rect, Vec2};
+    /// let r = Rect::new(Vec2::new(3, 0), Vec2::new(1, 2));
+    /// assert_eq!(r.min, Vec2::new(1, 0));
+    /// assert_eq!(r.max, Vec2::new(3, 2));
+    /// ```
+    pub fn new(a: Vec2, b: Vec2) -> Rect {
+        Rect {
+            min: Vec2::new(a.x.min(b.x), a.y.min(b.y)),
+            max: Vec2::new(a.x.max(b.x), a.y.max(b.y)),
+        }
+    }
+    /// Constructs a new `Rect` from center and half-extents.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Rect, Vec2};
+    /// let r = Rect::from_center(Vec2::new(2, 1), Vec2::new(1, 3));
+    /// assert_eq!(r, Rect::new(Vec2::new(1, -2), Vec2::new(3, 4)));
+    /// ```
+    pub fn from_center(center: Vec2, half_extents: Vec2) -> Rect {
+        Rect::new(center - half_extents, center + half_extents)
+    }
+    /// Smallest rectangle containing all points
+    ///
+    /// Returns `None` for empty iterator. NaN coordinates are ignored.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Rect, Vec2};
+    /// let points = vec![Vec2::new(1, 5), Vec2::new(-2, 3), Vec2::new(4, -1)];
+    /// let r = Rect::from_points(points).unwrap();
+    /// assert_eq!(r, Rect::new(Vec2::new(-2, -1), Vec2::new(4, 5)));
+    /// assert_eq!(Rect::from_points(Vec::new()), None);
+    /// ```
+    pub fn from_points<T: IntoIterator<Item = Vec2>>(points: T) -> Option<Rect> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(points.fold(Rect { min: first, max: first }, |r, p| Rect {
+            min: Vec2::new(r.min.x.min(p.x), r.min.y.min(p.y)),
+            max: Vec2::new(r.max.x.max(p.x), r.max.y.max(p.y)),
+        }))
+    }
+    /// Bounding rectangle of points, expanded by `margin` on each side
+    ///
+    /// Same as [`expand`](#method.expand), negative `margin` never inverts
+    /// rectangle.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Rect, Vec2};
+    /// let points = [Vec2::new(0, 0), Vec2::new(2, 1)];
+    /// let r = Rect::from_iter_with_margin(points.iter().cloned(), 0.5).unwrap();
+    /// assert_eq!(r, Rect::new(Vec2::new(-0.5, -0.5), Vec2::new(2.5, 1.5)));
+    /// ```
+    pub fn from_iter_with_margin<T, I>(points: T, margin: I) -> Option<Rect>
+        where T: IntoIterator<Item = Vec2>, I: Into<f64>
+    {
+        Rect::from_points(points).map(|r| r.expand(margin))
+    }
+    /// Center of rectangle
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Rect, Vec2};
+    /// let r = Rect::new(Vec2::new(0, 0), Vec2::new(4, 2));
+    /// assert_eq!(r.center(), Vec2::new(2, 1));
+    /// ```
+    pub fn center(self) -> Vec2 {
+        (self.min + self.max) * 0.5
+    }
+    /// Half-extents (half of width and height) of rectangle
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Rect, Vec2};
+    /// let r = Rect::new(Vec2::new(0, 0), Vec2::new(4, 2));
+    /// assert_eq!(r.half_extents(), Vec2::new(2, 1));
+    /// ```
+    pub fn half_extents(self) -> Vec2 {
+        (self.max - self.min) * 0.5
+    }
+    /// Checks if point lies inside rectangle or on its boundary
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Rect, Vec2};
+    /// let r = Rect::new(Vec2::new(0, 0), Vec2::new(4, 2));
+    /// assert!(r.contains(Vec2::new(1, 1)));
+    /// assert!(r.contains(Vec2::new(4, 2)));
+    /// assert!(!r.contains(Vec2::new(5, 1)));
+    /// ```
+    pub fn contains(self, point: Vec2) -> bool {
+        self.min.x <= point.x && point.x <= self.max.x &&
+        self.min.y <= point.y && point.y <= self.max.y
+    }
+    /// Checks if rectangles overlap or touch
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Rect, Vec2};
+    /// let a = Rect::new(Vec2::new(0, 0), Vec2::new(4, 2));
+    /// let b = Rect::new(Vec2::new(3, 1), Vec2::new(5, 5));
+    /// let c = Rect::new(Vec2::new(5, 0), Vec2::new(6, 1));
+    /// assert!(a.intersects(b));
+    /// assert!(!a.intersects(c));
+    /// ```
+    pub fn intersects(self, other: Rect) -> bool {
+        self.min.x <= other.max.x && other.min.x <= self.max.x &&
+        self.min.y <= other.max.y && other.min.y <= self.max.y
+    }
+    /// Point of rectangle closest to given
+    ///
+    /// Returns point itself if it lies inside rectangle.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Rect, Vec2};
+    /// let r = Rect::new(Vec2::new(0, 0), Vec2::new(4, 2));
+    /// assert_eq!(r.closest_point(Vec2::new(6, 1)), Vec2::new(4, 1));
+    /// assert_eq!(r.closest_point(Vec2::new(-1, -1)), Vec2::new(0, 0));
+    /// assert_eq!(r.closest_point(Vec2::new(1, 1)), Vec2::new(1, 1));
+    /// ```
+    pub fn closest_point(self, point: Vec2) -> Vec2 {
+        Vec2::new(point.x.clamp(self.min.x, self.max.x),
+                  point.y.clamp(self.min.y, self.max.y))
+    }
+    /// Smallest rectangle containing both rectangles
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Rect, Vec2};
+    /// let a = Rect::new(Vec2::new(0, 0), Vec2::new(1, 1));
+    /// let b = Rect::new(Vec2::new(2, 2), Vec2::new(3, 3));
+    /// assert_eq!(a.union(b), Rect::new(Vec2::new(0, 0), Vec2::new(3, 3)));
+    /// ```
+    pub fn union(self, other: Rect) -> Rect {
+        Rect {
+            min: Vec2::new(self.min.x.min(other.min.x), self.min.y.min(other.min.y)),
+            max: Vec2::new(self.max.x.max(other.max.x), self.max.y.max(other.max.y)),
+        }
+    }
+    /// Common part of rectangles
+    ///
+    /// Returns `None` if rectangles don't intersect, touching rectangles give
+    /// degenerate rectangle.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Rect, Vec2};
+    /// let a = Rect::new(Vec2::new(0, 0), Vec2::new(2, 2));
+    /// let b = Rect::new(Vec2::new(1, 1), Vec2::new(3, 3));
+    /// assert_eq!(a.intersection(b), Some(Rect::new(Vec2::new(1, 1), Vec2::new(2, 2))));
+    /// assert_eq!(a.intersection(Rect::new(Vec2::new(3, 3), Vec2::new(4, 4))), None);
+    /// ```
+    pub fn intersection(self, other: Rect) -> Option<Rect> {
+        if !self.intersects(other) {
+            return None;
+        }
+        Some(Rect {
+            min: Vec2::new(self.min.x.max(other.min.x), self.min.y.max(other.min.y)),
+            max: Vec2::new(self.max.x.min(other.max.x), self.max.y.min(other.max.y)),
+        })
+    }
+    /// Rectangle expanded by `margin` on each side
+    ///
+    /// Negative `margin` shrinks rectangle, sides that would pass each other
+    /// collapse to the center.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Rect, Vec2};
+    /// let r = Rect::new(Vec2::new(0, 0), Vec2::new(4, 2));
+    /// assert_eq!(r.expand(1), Rect::new(Vec2::new(-1, -1), Vec2::new(5, 3)));
+    /// assert_eq!(r.expand(-1.5), Rect::new(Vec2::new(1.5, 1.0), Vec2::new(2.5, 1.0)));
+    /// ```
+    pub fn expand<I: Into<f64>>(self, margin: I) -> Rect {
+        let margin = margin.into();
+        let (d, c) = (Vec2::new(margin, margin), self.center());
+        let (min, max) = (self.min - d, self.max + d);
+        Rect {
+            min: Vec2::new(min.x.min(c.x), min.y.min(c.y)),
+            max: Vec2::new(max.x.max(c.x), max.y.max(c.y)),
+        }
+    }
+    /// Checks if other rectangle lies inside this one, boundaries may touch
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Rect, Vec2};
+    /// let a = Rect::new(Vec2::new(0, 0), Vec2::new(3, 3));
+    /// assert!(a.contains_rect(Rect::new(Vec2::new(1, 1), Vec2::new(3, 3))));
+    /// assert!(!a.contains_rect(Rect::new(Vec2::new(1, 1), Vec2::new(4, 4))));
+    /// ```
+    pub fn contains_rect(self, other: Rect) -> bool {
+        self.contains(other.min) && self.contains(other.max)
+    }
+    /// Smallest rectangle containing this one and all points
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Rect, Vec2};
+    /// let r = Rect::new(Vec2::new(0, 0), Vec2::new(1, 1));
+    /// let merged = r.merge_points(&[Vec2::new(3, 3), Vec2::new(-1, -1)]);
+    /// assert_eq!(merged, Rect::new(Vec2::new(-1, -1), Vec2::new(3, 3)));
+    /// ```
+    pub fn merge_points(self, points: &[Vec2]) -> Rect {
+        points.iter().fold(self, |r, &p| r.union(Rect { min: p, max: p }))
+    }
+    /// Corners of rectangle in counterclockwise order, starting from `min`
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Rect, Vec2};
+    /// let r = Rect::new(Vec2::new(0, 0), Vec2::new(4, 2));
+    /// let corners = r.corners();
+    /// assert_eq!(corners[1], Vec2::new(4, 0));
+    /// assert_eq!(corners[3], Vec2::new(0, 2));
+    /// assert!(corners.iter().all(|&c| r.contains(c)));
+    /// ```
+    pub fn corners(self) -> [Vec2; 4] {
+        [self.min,
+         Vec2::new(self.max.x, self.min.y),
+         self.max,
+         Vec2::new(self.min.x, self.max.y)]
+    }
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    #[test]
+    fn rect_intersects() {
+        let a = Rect::new(Vec2::new(0, 0), Vec2::new(2, 2));
+        assert!(a.intersects(a));
+        assert!(a.intersects(Rect::new(Vec2::new(2, 2), Vec2::new(3, 3))));
+        assert!(a.intersects(Rect::new(Vec2::new(-1, -1), Vec2::new(3, 3))));
+        assert!(!a.intersects(Rect::new(Vec2::new(0, 3), Vec2::new(2, 4))));
+        assert!(!a.intersects(Rect::new(Vec2::new(-2, 0), Vec2::new(-1, 2))));
+    }
+
+    #[test]
+    fn rect_closest_point() {
+        let r = Rect::from_center(Vec2::new(0, 0), Vec2::new(1, 2));
+        assert_eq!(r.closest_point(Vec2::new(5, 5)), Vec2::new(1, 2));
+        assert_eq!(r.closest_point(Vec2::new(0, -5)), Vec2::new(0, -2));
+        assert_eq!(r.closest_point(Vec2::new(0.5, 0.5)), Vec2::new(0.5, 0.5));
+    }
+
+    #[test]
+    fn rect_from_center() {
+        let r = Rect::from_center(Vec2::new(1, 1), Vec2::new(2, 3));
+        assert_eq!(r.center(), Vec2::new(1, 1));
+        assert_eq!(r.half_extents(), Vec2::new(2, 3));
+    }
+
+    #[test]
+    fn rect_from_points() {
+        let points = [Vec2::new(f64::NAN, 1.0), Vec2::new(3, -2), Vec2::new(-1, 0)];
+        let r = Rect::from_points(points.iter().cloned()).unwrap();
+        assert_eq!(r, Rect::new(Vec2::new(-1, -2), Vec2::new(3, 1)));
+        assert!(points[1..].iter().all(|&p| r.contains(p)));
+        let single = Rect::from_points(Some(Vec2::new(1, 1))).unwrap();
+        assert_eq!(single.half_extents(), Vec2::zero());
+        let shrunk = Rect::from_iter_with_margin(points[1..].iter().cloned(), -2.0).unwrap();
+        assert_eq!(shrunk, Rect::new(Vec2::new(1, -1), Vec2::new(1, -1)));
+    }
+
+    #[test]
+    fn rect_set_operations() {
+        let a = Rect::new(Vec2::new(0, 0), Vec2::new(2, 2));
+        let b = Rect::new(Vec2::new(1, -1), Vec2::new(3, 1));
+        let u = a.union(b);
+        assert!(u.contains_rect(a) && u.contains_rect(b));
+        let i = a.intersection(b).unwrap();
+        assert_eq!(i, Rect::new(Vec2::new(1, 0), Vec2::new(2, 1)));
+        assert!(a.contains_rect(i) && b.contains_rect(i));
+        let touching = a.intersection(Rect::new(Vec2::new(2, 0), Vec2::new(3, 2))).unwrap();
+        assert_eq!(touching.half_extents().x, 0.0);
+        assert_eq!(a.expand(1).expand(-1), a);
+        assert_eq!(a.expand(-5), Rect::new(Vec2::new(1, 1), Vec2::new(1, 1)));
+        assert_eq!(a.merge_points(&[]), a);
+    }
+}
@@ -0,0 +1,218 @@
+//! Well-spread point sets: blue noise and low-discrepancy sequences.
+#[cfg(feature = "rand")]
+use std::f64::consts::PI;
+#[cfg(feature = "rand")]
+use rand::Rng;
+use aabb3::Aabb3;
+use rect::Rect;
+use vec2::Vec2;
+use vec3::Vec3;
+
+// candidates tried around each active sample
+#[cfg(feature = "rand")]
+const POISSON_ATTEMPTS: usize = 30;
+
+/// Radical inverse of `index` in given `base`
+///
+/// Digits of index are mirrored around the radix point, so the result lies
+/// in $[0, 1)$. It's base of van der Corput, Halton and Hammersley sequences.
+///
+/// # Panics
+/// Panics if `base` is less than 2.
+///
+/// # Example
+/// ```
+/// # use linal::sampling::radical_inverse;
+/// // 6 = 110 in binary, mirrored 0.011 = 3 / 8
+/// assert_eq!(radical_inverse(2, 6), 0.375);
+/// assert_eq!(radical_inverse(3, 1), 1.0 / 3.0);
+/// ```
+pub fn radical_inverse(base: u64, mut index: u64) -> f64 {
+    assert!(base >= 2, "base of radical inverse must be at least 2");
+    let inv = 1.0 / base as f64;
+    let (mut result, mut scale) = (0.0, inv);
+    while index > 0 {
+        result += (index % base) as f64 * scale;
+        index /= base;
+        scale *= inv;
+    }
+    result
+}
+
+/// First `n` points of Halton sequence in rectangle
+///
+/// Coordinates are radical inverses in bases 2 and 3, index starts from 1
+/// to skip `rect.min`. Prefix of any length is well spread, so sequence is
+/// suitable for progressive quasi-Monte Carlo integration.
+///
+/// # Example
+/// ```
+/// # use linal::{Rect, Vec2};
+/// # use linal::sampling::halton;
+/// let rect = Rect::new(Vec2::zero(), Vec2::new(2, 3));
+/// let points: Vec<Vec2> = halton(rect, 3).collect();
+/// assert_eq!(points[0], Vec2::new(1, 1));
+/// assert_eq!(points[1], Vec2::new(0.5, 2.0));
+/// ```
+pub fn halton(rect: Rect, n: usize) -> impl Iterator<Item = Vec2> {
+    let size = rect.max - rect.min;
+    (1..=n as u64).map(move |i| {
+        rect.min + Vec2::new(radical_inverse(2, i) * size.x, radical_inverse(3, i) * size.y)
+    })
+}
+
+/// First `n` points of Halton sequence in box
+///
+/// Coordinates are radical inverses in bases 2, 3 and 5.
+///
+/// # Example
+/// ```
+/// # use linal::{Aabb3, Vec3};
+/// # use linal::sampling::halton3;
+/// let aabb = Aabb3::new(Vec3::zero(), Vec3::new(1, 1, 1));
+/// let p = halton3(aabb, 1).next().unwrap();
+/// assert_eq!(p, Vec3::new(0.5, 1.0 / 3.0, 0.2));
+/// ```
+pub fn halton3(aabb: Aabb3, n: usize) -> impl Iterator<Item = Vec3> {
+    let size = aabb.max - aabb.min;
+    (1..=n as u64).map(move |i| {
+        aabb.min + Vec3::new(radical_inverse(2, i) * size.x, radical_inverse(3, i) * size.y,
+                             radical_inverse(5, i) * size.z)
+    })
+}
+
+/// Hammersley set of `n` points in rectangle
+///
+/// Point `i` has coordinates $(i / n, \Phi_2(i))$, where $\Phi_2$ is
+/// [`radical_inverse`](fn.radical_inverse.html) in base 2. Set has lower
+/// discrepancy than Halton sequence, but its size must be known in advance.
+///
+/// # Example
+/// ```
+/// # use linal::{Rect, Vec2};
+/// # use linal::sampling::hammersley;
+/// let rect = Rect::new(Vec2::zero(), Vec2::new(1, 1));
+/// let points: Vec<Vec2> = hammersley(rect, 4).collect();
+/// assert_eq!(points, vec![Vec2::new(0, 0), Vec2::new(0.25, 0.5),
+///                         Vec2::new(0.5, 0.25), Vec2::new(0.75, 0.75)]);
+/// ```
+pub fn hammersley(rect: Rect, n: usize) -> impl Iterator<Item = Vec2> {
+    let size = rect.max - rect.min;
+    (0..n as u64).map(move |i| {
+        rect.min + Vec2::new(i as f64 / n as f64 * size.x, radical_inverse(2, i) * size.y)
+    })
+}
+
+/// Poisson disk sampling of rectangle by Bridson's algorithm
+///
+/// Generates points in rectangle no closer than `radius` to each other,
+/// such that no more point can be added nearby any of them, so points are
+/// evenly but irregularly spread (blue noise). Available with `rand` feature.
+///
+/// # Panics
+/// Panics if `radius` isn't positive.
+///
+/// # Example
+/// ```
+/// # extern crate rand;
+/// # extern crate linal;
+/// # use linal::{Rect, Vec2};
+/// # use linal::sampling::poisson_disk;
+/// # fn main() {
+/// let rect = Rect::new(Vec2::zero(), Vec2::new(10, 10));
+/// let points = poisson_disk(rect, 1.0, &mut rand::thread_rng());
+/// assert!(points.iter().all(|p| rect.contains(*p)));
+/// for (i, p) in points.iter().enumerate() {
+///     assert!(points[..i].iter().all(|q| (*p - *q).len() >= 1.0));
+/// }
+/// # }
+/// ```
+#[cfg(feature = "rand")]
+pub fn poisson_disk<R: Rng + ?Sized>(rect: Rect, radius: f64, rng: &mut R) -> Vec<Vec2> {
+    assert!(radius > 0.0, "radius of Poisson disk sampling must be positive");
+    // cell is small enough to hold at most one sample
+    let cell = radius / 2f64.sqrt();
+    let size = rect.max - rect.min;
+    let nx = ((size.x / cell).ceil() as usize).max(1);
+    let ny = ((size.y / cell).ceil() as usize).max(1);
+    let cell_of = |p: Vec2| {
+        let i = (((p.x - rect.min.x) / cell) as usize).min(nx - 1);
+        let j = (((p.y - rect.min.y) / cell) as usize).min(ny - 1);
+        (i, j)
+    };
+    let mut grid: Vec<Option<usize>> = vec![None; nx * ny];
+    let mut points = Vec::new();
+    let mut active = Vec::new();
+
+    let first = rect.min + Vec2::new(rng.gen::<f64>() * size.x, rng.gen::<f64>() * size.y);
+    let (i, j) = cell_of(first);
+    grid[j * nx + i] = Some(0);
+    points.push(first);
+    active.push(0);
+
+    while !active.is_empty() {
+        let k = rng.gen_range(0..active.len());
+        let center = points[active[k]];
+        let mut found = false;
+        for _ in 0..POISSON_ATTEMPTS {
+            // uniform in annulus between `radius` and `2 * radius`
+            let r = radius * (1.0 + 3.0 * rng.gen::<f64>()).sqrt();
+            let p = center + Vec2::from_polar(r, rng.gen_range(0.0..2.0 * PI));
+            if !rect.contains(p) {
+                continue;
+            }
+            let (i, j) = cell_of(p);
+            let far = (j.saturating_sub(2)..(j + 3).min(ny)).all(|y| {
+                (i.saturating_sub(2)..(i + 3).min(nx)).all(|x| {
+                    grid[y * nx + x].map_or(true, |q| (points[q] - p).len() >= radius)
+                })
+            });
+            if far {
+                grid[j * nx + i] = Some(points.len());
+                active.push(points.len());
+                points.push(p);
+                found = true;
+                break;
+            }
+        }
+        if !found {
+            active.swap_remove(k);
+        }
+    }
+    points
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    #[test]
+    fn halton_discrepancy() {
+        // integral of x * y over unit square
+        let rect = Rect::new(Vec2::zero(), Vec2::new(1, 1));
+        let n = 4096;
+        let mean = halton(rect, n).map(|p| p.x * p.y).sum::<f64>() / n as f64;
+        assert!((mean - 0.25).abs() < 1e-3);
+        let mean = hammersley(rect, n).map(|p| p.x * p.y).sum::<f64>() / n as f64;
+        assert!((mean - 0.25).abs() < 1e-3);
+        let aabb = Aabb3::new(Vec3::zero(), Vec3::new(1, 1, 1));
+        let mean = halton3(aabb, n).map(|p| p.x * p.y * p.z).sum::<f64>() / n as f64;
+        assert!((mean - 0.125).abs() < 1e-3);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn poisson_disk_maximal() {
+        use rand::SeedableRng;
+        use rand::rngs::StdRng;
+        let mut rng = StdRng::seed_from_u64(5);
+        let rect = Rect::new(Vec2::new(-2, 1), Vec2::new(6, 5));
+        let points = poisson_disk(rect, 0.5, &mut rng);
+        for (i, p) in points.iter().enumerate() {
+            assert!(rect.contains(*p));
+            assert!(points[..i].iter().all(|q| (*p - *q).len() >= 0.5));
+        }
+        // dense packing: area per point is around radius^2
+        assert!(points.len() > 60);
+    }
+}
@@ -0,0 +1,151 @@
+//! Separating axis tests for convex shapes on a plane.
+//!
+//! Convex shapes don't overlap iff there is an axis on which their
+//! projections are disjoint, for polygons it's enough to check edge normals.
+//! Tests return minimum translation vector: the shortest translation of the
+//! first shape which resolves overlap. Touching shapes are not overlapping.
+use obb::Obb;
+use rect::Rect;
+use vec2::Vec2;
+
+// interval of projections of points onto axis
+fn project(points: &[Vec2], axis: Vec2) -> (f64, f64) {
+    points.iter().fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), p| {
+        let d = p.dot(axis);
+        (lo.min(d), hi.max(d))
+    })
+}
+
+// unit normals of polygon edges
+fn normals(points: &[Vec2]) -> impl Iterator<Item = Vec2> + '_ {
+    let n = points.len();
+    (0..n).filter_map(move |i| {
+        let e = points[(i + 1) % n] - points[i];
+        let len = e.len();
+        if len > 0.0 { Some(Vec2::new(-e.y, e.x) / len) } else { None }
+    })
+}
+
+// keeps axis with minimal overlap, None if axis separates
+fn update(best: &mut Option<(f64, Vec2)>, axis: Vec2, a: (f64, f64), b: (f64, f64)) -> bool {
+    let depth = (a.1 - b.0).min(b.1 - a.0);
+    if depth <= 0.0 {
+        return false;
+    }
+    // push `a` to the side where its center lies
+    let sign = if a.0 + a.1 < b.0 + b.1 { -1.0 } else { 1.0 };
+    if best.map_or(true, |(d, _)| depth < d) {
+        *best = Some((depth, axis * sign));
+    }
+    true
+}
+
+/// Minimum translation vector of convex polygon `a` out of convex polygon `b`
+///
+/// Polygons may have any orientation. Returns `None` if polygons don't
+/// overlap; otherwise moving `a` by returned vector makes them touch.
+///
+/// # Example
+/// ```
+/// # use linal::Vec2;
+/// # use linal::sat::overlap;
+/// let a = [Vec2::new(0, 0), Vec2::new(2, 0), Vec2::new(2, 2), Vec2::new(0, 2)];
+/// let b = [Vec2::new(1.5, 0.5), Vec2::new(4.0, 0.5), Vec2::new(4.0, 1.5)];
+/// let mtv = overlap(&a, &b).unwrap();
+/// assert!((mtv - Vec2::new(-0.5, 0.0)).len() < 1e-12);
+/// let far = [Vec2::new(5, 5), Vec2::new(6, 5), Vec2::new(6, 6)];
+/// assert_eq!(overlap(&a, &far), None);
+/// ```
+pub fn overlap(a: &[Vec2], b: &[Vec2]) -> Option<Vec2> {
+    if a.is_empty() || b.is_empty() {
+        return None;
+    }
+    let mut best = None;
+    for axis in normals(a).chain(normals(b)) {
+        if !update(&mut best, axis, project(a, axis), project(b, axis)) {
+            return None;
+        }
+    }
+    best.map(|(depth, axis)| axis * depth)
+}
+
+/// Minimum translation vector of rectangle `a` out of rectangle `b`
+///
+/// # Example
+/// ```
+/// # use linal::{Rect, Vec2};
+/// # use linal::sat::overlap_rects;
+/// let a = Rect::new(Vec2::new(0, 0), Vec2::new(2, 2));
+/// let b = Rect::new(Vec2::new(1.0, 1.5), Vec2::new(3.0, 3.0));
+/// assert_eq!(overlap_rects(a, b), Some(Vec2::new(0.0, -0.5)));
+/// ```
+pub fn overlap_rects(a: Rect, b: Rect) -> Option<Vec2> {
+    let mut best = None;
+    for &(axis, i) in &[(Vec2::new(1, 0), 0), (Vec2::new(0, 1), 1)] {
+        if !update(&mut best, axis, (a.min[i], a.max[i]), (b.min[i], b.max[i])) {
+            return None;
+        }
+    }
+    best.map(|(depth, axis)| axis * depth)
+}
+
+/// Minimum translation vector of oriented rectangle `a` out of `b`
+///
+/// # Example
+/// ```
+/// # use linal::{Obb, Vec2};
+/// # use linal::sat::overlap_obbs;
+/// let a = Obb::new(Vec2::zero(), Vec2::new(1, 1), Vec2::new(1, 1));
+/// let b = Obb::new(Vec2::new(2, 0), Vec2::new(1, 0), Vec2::new(1, 1));
+/// let mtv = overlap_obbs(a, b).unwrap();
+/// assert!((mtv - Vec2::new(-(2f64.sqrt() - 1.0), 0.0)).len() < 1e-12);
+/// ```
+pub fn overlap_obbs(a: Obb, b: Obb) -> Option<Vec2> {
+    let (au, av) = a.axes();
+    let (bu, bv) = b.axes();
+    // projection radius of rectangle onto axis
+    let radius = |o: Obb, u: Vec2, v: Vec2, axis: Vec2| {
+        o.half_extents.x * u.dot(axis).abs() + o.half_extents.y * v.dot(axis).abs()
+    };
+    let mut best = None;
+    for &axis in &[au, av, bu, bv] {
+        let (ca, cb) = (a.center.dot(axis), b.center.dot(axis));
+        let (ra, rb) = (radius(a, au, av, axis), radius(b, bu, bv, axis));
+        if !update(&mut best, axis, (ca - ra, ca + ra), (cb - rb, cb + rb)) {
+            return None;
+        }
+    }
+    best.map(|(depth, axis)| axis * depth)
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    #[test]
+    fn sat_mtv_separates() {
+        let a = [Vec2::new(0, 0), Vec2::new(3, 1), Vec2::new(1, 3)];
+        let b: Vec<Vec2> = [Vec2::new(2, 2), Vec2::new(4, 2), Vec2::new(4, 4), Vec2::new(2, 4)]
+            .iter().rev().map(|&p| p - Vec2::new(0.5, 0.5)).collect();
+        let mtv = overlap(&a, &b).unwrap();
+        let moved: Vec<Vec2> = a.iter().map(|&p| p + mtv * 1.001).collect();
+        assert_eq!(overlap(&moved, &b), None);
+        let moved: Vec<Vec2> = a.iter().map(|&p| p + mtv * 0.999).collect();
+        assert!(overlap(&moved, &b).is_some());
+        // symmetric
+        assert!((overlap(&b, &a).unwrap() + mtv).len() < 1e-12);
+    }
+
+    #[test]
+    fn sat_fast_paths_agree() {
+        let a = Obb::new(Vec2::new(0.3, 0.1), Vec2::new(2, 1), Vec2::new(1.5, 0.5));
+        let b = Obb::new(Vec2::new(1.2, 1.0), Vec2::new(-1, 3), Vec2::new(1.0, 0.7));
+        let general = overlap(&a.corners(), &b.corners()).unwrap();
+        assert!((overlap_obbs(a, b).unwrap() - general).len() < 1e-12);
+        let r = Rect::new(Vec2::new(0, 0), Vec2::new(2, 1));
+        let s = Rect::new(Vec2::new(1.9, -3.0), Vec2::new(5.0, 5.0));
+        assert!((overlap_rects(r, s).unwrap() - overlap(&r.corners(), &s.corners()).unwrap()).len() < 1e-12);
+        let touching = Rect::new(Vec2::new(2, 0), Vec2::new(3, 1));
+        assert_eq!(overlap_rects(r, touching), None);
+    }
+}
@@ -0,0 +1,48 @@
+//! Primitive numbers usable as scalar operands.
+
+/// Primitive number convertible to `f64`
+///
+/// Implemented for all primitive integers and floats, so `v * 3i64` and
+/// `v /= 2usize` work like `v * 3.0`. Conversion is the `as f64` cast, so it
+/// is lossy for 64 and 128 bit integers (and `isize`, `usize`) with magnitude
+/// above $2^{53}$: they are rounded to the nearest representable `f64`.
+///
+/// # Example
+/// ```
+/// # use linal::Vec2;
+/// let mut v = Vec2::new(2, 4) * 3i64;
+/// v /= 2usize;
+/// assert_eq!(v, Vec2::new(3, 6));
+/// assert_eq!(2u8 * v, Vec2::new(6, 12));
+/// ```
+pub trait Scalar: Copy {
+    /// Converts number to `f64`
+    fn as_f64(self) -> f64;
+}
+
+macro_rules! scalar_impl {
+    ($($type:ident),*) => {
+        $(
+            impl Scalar for $type {
+                fn as_f64(self) -> f64 {
+                    self as f64
+                }
+            }
+        )*
+    };
+}
+
+scalar_impl!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    #[test]
+    fn scalar_lossy() {
+        assert_eq!(u64::MAX.as_f64(), 18446744073709551616.0);
+        assert_eq!(((1i64 << 53) + 1).as_f64(), (1i64 << 53) as f64);
+        assert_eq!((-7i8).as_f64(), -7.0);
+        assert_eq!(0.1f32.as_f64(), 0.1f32 as f64);
+    }
+}
@@ -0,0 +1,297 @@
+//! Line segments on a plane.
+use vec2::Vec2;
+
+/// Result of intersection of two segments on a plane
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SegmentIntersection {
+    /// segments cross at point interior to both of them
+    Crossing(Vec2),
+    /// segments have single common point, which is an endpoint of at least
+    /// one of them
+    Touching(Vec2),
+    /// collinear segments share a segment of positive length
+    Overlap(Segment2),
+    /// segments are parallel (possibly collinear) and have no common points
+    Parallel,
+    /// segments are not parallel and have no common points
+    Disjoint,
+}
+
+/// Line segment on a plane between two points
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Segment2 {
+    /// start point of segment
+    pub a: Vec2,
+    /// end point of segment
+    pub b: Vec2,
+}
+
+impl Segment2 {
+    /// Constructs a new `Segment2` from `a` to `b`.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Segment2, Vec2};
+    /// let s = Segment2::new(Vec2::new(1, 1), Vec2::new(4, 5));
+    /// assert_eq!(s.a, Vec2::new(1, 1));
+    /// ```
+    pub fn new(a: Vec2, b: Vec2) -> Segment2 {
+        Segment2 { a, b }
+    }
+    /// Length of segment
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Segment2, Vec2};
+    /// let s = Segment2::new(Vec2::new(1, 1), Vec2::new(4, 5));
+    /// assert_eq!(s.length(), 5.0);
+    /// ```
+    pub fn length(self) -> f64 {
+        (self.b - self.a).len()
+    }
+    /// Middle point of segment
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Segment2, Vec2};
+    /// let s = Segment2::new(Vec2::new(1, 1), Vec2::new(4, 5));
+    /// assert_eq!(s.midpoint(), Vec2::new(2.5, 3.0));
+    /// ```
+    pub fn midpoint(self) -> Vec2 {
+        (self.a + self.b) * 0.5
+    }
+    /// Point of segment at parameter `t`
+    ///
+    /// Parameter `0` corresponds to `a` and `1` to `b`, values out of
+    /// `[0, 1]` give points on continuation of segment.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Segment2, Vec2};
+    /// let s = Segment2::new(Vec2::new(1, 1), Vec2::new(4, 5));
+    /// assert_eq!(s.point_at(0), s.a);
+    /// assert_eq!(s.point_at(1), s.b);
+    /// assert_eq!(s.point_at(0.5), s.midpoint());
+    /// ```
+    pub fn point_at<I: Into<f64>>(self, t: I) -> Vec2 {
+        self.a + (self.b - self.a) * t.into()
+    }
+    /// Point of segment closest to given
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Segment2, Vec2};
+    /// let s = Segment2::new(Vec2::new(1, 1), Vec2::new(4, 5));
+    /// assert_eq!(s.closest_point(Vec2::new(6.5, 0.0)), Vec2::new(2.5, 3.0));
+    /// assert_eq!(s.closest_point(Vec2::new(10, 10)), s.b);
+    /// ```
+    pub fn closest_point(self, point: Vec2) -> Vec2 {
+        self.project_param(point).1
+    }
+    /// Parameter and point of segment closest to given
+    ///
+    /// Parameter is clamped to `[0, 1]`, so point is
+    /// [`point_at`](#method.point_at) of it. Degenerate segment gives `0`.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Segment2, Vec2};
+    /// let s = Segment2::new(Vec2::new(0, 0), Vec2::new(4, 0));
+    /// assert_eq!(s.project_param(Vec2::new(1, 3)), (0.25, Vec2::new(1, 0)));
+    /// assert_eq!(s.project_param(Vec2::new(9, 3)), (1.0, s.b));
+    /// ```
+    pub fn project_param(self, point: Vec2) -> (f64, Vec2) {
+        let d = self.b - self.a;
+        let len2 = d.dot(d);
+        if len2 == 0.0 {
+            return (0.0, self.a);
+        }
+        let t = (d.dot(point - self.a) / len2).clamp(0.0, 1.0);
+        (t, self.point_at(t))
+    }
+    /// Distance from segment to point
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Segment2, Vec2};
+    /// let s = Segment2::new(Vec2::new(1, 1), Vec2::new(4, 5));
+    /// assert_eq!(s.distance_to(Vec2::new(6.5, 0.0)), 5.0);
+    /// ```
+    pub fn distance_to(self, point: Vec2) -> f64 {
+        (point - self.closest_point(point)).len()
+    }
+    /// Intersection with other segment
+    ///
+    /// All cases are distinguished with exact comparisons of orientation
+    /// determinants, before any division, so endpoint lying on other segment
+    /// is reported as `Touching` at that endpoint. Segments of zero length are
+    /// treated as points.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Segment2, Vec2};
+    /// # use linal::segment2::SegmentIntersection;
+    /// let s = Segment2::new(Vec2::new(0, 0), Vec2::new(4, 4));
+    /// let cross = Segment2::new(Vec2::new(0, 4), Vec2::new(4, 0));
+    /// assert_eq!(s.intersect(cross), SegmentIntersection::Crossing(Vec2::new(2, 2)));
+    /// let touch = Segment2::new(Vec2::new(4, 4), Vec2::new(5, 0));
+    /// assert_eq!(s.intersect(touch), SegmentIntersection::Touching(Vec2::new(4, 4)));
+    /// let overlap = Segment2::new(Vec2::new(2, 2), Vec2::new(6, 6));
+    /// let common = Segment2::new(Vec2::new(2, 2), Vec2::new(4, 4));
+    /// assert_eq!(s.intersect(overlap), SegmentIntersection::Overlap(common));
+    /// let parallel = Segment2::new(Vec2::new(1, 0), Vec2::new(5, 4));
+    /// assert_eq!(s.intersect(parallel), SegmentIntersection::Parallel);
+    /// ```
+    pub fn intersect(self, other: Segment2) -> SegmentIntersection {
+        let r = self.b - self.a;
+        let s = other.b - other.a;
+        let zero = Vec2::zero();
+        if r == zero {
+            return if other.intersect_point(self.a) {
+                SegmentIntersection::Touching(self.a)
+            } else {
+                SegmentIntersection::Disjoint
+            };
+        }
+        if s == zero {
+            return if self.intersect_point(other.a) {
+                SegmentIntersection::Touching(other.a)
+            } else {
+                SegmentIntersection::Disjoint
+            };
+        }
+        let qp = other.a - self.a;
+        let denom = r.area(s);
+        if denom == 0.0 {
+            if qp.area(r) != 0.0 {
+                return SegmentIntersection::Parallel;
+            }
+            // collinear, compare parameters along `self`
+            let rr = r.dot(r);
+            let t0 = qp.dot(r) / rr;
+            let t1 = t0 + s.dot(r) / rr;
+            let lo = t0.min(t1).max(0.0);
+            let hi = t0.max(t1).min(1.0);
+            return if lo > hi {
+                SegmentIntersection::Parallel
+            } else if lo == hi {
+                SegmentIntersection::Touching(self.point_at(lo))
+            } else {
+                SegmentIntersection::Overlap(Segment2::new(self.point_at(lo), self.point_at(hi)))
+            };
+        }
+        // parameters are t = tn / denom along `self` and u = un / denom along
+        // `other`, signs are normalized to compare numerators with `denom`
+        let sign = denom.signum();
+        let (tn, un, denom) = (qp.area(s) * sign, qp.area(r) * sign, denom * sign);
+        let inside = |v: f64| (0.0..=denom).contains(&v);
+        if !inside(tn) || !inside(un) {
+            return SegmentIntersection::Disjoint;
+        }
+        if tn == 0.0 {
+            SegmentIntersection::Touching(self.a)
+        } else if tn == denom {
+            SegmentIntersection::Touching(self.b)
+        } else if un == 0.0 {
+            SegmentIntersection::Touching(other.a)
+        } else if un == denom {
+            SegmentIntersection::Touching(other.b)
+        } else {
+            SegmentIntersection::Crossing(self.point_at(tn / denom))
+        }
+    }
+
+    // checks if point lies on segment exactly
+    fn intersect_point(self, point: Vec2) -> bool {
+        let r = self.b - self.a;
+        let q = point - self.a;
+        if r == Vec2::zero() {
+            return q == Vec2::zero();
+        }
+        r.area(q) == 0.0 && (0.0..=r.dot(r)).contains(&r.dot(q))
+    }
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    #[test]
+    fn segment2_closest_point() {
+        let s = Segment2::new(Vec2::new(0, 0), Vec2::new(4, 0));
+        assert_eq!(s.closest_point(Vec2::new(1, 3)), Vec2::new(1, 0));
+        assert_eq!(s.closest_point(Vec2::new(-2, -1)), s.a);
+        assert_eq!(s.closest_point(Vec2::new(7, 2)), s.b);
+    }
+
+    #[test]
+    fn segment2_distance_to() {
+        let s = Segment2::new(Vec2::new(0, 0), Vec2::new(4, 0));
+        assert_eq!(s.distance_to(Vec2::new(1, 3)), 3.0);
+        assert_eq!(s.distance_to(s.midpoint()), 0.0);
+    }
+
+    #[test]
+    fn segment2_degenerate() {
+        let s = Segment2::new(Vec2::new(0, 0), Vec2::new(0, 0));
+        assert_eq!(s.length(), 0.0);
+        assert_eq!(s.closest_point(Vec2::new(1, 3)), s.a);
+    }
+
+    #[test]
+    fn segment2_intersect_crossing() {
+        let s = Segment2::new(Vec2::new(0, 0), Vec2::new(4, 2));
+        let o = Segment2::new(Vec2::new(1, 3), Vec2::new(3, -1));
+        match s.intersect(o) {
+            SegmentIntersection::Crossing(p) => assert!((p - Vec2::new(2, 1)).len() < 1e-12),
+            r => panic!("unexpected {:?}", r),
+        }
+        assert_eq!(o.intersect(Segment2::new(Vec2::new(5, 5), Vec2::new(6, 0))),
+                   SegmentIntersection::Disjoint);
+    }
+
+    #[test]
+    fn segment2_intersect_touching() {
+        let s = Segment2::new(Vec2::new(0, 0), Vec2::new(4, 0));
+        // T-junction
+        let t = Segment2::new(Vec2::new(2, 0), Vec2::new(2, 3));
+        assert_eq!(s.intersect(t), SegmentIntersection::Touching(Vec2::new(2, 0)));
+        assert_eq!(t.intersect(s), SegmentIntersection::Touching(Vec2::new(2, 0)));
+        // collinear, common endpoint
+        let c = Segment2::new(Vec2::new(4, 0), Vec2::new(7, 0));
+        assert_eq!(s.intersect(c), SegmentIntersection::Touching(Vec2::new(4, 0)));
+        // endpoint is returned exactly, not recomputed from parameter
+        let d = Segment2::new(Vec2::new(0.1, 0.7), Vec2::new(0.9, -0.3));
+        let e = Segment2::new(d.point_at(0.5), Vec2::new(3, 3));
+        assert_eq!(d.intersect(e), SegmentIntersection::Touching(e.a));
+    }
+
+    #[test]
+    fn segment2_intersect_near_endpoint() {
+        // parameter along `s` underflows to zero, but crossing is proper
+        let s = Segment2::new(Vec2::new(0, 0), Vec2::new(1e200, 0.0));
+        let o = Segment2::new(Vec2::new(1e-150, -1e100), Vec2::new(1e-150, 1e100));
+        assert!(matches!(s.intersect(o), SegmentIntersection::Crossing(_)));
+    }
+
+    #[test]
+    fn segment2_intersect_collinear() {
+        let s = Segment2::new(Vec2::new(0, 0), Vec2::new(4, 0));
+        let inner = Segment2::new(Vec2::new(3, 0), Vec2::new(1, 0));
+        assert_eq!(s.intersect(inner),
+                   SegmentIntersection::Overlap(Segment2::new(Vec2::new(1, 0), Vec2::new(3, 0))));
+        let apart = Segment2::new(Vec2::new(5, 0), Vec2::new(7, 0));
+        assert_eq!(s.intersect(apart), SegmentIntersection::Parallel);
+    }
+
+    #[test]
+    fn segment2_intersect_points() {
+        let s = Segment2::new(Vec2::new(0, 0), Vec2::new(4, 0));
+        let on = Segment2::new(Vec2::new(1, 0), Vec2::new(1, 0));
+        let off = Segment2::new(Vec2::new(1, 1), Vec2::new(1, 1));
+        assert_eq!(s.intersect(on), SegmentIntersection::Touching(Vec2::new(1, 0)));
+        assert_eq!(on.intersect(s), SegmentIntersection::Touching(Vec2::new(1, 0)));
+        assert_eq!(s.intersect(off), SegmentIntersection::Disjoint);
+        assert_eq!(on.intersect(on), SegmentIntersection::Touching(Vec2::new(1, 0)));
+    }
+}
@@ -0,0 +1,119 @@
+//! Line segments in 3-dimensional euclidian space.
+use vec3::Vec3;
+
+/// Line segment in 3-dimensional euclidian space between two points
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Segment3 {
+    /// start point of segment
+    pub a: Vec3,
+    /// end point of segment
+    pub b: Vec3,
+}
+
+impl Segment3 {
+    /// Constructs a new `Segment3` from `a` to `b`.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Segment3, Vec3};
+    /// let s = Segment3::new(Vec3::new(1, 1, 0), Vec3::new(4, 5, 0));
+    /// assert_eq!(s.a, Vec3::new(1, 1, 0));
+    /// ```
+    pub fn new(a: Vec3, b: Vec3) -> Segment3 {
+        Segment3 { a, b }
+    }
+    /// Length of segment
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Segment3, Vec3};
+    /// let s = Segment3::new(Vec3::new(1, 1, 0), Vec3::new(4, 5, 0));
+    /// assert_eq!(s.length(), 5.0);
+    /// ```
+    pub fn length(self) -> f64 {
+        (self.b - self.a).len()
+    }
+    /// Middle point of segment
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Segment3, Vec3};
+    /// let s = Segment3::new(Vec3::new(1, 1, 0), Vec3::new(4, 5, 0));
+    /// assert_eq!(s.midpoint(), Vec3::new(2.5, 3.0, 0.0));
+    /// ```
+    pub fn midpoint(self) -> Vec3 {
+        (self.a + self.b) * 0.5
+    }
+    /// Point of segment at parameter `t`
+    ///
+    /// Parameter `0` corresponds to `a` and `1` to `b`, values out of
+    /// `[0, 1]` give points on continuation of segment.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Segment3, Vec3};
+    /// let s = Segment3::new(Vec3::new(1, 1, 0), Vec3::new(4, 5, 0));
+    /// assert_eq!(s.point_at(0), s.a);
+    /// assert_eq!(s.point_at(1), s.b);
+    /// assert_eq!(s.point_at(0.5), s.midpoint());
+    /// ```
+    pub fn point_at<I: Into<f64>>(self, t: I) -> Vec3 {
+        self.a + (self.b - self.a) * t.into()
+    }
+    /// Point of segment closest to given
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Segment3, Vec3};
+    /// let s = Segment3::new(Vec3::new(1, 1, 0), Vec3::new(4, 5, 0));
+    /// assert_eq!(s.closest_point(Vec3::new(6.5, 0.0, 0.0)), Vec3::new(2.5, 3.0, 0.0));
+    /// assert_eq!(s.closest_point(Vec3::new(10, 10, 0)), s.b);
+    /// ```
+    pub fn closest_point(self, point: Vec3) -> Vec3 {
+        let d = self.b - self.a;
+        let len2 = d.dot(d);
+        if len2 == 0.0 {
+            return self.a;
+        }
+        let t = (d.dot(point - self.a) / len2).clamp(0.0, 1.0);
+        self.point_at(t)
+    }
+    /// Distance from segment to point
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Segment3, Vec3};
+    /// let s = Segment3::new(Vec3::new(1, 1, 0), Vec3::new(4, 5, 0));
+    /// assert_eq!(s.distance_to(Vec3::new(6.5, 0.0, 0.0)), 5.0);
+    /// ```
+    pub fn distance_to(self, point: Vec3) -> f64 {
+        (point - self.closest_point(point)).len()
+    }
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    #[test]
+    fn segment3_closest_point() {
+        let s = Segment3::new(Vec3::new(0, 0, 1), Vec3::new(4, 0, 1));
+        assert_eq!(s.closest_point(Vec3::new(1, 3, 5)), Vec3::new(1, 0, 1));
+        assert_eq!(s.closest_point(Vec3::new(-2, -1, 0)), s.a);
+        assert_eq!(s.closest_point(Vec3::new(7, 2, 1)), s.b);
+    }
+
+    #[test]
+    fn segment3_distance_to() {
+        let s = Segment3::new(Vec3::new(0, 0, 1), Vec3::new(4, 0, 1));
+        assert_eq!(s.distance_to(Vec3::new(1, 3, 5)), 5.0);
+        assert_eq!(s.distance_to(s.midpoint()), 0.0);
+    }
+
+    #[test]
+    fn segment3_degenerate() {
+        let s = Segment3::new(Vec3::new(0, 0, 1), Vec3::new(0, 0, 1));
+        assert_eq!(s.length(), 0.0);
+        assert_eq!(s.closest_point(Vec3::new(1, 3, 5)), s.a);
+    }
+}
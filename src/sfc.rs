@@ -0,0 +1,195 @@
+//! Space-filling curves: Morton (Z-order) and Hilbert keys of points.
+//!
+//! Bounding region is split into $2^{order}$ cells along each axis and
+//! points are keyed by position of their cell along the curve. Sorting by
+//! key keeps nearby points close in sequence; Hilbert keys preserve
+//! locality better, since consecutive cells along Hilbert curve always share
+//! a side.
+use aabb3::Aabb3;
+use rect::Rect;
+use vec2::Vec2;
+use vec3::Vec3;
+
+// cell of coordinate along axis, points outside of range are clamped
+fn cell(value: f64, min: f64, max: f64, order: u32) -> u64 {
+    let cells = (1u64 << order) as f64;
+    let t = if max > min { (value - min) / (max - min) } else { 0.0 };
+    (t * cells).clamp(0.0, cells - 1.0) as u64
+}
+
+fn cells2(p: Vec2, bounds: Rect, order: u32) -> [u64; 2] {
+    assert!((1..=32).contains(&order), "order of 2D curve must be in [1, 32]");
+    [cell(p.x, bounds.min.x, bounds.max.x, order),
+     cell(p.y, bounds.min.y, bounds.max.y, order)]
+}
+
+fn cells3(p: Vec3, bounds: Aabb3, order: u32) -> [u64; 3] {
+    assert!((1..=21).contains(&order), "order of 3D curve must be in [1, 21]");
+    [cell(p.x, bounds.min.x, bounds.max.x, order),
+     cell(p.y, bounds.min.y, bounds.max.y, order),
+     cell(p.z, bounds.min.z, bounds.max.z, order)]
+}
+
+// bits of coordinates interleaved from the most significant, first axis leads
+fn interleave(x: &[u64], order: u32) -> u64 {
+    (0..order).rev().fold(0, |key, bit| {
+        x.iter().fold(key, |key, &c| key << 1 | (c >> bit & 1))
+    })
+}
+
+// Skilling's transform of cell coordinates into transposed Hilbert index
+fn hilbert(x: &mut [u64], order: u32) -> u64 {
+    let n = x.len();
+    let mut q = 1u64 << (order - 1);
+    while q > 1 {
+        let p = q - 1;
+        for i in 0..n {
+            if x[i] & q != 0 {
+                x[0] ^= p;
+            } else {
+                let t = (x[0] ^ x[i]) & p;
+                x[0] ^= t;
+                x[i] ^= t;
+            }
+        }
+        q >>= 1;
+    }
+    for i in 1..n {
+        x[i] ^= x[i - 1];
+    }
+    let mut t = 0;
+    q = 1u64 << (order - 1);
+    while q > 1 {
+        if x[n - 1] & q != 0 {
+            t ^= q - 1;
+        }
+        q >>= 1;
+    }
+    for c in x.iter_mut() {
+        *c ^= t;
+    }
+    interleave(x, order)
+}
+
+/// Morton (Z-order) key of point within rectangle
+///
+/// Points outside of `bounds` are clamped to it.
+///
+/// # Panics
+/// Panics if `order` is not in $[1, 32]$.
+///
+/// # Example
+/// ```
+/// # use linal::{Rect, Vec2};
+/// # use linal::sfc::morton2;
+/// let bounds = Rect::new(Vec2::new(0, 0), Vec2::new(4, 4));
+/// assert_eq!(morton2(Vec2::new(0.5, 0.5), bounds, 2), 0);
+/// assert_eq!(morton2(Vec2::new(1.5, 0.5), bounds, 2), 2);
+/// assert_eq!(morton2(Vec2::new(0.5, 1.5), bounds, 2), 1);
+/// assert_eq!(morton2(Vec2::new(3.5, 3.5), bounds, 2), 15);
+/// ```
+pub fn morton2(p: Vec2, bounds: Rect, order: u32) -> u64 {
+    interleave(&cells2(p, bounds, order), order)
+}
+
+/// Morton (Z-order) key of point within box
+///
+/// Points outside of `bounds` are clamped to it.
+///
+/// # Panics
+/// Panics if `order` is not in $[1, 21]$.
+pub fn morton3(p: Vec3, bounds: Aabb3, order: u32) -> u64 {
+    interleave(&cells3(p, bounds, order), order)
+}
+
+/// Hilbert key of point within rectangle
+///
+/// Keys of cells lie in $[0, 4^{order})$, cells with consecutive keys share
+/// a side. Points outside of `bounds` are clamped to it.
+///
+/// # Panics
+/// Panics if `order` is not in $[1, 32]$.
+///
+/// # Example
+/// ```
+/// # use linal::{Rect, Vec2};
+/// # use linal::sfc::hilbert2;
+/// let bounds = Rect::new(Vec2::new(0, 0), Vec2::new(1, 1));
+/// let mut points = vec![Vec2::new(0.9, 0.1), Vec2::new(0.1, 0.9), Vec2::new(0.1, 0.1)];
+/// points.sort_by_key(|&p| hilbert2(p, bounds, 16));
+/// assert_eq!(points[0], Vec2::new(0.1, 0.1));
+/// assert_eq!(points[2], Vec2::new(0.9, 0.1));
+/// ```
+pub fn hilbert2(p: Vec2, bounds: Rect, order: u32) -> u64 {
+    hilbert(&mut cells2(p, bounds, order), order)
+}
+
+/// Hilbert key of point within box
+///
+/// Keys of cells lie in $[0, 8^{order})$, cells with consecutive keys share
+/// a face. Points outside of `bounds` are clamped to it.
+///
+/// # Panics
+/// Panics if `order` is not in $[1, 21]$.
+pub fn hilbert3(p: Vec3, bounds: Aabb3, order: u32) -> u64 {
+    hilbert(&mut cells3(p, bounds, order), order)
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    // cells in order of keys, checks that keys are bijective
+    fn walk<F: Fn(&[u64]) -> u64>(dims: usize, order: u32, key: F) -> Vec<Vec<u64>> {
+        let side = 1u64 << order;
+        let total = side.pow(dims as u32);
+        let mut cells = vec![None; total as usize];
+        for index in 0..total {
+            let c: Vec<u64> = (0..dims).map(|k| index / side.pow(k as u32) % side).collect();
+            let k = key(&c) as usize;
+            assert!(cells[k].is_none());
+            cells[k] = Some(c);
+        }
+        cells.into_iter().map(|c| c.unwrap()).collect()
+    }
+
+    fn center(c: u64, order: u32) -> f64 {
+        (c as f64 + 0.5) / (1u64 << order) as f64
+    }
+
+    #[test]
+    fn hilbert_adjacent() {
+        let rect = Rect::new(Vec2::new(0, 0), Vec2::new(1, 1));
+        let order = 4;
+        let cells = walk(2, order, |c| {
+            hilbert2(Vec2::new(center(c[0], order), center(c[1], order)), rect, order)
+        });
+        let aabb = Aabb3::new(Vec3::new(0, 0, 0), Vec3::new(1, 1, 1));
+        let cells3 = walk(3, 3, |c| {
+            hilbert3(Vec3::new(center(c[0], 3), center(c[1], 3), center(c[2], 3)), aabb, 3)
+        });
+        for cells in [cells, cells3].iter() {
+            for w in cells.windows(2) {
+                let step: u64 = w[0].iter().zip(w[1].iter()).map(|(&a, &b)| a.max(b) - a.min(b)).sum();
+                assert_eq!(step, 1);
+            }
+            assert!(cells[0].iter().all(|&c| c == 0));
+        }
+    }
+
+    #[test]
+    fn morton_clamped() {
+        let aabb = Aabb3::new(Vec3::new(-1, -1, -1), Vec3::new(1, 1, 1));
+        assert_eq!(morton3(Vec3::new(5, 5, 5), aabb, 21), (1 << 63) - 1);
+        assert_eq!(morton3(Vec3::new(-5, -5, -5), aabb, 21), 0);
+        let cells = walk(3, 2, |c| {
+            let p = Vec3::new(center(c[0], 2), center(c[1], 2), center(c[2], 2));
+            morton3(p * 2.0 - Vec3::new(1, 1, 1), aabb, 2)
+        });
+        assert_eq!(cells[1], vec![0, 0, 1]);
+        assert_eq!(cells[4], vec![1, 0, 0]);
+        // degenerate extent maps everything to the first cell along it
+        let flat = Rect::new(Vec2::new(0, 0), Vec2::new(0, 1));
+        assert_eq!(morton2(Vec2::new(3, 1), flat, 1), 1);
+    }
+}
@@ -0,0 +1,165 @@
+//! Real spherical harmonics of directions.
+//!
+//! Harmonics $Y_l^m$ are orthonormal over unit sphere and include
+//! Condon-Shortley phase, so that $Y_1^{-1}$, $Y_1^0$, $Y_1^1$ are
+//! proportional to $-y$, $z$, $-x$. Coefficients of functions are stored by
+//! bands: coefficient of $Y_l^m$ has index $l^2 + l + m$, `bands` bands take
+//! $bands^2$ coefficients.
+use std::f64::consts::PI;
+use vec3::Vec3;
+
+// associated Legendre polynomial P_l^m(x) for 0 <= m <= l
+fn legendre(l: u32, m: u32, x: f64) -> f64 {
+    let mut pmm = 1.0;
+    let sin = ((1.0 - x) * (1.0 + x)).max(0.0).sqrt();
+    for i in 0..m {
+        pmm *= -((2 * i + 1) as f64) * sin;
+    }
+    if l == m {
+        return pmm;
+    }
+    let mut pmm1 = x * (2 * m + 1) as f64 * pmm;
+    for n in m + 2..=l {
+        let pnm = ((2 * n - 1) as f64 * x * pmm1 - (n + m - 1) as f64 * pmm) / (n - m) as f64;
+        pmm = pmm1;
+        pmm1 = pnm;
+    }
+    pmm1
+}
+
+// normalization of harmonic, sqrt((2l + 1) / 4pi * (l - m)! / (l + m)!)
+fn scale(l: u32, m: u32) -> f64 {
+    let ratio: f64 = (l - m + 1..=l + m).map(|k| 1.0 / k as f64).product();
+    ((2 * l + 1) as f64 / (4.0 * PI) * ratio).sqrt()
+}
+
+// position of coefficient of Y_l^m
+fn index(l: u32, m: i32) -> usize {
+    (l as i64 * (l as i64 + 1) + m as i64) as usize
+}
+
+/// Real spherical harmonic $Y_l^m$ at unit direction
+///
+/// # Panics
+/// Panics if $|m| > l$.
+///
+/// # Example
+/// ```
+/// # use linal::Vec3;
+/// # use linal::sh::eval_band;
+/// # use std::f64::consts::PI;
+/// let y00 = eval_band(0, 0, Vec3::new(0, 0, 1));
+/// assert!((y00 - 0.5 / PI.sqrt()).abs() < 1e-12);
+/// let dir = Vec3::new(1, 2, 2).ort();
+/// assert!((eval_band(1, -1, dir) + (3.0 / (4.0 * PI)).sqrt() * dir.y).abs() < 1e-12);
+/// ```
+pub fn eval_band(l: u32, m: i32, dir: Vec3) -> f64 {
+    let order = m.unsigned_abs();
+    assert!(order <= l, "order of harmonic must not exceed its degree");
+    let p = legendre(l, order, dir.z.clamp(-1.0, 1.0));
+    let k = scale(l, order);
+    let phi = dir.y.atan2(dir.x);
+    match m {
+        0 => k * p,
+        m if m > 0 => 2f64.sqrt() * k * (m as f64 * phi).cos() * p,
+        m => 2f64.sqrt() * k * (-m as f64 * phi).sin() * p,
+    }
+}
+
+/// Coefficients of first `bands` bands of function given by samples
+///
+/// Samples are pairs of unit direction and value of function; directions
+/// should be spread uniformly over sphere. Coefficients are Monte Carlo
+/// estimates $\frac{4\pi}{N} \sum f(d_i) Y_l^m(d_i)$. Empty samples give zero
+/// coefficients.
+///
+/// # Example
+/// ```
+/// # use linal::Vec3;
+/// # use linal::sh::{project, reconstruct};
+/// // Fibonacci sphere
+/// let n = 2000;
+/// let dirs: Vec<Vec3> = (0..n).map(|i| {
+///     let z = 1.0 - (2 * i + 1) as f64 / n as f64;
+///     let phi = i as f64 * 2.399963229728653;
+///     let r = (1.0 - z * z).sqrt();
+///     Vec3::new(r * phi.cos(), r * phi.sin(), z)
+/// }).collect();
+/// let samples: Vec<(Vec3, f64)> = dirs.iter().map(|&d| (d, 1.0 + d.z)).collect();
+/// let coeffs = project(&samples, 2);
+/// let up = reconstruct(&coeffs, Vec3::new(0, 0, 1));
+/// assert!((up - 2.0).abs() < 1e-3);
+/// ```
+pub fn project(samples: &[(Vec3, f64)], bands: u32) -> Vec<f64> {
+    let mut coeffs = vec![0.0; (bands * bands) as usize];
+    if samples.is_empty() {
+        return coeffs;
+    }
+    let weight = 4.0 * PI / samples.len() as f64;
+    for &(dir, value) in samples {
+        for l in 0..bands {
+            for m in -(l as i32)..=l as i32 {
+                coeffs[index(l, m)] += weight * value * eval_band(l, m, dir);
+            }
+        }
+    }
+    coeffs
+}
+
+/// Value of function with given coefficients at unit direction
+///
+/// Number of bands is taken from length of `coeffs`, extra coefficients of
+/// incomplete band are ignored.
+pub fn reconstruct(coeffs: &[f64], dir: Vec3) -> f64 {
+    let mut value = 0.0;
+    let mut l = 0;
+    while ((l + 1) * (l + 1)) as usize <= coeffs.len() {
+        for m in -(l as i32)..=l as i32 {
+            value += coeffs[index(l, m)] * eval_band(l, m, dir);
+        }
+        l += 1;
+    }
+    value
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    fn fibonacci_sphere(n: usize) -> Vec<Vec3> {
+        (0..n).map(|i| {
+            let z = 1.0 - (2 * i + 1) as f64 / n as f64;
+            let phi = i as f64 * PI * (3.0 - 5f64.sqrt());
+            let r = (1.0 - z * z).sqrt();
+            Vec3::new(r * phi.cos(), r * phi.sin(), z)
+        }).collect()
+    }
+
+    #[test]
+    fn sh_orthonormal() {
+        let dirs = fibonacci_sphere(20000);
+        let bands = 4;
+        for l in 0..bands {
+            for m in -(l as i32)..=l as i32 {
+                let samples: Vec<(Vec3, f64)> = dirs.iter().map(|&d| (d, eval_band(l, m, d))).collect();
+                let coeffs = project(&samples, bands);
+                for (i, &c) in coeffs.iter().enumerate() {
+                    let expected = if i == index(l, m) { 1.0 } else { 0.0 };
+                    assert!((c - expected).abs() < 1e-3, "l = {}, m = {}, i = {}", l, m, i);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn sh_known_values() {
+        let d = Vec3::new(2, -3, 6).ort();
+        let c2 = (15.0 / (4.0 * PI)).sqrt();
+        assert!((eval_band(1, 1, d) + (3.0 / (4.0 * PI)).sqrt() * d.x).abs() < 1e-12);
+        assert!((eval_band(2, -2, d) - c2 * d.x * d.y).abs() < 1e-12);
+        assert!((eval_band(2, 1, d) + c2 * d.x * d.z).abs() < 1e-12);
+        assert!((eval_band(2, 2, d) - 0.5 * c2 * (d.x * d.x - d.y * d.y)).abs() < 1e-12);
+        let c20 = (5.0 / (16.0 * PI)).sqrt();
+        assert!((eval_band(2, 0, d) - c20 * (3.0 * d.z * d.z - 1.0)).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,44 @@
+//! Exhaustive search over points.
+use finite::Coordinates;
+use spatial::NearestNeighbors;
+
+/// Linear scan over points
+///
+/// Reference implementation of [`NearestNeighbors`], faster than trees for
+/// few dozens of points.
+#[derive(Debug, Clone)]
+pub struct BruteForce<P> {
+    points: Vec<P>,
+}
+
+impl<P: Coordinates> BruteForce<P> {
+    /// Wraps copy of points
+    pub fn new(points: &[P]) -> BruteForce<P> {
+        BruteForce { points: points.to_vec() }
+    }
+    /// Points in original order
+    pub fn points(&self) -> &[P] {
+        &self.points
+    }
+    // distances to all points
+    fn distances(&self, query: P) -> impl Iterator<Item = (usize, f64)> + '_ {
+        self.points.iter().enumerate().map(move |(i, p)| {
+            (i, (0..P::DIM).map(|j| (p[j] - query[j]) * (p[j] - query[j])).sum::<f64>().sqrt())
+        })
+    }
+}
+
+impl<P: Coordinates> NearestNeighbors for BruteForce<P> {
+    type Point = P;
+
+    fn k_nearest(&self, query: P, k: usize) -> Vec<(usize, f64)> {
+        let mut all: Vec<(usize, f64)> = self.distances(query).collect();
+        all.sort_by(|a, b| a.1.total_cmp(&b.1).then(a.0.cmp(&b.0)));
+        all.truncate(k);
+        all
+    }
+
+    fn within_radius(&self, query: P, radius: f64) -> Vec<usize> {
+        self.distances(query).filter(|&(_, d)| d <= radius).map(|(i, _)| i).collect()
+    }
+}
@@ -0,0 +1,270 @@
+//! Spatial hashing of points on a plane.
+use std::collections::HashMap;
+use rect::Rect;
+use spatial::NearestNeighbors;
+use vec2::Vec2;
+
+/// Uniform grid of square cells, stored sparsely in hash map
+///
+/// Each entry is point with caller-provided id. Only cells containing points
+/// are allocated, so grid is unbounded. Suited for broad-phase neighbor
+/// lookup of many moving entities, when cell size is comparable to query size.
+///
+/// # Example
+/// ```
+/// # use linal::{Rect, Vec2};
+/// # use linal::spatial::SpatialHash;
+/// let mut grid = SpatialHash::new(1.0);
+/// grid.insert(7, Vec2::new(0.5, 0.5));
+/// grid.insert(8, Vec2::new(3.0, 0.5));
+/// assert_eq!(grid.query_radius(Vec2::new(0, 0), 1.0), vec![7]);
+/// grid.insert(7, Vec2::new(2.5, 0.5)); // moved
+/// assert_eq!(grid.query_aabb(Rect::new(Vec2::new(2, 0), Vec2::new(4, 1))), vec![7, 8]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SpatialHash {
+    cell_size: f64,
+    cells: HashMap<(i64, i64), Vec<usize>>,
+    positions: HashMap<usize, Vec2>,
+}
+
+impl SpatialHash {
+    /// Constructs empty grid with given cell size
+    ///
+    /// # Panics
+    /// Panics if `cell_size` is not positive and finite.
+    pub fn new<I: Into<f64>>(cell_size: I) -> SpatialHash {
+        let cell_size = cell_size.into();
+        assert!(cell_size > 0.0 && cell_size.is_finite(), "cell size must be positive");
+        SpatialHash { cell_size, cells: HashMap::new(), positions: HashMap::new() }
+    }
+    /// Size of cell
+    pub fn cell_size(&self) -> f64 {
+        self.cell_size
+    }
+    /// Number of points in grid
+    pub fn len(&self) -> usize {
+        self.positions.len()
+    }
+    /// Checks if grid has no points
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty()
+    }
+    /// Cell containing point
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Vec2;
+    /// # use linal::spatial::SpatialHash;
+    /// let grid = SpatialHash::new(2.0);
+    /// assert_eq!(grid.cell(Vec2::new(3.0, -0.5)), (1, -1));
+    /// ```
+    pub fn cell(&self, point: Vec2) -> (i64, i64) {
+        ((point.x / self.cell_size).floor() as i64, (point.y / self.cell_size).floor() as i64)
+    }
+    /// Inserts point with id, replacing previous position of the same id
+    ///
+    /// Returns previous position, if any.
+    pub fn insert(&mut self, id: usize, point: Vec2) -> Option<Vec2> {
+        let old = self.remove(id);
+        self.positions.insert(id, point);
+        let cell = self.cell(point);
+        self.cells.entry(cell).or_default().push(id);
+        old
+    }
+    /// Removes point with id, returns its position
+    pub fn remove(&mut self, id: usize) -> Option<Vec2> {
+        let point = self.positions.remove(&id)?;
+        let cell = self.cell(point);
+        if let Some(ids) = self.cells.get_mut(&cell) {
+            ids.retain(|&i| i != id);
+            if ids.is_empty() {
+                self.cells.remove(&cell);
+            }
+        }
+        Some(point)
+    }
+    /// Position of point with id
+    pub fn get(&self, id: usize) -> Option<Vec2> {
+        self.positions.get(&id).cloned()
+    }
+    /// Removes all points
+    pub fn clear(&mut self) {
+        self.cells.clear();
+        self.positions.clear();
+    }
+    /// Ids of points lying in rectangle (boundary inclusive), in ascending order
+    pub fn query_aabb(&self, rect: Rect) -> Vec<usize> {
+        self.query(rect, |p| rect.contains(p))
+    }
+    /// Ids of points within distance `radius` from center, in ascending order
+    pub fn query_radius(&self, center: Vec2, radius: f64) -> Vec<usize> {
+        if radius < 0.0 {
+            return Vec::new();
+        }
+        let half = Vec2::new(radius, radius);
+        let r2 = radius * radius;
+        self.query(Rect::new(center - half, center + half), |p| (p - center).dot(p - center) <= r2)
+    }
+    /// Ids of `k` points nearest to query with distances to them
+    ///
+    /// Cells are visited in growing square rings around query until no
+    /// unvisited point can be closer, result is sorted by distance and id.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Vec2;
+    /// # use linal::spatial::SpatialHash;
+    /// let mut grid = SpatialHash::new(1.0);
+    /// grid.insert(3, Vec2::new(10, 0));
+    /// grid.insert(4, Vec2::new(0.5, 0.5));
+    /// grid.insert(5, Vec2::new(-2, 0));
+    /// let near: Vec<usize> = grid.k_nearest(Vec2::zero(), 2).iter().map(|&(id, _)| id).collect();
+    /// assert_eq!(near, vec![4, 5]);
+    /// ```
+    pub fn k_nearest(&self, query: Vec2, k: usize) -> Vec<(usize, f64)> {
+        let (cx, cy) = self.cell(query);
+        let mut found: Vec<(usize, f64)> = Vec::new();
+        let mut ring: i64 = 0;
+        loop {
+            let side = ring.saturating_mul(2).saturating_add(1);
+            if side.saturating_mul(side) as usize > self.cells.len() || !query.x.is_finite() || !query.y.is_finite() {
+                // rings are sparser than occupied cells, scan everything
+                found = self.positions.iter().map(|(&id, &p)| (id, (p - query).len())).collect();
+                break;
+            }
+            // cells of far away queries saturate at bounds of i64
+            for x in cx.saturating_sub(ring)..=cx.saturating_add(ring) {
+                for y in cy.saturating_sub(ring)..=cy.saturating_add(ring) {
+                    if (x - cx).abs() != ring && (y - cy).abs() != ring {
+                        continue;
+                    }
+                    if let Some(ids) = self.cells.get(&(x, y)) {
+                        found.extend(ids.iter().map(|id| (*id, (self.positions[id] - query).len())));
+                    }
+                }
+            }
+            // points outside visited block are farther than this bound
+            let bound = ring as f64 * self.cell_size;
+            let all_found = found.len() == self.positions.len();
+            if all_found || found.iter().filter(|c| c.1 <= bound).count() >= k {
+                break;
+            }
+            ring += 1;
+        }
+        found.sort_by(|a, b| a.1.total_cmp(&b.1).then(a.0.cmp(&b.0)));
+        found.truncate(k);
+        found
+    }
+    // ids of points in cells overlapping rectangle which pass the filter
+    fn query<F: Fn(Vec2) -> bool>(&self, rect: Rect, filter: F) -> Vec<usize> {
+        let (x0, y0) = self.cell(rect.min);
+        let (x1, y1) = self.cell(rect.max);
+        let mut found = Vec::new();
+        // for huge rectangles walking occupied cells is cheaper than walking range
+        let area = x1.saturating_sub(x0).saturating_add(1)
+            .saturating_mul(y1.saturating_sub(y0).saturating_add(1));
+        if area as usize > self.cells.len() {
+            for (&(x, y), ids) in &self.cells {
+                if x0 <= x && x <= x1 && y0 <= y && y <= y1 {
+                    found.extend(ids.iter().filter(|&id| filter(self.positions[id])));
+                }
+            }
+        } else {
+            for x in x0..=x1 {
+                for y in y0..=y1 {
+                    if let Some(ids) = self.cells.get(&(x, y)) {
+                        found.extend(ids.iter().filter(|&id| filter(self.positions[id])));
+                    }
+                }
+            }
+        }
+        found.sort_unstable();
+        found
+    }
+}
+
+impl NearestNeighbors for SpatialHash {
+    type Point = Vec2;
+
+    fn k_nearest(&self, query: Vec2, k: usize) -> Vec<(usize, f64)> {
+        SpatialHash::k_nearest(self, query, k)
+    }
+
+    fn within_radius(&self, query: Vec2, radius: f64) -> Vec<usize> {
+        self.query_radius(query, radius)
+    }
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    #[test]
+    fn spatial_hash_queries() {
+        let mut grid = SpatialHash::new(0.7);
+        let points: Vec<Vec2> = (0..200)
+            .map(|i| Vec2::new((i * 37 % 101) as f64 / 10.0 - 5.0, (i * 53 % 97) as f64 / 10.0 - 5.0))
+            .collect();
+        for (i, &p) in points.iter().enumerate() {
+            grid.insert(i, p);
+        }
+        assert_eq!(grid.len(), 200);
+        let center = Vec2::new(0.3, -1.2);
+        let expected: Vec<usize> = (0..200).filter(|&i| (points[i] - center).len() <= 2.5).collect();
+        assert_eq!(grid.query_radius(center, 2.5), expected);
+        let rect = Rect::new(Vec2::new(-1, -3), Vec2::new(2, 0));
+        let expected: Vec<usize> = (0..200).filter(|&i| rect.contains(points[i])).collect();
+        assert_eq!(grid.query_aabb(rect), expected);
+        let everything = Rect::new(Vec2::new(f64::NEG_INFINITY, -1e300), Vec2::new(f64::INFINITY, 1e300));
+        assert_eq!(grid.query_aabb(everything).len(), 200);
+    }
+
+    #[test]
+    fn spatial_hash_remove() {
+        let mut grid = SpatialHash::new(1);
+        assert_eq!(grid.insert(1, Vec2::new(-0.5, -0.5)), None);
+        assert_eq!(grid.insert(1, Vec2::new(5, 5)), Some(Vec2::new(-0.5, -0.5)));
+        assert_eq!(grid.len(), 1);
+        assert!(grid.query_radius(Vec2::zero(), 1.0).is_empty());
+        assert_eq!(grid.remove(1), Some(Vec2::new(5, 5)));
+        assert_eq!(grid.remove(1), None);
+        assert!(grid.is_empty() && grid.cells.is_empty());
+    }
+
+    #[test]
+    fn spatial_hash_k_nearest() {
+        use spatial::BruteForce;
+        let points: Vec<Vec2> = (0..300)
+            .map(|i| Vec2::new((i * 37 % 101) as f64 / 3.0, (i * 53 % 97) as f64 / 7.0))
+            .collect();
+        let mut grid = SpatialHash::new(0.5);
+        for (i, &p) in points.iter().enumerate() {
+            grid.insert(i, p);
+        }
+        let brute = BruteForce::new(&points);
+        for &q in &[Vec2::new(10, 5), Vec2::new(-50, 3), Vec2::new(33.3, 13.8)] {
+            for &k in &[1, 5, 40, 400] {
+                let a: Vec<usize> = grid.k_nearest(q, k).iter().map(|c| c.0).collect();
+                let b: Vec<usize> = NearestNeighbors::k_nearest(&brute, q, k).iter().map(|c| c.0).collect();
+                assert_eq!(a, b);
+            }
+            assert_eq!(grid.query_radius(q, 2.0), brute.within_radius(q, 2.0));
+            assert!(grid.query_radius(q, -2.0).is_empty() && brute.within_radius(q, -2.0).is_empty());
+        }
+        assert!(SpatialHash::new(1).k_nearest(Vec2::zero(), 3).is_empty());
+    }
+
+    #[test]
+    fn spatial_hash_far_query() {
+        let mut grid = SpatialHash::new(1.0);
+        grid.insert(0, Vec2::new(0, 0));
+        grid.insert(1, Vec2::new(1e300, 0.0));
+        grid.insert(2, Vec2::new(-5e19, -1e20));
+        let near: Vec<usize> = grid.k_nearest(Vec2::new(1e300, 0.0), 2).iter().map(|&(id, _)| id).collect();
+        assert_eq!(near, vec![1, 0]);
+        // cells of both query and point saturate at i64::MIN
+        let near = grid.k_nearest(Vec2::new(-1e20, -1e20), 1);
+        assert_eq!(near[0].0, 2);
+    }
+}
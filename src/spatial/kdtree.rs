@@ -0,0 +1,249 @@
+//! kd-tree over points of any dimension.
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use finite::Coordinates;
+use spatial::NearestNeighbors;
+
+// squared euclidean distance
+fn dist2<P: Coordinates>(a: &P, b: &P) -> f64 {
+    (0..P::DIM).map(|i| (a[i] - b[i]) * (a[i] - b[i])).sum()
+}
+
+// candidate of k-nearest search, ordered by distance
+struct Candidate {
+    dist2: f64,
+    index: usize,
+}
+
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Candidate) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Candidate {}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Candidate) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Candidate) -> Ordering {
+        self.dist2.total_cmp(&other.dist2).then(self.index.cmp(&other.index))
+    }
+}
+
+/// Balanced kd-tree over points
+///
+/// Tree is stored implicitly: every subrange of `order` has its splitting
+/// point in the middle, split axis cycles with depth. Queries return indices
+/// of points in slice given to [`KdTree::new`].
+///
+/// # Example
+/// ```
+/// # use linal::Vec2;
+/// # use linal::spatial::KdTree;
+/// let points = [Vec2::new(0, 0), Vec2::new(5, 5), Vec2::new(1, 2), Vec2::new(-3, 4)];
+/// let tree = KdTree::new(&points);
+/// assert_eq!(tree.nearest(Vec2::new(1, 1)).map(|(i, _)| i), Some(2));
+/// ```
+#[derive(Debug, Clone)]
+pub struct KdTree<P> {
+    points: Vec<P>,
+    order: Vec<usize>,
+}
+
+impl<P: Coordinates> KdTree<P> {
+    /// Builds tree in $O(n \log n)$
+    pub fn new(points: &[P]) -> KdTree<P> {
+        let mut order: Vec<usize> = (0..points.len()).collect();
+        build(points, &mut order, 0);
+        KdTree { points: points.to_vec(), order }
+    }
+    /// Number of points in tree
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+    /// Checks if tree has no points
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+    /// Points in tree, in original order
+    pub fn points(&self) -> &[P] {
+        &self.points
+    }
+    /// Index of point nearest to query and distance to it
+    ///
+    /// Returns `None` for empty tree.
+    pub fn nearest(&self, query: P) -> Option<(usize, f64)> {
+        self.k_nearest(query, 1).pop()
+    }
+    /// Indices of `k` points nearest to query with distances to them
+    ///
+    /// Result is sorted by distance, ties are broken by index. Fewer than `k`
+    /// points are returned if tree is smaller.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Vec3;
+    /// # use linal::spatial::KdTree;
+    /// let points: Vec<Vec3> = (0..10).map(|i| Vec3::new(i, 0, 0)).collect();
+    /// let tree = KdTree::new(&points);
+    /// let near: Vec<usize> = tree.k_nearest(Vec3::new(6.2, 1.0, 0.0), 3).iter().map(|&(i, _)| i).collect();
+    /// assert_eq!(near, vec![6, 7, 5]);
+    /// ```
+    pub fn k_nearest(&self, query: P, k: usize) -> Vec<(usize, f64)> {
+        if k == 0 {
+            return Vec::new();
+        }
+        let mut heap = BinaryHeap::with_capacity(k + 1);
+        self.search_k(&query, k, 0, self.order.len(), 0, &mut heap);
+        heap.into_sorted_vec()
+            .into_iter()
+            .map(|c| (c.index, c.dist2.sqrt()))
+            .collect()
+    }
+    /// Indices of points within distance `radius` from query, inclusive
+    ///
+    /// Indices are returned in ascending order.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Vec2;
+    /// # use linal::spatial::KdTree;
+    /// let points = [Vec2::new(0, 0), Vec2::new(3, 4), Vec2::new(1, 1), Vec2::new(6, 0)];
+    /// let tree = KdTree::new(&points);
+    /// assert_eq!(tree.within_radius(Vec2::zero(), 5.0), vec![0, 1, 2]);
+    /// ```
+    pub fn within_radius(&self, query: P, radius: f64) -> Vec<usize> {
+        let mut found = Vec::new();
+        if radius < 0.0 {
+            return found;
+        }
+        self.search_radius(&query, radius * radius, 0, self.order.len(), 0, &mut found);
+        found.sort_unstable();
+        found
+    }
+
+    fn search_k(&self, query: &P, k: usize, lo: usize, hi: usize, axis: usize,
+                heap: &mut BinaryHeap<Candidate>) {
+        if lo >= hi {
+            return;
+        }
+        let mid = (lo + hi) / 2;
+        let index = self.order[mid];
+        let point = &self.points[index];
+        heap.push(Candidate { dist2: dist2(query, point), index });
+        if heap.len() > k {
+            heap.pop();
+        }
+        let diff = query[axis] - point[axis];
+        let next = (axis + 1) % P::DIM;
+        let (near, far) = if diff < 0.0 { ((lo, mid), (mid + 1, hi)) } else { ((mid + 1, hi), (lo, mid)) };
+        self.search_k(query, k, near.0, near.1, next, heap);
+        let worst = heap.peek().map_or(f64::INFINITY, |c| c.dist2);
+        if heap.len() < k || diff * diff <= worst {
+            self.search_k(query, k, far.0, far.1, next, heap);
+        }
+    }
+
+    fn search_radius(&self, query: &P, r2: f64, lo: usize, hi: usize, axis: usize,
+                     found: &mut Vec<usize>) {
+        if lo >= hi {
+            return;
+        }
+        let mid = (lo + hi) / 2;
+        let index = self.order[mid];
+        let point = &self.points[index];
+        if dist2(query, point) <= r2 {
+            found.push(index);
+        }
+        let diff = query[axis] - point[axis];
+        let next = (axis + 1) % P::DIM;
+        if diff <= 0.0 || diff * diff <= r2 {
+            self.search_radius(query, r2, lo, mid, next, found);
+        }
+        if diff >= 0.0 || diff * diff <= r2 {
+            self.search_radius(query, r2, mid + 1, hi, next, found);
+        }
+    }
+}
+
+impl<P: Coordinates> NearestNeighbors for KdTree<P> {
+    type Point = P;
+
+    fn k_nearest(&self, query: P, k: usize) -> Vec<(usize, f64)> {
+        KdTree::k_nearest(self, query, k)
+    }
+
+    fn within_radius(&self, query: P, radius: f64) -> Vec<usize> {
+        KdTree::within_radius(self, query, radius)
+    }
+}
+
+// reorders indices so that every range has median along its axis in the middle
+fn build<P: Coordinates>(points: &[P], order: &mut [usize], axis: usize) {
+    if order.len() <= 1 {
+        return;
+    }
+    let mid = order.len() / 2;
+    order.select_nth_unstable_by(mid, |&a, &b| points[a][axis].total_cmp(&points[b][axis]));
+    let next = (axis + 1) % P::DIM;
+    let (left, right) = order.split_at_mut(mid);
+    build(points, left, next);
+    build(points, &mut right[1..], next);
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+    use vec2::Vec2;
+    use vec3::Vec3;
+
+    // deterministic pseudo-random points
+    fn cloud(n: usize) -> Vec<Vec3> {
+        let mut s = 12345u64;
+        let mut next = move || {
+            s ^= s << 13;
+            s ^= s >> 7;
+            s ^= s << 17;
+            (s % 2000) as f64 / 100.0 - 10.0
+        };
+        (0..n).map(|_| Vec3::new(next(), next(), next())).collect()
+    }
+
+    #[test]
+    fn kdtree_matches_brute_force() {
+        let points = cloud(500);
+        let tree = KdTree::new(&points);
+        for q in cloud(20).iter().map(|&q| q * 1.3) {
+            let mut brute: Vec<(usize, f64)> = points.iter()
+                .enumerate()
+                .map(|(i, &p)| (i, (p - q).len()))
+                .collect();
+            brute.sort_by(|a, b| a.1.total_cmp(&b.1).then(a.0.cmp(&b.0)));
+            let knn = tree.k_nearest(q, 7);
+            assert_eq!(knn.iter().map(|c| c.0).collect::<Vec<_>>(),
+                       brute[..7].iter().map(|c| c.0).collect::<Vec<_>>());
+            assert!((knn[0].1 - brute[0].1).abs() < 1e-12);
+            let mut inside: Vec<usize> = brute.iter().filter(|c| c.1 <= 4.0).map(|c| c.0).collect();
+            inside.sort_unstable();
+            assert_eq!(tree.within_radius(q, 4.0), inside);
+        }
+    }
+
+    #[test]
+    fn kdtree_edge_cases() {
+        let empty: KdTree<Vec2> = KdTree::new(&[]);
+        assert!(empty.is_empty());
+        assert_eq!(empty.nearest(Vec2::zero()), None);
+        let same = [Vec2::new(1, 1); 5];
+        let tree = KdTree::new(&same);
+        assert_eq!(tree.k_nearest(Vec2::zero(), 10).len(), 5);
+        assert_eq!(tree.within_radius(Vec2::new(1, 1), 0.0), vec![0, 1, 2, 3, 4]);
+        assert!(tree.within_radius(Vec2::new(1, 1), -2.0).is_empty());
+        assert_eq!(tree.k_nearest(Vec2::zero(), 0), vec![]);
+    }
+}
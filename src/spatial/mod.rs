@@ -0,0 +1,47 @@
+//! Spatial indices over points.
+//!
+//! All indices implement [`NearestNeighbors`], so algorithms can be written
+//! once and used with any of them.
+pub mod kdtree;
+pub mod hash;
+pub mod brute;
+pub mod orthtree;
+
+pub use self::kdtree::KdTree;
+pub use self::hash::SpatialHash;
+pub use self::brute::BruteForce;
+pub use self::orthtree::{BoundingBox, Orthtree, Quadtree, Octree};
+
+/// Nearest neighbor and radius queries over indexed points
+///
+/// Points are identified by `usize`: index in source slice for [`KdTree`]
+/// and [`BruteForce`], caller-provided id for [`SpatialHash`], id returned
+/// on insertion for [`Orthtree`].
+///
+/// # Example
+/// ```
+/// # use linal::Vec2;
+/// # use linal::spatial::{BruteForce, KdTree, NearestNeighbors};
+/// fn closest_pair_to<N: NearestNeighbors<Point = Vec2>>(index: &N, q: Vec2) -> Vec<usize> {
+///     index.k_nearest(q, 2).into_iter().map(|(i, _)| i).collect()
+/// }
+/// let points = [Vec2::new(0, 0), Vec2::new(4, 0), Vec2::new(1, 0)];
+/// assert_eq!(closest_pair_to(&KdTree::new(&points), Vec2::new(-1, 0)), vec![0, 2]);
+/// assert_eq!(closest_pair_to(&BruteForce::new(&points), Vec2::new(-1, 0)), vec![0, 2]);
+/// ```
+pub trait NearestNeighbors {
+    /// type of points
+    type Point;
+    /// Ids of `k` points nearest to query with distances to them
+    ///
+    /// Result is sorted by distance, ties are broken by id.
+    fn k_nearest(&self, query: Self::Point, k: usize) -> Vec<(usize, f64)>;
+    /// Ids of points within distance `radius` from query, in ascending order
+    ///
+    /// Negative radius gives empty result.
+    fn within_radius(&self, query: Self::Point, radius: f64) -> Vec<usize>;
+    /// Id of point nearest to query and distance to it
+    fn nearest(&self, query: Self::Point) -> Option<(usize, f64)> {
+        self.k_nearest(query, 1).pop()
+    }
+}
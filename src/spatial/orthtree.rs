@@ -0,0 +1,400 @@
+//! Loose quadtrees and octrees of values placed at points or boxes.
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap};
+use std::ops::IndexMut;
+use aabb3::Aabb3;
+use finite::Coordinates;
+use rect::Rect;
+use spatial::NearestNeighbors;
+use vec2::Vec2;
+use vec3::Vec3;
+
+// entries kept in leaf before it's split
+const BUCKET: usize = 8;
+// depth at which leaves are no longer split
+const MAX_DEPTH: usize = 16;
+
+/// Axis-aligned box, region of [`Orthtree`] node
+pub trait BoundingBox: Copy {
+    /// type of corners
+    type Point: Coordinates + IndexMut<usize, Output = f64>;
+    /// Box with given minimal and maximal corners
+    fn from_corners(min: Self::Point, max: Self::Point) -> Self;
+    /// corner with minimal coordinates
+    fn min_corner(&self) -> Self::Point;
+    /// corner with maximal coordinates
+    fn max_corner(&self) -> Self::Point;
+}
+
+impl BoundingBox for Rect {
+    type Point = Vec2;
+
+    fn from_corners(min: Vec2, max: Vec2) -> Rect {
+        Rect { min, max }
+    }
+
+    fn min_corner(&self) -> Vec2 {
+        self.min
+    }
+
+    fn max_corner(&self) -> Vec2 {
+        self.max
+    }
+}
+
+impl BoundingBox for Aabb3 {
+    type Point = Vec3;
+
+    fn from_corners(min: Vec3, max: Vec3) -> Aabb3 {
+        Aabb3 { min, max }
+    }
+
+    fn min_corner(&self) -> Vec3 {
+        self.min
+    }
+
+    fn max_corner(&self) -> Vec3 {
+        self.max
+    }
+}
+
+fn dim<B: BoundingBox>() -> usize {
+    <B::Point as Coordinates>::DIM
+}
+
+fn center<B: BoundingBox>(b: &B) -> B::Point {
+    let (mut c, max) = (b.min_corner(), b.max_corner());
+    for k in 0..dim::<B>() {
+        c[k] = 0.5 * (c[k] + max[k]);
+    }
+    c
+}
+
+// checks if `inner` lies in `outer`, boundaries may touch
+fn contains<B: BoundingBox>(outer: &B, inner: &B) -> bool {
+    let (a, b) = (outer.min_corner(), outer.max_corner());
+    let (c, d) = (inner.min_corner(), inner.max_corner());
+    (0..dim::<B>()).all(|k| a[k] <= c[k] && d[k] <= b[k])
+}
+
+fn intersects<B: BoundingBox>(x: &B, y: &B) -> bool {
+    let (a, b) = (x.min_corner(), x.max_corner());
+    let (c, d) = (y.min_corner(), y.max_corner());
+    (0..dim::<B>()).all(|k| a[k] <= d[k] && c[k] <= b[k])
+}
+
+// squared distance from point to box, zero inside
+fn dist2<B: BoundingBox>(b: &B, p: &B::Point) -> f64 {
+    let (min, max) = (b.min_corner(), b.max_corner());
+    (0..dim::<B>()).map(|k| {
+        let d = (min[k] - p[k]).max(p[k] - max[k]).max(0.0);
+        d * d
+    }).sum()
+}
+
+// candidate of nearest search, ordered by distance
+struct Candidate {
+    dist2: f64,
+    index: usize,
+}
+
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Candidate) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Candidate {}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Candidate) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Candidate) -> Ordering {
+        self.dist2.total_cmp(&other.dist2).then(self.index.cmp(&other.index))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Node<B> {
+    // region split between children
+    cell: B,
+    // cell enlarged twice, all entries of subtree lie in it
+    loose: B,
+    depth: usize,
+    // index of the first of consecutive children
+    children: Option<usize>,
+    ids: Vec<usize>,
+}
+
+/// Loose tree of axis-aligned boxes: quadtree in plane, octree in space
+///
+/// Values are placed at points or boxes and identified by ids returned on
+/// insertion. Every node splits its cell into $2^d$ equal children, while
+/// entries are kept in the deepest node whose loose bounds (cell enlarged
+/// twice around its center) contain them, so boxes straddling cell borders
+/// don't pile up near the root. Entries outside of tree bounds are kept in
+/// the root, which keeps queries correct but slow for them.
+///
+/// # Example
+/// ```
+/// # use linal::{Rect, Vec2};
+/// # use linal::spatial::Quadtree;
+/// let mut tree = Quadtree::new(Rect::new(Vec2::new(0, 0), Vec2::new(100, 100)));
+/// let a = tree.insert(Vec2::new(10, 10), "a");
+/// let b = tree.insert_box(Rect::new(Vec2::new(40, 40), Vec2::new(60, 50)), "b");
+/// assert_eq!(tree.query(Rect::new(Vec2::new(0, 0), Vec2::new(45, 45))), vec![a, b]);
+/// assert_eq!(tree.nearest(Vec2::new(50, 55)), Some((b, 5.0)));
+/// assert_eq!(tree.remove(b), Some("b"));
+/// assert_eq!(tree.nearest(Vec2::new(50, 55)).map(|(id, _)| id), Some(a));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Orthtree<B: BoundingBox, T> {
+    nodes: Vec<Node<B>>,
+    entries: HashMap<usize, (B, T)>,
+    next_id: usize,
+}
+
+/// Loose quadtree, see [`Orthtree`]
+pub type Quadtree<T> = Orthtree<Rect, T>;
+
+/// Loose octree, see [`Orthtree`]
+pub type Octree<T> = Orthtree<Aabb3, T>;
+
+impl<B: BoundingBox, T> Orthtree<B, T> {
+    /// Constructs empty tree covering `bounds`
+    pub fn new(bounds: B) -> Orthtree<B, T> {
+        let root = Node { cell: bounds, loose: bounds, depth: 0, children: None, ids: Vec::new() };
+        Orthtree { nodes: vec![root], entries: HashMap::new(), next_id: 0 }
+    }
+    /// Region covered by tree
+    pub fn bounds(&self) -> B {
+        self.nodes[0].cell
+    }
+    /// Number of entries in tree
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+    /// Checks if tree has no entries
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+    /// Inserts value at point, returns its id
+    pub fn insert(&mut self, point: B::Point, value: T) -> usize {
+        self.insert_box(B::from_corners(point, point), value)
+    }
+    /// Inserts value occupying box, returns its id
+    pub fn insert_box(&mut self, bounds: B, value: T) -> usize {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.entries.insert(id, (bounds, value));
+        let node = self.home(bounds);
+        self.nodes[node].ids.push(id);
+        self.split(node);
+        id
+    }
+    /// Removes entry with id, returns its value
+    pub fn remove(&mut self, id: usize) -> Option<T> {
+        let (bounds, value) = self.entries.remove(&id)?;
+        let node = self.home(bounds);
+        self.nodes[node].ids.retain(|&i| i != id);
+        Some(value)
+    }
+    /// Value of entry with id
+    pub fn get(&self, id: usize) -> Option<&T> {
+        self.entries.get(&id).map(|e| &e.1)
+    }
+    /// Mutable value of entry with id
+    pub fn get_mut(&mut self, id: usize) -> Option<&mut T> {
+        self.entries.get_mut(&id).map(|e| &mut e.1)
+    }
+    /// Box occupied by entry with id, degenerate for points
+    pub fn bounds_of(&self, id: usize) -> Option<B> {
+        self.entries.get(&id).map(|e| e.0)
+    }
+    /// Ids of entries intersecting region (boundary inclusive), in ascending order
+    pub fn query(&self, region: B) -> Vec<usize> {
+        self.collect(|b| intersects(b, &region))
+    }
+    /// Ids of entries within distance `radius` from center, in ascending order
+    pub fn query_radius(&self, center: B::Point, radius: f64) -> Vec<usize> {
+        if radius < 0.0 {
+            return Vec::new();
+        }
+        let r2 = radius * radius;
+        self.collect(|b| dist2(b, &center) <= r2)
+    }
+    /// Ids of `k` entries nearest to query with distances to them
+    ///
+    /// Distance to box is zero for query inside it. Nodes are visited in
+    /// order of distance to their loose bounds, result is sorted by distance
+    /// and id.
+    pub fn k_nearest(&self, query: B::Point, k: usize) -> Vec<(usize, f64)> {
+        let mut found: BinaryHeap<Candidate> = BinaryHeap::with_capacity(k + 1);
+        let mut queue = BinaryHeap::new();
+        queue.push(Reverse(Candidate { dist2: 0.0, index: 0 }));
+        while let Some(Reverse(node)) = queue.pop() {
+            if k == 0 || (found.len() == k && found.peek().map_or(false, |w| node.dist2 > w.dist2)) {
+                break;
+            }
+            let node = &self.nodes[node.index];
+            for &id in &node.ids {
+                found.push(Candidate { dist2: dist2(&self.entries[&id].0, &query), index: id });
+                if found.len() > k {
+                    found.pop();
+                }
+            }
+            if let Some(first) = node.children {
+                for index in first..first + (1 << dim::<B>()) {
+                    queue.push(Reverse(Candidate { dist2: dist2(&self.nodes[index].loose, &query), index }));
+                }
+            }
+        }
+        found.into_sorted_vec().into_iter().map(|c| (c.index, c.dist2.sqrt())).collect()
+    }
+    /// Id of entry nearest to query and distance to it
+    pub fn nearest(&self, query: B::Point) -> Option<(usize, f64)> {
+        self.k_nearest(query, 1).pop()
+    }
+    // node where entry with given bounds is kept
+    fn home(&self, bounds: B) -> usize {
+        let mut node = 0;
+        while let Some(child) = self.child_for(node, &bounds) {
+            node = child;
+        }
+        node
+    }
+    // child of node whose loose bounds contain box, if any
+    fn child_for(&self, node: usize, bounds: &B) -> Option<usize> {
+        let first = self.nodes[node].children?;
+        let (c, p) = (center(&self.nodes[node].cell), center(bounds));
+        let offset = (0..dim::<B>()).filter(|&k| p[k] >= c[k]).fold(0, |o, k| o | 1 << k);
+        Some(first + offset).filter(|&child| contains(&self.nodes[child].loose, bounds))
+    }
+    // splits overfull leaf and pushes its entries down
+    fn split(&mut self, node: usize) {
+        let Node { cell, depth, ref ids, children, .. } = self.nodes[node];
+        if children.is_some() || ids.len() <= BUCKET || depth >= MAX_DEPTH {
+            return;
+        }
+        let (min, max, c) = (cell.min_corner(), cell.max_corner(), center(&cell));
+        let first = self.nodes.len();
+        for offset in 0..1 << dim::<B>() {
+            let (mut lo, mut hi, mut loose_lo, mut loose_hi) = (min, c, min, c);
+            for k in 0..dim::<B>() {
+                if offset & 1 << k != 0 {
+                    lo[k] = c[k];
+                    hi[k] = max[k];
+                }
+                let half = 0.5 * (hi[k] - lo[k]);
+                loose_lo[k] = lo[k] - half;
+                loose_hi[k] = hi[k] + half;
+            }
+            self.nodes.push(Node {
+                cell: B::from_corners(lo, hi),
+                loose: B::from_corners(loose_lo, loose_hi),
+                depth: depth + 1,
+                children: None,
+                ids: Vec::new(),
+            });
+        }
+        self.nodes[node].children = Some(first);
+        let ids = ::std::mem::take(&mut self.nodes[node].ids);
+        for id in ids {
+            match self.child_for(node, &self.entries[&id].0) {
+                Some(child) => self.nodes[child].ids.push(id),
+                None => self.nodes[node].ids.push(id),
+            }
+        }
+        for child in first..first + (1 << dim::<B>()) {
+            self.split(child);
+        }
+    }
+    // ids of entries passing the filter, subtrees are pruned by loose bounds
+    fn collect<F: Fn(&B) -> bool>(&self, filter: F) -> Vec<usize> {
+        let mut found = Vec::new();
+        let mut stack = vec![0];
+        while let Some(node) = stack.pop() {
+            let node = &self.nodes[node];
+            found.extend(node.ids.iter().filter(|&id| filter(&self.entries[id].0)));
+            if let Some(first) = node.children {
+                stack.extend((first..first + (1 << dim::<B>())).filter(|&c| filter(&self.nodes[c].loose)));
+            }
+        }
+        found.sort_unstable();
+        found
+    }
+}
+
+impl<B: BoundingBox, T> NearestNeighbors for Orthtree<B, T> {
+    type Point = B::Point;
+
+    fn k_nearest(&self, query: B::Point, k: usize) -> Vec<(usize, f64)> {
+        Orthtree::k_nearest(self, query, k)
+    }
+
+    fn within_radius(&self, query: B::Point, radius: f64) -> Vec<usize> {
+        self.query_radius(query, radius)
+    }
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+    use spatial::BruteForce;
+
+    #[test]
+    fn quadtree_boxes() {
+        let mut tree = Quadtree::new(Rect::new(Vec2::new(-5, -5), Vec2::new(5, 5)));
+        let boxes: Vec<Rect> = (0..300)
+            .map(|i| {
+                let c = Vec2::new((i * 37 % 101) as f64 / 10.0 - 5.0, (i * 53 % 97) as f64 / 10.0 - 5.0);
+                Rect::from_center(c, Vec2::new((i % 7) as f64 * 0.1, (i % 3) as f64 * 0.2))
+            })
+            .collect();
+        for (i, &b) in boxes.iter().enumerate() {
+            assert_eq!(tree.insert_box(b, i), i);
+        }
+        assert!(tree.nodes.len() > 1);
+        let region = Rect::new(Vec2::new(-1, -3), Vec2::new(2, 0));
+        let expected: Vec<usize> = (0..300).filter(|&i| boxes[i].intersects(region)).collect();
+        assert_eq!(tree.query(region), expected);
+        for i in (0..300).filter(|i| i % 3 == 0) {
+            assert_eq!(tree.remove(i), Some(i));
+        }
+        assert_eq!(tree.remove(0), None);
+        assert_eq!(tree.len(), 200);
+        let center = Vec2::new(0.3, -1.2);
+        let expected: Vec<usize> = (0..300)
+            .filter(|&i| i % 3 != 0 && (boxes[i].closest_point(center) - center).len() <= 1.5)
+            .collect();
+        assert_eq!(tree.query_radius(center, 1.5), expected);
+        // entry outside of bounds stays in root
+        let far = tree.insert(Vec2::new(100, 100), 1000);
+        assert_eq!(tree.nearest(Vec2::new(90, 90)).map(|c| c.0), Some(far));
+    }
+
+    #[test]
+    fn octree_k_nearest() {
+        let points: Vec<Vec3> = (0..500)
+            .map(|i| Vec3::new((i * 37 % 101) as f64 / 3.0, (i * 53 % 97) as f64 / 7.0, (i * 11 % 13) as f64))
+            .collect();
+        let mut tree = Octree::new(Aabb3::from_points(points.iter().cloned()).unwrap());
+        for &p in &points {
+            tree.insert(p, ());
+        }
+        let brute = BruteForce::new(&points);
+        for &q in &[Vec3::new(10, 5, 3), Vec3::new(-50, 3, 0), Vec3::new(33.3, 13.8, 12.0)] {
+            for &k in &[0, 1, 5, 40, 600] {
+                let a: Vec<usize> = tree.k_nearest(q, k).iter().map(|c| c.0).collect();
+                let b: Vec<usize> = NearestNeighbors::k_nearest(&brute, q, k).iter().map(|c| c.0).collect();
+                assert_eq!(a, b);
+            }
+            assert_eq!(tree.within_radius(q, 4.0), brute.within_radius(q, 4.0));
+            assert!(tree.within_radius(q, -4.0).is_empty() && brute.within_radius(q, -4.0).is_empty());
+        }
+    }
+}
@@ -0,0 +1,183 @@
+//! Geometry of directions on the unit sphere.
+use vec3::Vec3;
+use mat3::orthogonal_complement;
+
+// sine of angle below which arc is treated as degenerate
+const ARC_EPS: f64 = 1e-12;
+
+impl Vec3 {
+    /// Length of great circle arc between points of unit sphere
+    ///
+    /// Equals angle between vectors, which is computed with `atan2`, so it's
+    /// accurate both for close and for nearly opposite points. Vectors don't
+    /// have to be unit, but mustn't be zero.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Vec3;
+    /// let d = Vec3::new(1, 0, 0).great_circle_distance(Vec3::new(0, 0, 5));
+    /// assert_eq!(d, std::f64::consts::FRAC_PI_2);
+    /// ```
+    pub fn great_circle_distance(self, other: Vec3) -> f64 {
+        self.cross(other).len().atan2(self.dot(other))
+    }
+    /// Point of great circle arc from `self` to `other` at parameter `t`
+    ///
+    /// Both vectors should be unit, so result is unit too and moves along
+    /// arc with constant speed. For opposite points arc isn't unique, then it
+    /// goes through some point perpendicular to them.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Vec3;
+    /// let a = Vec3::new(1, 0, 0);
+    /// let b = Vec3::new(0, 1, 0);
+    /// let m = a.slerp_on_sphere(b, 0.5);
+    /// assert!((m - Vec3::new(1, 1, 0).ort()).len() < 1e-12);
+    /// ```
+    pub fn slerp_on_sphere<I: Into<f64>>(self, other: Vec3, t: I) -> Vec3 {
+        let t = t.into();
+        let angle = self.great_circle_distance(other);
+        let sin = angle.sin();
+        if sin > ARC_EPS {
+            return (self * ((1.0 - t) * angle).sin() + other * (t * angle).sin()) / sin;
+        }
+        if self.dot(other) > 0.0 {
+            return (self * (1.0 - t) + other * t).ort();
+        }
+        // rotate around any axis perpendicular to `self`
+        let axis = orthogonal_complement(self).0;
+        let (sin, cos) = (t * std::f64::consts::PI).sin_cos();
+        self * cos + axis.cross(self) * sin
+    }
+    /// Area of spherical triangle with vertices on unit sphere
+    ///
+    /// Area equals spherical excess of triangle and is computed by formula of
+    /// Van Oosterom and Strackee, it doesn't depend on vertices order.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Vec3;
+    /// // octant is eighth part of sphere
+    /// let area = Vec3::spherical_triangle_area(Vec3::new(1, 0, 0), Vec3::new(0, 1, 0),
+    ///                                          Vec3::new(0, 0, 1));
+    /// assert!((area - std::f64::consts::PI / 2.0).abs() < 1e-12);
+    /// ```
+    pub fn spherical_triangle_area(a: Vec3, b: Vec3, c: Vec3) -> f64 {
+        let triple = a.dot(b.cross(c)).abs();
+        let denom = 1.0 + a.dot(b) + b.dot(c) + c.dot(a);
+        2.0 * triple.atan2(denom)
+    }
+    /// Point of unit sphere with given latitude and longitude in radians
+    ///
+    /// Axes are earth-centered: `z` points to north pole (latitude $\pi/2$),
+    /// `x` to point with zero latitude and longitude and `y` to zero latitude
+    /// and longitude $\pi/2$ (east). It's
+    /// [`from_spherical`](#method.from_spherical) with polar angle
+    /// $\pi/2 - lat$ and azimuth $lon$.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Vec3;
+    /// # use std::f64::consts::FRAC_PI_2;
+    /// assert_eq!(Vec3::from_lat_lon(0, 0), Vec3::new(1, 0, 0));
+    /// assert!((Vec3::from_lat_lon(0.0, FRAC_PI_2) - Vec3::new(0, 1, 0)).len() < 1e-15);
+    /// assert!((Vec3::from_lat_lon(FRAC_PI_2, 1.0) - Vec3::new(0, 0, 1)).len() < 1e-15);
+    /// ```
+    pub fn from_lat_lon<I: Into<f64>>(lat: I, lon: I) -> Vec3 {
+        Vec3::from_lat_lon_radius(lat.into(), lon.into(), 1.0)
+    }
+    /// Point of sphere with given radius, latitude and longitude in radians
+    ///
+    /// See [`from_lat_lon`](#method.from_lat_lon) for axes convention.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Vec3;
+    /// # use std::f64::consts::PI;
+    /// let p = Vec3::from_lat_lon_radius(0.0, PI, 6371.0);
+    /// assert!((p - Vec3::new(-6371, 0, 0)).len() < 1e-9);
+    /// ```
+    pub fn from_lat_lon_radius<I: Into<f64>>(lat: I, lon: I, radius: I) -> Vec3 {
+        let (lat, lon, radius) = (lat.into(), lon.into(), radius.into());
+        let (sin_lat, cos_lat) = lat.sin_cos();
+        let (sin_lon, cos_lon) = lon.sin_cos();
+        Vec3::new(cos_lat * cos_lon, cos_lat * sin_lon, sin_lat) * radius
+    }
+    /// Latitude and longitude of vector direction in radians
+    ///
+    /// Inverse of [`from_lat_lon`](#method.from_lat_lon), latitude is in
+    /// $[-\pi/2, \pi/2]$ and longitude in $(-\pi, \pi]$. Length of vector is
+    /// ignored, longitude of poles is zero.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Vec3;
+    /// let (lat, lon) = Vec3::new(0, 5, 5).to_lat_lon();
+    /// assert!((lat - std::f64::consts::FRAC_PI_4).abs() < 1e-15);
+    /// assert_eq!(lon, std::f64::consts::FRAC_PI_2);
+    /// ```
+    pub fn to_lat_lon(self) -> (f64, f64) {
+        let (lat, lon, _) = self.to_lat_lon_radius();
+        (lat, lon)
+    }
+    /// Latitude, longitude in radians and distance from origin
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Vec3;
+    /// let (lat, lon, r) = Vec3::new(0, 0, -2).to_lat_lon_radius();
+    /// assert_eq!((lat, lon, r), (-std::f64::consts::FRAC_PI_2, 0.0, 2.0));
+    /// ```
+    pub fn to_lat_lon_radius(self) -> (f64, f64, f64) {
+        let horizontal = self.x.hypot(self.y);
+        (self.z.atan2(horizontal), self.y.atan2(self.x), self.len())
+    }
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    #[test]
+    fn sphere_slerp_constant_speed() {
+        let a = Vec3::new(1, 2, -1).ort();
+        let b = Vec3::new(-2, 0, 3).ort();
+        let total = a.great_circle_distance(b);
+        for i in 0..=10 {
+            let t = i as f64 / 10.0;
+            let p = a.slerp_on_sphere(b, t);
+            assert!((p.len() - 1.0).abs() < 1e-12);
+            assert!((a.great_circle_distance(p) - t * total).abs() < 1e-12);
+        }
+        let m = a.slerp_on_sphere(-a, 0.5);
+        assert!(m.dot(a).abs() < 1e-12 && (m.len() - 1.0).abs() < 1e-12);
+        assert!((a.slerp_on_sphere(-a, 1.0) + a).len() < 1e-12);
+    }
+
+    #[test]
+    fn sphere_triangle_area_additive() {
+        let (a, b, c) = (Vec3::new(1.0, 0.2, 0.1).ort(), Vec3::new(0.1, 1.0, 0.3).ort(),
+                         Vec3::new(-0.2, 0.4, 1.0).ort());
+        let m = (a + b + c).ort();
+        let whole = Vec3::spherical_triangle_area(a, b, c);
+        let parts = Vec3::spherical_triangle_area(a, b, m) + Vec3::spherical_triangle_area(b, c, m)
+            + Vec3::spherical_triangle_area(c, a, m);
+        assert!((whole - parts).abs() < 1e-12);
+        let tiny = Vec3::new(1e-7, 0.0, 0.0);
+        let small = Vec3::spherical_triangle_area(Vec3::new(0, 0, 1), (Vec3::new(0, 0, 1) + tiny).ort(),
+                                                  (Vec3::new(0.0, 1e-7, 1.0)).ort());
+        assert!((small - 0.5e-14).abs() < 1e-20);
+    }
+
+    #[test]
+    fn sphere_lat_lon_round_trip() {
+        for &(lat, lon) in &[(0.3, -2.5), (-1.2, 0.7), (1.5, 3.0)] {
+            let p = Vec3::from_lat_lon_radius(lat, lon, 3.0);
+            let (la, lo, r) = p.to_lat_lon_radius();
+            assert!((la - lat).abs() < 1e-12 && (lo - lon).abs() < 1e-12 && (r - 3.0).abs() < 1e-12);
+            let spherical = Vec3::from_spherical(3.0, std::f64::consts::FRAC_PI_2 - lat, lon);
+            assert!((p - spherical).len() < 1e-12);
+        }
+    }
+}
@@ -0,0 +1,246 @@
+//! Interpolating splines in space.
+use vec3::Vec3;
+
+/// Cubic Hermite spline in space
+///
+/// Passes through `points` having given `tangents` (derivatives by
+/// parameter) at them. Parameter `t` runs from $0$ to $n - 1$, where $n$ is
+/// number of points, integer values correspond to points.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Hermite3 {
+    /// interpolated points
+    pub points: Vec<Vec3>,
+    /// tangents at points
+    pub tangents: Vec<Vec3>,
+}
+
+impl Hermite3 {
+    /// Constructs a new `Hermite3`
+    ///
+    /// # Panics
+    /// Panics if `points` is empty or lengths of `points` and `tangents`
+    /// differ.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Hermite3, Vec3};
+    /// let s = Hermite3::new(vec![Vec3::new(0, 0, 0), Vec3::new(2, 0, 0)],
+    ///                       vec![Vec3::new(0, 2, 1), Vec3::new(0, -2, -1)]);
+    /// assert_eq!(s.eval(1), Vec3::new(2, 0, 0));
+    /// ```
+    pub fn new(points: Vec<Vec3>, tangents: Vec<Vec3>) -> Hermite3 {
+        assert!(!points.is_empty(), "spline requires at least one point");
+        assert_eq!(points.len(), tangents.len(), "every point requires a tangent");
+        Hermite3 { points, tangents }
+    }
+    /// Point of spline at parameter `t`
+    ///
+    /// Parameter is clamped to $[0, n - 1]$.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Hermite3, Vec3};
+    /// let s = Hermite3::new(vec![Vec3::new(0, 0, 0), Vec3::new(2, 0, 0)],
+    ///                       vec![Vec3::new(0, 2, 1), Vec3::new(0, -2, -1)]);
+    /// assert_eq!(s.eval(0.5), Vec3::new(1.0, 0.5, 0.25));
+    /// ```
+    pub fn eval<I: Into<f64>>(&self, t: I) -> Vec3 {
+        let (i, u) = segment(self.points.len(), t.into());
+        let j = (i + 1).min(self.points.len() - 1);
+        hermite(self.points[i], self.tangents[i], self.points[j], self.tangents[j], u)
+    }
+    /// Tangent (derivative by parameter) of spline at `t`
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Hermite3, Vec3};
+    /// let s = Hermite3::new(vec![Vec3::new(0, 0, 0), Vec3::new(2, 0, 0)],
+    ///                       vec![Vec3::new(0, 2, 1), Vec3::new(0, -2, -1)]);
+    /// assert_eq!(s.tangent(0), Vec3::new(0, 2, 1));
+    /// assert_eq!(s.tangent(0.5), Vec3::new(3.0, 0.0, 0.0));
+    /// ```
+    pub fn tangent<I: Into<f64>>(&self, t: I) -> Vec3 {
+        let (i, u) = segment(self.points.len(), t.into());
+        let j = (i + 1).min(self.points.len() - 1);
+        hermite_derivative(self.points[i], self.tangents[i], self.points[j], self.tangents[j], u)
+    }
+}
+
+/// Cardinal (Catmull-Rom) spline in space
+///
+/// Interpolates `points`, tangent at each inner point is
+/// $(1 - \tau) (p_{i+1} - p_{i-1}) / 2$, where $\tau$ is `tension`. Zero
+/// tension gives classic Catmull-Rom spline, unit tension gives polyline-like
+/// curve with zero tangents. End tangents use reflected neighbours.
+/// Parameter `t` runs from $0$ to $n - 1$.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CatmullRom3 {
+    /// interpolated points
+    pub points: Vec<Vec3>,
+    /// tension of spline
+    pub tension: f64,
+}
+
+impl CatmullRom3 {
+    /// Constructs a new Catmull-Rom spline (zero tension)
+    ///
+    /// # Panics
+    /// Panics if `points` is empty.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{CatmullRom3, Vec3};
+    /// let s = CatmullRom3::new(vec![Vec3::new(0, 0, 0), Vec3::new(1, 1, 0), Vec3::new(2, 0, 0)]);
+    /// assert_eq!(s.eval(1), Vec3::new(1, 1, 0));
+    /// ```
+    pub fn new(points: Vec<Vec3>) -> CatmullRom3 {
+        CatmullRom3::with_tension(points, 0)
+    }
+    /// Constructs a new cardinal spline with given tension
+    ///
+    /// # Panics
+    /// Panics if `points` is empty.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{CatmullRom3, Vec3};
+    /// let s = CatmullRom3::with_tension(vec![Vec3::new(0, 0, 0), Vec3::new(1, 1, 0)], 1);
+    /// assert_eq!(s.tangent(0), Vec3::zero());
+    /// ```
+    pub fn with_tension<I: Into<f64>>(points: Vec<Vec3>, tension: I) -> CatmullRom3 {
+        assert!(!points.is_empty(), "spline requires at least one point");
+        CatmullRom3 { points, tension: tension.into() }
+    }
+    /// Tangent vectors at spline points
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{CatmullRom3, Vec3};
+    /// let s = CatmullRom3::new(vec![Vec3::new(0, 0, 0), Vec3::new(1, 1, 0), Vec3::new(2, 0, 0)]);
+    /// assert_eq!(s.tangents(), vec![Vec3::new(1, 1, 0), Vec3::new(1, 0, 0), Vec3::new(1, -1, 0)]);
+    /// ```
+    pub fn tangents(&self) -> Vec<Vec3> {
+        (0..self.points.len()).map(|i| self.tangent_at(i)).collect()
+    }
+    /// Point of spline at parameter `t`
+    ///
+    /// Parameter is clamped to $[0, n - 1]$.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{CatmullRom3, Vec3};
+    /// let s = CatmullRom3::new(vec![Vec3::new(0, 0, 0), Vec3::new(1, 1, 0), Vec3::new(2, 0, 0)]);
+    /// assert_eq!(s.eval(0), Vec3::new(0, 0, 0));
+    /// assert_eq!(s.eval(0.5), Vec3::new(0.5, 0.625, 0.0));
+    /// ```
+    pub fn eval<I: Into<f64>>(&self, t: I) -> Vec3 {
+        let (i, j, u) = self.segment(t.into());
+        let (m0, m1) = (self.tangent_at(i), self.tangent_at(j));
+        hermite(self.points[i], m0, self.points[j], m1, u)
+    }
+    /// Tangent (derivative by parameter) of spline at `t`
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{CatmullRom3, Vec3};
+    /// let s = CatmullRom3::new(vec![Vec3::new(0, 0, 0), Vec3::new(1, 1, 0), Vec3::new(2, 0, 0)]);
+    /// assert_eq!(s.tangent(1), Vec3::new(1, 0, 0));
+    /// ```
+    pub fn tangent<I: Into<f64>>(&self, t: I) -> Vec3 {
+        let (i, j, u) = self.segment(t.into());
+        let (m0, m1) = (self.tangent_at(i), self.tangent_at(j));
+        hermite_derivative(self.points[i], m0, self.points[j], m1, u)
+    }
+    fn segment(&self, t: f64) -> (usize, usize, f64) {
+        let (i, u) = segment(self.points.len(), t);
+        (i, (i + 1).min(self.points.len() - 1), u)
+    }
+    fn tangent_at(&self, i: usize) -> Vec3 {
+        let n = self.points.len();
+        if n == 1 {
+            return Vec3::zero();
+        }
+        // reflect neighbours over the ends
+        let prev = if i > 0 { self.points[i - 1] } else { self.points[0] * 2.0 - self.points[1] };
+        let next = if i + 1 < n { self.points[i + 1] } else { self.points[i] * 2.0 - self.points[i - 1] };
+        (next - prev) * (0.5 * (1.0 - self.tension))
+    }
+}
+
+impl From<CatmullRom3> for Hermite3 {
+    fn from(spline: CatmullRom3) -> Hermite3 {
+        let tangents = spline.tangents();
+        Hermite3::new(spline.points, tangents)
+    }
+}
+
+// segment index and local parameter for global parameter `t`
+fn segment(n: usize, t: f64) -> (usize, f64) {
+    let last = n.saturating_sub(1);
+    if last == 0 {
+        return (0, 0.0);
+    }
+    let t = t.clamp(0.0, last as f64);
+    let i = (t.floor() as usize).min(last - 1);
+    (i, t - i as f64)
+}
+
+fn hermite(p0: Vec3, m0: Vec3, p1: Vec3, m1: Vec3, u: f64) -> Vec3 {
+    let (u2, u3) = (u * u, u * u * u);
+    p0 * (2.0 * u3 - 3.0 * u2 + 1.0) + m0 * (u3 - 2.0 * u2 + u) +
+    p1 * (-2.0 * u3 + 3.0 * u2) + m1 * (u3 - u2)
+}
+
+fn hermite_derivative(p0: Vec3, m0: Vec3, p1: Vec3, m1: Vec3, u: f64) -> Vec3 {
+    let u2 = u * u;
+    p0 * (6.0 * u2 - 6.0 * u) + m0 * (3.0 * u2 - 4.0 * u + 1.0) +
+    p1 * (-6.0 * u2 + 6.0 * u) + m1 * (3.0 * u2 - 2.0 * u)
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    #[test]
+    fn catmull_rom3_interpolates() {
+        let points: Vec<Vec3> = (0..6).map(|i| Vec3::from_spherical(1.0 + i as f64, 0.3 * i as f64, i as f64)).collect();
+        let s = CatmullRom3::with_tension(points.clone(), 0.3);
+        for (i, &p) in points.iter().enumerate() {
+            assert!((s.eval(i as f64) - p).len() < 1e-12);
+        }
+        // tangent is continuous across points
+        let h = 1e-9;
+        for i in 1..5 {
+            let t = i as f64;
+            assert!((s.tangent(t - h) - s.tangent(t + h)).len() < 1e-6);
+        }
+        // clamped parameter
+        assert_eq!(s.eval(-1), s.eval(0));
+        assert!((s.eval(10) - points[5]).len() < 1e-12);
+    }
+
+    #[test]
+    fn catmull_rom3_matches_hermite() {
+        let points = vec![Vec3::new(0, 0, 0), Vec3::new(1, 2, 1), Vec3::new(3, 1, -2), Vec3::new(4, 4, 0)];
+        let s = CatmullRom3::with_tension(points, 0.5);
+        let h = Hermite3::from(s.clone());
+        for i in 0..=30 {
+            let t = i as f64 * 0.1;
+            assert!((s.eval(t) - h.eval(t)).len() < 1e-12);
+            assert!((s.tangent(t) - h.tangent(t)).len() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn hermite3_derivative() {
+        let h = Hermite3::new(vec![Vec3::new(0, 0, 0), Vec3::new(1, 2, 0), Vec3::new(3, 1, 0)],
+                              vec![Vec3::new(1, 0, 1), Vec3::new(0, 1, 0), Vec3::new(-1, -1, 2)]);
+        let d = 1e-6;
+        for &t in &[0.2, 0.7, 1.3, 1.9] {
+            let numeric = (h.eval(t + d) - h.eval(t - d)) / (2.0 * d);
+            assert!((numeric - h.tangent(t)).len() < 1e-6);
+        }
+        let single = Hermite3::new(vec![Vec3::new(1, 1, 0)], vec![Vec3::new(1, 0, 0)]);
+        assert_eq!(single.eval(0.5), Vec3::new(1, 1, 0));
+    }
+}
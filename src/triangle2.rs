@@ -0,0 +1,147 @@
+//! Triangles on a plane.
+use rect::Rect;
+use vec2::Vec2;
+use vec3::Vec3;
+
+/// Triangle on a plane, defined by vertices
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Triangle2 {
+    /// vertex of triangle
+    pub a: Vec2,
+    /// vertex of triangle
+    pub b: Vec2,
+    /// vertex of triangle
+    pub c: Vec2,
+}
+
+impl Triangle2 {
+    /// Constructs a new `Triangle2` from vertices.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Triangle2, Vec2};
+    /// let t = Triangle2::new(Vec2::new(0, 0), Vec2::new(4, 0), Vec2::new(0, 3));
+    /// assert_eq!(t.b, Vec2::new(4, 0));
+    /// ```
+    pub fn new(a: Vec2, b: Vec2, c: Vec2) -> Triangle2 {
+        Triangle2 { a, b, c }
+    }
+    /// Signed area, positive for counterclockwise order of vertices
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Triangle2, Vec2};
+    /// let t = Triangle2::new(Vec2::new(0, 0), Vec2::new(4, 0), Vec2::new(0, 3));
+    /// assert_eq!(t.signed_area(), 6.0);
+    /// let t = Triangle2::new(Vec2::new(0, 0), Vec2::new(0, 3), Vec2::new(4, 0));
+    /// assert_eq!(t.signed_area(), -6.0);
+    /// ```
+    pub fn signed_area(self) -> f64 {
+        0.5 * (self.b - self.a).area(self.c - self.a)
+    }
+    /// Area of triangle
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Triangle2, Vec2};
+    /// let t = Triangle2::new(Vec2::new(0, 0), Vec2::new(0, 3), Vec2::new(4, 0));
+    /// assert_eq!(t.area(), 6.0);
+    /// ```
+    pub fn area(self) -> f64 {
+        self.signed_area().abs()
+    }
+    /// Centroid (intersection of medians)
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Triangle2, Vec2};
+    /// let t = Triangle2::new(Vec2::new(0, 0), Vec2::new(6, 0), Vec2::new(0, 3));
+    /// assert_eq!(t.centroid(), Vec2::new(2, 1));
+    /// ```
+    pub fn centroid(self) -> Vec2 {
+        (self.a + self.b + self.c) / 3.0
+    }
+    /// Barycentric coordinates of point
+    ///
+    /// Returns weights $(w_a, w_b, w_c)$ with $w_a + w_b + w_c = 1$, such that
+    /// $\vec{p} = w_a \vec{a} + w_b \vec{b} + w_c \vec{c}$. Coordinates are
+    /// not finite for degenerate triangle.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Triangle2, Vec2, Vec3};
+    /// let t = Triangle2::new(Vec2::new(0, 0), Vec2::new(4, 0), Vec2::new(0, 4));
+    /// assert_eq!(t.barycentric(Vec2::new(1, 2)), Vec3::new(0.25, 0.25, 0.5));
+    /// ```
+    pub fn barycentric(self, point: Vec2) -> Vec3 {
+        let area = (self.b - self.a).area(self.c - self.a);
+        let wb = (point - self.a).area(self.c - self.a) / area;
+        let wc = (self.b - self.a).area(point - self.a) / area;
+        Vec3::new(1.0 - wb - wc, wb, wc)
+    }
+    /// Checks if point lies inside triangle or on its boundary
+    ///
+    /// Works for both orders of vertices. Degenerate triangle contains only
+    /// points of segment spanned by its vertices.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Triangle2, Vec2};
+    /// let t = Triangle2::new(Vec2::new(0, 0), Vec2::new(4, 0), Vec2::new(0, 4));
+    /// assert!(t.contains(Vec2::new(1, 1)));
+    /// assert!(t.contains(Vec2::new(2, 2)));
+    /// assert!(!t.contains(Vec2::new(3, 3)));
+    /// ```
+    pub fn contains(self, point: Vec2) -> bool {
+        let ab = (self.b - self.a).area(point - self.a);
+        let bc = (self.c - self.b).area(point - self.b);
+        let ca = (self.a - self.c).area(point - self.c);
+        if ab == 0.0 && bc == 0.0 && ca == 0.0 {
+            // point is collinear with vertices, check that it is between them
+            return Rect::new(self.a, self.b).union(Rect::new(self.b, self.c)).contains(point);
+        }
+        (ab >= 0.0 && bc >= 0.0 && ca >= 0.0) || (ab <= 0.0 && bc <= 0.0 && ca <= 0.0)
+    }
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    #[test]
+    fn triangle2_barycentric() {
+        let t = Triangle2::new(Vec2::new(1, 1), Vec2::new(5, 2), Vec2::new(2, 6));
+        assert!((t.barycentric(t.a) - Vec3::new(1, 0, 0)).len() < 1e-12);
+        assert!((t.barycentric(t.b) - Vec3::new(0, 1, 0)).len() < 1e-12);
+        assert!((t.barycentric(t.c) - Vec3::new(0, 0, 1)).len() < 1e-12);
+        let w = t.barycentric(t.centroid());
+        assert!((w - Vec3::new(1, 1, 1) / 3).len() < 1e-12);
+        let p = Vec2::new(7, -3);
+        let w = t.barycentric(p);
+        assert!((t.a * w.x + t.b * w.y + t.c * w.z - p).len() < 1e-12);
+    }
+
+    #[test]
+    fn triangle2_contains() {
+        let t = Triangle2::new(Vec2::new(1, 1), Vec2::new(2, 6), Vec2::new(5, 2));
+        assert!(t.contains(t.centroid()));
+        assert!(t.contains(t.a));
+        assert!(t.contains((t.b + t.c) * 0.5));
+        assert!(!t.contains(Vec2::new(0, 0)));
+        assert!(!t.contains(Vec2::new(5, 5)));
+    }
+
+    #[test]
+    fn triangle2_degenerate() {
+        let t = Triangle2::new(Vec2::new(0, 0), Vec2::new(1, 1), Vec2::new(2, 2));
+        assert_eq!(t.area(), 0.0);
+        assert!(t.contains(Vec2::new(1, 1)));
+        assert!(t.contains(Vec2::new(2, 2)));
+        assert!(!t.contains(Vec2::new(3, 3)));
+        assert!(!t.contains(Vec2::new(-1, -1)));
+        let point = Triangle2::new(Vec2::new(1, 2), Vec2::new(1, 2), Vec2::new(1, 2));
+        assert!(point.contains(Vec2::new(1, 2)));
+        assert!(!point.contains(Vec2::new(0, 0)));
+        assert!(!t.barycentric(Vec2::new(1, 0)).x.is_finite());
+    }
+}
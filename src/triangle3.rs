@@ -0,0 +1,224 @@
+//! Triangles in 3-dimensional euclidian space.
+use std::ops::{Add, Mul};
+use vec2::Vec2;
+use vec3::Vec3;
+
+// area of texture triangle, relative to product of its sides, treated as zero
+const UV_EPS: f64 = 1e-12;
+
+/// Triangle in space, defined by vertices
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Triangle3 {
+    /// vertex of triangle
+    pub a: Vec3,
+    /// vertex of triangle
+    pub b: Vec3,
+    /// vertex of triangle
+    pub c: Vec3,
+}
+
+impl Triangle3 {
+    /// Constructs a new `Triangle3` from vertices.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Triangle3, Vec3};
+    /// let t = Triangle3::new(Vec3::new(0, 0, 0), Vec3::new(4, 0, 0), Vec3::new(0, 3, 0));
+    /// assert_eq!(t.b, Vec3::new(4, 0, 0));
+    /// ```
+    pub fn new(a: Vec3, b: Vec3, c: Vec3) -> Triangle3 {
+        Triangle3 { a, b, c }
+    }
+    /// Area of triangle
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Triangle3, Vec3};
+    /// let t = Triangle3::new(Vec3::new(0, 0, 1), Vec3::new(4, 0, 1), Vec3::new(0, 3, 1));
+    /// assert_eq!(t.area(), 6.0);
+    /// ```
+    pub fn area(self) -> f64 {
+        0.5 * (self.b - self.a).cross(self.c - self.a).len()
+    }
+    /// Unit normal of triangle
+    ///
+    /// Normal is directed so that vertices go counterclockwise when viewed
+    /// from its end. Normal is not finite for degenerate triangle.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Triangle3, Vec3};
+    /// let t = Triangle3::new(Vec3::new(0, 0, 1), Vec3::new(4, 0, 1), Vec3::new(0, 3, 1));
+    /// assert_eq!(t.normal(), Vec3::new(0, 0, 1));
+    /// ```
+    pub fn normal(self) -> Vec3 {
+        (self.b - self.a).cross(self.c - self.a).ort()
+    }
+    /// Centroid (intersection of medians)
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Triangle3, Vec3};
+    /// let t = Triangle3::new(Vec3::new(0, 0, 3), Vec3::new(6, 0, 0), Vec3::new(0, 3, 0));
+    /// assert_eq!(t.centroid(), Vec3::new(2, 1, 1));
+    /// ```
+    pub fn centroid(self) -> Vec3 {
+        (self.a + self.b + self.c) / 3.0
+    }
+    /// Barycentric coordinates of point
+    ///
+    /// Returns weights $(w_a, w_b, w_c)$ with $w_a + w_b + w_c = 1$ of the
+    /// orthogonal projection of point onto the plane of triangle. Coordinates
+    /// are not finite for degenerate triangle.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Triangle3, Vec3};
+    /// let t = Triangle3::new(Vec3::new(0, 0, 0), Vec3::new(4, 0, 0), Vec3::new(0, 4, 0));
+    /// assert_eq!(t.barycentric(Vec3::new(1, 2, 7)), Vec3::new(0.25, 0.25, 0.5));
+    /// ```
+    pub fn barycentric(self, point: Vec3) -> Vec3 {
+        let (u, v, w) = (self.b - self.a, self.c - self.a, point - self.a);
+        let (uu, uv, vv) = (u.dot(u), u.dot(v), v.dot(v));
+        let (wu, wv) = (w.dot(u), w.dot(v));
+        let det = uu * vv - uv * uv;
+        let wb = (vv * wu - uv * wv) / det;
+        let wc = (uu * wv - uv * wu) / det;
+        Vec3::new(1.0 - wb - wc, wb, wc)
+    }
+    /// Checks if projection of point onto the plane of triangle lies inside
+    /// triangle or on its boundary
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Triangle3, Vec3};
+    /// let t = Triangle3::new(Vec3::new(0, 0, 0), Vec3::new(4, 0, 0), Vec3::new(0, 4, 0));
+    /// assert!(t.contains(Vec3::new(1, 1, 0)));
+    /// assert!(!t.contains(Vec3::new(3, 3, 0)));
+    /// ```
+    pub fn contains(self, point: Vec3) -> bool {
+        let w = self.barycentric(point);
+        w.x >= 0.0 && w.y >= 0.0 && w.z >= 0.0
+    }
+    /// Interpolates values given at vertices by barycentric weights
+    ///
+    /// Values are attributes of vertices `a`, `b`, `c` in that order: colors,
+    /// normals, texture coordinates and so on. Weights usually come from
+    /// [`barycentric`](#method.barycentric).
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Triangle3, Vec2, Vec3};
+    /// let t = Triangle3::new(Vec3::new(0, 0, 0), Vec3::new(4, 0, 0), Vec3::new(0, 4, 0));
+    /// let uv = (Vec2::new(0, 0), Vec2::new(1, 0), Vec2::new(0, 1));
+    /// let w = t.barycentric(Vec3::new(1, 2, 0));
+    /// assert_eq!(Triangle3::interpolate(w, uv), Vec2::new(0.25, 0.5));
+    /// assert_eq!(Triangle3::interpolate(w, (1.0, 2.0, 3.0)), 2.25);
+    /// ```
+    pub fn interpolate<T>(bary: Vec3, values: (T, T, T)) -> T
+        where T: Add<Output = T> + Mul<f64, Output = T>
+    {
+        values.0 * bary.x + values.1 * bary.y + values.2 * bary.z
+    }
+    /// Unit tangent and bitangent of triangle from texture coordinates
+    ///
+    /// Tangent points along increasing $u$ and bitangent along increasing $v$
+    /// of texture coordinates `uv0`, `uv1`, `uv2` of vertices `a`, `b`, `c`.
+    /// Tangent is orthogonalized against normal (Gram-Schmidt), bitangent is
+    /// cross product of normal and tangent, negated for mirrored texture
+    /// mapping. If texture triangle is degenerate, tangent is directed along
+    /// edge `ab`. Results are not finite for degenerate triangle.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Triangle3, Vec2, Vec3};
+    /// let t = Triangle3::new(Vec3::new(0, 0, 0), Vec3::new(0, 2, 0), Vec3::new(-2, 0, 0));
+    /// let (tangent, bitangent) = t.tangent_bitangent(Vec2::new(0, 0), Vec2::new(1, 0), Vec2::new(0, 1));
+    /// assert_eq!(tangent, Vec3::new(0, 1, 0));
+    /// assert_eq!(bitangent, Vec3::new(-1, 0, 0));
+    /// ```
+    pub fn tangent_bitangent(self, uv0: Vec2, uv1: Vec2, uv2: Vec2) -> (Vec3, Vec3) {
+        let (e1, e2) = (self.b - self.a, self.c - self.a);
+        let (d1, d2) = (uv1 - uv0, uv2 - uv0);
+        let normal = self.normal();
+        let det = d1.area(d2);
+        if det.abs() <= UV_EPS * d1.len() * d2.len() || !det.is_finite() {
+            let tangent = e1.ort();
+            return (tangent, normal.cross(tangent));
+        }
+        let tangent = (e1 * d2.y - e2 * d1.y) / det;
+        let bitangent = (e2 * d1.x - e1 * d2.x) / det;
+        let tangent = (tangent - normal * normal.dot(tangent)).ort();
+        let orthogonal = normal.cross(tangent);
+        if orthogonal.dot(bitangent) < 0.0 {
+            (tangent, -orthogonal)
+        } else {
+            (tangent, orthogonal)
+        }
+    }
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    #[test]
+    fn triangle3_barycentric() {
+        let t = Triangle3::new(Vec3::new(1, 1, 0), Vec3::new(5, 2, 1), Vec3::new(2, 6, -1));
+        assert!((t.barycentric(t.a) - Vec3::new(1, 0, 0)).len() < 1e-12);
+        assert!((t.barycentric(t.b) - Vec3::new(0, 1, 0)).len() < 1e-12);
+        assert!((t.barycentric(t.c) - Vec3::new(0, 0, 1)).len() < 1e-12);
+        let p = t.a * 0.2 + t.b * 0.5 + t.c * 0.3;
+        let w = t.barycentric(p + t.normal() * 10);
+        assert!((w - Vec3::new(0.2, 0.5, 0.3)).len() < 1e-12);
+    }
+
+    #[test]
+    fn triangle3_normal() {
+        let t = Triangle3::new(Vec3::new(1, 0, 0), Vec3::new(0, 1, 0), Vec3::new(0, 0, 1));
+        assert!((t.normal() - Vec3::new(1, 1, 1).ort()).len() < 1e-12);
+        assert!((t.area() - 3f64.sqrt() / 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn triangle3_contains() {
+        let t = Triangle3::new(Vec3::new(1, 0, 0), Vec3::new(0, 1, 0), Vec3::new(0, 0, 1));
+        assert!(t.contains(t.centroid()));
+        assert!(t.contains(t.centroid() + t.normal()));
+        assert!(!t.contains(Vec3::new(1, 1, 0)));
+    }
+
+    #[test]
+    fn triangle3_interpolate() {
+        let t = Triangle3::new(Vec3::new(1, 0, 2), Vec3::new(3, 1, 0), Vec3::new(0, 4, 1));
+        let p = t.a * 0.1 + t.b * 0.6 + t.c * 0.3;
+        // interpolation of vertex positions restores point
+        let q = Triangle3::interpolate(t.barycentric(p), (t.a, t.b, t.c));
+        assert!((p - q).len() < 1e-12);
+        let normals = (Vec3::new(0, 0, 1), Vec3::new(1, 0, 0), Vec3::new(0, 1, 0));
+        assert_eq!(Triangle3::interpolate(Vec3::new(0, 1, 0), normals), normals.1);
+    }
+
+    #[test]
+    fn triangle3_tangent_bitangent() {
+        let t = Triangle3::new(Vec3::new(1, 0, 2), Vec3::new(3, 1, 0), Vec3::new(0, 4, 1));
+        let uv = (Vec2::new(0.1, 0.2), Vec2::new(0.7, 0.1), Vec2::new(0.3, 0.9));
+        let (tangent, bitangent) = t.tangent_bitangent(uv.0, uv.1, uv.2);
+        let n = t.normal();
+        assert!((tangent.len() - 1.0).abs() < 1e-12 && tangent.dot(n).abs() < 1e-12);
+        assert!((bitangent - n.cross(tangent)).len() < 1e-12);
+        // derivative of position along u has positive projection on tangent
+        let p = |w: Vec3| t.a * w.x + t.b * w.y + t.c * w.z;
+        let w0 = Vec3::new(1.0, 0.0, 0.0);
+        let uv_of = |w: Vec3| Triangle3::interpolate(w, uv);
+        let step = Vec3::new(-0.01, 0.01, 0.0);
+        assert!((p(w0 + step) - p(w0)).dot(tangent) * (uv_of(w0 + step) - uv_of(w0)).x > 0.0);
+        // mirrored mapping flips bitangent
+        let mirrored = |v: Vec2| Vec2::new(v.x, -v.y);
+        let (t2, b2) = t.tangent_bitangent(mirrored(uv.0), mirrored(uv.1), mirrored(uv.2));
+        assert!((t2 - tangent).len() < 1e-12 && (b2 + n.cross(t2)).len() < 1e-12);
+        // degenerate texture coordinates fall back to first edge
+        let (t3, b3) = t.tangent_bitangent(uv.0, uv.0, uv.1);
+        assert!((t3 - (t.b - t.a).ort()).len() < 1e-12 && b3.dot(n).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,188 @@
+//! Twists and screw motions of rigid bodies.
+use dir3::Dir3;
+use isometry3::Isometry3;
+use mat3::Mat3;
+use vec3::Vec3;
+
+// angle below which series expansions are used
+const SMALL_ANGLE: f64 = 1e-6;
+
+/// Twist, element of Lie algebra $se(3)$
+///
+/// Twist is pair of angular and linear velocities, integrated over unit time
+/// by [`Twist::exp`] into rigid motion.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Twist {
+    /// angular part, rotation axis scaled by angle
+    pub angular: Vec3,
+    /// linear part
+    pub linear: Vec3,
+}
+
+/// Screw axis: line in space with pitch
+///
+/// Motion along screw rotates around `axis` passing through `point` and
+/// translates along it by `pitch` per radian.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Screw {
+    /// direction of axis
+    pub axis: Dir3,
+    /// any point on axis
+    pub point: Vec3,
+    /// translation along axis per radian of rotation
+    pub pitch: f64,
+}
+
+// V matrix of se(3) exponent and its inverse
+fn left_jacobian(w: Vec3) -> (Mat3, Mat3) {
+    let theta = w.len();
+    let k = Mat3::skew(w);
+    let k2 = k * k;
+    let (a, b, c) = if theta < SMALL_ANGLE {
+        (0.5, 1.0 / 6.0, 1.0 / 12.0)
+    } else {
+        let (sin, cos) = theta.sin_cos();
+        let t2 = theta * theta;
+        ((1.0 - cos) / t2,
+         (theta - sin) / (t2 * theta),
+         (1.0 - theta * sin / (2.0 * (1.0 - cos))) / t2)
+    };
+    (Mat3::identity() + k * a + k2 * b,
+     Mat3::identity() - k * 0.5 + k2 * c)
+}
+
+// rotation vector (axis scaled by angle in [0, pi]) of rotation matrix
+fn rotation_log(r: Mat3) -> Vec3 {
+    let vee = Vec3::new(r.z.y - r.y.z, r.x.z - r.z.x, r.y.x - r.x.y);
+    let cos = ((r.trace() - 1.0) * 0.5).clamp(-1.0, 1.0);
+    let sin = 0.5 * vee.len();
+    let theta = sin.atan2(cos);
+    if theta < SMALL_ANGLE {
+        return vee * 0.5;
+    }
+    if cos > -0.9 {
+        return vee * (0.5 * theta / sin);
+    }
+    // near pi antisymmetric part vanishes, axis is read from symmetric part
+    // (R + R^T) / 2 - cos I = (1 - cos) a a^T
+    let b = (r + r.transpose()) * 0.5 - Mat3::identity() * cos;
+    let i = if b.x.x >= b.y.y && b.x.x >= b.z.z { 0 } else if b.y.y >= b.z.z { 1 } else { 2 };
+    let mut axis = b[i].ort();
+    if axis.dot(vee) < 0.0 {
+        axis = -axis;
+    }
+    axis * theta
+}
+
+impl Twist {
+    /// Constructs a new `Twist` from angular and linear parts
+    pub fn new(angular: Vec3, linear: Vec3) -> Twist {
+        Twist { angular, linear }
+    }
+    /// Twist of screw motion by angle `theta`
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Dir3, Screw, Twist, Vec3};
+    /// # use std::f64::consts::PI;
+    /// // half turn around vertical axis through (1, 0, 0), rising by 2
+    /// let screw = Screw { axis: Dir3::Z, point: Vec3::new(1, 0, 0), pitch: 2.0 / PI };
+    /// let motion = Twist::from_screw(screw, PI).exp();
+    /// assert!((motion.transform_point(Vec3::zero()) - Vec3::new(2, 0, 2)).len() < 1e-12);
+    /// ```
+    pub fn from_screw<I: Into<f64>>(screw: Screw, theta: I) -> Twist {
+        let theta = theta.into();
+        let s = screw.axis.vec();
+        Twist::new(s * theta, (screw.point.cross(s) + s * screw.pitch) * theta)
+    }
+    /// Pure translation twist
+    pub fn translation(v: Vec3) -> Twist {
+        Twist::new(Vec3::zero(), v)
+    }
+    /// Screw axis and angle of twist
+    ///
+    /// Returns `None` for twist without rotation, which is pure translation
+    /// (screw with infinite pitch). Point on axis is the closest to origin.
+    pub fn to_screw(self) -> Option<(Screw, f64)> {
+        let theta = self.angular.len();
+        let axis = Dir3::new(self.angular)?;
+        let (s, v) = (axis.vec(), self.linear / theta);
+        let pitch = s.dot(v);
+        Some((Screw { axis, point: s.cross(v), pitch }, theta))
+    }
+    /// Exponential map, rigid motion of following twist for unit time
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Twist, Vec3};
+    /// let m = Twist::new(Vec3::zero(), Vec3::new(1, 2, 3)).exp();
+    /// assert_eq!(m.transform_point(Vec3::new(1, 1, 1)), Vec3::new(2, 3, 4));
+    /// ```
+    pub fn exp(self) -> Isometry3 {
+        let (v, _) = left_jacobian(self.angular);
+        let theta = self.angular.len();
+        let rotation = if theta == 0.0 {
+            Mat3::identity()
+        } else {
+            Mat3::rotation(self.angular, theta)
+        };
+        Isometry3::new(rotation, v * self.linear)
+    }
+}
+
+impl Isometry3 {
+    /// Logarithm map, twist with given exponent
+    ///
+    /// Rotation angle of resulting twist lies in $[0, \pi]$.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Twist, Vec3};
+    /// let t = Twist::new(Vec3::new(0.1, -0.2, 0.3), Vec3::new(1, 2, 3));
+    /// let log = t.exp().log();
+    /// assert!((log.angular - t.angular).len() < 1e-12);
+    /// assert!((log.linear - t.linear).len() < 1e-12);
+    /// ```
+    pub fn log(self) -> Twist {
+        let angular = rotation_log(self.rotation);
+        let (_, v_inv) = left_jacobian(angular);
+        Twist::new(angular, v_inv * self.translation)
+    }
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+    use std::f64::consts::PI;
+
+    #[test]
+    fn twist_exp_log_round_trip() {
+        let twists = [
+            Twist::new(Vec3::new(1e-9, 0.0, 2e-9), Vec3::new(1, 0, 0)),
+            Twist::new(Vec3::new(0.3, 0.4, 0.0), Vec3::new(-1.0, 2.0, 0.5)),
+            Twist::new(Vec3::new(0.0, 0.0, PI - 1e-7), Vec3::new(0, 1, 0)),
+            Twist::new(Vec3::new(1, 1, 1).ort() * (PI - 1e-3), Vec3::new(3, 0, -1)),
+        ];
+        for &t in &twists {
+            let log = t.exp().log();
+            assert!((log.angular - t.angular).len() < 1e-6, "{:?} {:?}", t, log);
+            assert!((log.linear - t.linear).len() < 1e-6, "{:?} {:?}", t, log);
+        }
+    }
+
+    #[test]
+    fn twist_screw_round_trip() {
+        let screw = Screw { axis: Dir3::new(Vec3::new(1, -1, 2)).unwrap(), point: Vec3::new(0, 3, 1), pitch: 0.7 };
+        let (s, theta) = Twist::from_screw(screw, 1.3).to_screw().unwrap();
+        assert!((theta - 1.3).abs() < 1e-12);
+        assert!((s.pitch - 0.7).abs() < 1e-12);
+        assert!((*s.axis - *screw.axis).len() < 1e-12);
+        // points lie on the same line
+        assert!((s.point - screw.point).cross(*s.axis).len() < 1e-12);
+        // points on axis only move along it
+        let m = Twist::from_screw(screw, 1.3).exp();
+        let q = m.transform_point(screw.point);
+        assert!((q - screw.point - *screw.axis * (0.7 * 1.3)).len() < 1e-12);
+        assert!(Twist::translation(Vec3::new(1, 0, 0)).to_screw().is_none());
+    }
+}
@@ -0,0 +1,160 @@
+//! Vectors of physical quantities, available with `uom` feature.
+//!
+//! Components of [`QVec3`](struct.QVec3.html) are `uom` quantities, so
+//! dimensions are checked at compile time: displacement divided by time is
+//! velocity, and adding length to velocity doesn't compile.
+//!
+//! # Example
+//! ```
+//! # extern crate uom;
+//! # extern crate linal;
+//! # use linal::Vec3;
+//! # use linal::units::QVec3;
+//! use uom::si::f64::{Length, Time, Velocity};
+//! use uom::si::length::meter;
+//! use uom::si::time::second;
+//! use uom::si::velocity::meter_per_second;
+//! # fn main() {
+//! let displacement: QVec3<Length> = QVec3::from_base(Vec3::new(3, 0, 4));
+//! let velocity: QVec3<Velocity> = displacement / Time::new::<second>(2.0);
+//! assert_eq!(velocity.len().get::<meter_per_second>(), 2.5);
+//! let back = displacement + velocity * Time::new::<second>(2.0);
+//! assert_eq!(back.x.get::<meter>(), 6.0);
+//! # }
+//! ```
+use std::ops::{Add, Div, Mul, Neg, Sub};
+use uom::si::{Dimension, Quantity, Units};
+use vec3::Vec3;
+
+/// Vector with components of quantity type `Q`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct QVec3<Q> {
+    /// x coordinate
+    pub x: Q,
+    /// y coordinate
+    pub y: Q,
+    /// z coordinate
+    pub z: Q,
+}
+
+impl<Q> QVec3<Q> {
+    /// Constructs a new `QVec3` from components
+    pub fn new(x: Q, y: Q, z: Q) -> QVec3<Q> {
+        QVec3 { x, y, z }
+    }
+    /// Scalar product, its dimension is product of dimensions
+    ///
+    /// # Example
+    /// ```
+    /// # extern crate uom;
+    /// # extern crate linal;
+    /// # use linal::Vec3;
+    /// # use linal::units::QVec3;
+    /// use uom::si::f64::{Force, Length};
+    /// use uom::si::energy::joule;
+    /// # fn main() {
+    /// let force: QVec3<Force> = QVec3::from_base(Vec3::new(0, 0, -10));
+    /// let path: QVec3<Length> = QVec3::from_base(Vec3::new(5, 0, -2));
+    /// assert_eq!(force.dot(path).get::<joule>(), 20.0);
+    /// # }
+    /// ```
+    pub fn dot<R, P>(self, rhs: QVec3<R>) -> P
+    where
+        Q: Mul<R, Output = P>,
+        P: Add<Output = P>,
+    {
+        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z
+    }
+    /// Vector product, its dimension is product of dimensions
+    pub fn cross<R, P>(self, rhs: QVec3<R>) -> QVec3<P>
+    where
+        Q: Mul<R, Output = P> + Copy,
+        R: Copy,
+        P: Sub<Output = P>,
+    {
+        QVec3::new(self.y * rhs.z - self.z * rhs.y,
+                   self.z * rhs.x - self.x * rhs.z,
+                   self.x * rhs.y - self.y * rhs.x)
+    }
+}
+
+impl<D, U> QVec3<Quantity<D, U, f64>>
+where
+    D: Dimension + ?Sized,
+    U: Units<f64> + ?Sized,
+{
+    /// Constructs a new `QVec3` from components in base SI units
+    pub fn from_base(v: Vec3) -> QVec3<Quantity<D, U, f64>> {
+        let q = |value| Quantity { dimension: Default::default(), units: Default::default(), value };
+        QVec3::new(q(v.x), q(v.y), q(v.z))
+    }
+    /// Components in base SI units
+    pub fn to_base(self) -> Vec3 {
+        Vec3::new(self.x.value, self.y.value, self.z.value)
+    }
+    /// Length of vector, it has dimension of components
+    pub fn len(self) -> Quantity<D, U, f64> {
+        Quantity { dimension: Default::default(), units: Default::default(), value: self.to_base().len() }
+    }
+}
+
+impl<Q: Add<Output = Q>> Add for QVec3<Q> {
+    type Output = QVec3<Q>;
+    fn add(self, rhs: QVec3<Q>) -> QVec3<Q> {
+        QVec3::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl<Q: Sub<Output = Q>> Sub for QVec3<Q> {
+    type Output = QVec3<Q>;
+    fn sub(self, rhs: QVec3<Q>) -> QVec3<Q> {
+        QVec3::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl<Q: Neg<Output = Q>> Neg for QVec3<Q> {
+    type Output = QVec3<Q>;
+    fn neg(self) -> QVec3<Q> {
+        QVec3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl<Q: Mul<R>, R: Copy> Mul<R> for QVec3<Q> {
+    type Output = QVec3<Q::Output>;
+    /// Product with scalar quantity, dimensions are multiplied
+    fn mul(self, rhs: R) -> QVec3<Q::Output> {
+        QVec3::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl<Q: Div<R>, R: Copy> Div<R> for QVec3<Q> {
+    type Output = QVec3<Q::Output>;
+    /// Quotient by scalar quantity, dimensions are divided
+    fn div(self, rhs: R) -> QVec3<Q::Output> {
+        QVec3::new(self.x / rhs, self.y / rhs, self.z / rhs)
+    }
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+    use uom::si::f64::{Acceleration, Length, Mass, Time};
+    use uom::si::force::newton;
+    use uom::si::length::kilometer;
+    use uom::si::time::second;
+
+    #[test]
+    fn units_dimensional_arithmetic() {
+        let a: QVec3<Acceleration> = QVec3::from_base(Vec3::new(0.0, 0.0, -9.8));
+        let m = Mass::new::<uom::si::mass::kilogram>(2.0);
+        let force = a * m;
+        assert_eq!(force.z.get::<newton>(), -19.6);
+        let t = Time::new::<second>(10.0);
+        let drop: QVec3<Length> = a * t * t * 0.5;
+        assert!((drop.len().get::<kilometer>() - 0.49).abs() < 1e-12);
+        assert_eq!((-drop).to_base(), Vec3::new(0, 0, 490));
+        let r: QVec3<Length> = QVec3::from_base(Vec3::new(1, 0, 0));
+        let torque = r.cross(force);
+        assert_eq!(torque.y.value, 19.6);
+    }
+}
@@ -1,11 +1,43 @@
 //! Vectors on a plane.
-use std::ops::{Add, Sub, Mul, Div, Neg};
-use std::ops::{AddAssign, SubAssign, MulAssign, DivAssign};
+use std::ops::{Add, Sub, Mul, Div, Rem, Neg};
+use std::ops::{AddAssign, SubAssign, MulAssign, DivAssign, RemAssign};
 use std::ops::{Index, IndexMut};
 use std::cmp::PartialEq;
 use std::str::FromStr;
 use std::fmt;
-use std::num;
+use parse::{parse_components, ParseVecError};
+use mat2::Mat2;
+use rect::Rect;
+use vec3::Vec3;
+
+// length of sum of unit vectors below which they are treated as opposite,
+// direction of the sum is lost to rounding there
+pub(crate) const OPPOSITE_EPS: f64 = 1e-8;
+
+// coordinate moved by whole periods into [min, max)
+pub(crate) fn wrap_coord(x: f64, min: f64, max: f64) -> f64 {
+    let size = max - min;
+    let t = (x - min).rem_euclid(size);
+    // tiny negative offsets round up to full period
+    if t < size { min + t } else { min }
+}
+
+// shortest of offsets equal to `d` modulo period `size`, in [-size/2, size/2];
+// zero period means axis is not periodic
+pub(crate) fn min_image(d: f64, size: f64) -> f64 {
+    if size == 0.0 {
+        return d;
+    }
+    d - size * (d / size).round()
+}
+
+// nearest multiple of `cell`, ties rounded up; `(q + 0.5).floor()` is off
+// just below half and for odd integers above 2^52
+pub(crate) fn snap(x: f64, cell: f64) -> f64 {
+    let q = x / cell;
+    let f = q.floor();
+    if q - f >= 0.5 { (f + 1.0) * cell } else { f * cell }
+}
 
 /// 2D vector in cartesian coordinates
 #[derive(Debug, Clone, Copy)]
@@ -153,6 +185,35 @@ impl Vec2 {
     pub fn ort(self) -> Vec2 {
         self / self.len()
     }
+    /// Vector length without overflow and underflow of intermediate squares
+    ///
+    /// Slower than [`len`](#method.len), but accurate for components as
+    /// large as `1e200` or as small as `1e-200`.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Vec2;
+    /// let v = Vec2::new(3e200, 4e200);
+    /// assert_eq!(v.len(), f64::INFINITY);
+    /// assert!((v.len_stable() / 5e200 - 1.0).abs() < 1e-15);
+    /// ```
+    pub fn len_stable(self) -> f64 {
+        self.x.hypot(self.y)
+    }
+    /// Unary vector, co-directed with given, without overflow and underflow
+    /// of intermediate squares
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Vec2;
+    /// let v = Vec2::new(3e-200, 4e-200);
+    /// assert!(v.ort().x.is_infinite());
+    /// assert!((v.ort_stable() - Vec2::new(0.6, 0.8)).len() < 1e-15);
+    /// ```
+    pub fn ort_stable(self) -> Vec2 {
+        let v = self / self.x.abs().max(self.y.abs());
+        v / v.len()
+    }
     /// Squares of the vector coordinates
     ///
     /// # Example
@@ -201,6 +262,407 @@ impl Vec2 {
         let area = a.area(b);
         (b.cross() / area, -a.cross() / area)
     }
+    /// Centroid (arithmetic mean) of points
+    ///
+    /// Returns zero vector for empty slice.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Vec2;
+    /// let points = [Vec2::new(0, 0), Vec2::new(2, 0), Vec2::new(1, 3)];
+    /// assert_eq!(Vec2::centroid(&points), Vec2::new(1, 1));
+    /// ```
+    pub fn centroid(points: &[Vec2]) -> Vec2 {
+        if points.is_empty() {
+            return Vec2::zero();
+        }
+        points.iter().fold(Vec2::zero(), |acc, &p| acc + p) / points.len() as f64
+    }
+    /// Covariance matrix of points
+    ///
+    /// Population covariance $\frac{1}{n} \sum_i (\vec{p}_i - \vec{c}) \otimes (\vec{p}_i - \vec{c})$
+    /// around centroid $\vec{c}$. Returns zero matrix for empty slice.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Vec2, Mat2};
+    /// let points = [Vec2::new(-1, 0), Vec2::new(1, 0), Vec2::new(0, -2), Vec2::new(0, 2)];
+    /// let cov = Vec2::covariance(&points);
+    /// assert_eq!(cov, Mat2::new(Vec2::new(0.5, 0.0), Vec2::new(0, 2)));
+    /// ```
+    pub fn covariance(points: &[Vec2]) -> Mat2 {
+        if points.is_empty() {
+            return Mat2::zero();
+        }
+        let c = Vec2::centroid(points);
+        let sum = points.iter().fold(Mat2::zero(), |acc, &p| {
+            let d = p - c;
+            acc + Mat2::new(d * d.x, d * d.y)
+        });
+        sum * (1.0 / points.len() as f64)
+    }
+    /// Principal axes of points
+    ///
+    /// Returns variances along principal axes in descending order and unit
+    /// vectors of corresponding axes, i.e. eigen decomposition of covariance
+    /// matrix.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Vec2;
+    /// let points = [Vec2::new(-2, -2), Vec2::new(2, 2), Vec2::new(-1, 1), Vec2::new(1, -1)];
+    /// let (variances, (major, minor)) = Vec2::principal_axes(&points);
+    /// assert!((variances - Vec2::new(4, 1)).len() < 1e-12);
+    /// assert!(major.dot(Vec2::new(1, 1)).abs() > 1.414);
+    /// assert!(minor.dot(Vec2::new(1, -1)).abs() > 1.414);
+    /// ```
+    pub fn principal_axes(points: &[Vec2]) -> (Vec2, (Vec2, Vec2)) {
+        let (values, (a, b)) = Vec2::covariance(points).symmetric_eigen();
+        (Vec2::new(values.y, values.x), (b, a))
+    }
+    /// Homogeneous coordinates of point, $(x, y, 1)$
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Vec2, Vec3};
+    /// assert_eq!(Vec2::new(2, 3).to_homogeneous(), Vec3::new(2, 3, 1));
+    /// ```
+    pub fn to_homogeneous(self) -> Vec3 {
+        Vec3::new(self.x, self.y, 1.0)
+    }
+    /// Homogeneous coordinates of direction, $(x, y, 0)$
+    ///
+    /// Directions are not affected by translation part of projective transform.
+    pub fn to_homogeneous_direction(self) -> Vec3 {
+        Vec3::new(self.x, self.y, 0.0)
+    }
+    /// Point from homogeneous coordinates with perspective divide
+    ///
+    /// Returns `None` for point at infinity ($w = 0$).
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Vec2, Vec3};
+    /// assert_eq!(Vec2::from_homogeneous(Vec3::new(4, 6, 2)), Some(Vec2::new(2, 3)));
+    /// assert_eq!(Vec2::from_homogeneous(Vec3::new(1, 0, 0)), None);
+    /// ```
+    pub fn from_homogeneous(h: Vec3) -> Option<Vec2> {
+        if h.z == 0.0 {
+            None
+        } else {
+            Some(Vec2::new(h.x / h.z, h.y / h.z))
+        }
+    }
+    /// Evenly spaced points from `a` to `b`
+    ///
+    /// Yields `n` points including both ends, single point is `a`.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Vec2;
+    /// let points: Vec<Vec2> = Vec2::linspace(Vec2::new(0, 0), Vec2::new(2, 1), 3).collect();
+    /// assert_eq!(points, vec![Vec2::new(0, 0), Vec2::new(1.0, 0.5), Vec2::new(2, 1)]);
+    /// ```
+    pub fn linspace(a: Vec2, b: Vec2, n: usize) -> impl Iterator<Item = Vec2> {
+        let last = n.saturating_sub(1).max(1) as f64;
+        (0..n).map(move |i| {
+            let t = i as f64 / last;
+            a * (1.0 - t) + b * t
+        })
+    }
+    /// Product of vectors as complex numbers
+    ///
+    /// Treating vectors as $x + iy$, result is rotation of `self` by angle of
+    /// `rhs` scaled by its length.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Vec2;
+    /// let a = Vec2::new(1, 2);
+    /// // multiplication by i is rotation by right angle
+    /// assert_eq!(a.complex_mul(Vec2::new(0, 1)), Vec2::new(-2, 1));
+    /// assert_eq!(a.complex_mul(Vec2::new(3, -1)), Vec2::new(5, 5));
+    /// ```
+    pub fn complex_mul(self, rhs: Vec2) -> Vec2 {
+        Vec2::new(self.x * rhs.x - self.y * rhs.y, self.x * rhs.y + self.y * rhs.x)
+    }
+    /// Checks if vectors are parallel (or antiparallel) within tolerance
+    ///
+    /// Tolerance bounds sine of angle between vectors, so result doesn't
+    /// depend on their lengths. Zero vector is parallel to any vector.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Vec2;
+    /// let a = Vec2::new(1e-9, 2e-9);
+    /// assert!(a.is_parallel_to(Vec2::new(-3e6, -6e6), 1e-12));
+    /// assert!(!a.is_parallel_to(Vec2::new(2, 1), 1e-12));
+    /// ```
+    pub fn is_parallel_to(self, other: Vec2, eps: f64) -> bool {
+        self.area(other).abs() <= eps * self.len() * other.len()
+    }
+    /// Checks if vectors are perpendicular within tolerance
+    ///
+    /// Tolerance bounds cosine of angle between vectors, so result doesn't
+    /// depend on their lengths. Zero vector is perpendicular to any vector.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Vec2;
+    /// let a = Vec2::new(3e8, 4e8);
+    /// assert!(a.is_perpendicular_to(Vec2::new(-4e-8, 3e-8), 1e-12));
+    /// assert!(!a.is_perpendicular_to(Vec2::new(-4.0, 3.1), 1e-3));
+    /// ```
+    pub fn is_perpendicular_to(self, other: Vec2, eps: f64) -> bool {
+        self.dot(other).abs() <= eps * self.len() * other.len()
+    }
+    /// Checks if three points lie on one line within tolerance
+    ///
+    /// Tolerance is relative to size of triangle: distance from line through
+    /// two farthest points to the third one must not exceed `eps` times
+    /// distance between them. Coinciding points are collinear.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Vec2;
+    /// let (a, b) = (Vec2::new(0, 0), Vec2::new(1e6, 1e6));
+    /// assert!(Vec2::are_collinear(a, b, Vec2::new(5e5, 5e5 + 1e-4), 1e-9));
+    /// assert!(!Vec2::are_collinear(a, b * 1e-6, Vec2::new(0.5, 0.5001), 1e-9));
+    /// ```
+    pub fn are_collinear(a: Vec2, b: Vec2, c: Vec2, eps: f64) -> bool {
+        let longest = [b - a, c - b, a - c].iter().map(|e| e.dot(*e)).fold(0.0, f64::max);
+        (b - a).area(c - a).abs() <= eps * longest
+    }
+    /// Unit direction bisecting angle between vectors
+    ///
+    /// For opposite vectors bisector is perpendicular to them, it's taken
+    /// rotated counterclockwise from `self`. Nearly opposite vectors are
+    /// handled without loss of precision. Returns `None` if any vector is
+    /// zero.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Vec2;
+    /// let b = Vec2::new(5, 0).bisector(Vec2::new(0, 2)).unwrap();
+    /// assert!((b - Vec2::new(1, 1).ort()).len() < 1e-12);
+    /// assert_eq!(Vec2::new(1, 0).bisector(Vec2::new(-3, 0)), Some(Vec2::new(0, 1)));
+    /// assert_eq!(Vec2::new(1, 0).bisector(Vec2::zero()), None);
+    /// ```
+    pub fn bisector(self, other: Vec2) -> Option<Vec2> {
+        let (la, lb) = (self.len(), other.len());
+        if la == 0.0 || lb == 0.0 {
+            return None;
+        }
+        let (a, b) = (self / la, other / lb);
+        let sum = a + b;
+        // sum loses precision for nearly opposite vectors
+        if sum.len() < OPPOSITE_EPS {
+            let normal = -a.cross();
+            // keep bisector on side of `other`
+            return Some(if normal.dot(b) < 0.0 && a.area(b) != 0.0 { -normal } else { normal });
+        }
+        Some(sum.ort())
+    }
+    /// Fused multiply-add $self \cdot a + b$ with single rounding per component
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Vec2;
+    /// let v = Vec2::new(1, 2).mul_add(3.0, Vec2::new(1, 1));
+    /// assert_eq!(v, Vec2::new(4, 7));
+    /// ```
+    pub fn mul_add(self, a: f64, b: Vec2) -> Vec2 {
+        Vec2::new(self.x.mul_add(a, b.x), self.y.mul_add(a, b.y))
+    }
+    /// Componentwise fused multiply-add $self_i a_i + b_i$ with single rounding
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Vec2;
+    /// let v = Vec2::new(1, 2).mul_add_vec(Vec2::new(3, 4), Vec2::new(1, 1));
+    /// assert_eq!(v, Vec2::new(4, 9));
+    /// ```
+    pub fn mul_add_vec(self, a: Vec2, b: Vec2) -> Vec2 {
+        Vec2::new(self.x.mul_add(a.x, b.x), self.y.mul_add(a.y, b.y))
+    }
+    /// Scalar product accumulated with fused multiply-add
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Vec2;
+    /// assert_eq!(Vec2::new(1, 2).dot_fma(Vec2::new(3, 4)), 11.0);
+    /// ```
+    pub fn dot_fma(self, rhs: Vec2) -> f64 {
+        self.y.mul_add(rhs.y, self.x * rhs.x)
+    }
+    /// Reciprocals of coordinates
+    ///
+    /// Follows IEEE 754 division: zero coordinate gives infinity of the same
+    /// sign as zero, so reciprocal of direction can be used directly in slab
+    /// tests against axis-aligned boxes. See [`try_recip`](#method.try_recip)
+    /// for checked variant.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Vec2;
+    /// let inv = Vec2::new(2.0, -0.0).recip();
+    /// assert_eq!(inv.x, 0.5);
+    /// assert_eq!(inv.y, f64::NEG_INFINITY);
+    /// ```
+    pub fn recip(self) -> Vec2 {
+        Vec2::new(1.0 / self.x, 1.0 / self.y)
+    }
+    /// Reciprocals of coordinates, `None` if any of them is not finite
+    ///
+    /// That is the case for zero coordinates and for ones so small that
+    /// their reciprocals overflow.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Vec2;
+    /// assert_eq!(Vec2::new(4, -2).try_recip(), Some(Vec2::new(0.25, -0.5)));
+    /// assert_eq!(Vec2::new(1, 0).try_recip(), None);
+    /// ```
+    pub fn try_recip(self) -> Option<Vec2> {
+        let r = self.recip();
+        if r.x.is_finite() && r.y.is_finite() {
+            Some(r)
+        } else {
+            None
+        }
+    }
+    /// Nearest node of grid with given cell sizes along axes
+    ///
+    /// Grid has node at origin. Coordinates halfway between nodes are
+    /// rounded up, towards positive infinity, for negative coordinates as
+    /// well, so snapping commutes with shifts by whole cells.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Vec2;
+    /// let cell = Vec2::new(2.0, 0.5);
+    /// assert_eq!(Vec2::new(2.9, -0.3).snap_to_grid(cell), Vec2::new(2.0, -0.5));
+    /// assert_eq!(Vec2::new(-1.0, -0.25).snap_to_grid(cell), Vec2::new(0, 0));
+    /// ```
+    pub fn snap_to_grid(self, cell: Vec2) -> Vec2 {
+        Vec2::new(snap(self.x, cell.x), snap(self.y, cell.y))
+    }
+    /// Coordinates rounded to multiples of `step`
+    ///
+    /// Same as [`snap_to_grid`](#method.snap_to_grid) with square cells.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Vec2;
+    /// assert_eq!(Vec2::new(0.37, -0.52).quantize(0.25), Vec2::new(0.25, -0.5));
+    /// ```
+    pub fn quantize<I: Into<f64>>(self, step: I) -> Vec2 {
+        let step = step.into();
+        self.snap_to_grid(Vec2::new(step, step))
+    }
+    /// Cell of grid with given cell sizes containing point
+    ///
+    /// Cell $(i, j)$ covers $[i c_x, (i + 1) c_x) \times [j c_y, (j + 1) c_y)$,
+    /// so points with small negative coordinates belong to cell $-1$, not $0$.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Vec2;
+    /// let cell = Vec2::new(16, 16);
+    /// assert_eq!(Vec2::new(17, 3).to_cell(cell), (1, 0));
+    /// assert_eq!(Vec2::new(-0.5, -16.0).to_cell(cell), (-1, -1));
+    /// ```
+    pub fn to_cell(self, cell: Vec2) -> (i64, i64) {
+        ((self.x / cell.x).floor() as i64, (self.y / cell.y).floor() as i64)
+    }
+    /// Point moved by whole periods into rectangle of periodic domain
+    ///
+    /// Result lies in $[min, max)$ along each axis, so opposite sides of
+    /// `bounds` are identified as on a torus.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Rect, Vec2};
+    /// let bounds = Rect::new(Vec2::new(0, 0), Vec2::new(10, 5));
+    /// assert_eq!(Vec2::new(12, -1).wrap(bounds), Vec2::new(2, 4));
+    /// assert_eq!(Vec2::new(10, 5).wrap(bounds), Vec2::new(0, 0));
+    /// ```
+    pub fn wrap(self, bounds: Rect) -> Vec2 {
+        Vec2::new(wrap_coord(self.x, bounds.min.x, bounds.max.x),
+                  wrap_coord(self.y, bounds.min.y, bounds.max.y))
+    }
+    /// Shortest vector from point to `other` in periodic domain
+    ///
+    /// Minimum image convention: among all periodic images of `other` the
+    /// nearest one is taken, so each coordinate of result is at most half of
+    /// period of `bounds` by magnitude. Points need not be wrapped. Axis with
+    /// zero extent is not periodic, its coordinate of difference is kept.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Rect, Vec2};
+    /// let bounds = Rect::new(Vec2::new(0, 0), Vec2::new(10, 10));
+    /// let (a, b) = (Vec2::new(1, 9), Vec2::new(9, 2));
+    /// assert_eq!(a.wrapped_delta(b, bounds), Vec2::new(-2, 3));
+    /// ```
+    pub fn wrapped_delta(self, other: Vec2, bounds: Rect) -> Vec2 {
+        let d = other - self;
+        let size = bounds.max - bounds.min;
+        Vec2::new(min_image(d.x, size.x), min_image(d.y, size.y))
+    }
+    /// Distance between points in periodic domain
+    ///
+    /// Length of [`wrapped_delta`](#method.wrapped_delta).
+    pub fn wrapped_distance(self, other: Vec2, bounds: Rect) -> f64 {
+        self.wrapped_delta(other, bounds).len()
+    }
+    /// Monotone surrogate of polar angle in $[0, 4)$, computed without
+    /// trigonometry
+    ///
+    /// Increases with counterclockwise angle from $x$ axis as `atan2` mapped
+    /// into $[0, 2\pi)$ does, so it can replace angle in comparisons. Zero
+    /// vector gets $0$.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Vec2;
+    /// assert_eq!(Vec2::new(1, 0).pseudo_angle(), 0.0);
+    /// assert_eq!(Vec2::new(1, 1).pseudo_angle(), 0.5);
+    /// assert_eq!(Vec2::new(-1, 0).pseudo_angle(), 2.0);
+    /// assert_eq!(Vec2::new(0, -1).pseudo_angle(), 3.0);
+    /// ```
+    pub fn pseudo_angle(self) -> f64 {
+        let norm = self.x.abs() + self.y.abs();
+        if norm == 0.0 {
+            return 0.0;
+        }
+        let p = self.y / norm;
+        if self.x < 0.0 {
+            2.0 - p
+        } else if self.y < 0.0 {
+            4.0 + p
+        } else {
+            p
+        }
+    }
+    /// Sorts points counterclockwise around `center`, starting from $x$ axis
+    ///
+    /// Points in the same direction from center are ordered by distance.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Vec2;
+    /// let mut square = [Vec2::new(1, -1), Vec2::new(-1, 1), Vec2::new(1, 1), Vec2::new(-1, -1)];
+    /// Vec2::sort_ccw_around(Vec2::zero(), &mut square);
+    /// assert_eq!(square, [Vec2::new(1, 1), Vec2::new(-1, 1), Vec2::new(-1, -1), Vec2::new(1, -1)]);
+    /// ```
+    pub fn sort_ccw_around(center: Vec2, points: &mut [Vec2]) {
+        points.sort_by(|&a, &b| {
+            let (a, b) = (a - center, b - center);
+            a.pseudo_angle().total_cmp(&b.pseudo_angle())
+                .then(a.dot(a).total_cmp(&b.dot(b)))
+        });
+    }
 
     // need for op_default & op_assign
     fn size(&self) -> usize { 2 }
@@ -209,11 +671,16 @@ impl Vec2 {
 op_default!(add, Add, +=, Vec2);
 op_default!(sub, Sub, -=, Vec2);
 op_default!(mul, Mul, *=, Vec2);
+op_default!(div, Div, /=, Vec2);
+op_default!(rem, Rem, %=, Vec2);
 op_default!(f64, mul, Mul, *=, Vec2);
+op_scalar_left!(Vec2);
 op_default!(f64, div, Div, /=, Vec2);
 op_assign!(add_assign, AddAssign, +=, Vec2);
 op_assign!(sub_assign, SubAssign, -=, Vec2);
 op_assign!(mul_assign, MulAssign, *=, Vec2);
+op_assign!(div_assign, DivAssign, /=, Vec2);
+op_assign!(rem_assign, RemAssign, %=, Vec2);
 op_assign!(f64, mul_assign, MulAssign, *=, Vec2);
 op_assign!(f64, div_assign, DivAssign, /=, Vec2);
 
@@ -253,19 +720,46 @@ impl PartialEq for Vec2 {
     }
 }
 
+/// Formats vector as space separated components
+///
+/// Alternate form `{:#}` gives `(x, y)`. Precision, width, fill, alignment
+/// and sign flags are applied to every component.
+///
+/// # Example
+/// ```
+/// # use linal::Vec2;
+/// let v = Vec2::new(1.5, -2.0);
+/// assert_eq!(format!("{}", v), "1.5 -2");
+/// assert_eq!(format!("{:#}", v), "(1.5, -2)");
+/// assert_eq!(format!("{:+.2}", v), "+1.50 -2.00");
+/// assert_eq!(format!("{:>6.1}", v), "   1.5   -2.0");
+/// ```
 impl fmt::Display for Vec2 {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{} {}", self.x, self.y)
+        let (open, sep, close) = if f.alternate() { ("(", ", ", ")") } else { ("", " ", "") };
+        f.write_str(open)?;
+        fmt::Display::fmt(&self.x, f)?;
+        f.write_str(sep)?;
+        fmt::Display::fmt(&self.y, f)?;
+        f.write_str(close)
     }
 }
 
+/// Parses vector from two numbers separated by whitespace or comma,
+/// optionally wrapped in parentheses or square brackets
+///
+/// # Example
+/// ```
+/// # use linal::Vec2;
+/// for s in &["1 2", "1,2", "(1, 2)", "[1 2]", "1e0 2e0"] {
+///     assert_eq!(s.parse::<Vec2>().unwrap(), Vec2::new(1, 2));
+/// }
+/// ```
 impl FromStr for Vec2 {
-    type Err = num::ParseFloatError;
+    type Err = ParseVecError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let words: Vec<&str> = s.split_whitespace().collect();
-        let x: f64 = words[0].parse()?;
-        let y: f64 = words[1].parse()?;
-        Ok(Self::new(x, y))
+        let c = parse_components(s, 2)?;
+        Ok(Self::new(c[0], c[1]))
     }
 }
 
@@ -285,6 +779,7 @@ mod linal_test {
         assert_eq!(r, b);
         assert_eq!(z, b);
         assert_eq!(x, Vec2::new(3, 12));
+        assert_eq!(3.0 * a, b);
     }
 
     #[test]
@@ -403,4 +898,143 @@ mod linal_test {
         let a: Vec2 = "1 2".parse().unwrap();
         assert_eq!(a, Vec2::new(1, 2));
     }
+
+    #[test]
+    fn vec2_display_round_trip() {
+        let v = Vec2::new(0.1, -1e-20);
+        assert_eq!(format!("{}", v).parse::<Vec2>(), Ok(v));
+        assert_eq!(format!("{:#}", v).parse::<Vec2>(), Ok(v));
+    }
+
+    #[test]
+    fn vec2_parse_errors() {
+        assert_eq!("1".parse::<Vec2>(), Err(ParseVecError::MissingComponent { index: 1 }));
+        assert_eq!("1 2 3".parse::<Vec2>(), Err(ParseVecError::ExtraTokens { expected: 2, found: 3 }));
+        assert!("1 two".parse::<Vec2>().is_err());
+    }
+
+    #[test]
+    fn vec2_covariance() {
+        let points = [Vec2::new(1, 1), Vec2::new(3, 5), Vec2::new(5, 3)];
+        let cov = Vec2::covariance(&points);
+        let expected = Mat2::new(Vec2::new(8, 4), Vec2::new(4, 8)) * (1.0 / 3.0);
+        assert!((cov - expected).x.len() + (cov - expected).y.len() < 1e-12);
+        assert_eq!(Vec2::covariance(&[]), Mat2::zero());
+        assert_eq!(Vec2::covariance(&[Vec2::new(3, 4)]), Mat2::zero());
+    }
+
+    #[test]
+    fn vec2_principal_axes() {
+        let dir = Vec2::new(3, 4).ort();
+        let points: Vec<Vec2> = (-5..6i32).map(|i| dir * i + dir.cross() * (i.abs() % 2)).collect();
+        let (variances, (major, minor)) = Vec2::principal_axes(&points);
+        assert!(variances.x > variances.y);
+        assert!(major.dot(dir).abs() > 0.99);
+        assert!(minor.dot(dir).abs() < 0.1);
+    }
+
+    #[test]
+    fn vec2_div_rem_componentwise() {
+        let a = Vec2::new(7, -9);
+        let b = Vec2::new(2, 4);
+        assert_eq!(a / b, Vec2::new(3.5, -2.25));
+        assert_eq!(a % b, Vec2::new(1, -1));
+        let mut z = a;
+        z /= b;
+        assert_eq!(z, a / b);
+        z = a;
+        z %= b;
+        assert_eq!(z, a % b);
+    }
+
+    #[test]
+    fn vec2_parallel_perpendicular() {
+        let a = Vec2::new(1, 1);
+        // angle of 1e-6 radians
+        let b = Vec2::from_polar(1.0, std::f64::consts::FRAC_PI_4 + 1e-6);
+        for &k in &[1e-12, 1.0, 1e12] {
+            assert!((a * k).is_parallel_to(b, 2e-6));
+            assert!(!(a * k).is_parallel_to(b, 5e-7));
+            assert!((a * k).is_perpendicular_to(b.cross(), 2e-6));
+        }
+        assert!(Vec2::zero().is_parallel_to(a, 0.0));
+    }
+
+    #[test]
+    fn vec2_collinear_scale_invariant() {
+        let (a, b, c) = (Vec2::new(1, 2), Vec2::new(4, 6), Vec2::new(7.0, 10.0001));
+        for &k in &[1e-9, 1.0, 1e9] {
+            assert!(Vec2::are_collinear(a * k, b * k, c * k, 1e-5));
+            assert!(!Vec2::are_collinear(a * k, b * k, c * k, 1e-6));
+            assert!(Vec2::are_collinear(c * k, a * k, b * k, 1e-5));
+        }
+        assert!(Vec2::are_collinear(a, a, a, 0.0));
+    }
+
+    #[test]
+    fn vec2_bisector_nearly_opposite() {
+        let a = Vec2::new(2, 0);
+        let b = Vec2::from_polar(1.0, std::f64::consts::PI - 1e-10);
+        let m = a.bisector(b).unwrap();
+        assert!((m - Vec2::new(0, 1)).len() < 1e-9);
+        let b = Vec2::from_polar(1.0, std::f64::consts::PI + 1e-10);
+        let m = a.bisector(b).unwrap();
+        assert!((m - Vec2::new(0, -1)).len() < 1e-9);
+        let c = Vec2::from_polar(3.0, 2.0);
+        let m = a.bisector(c).unwrap();
+        assert!((m.dot(a.ort()) - m.dot(c.ort())).abs() < 1e-12);
+    }
+
+    #[test]
+    fn vec2_grid_snapping() {
+        let cell = Vec2::new(0.5, 3.0);
+        // ties go up on both sides of zero, so shifts by whole cells commute
+        for &p in &[Vec2::new(-0.25, -1.5), Vec2::new(0.25, 1.5), Vec2::new(-1.7, 4.0)] {
+            let shift = Vec2::new(-2.0, -9.0);
+            assert_eq!((p + shift).snap_to_grid(cell), p.snap_to_grid(cell) + shift);
+            let (i, j) = p.to_cell(cell);
+            assert_eq!((p + shift).to_cell(cell), (i - 4, j - 3));
+        }
+        assert_eq!(Vec2::new(-0.25, -1.5).snap_to_grid(cell), Vec2::new(0, 0));
+        assert_eq!(Vec2::new(-1e-12, 0.0).to_cell(cell), (-1, 0));
+        let one = Vec2::new(1, 1);
+        assert_eq!(Vec2::new(0.49999999999999994, 0.0).snap_to_grid(one), Vec2::new(0, 0));
+        let odd = Vec2::new(4503599627370497.0, -4503599627370497.0);
+        assert_eq!(odd.snap_to_grid(one), odd);
+    }
+
+    #[test]
+    fn vec2_periodic() {
+        let bounds = Rect::new(Vec2::new(-2, 1), Vec2::new(3, 4));
+        let a = Vec2::new(-1.5, 3.5);
+        for &b in &[Vec2::new(2.5, 1.5), Vec2::new(-7.0, 10.0), Vec2::new(100.25, -33.0)] {
+            let d = a.wrapped_delta(b, bounds);
+            assert!(d.x.abs() <= 2.5 && d.y.abs() <= 1.5);
+            // delta leads to periodic image of the other point
+            assert!(((a + d).wrap(bounds) - b.wrap(bounds)).len() < 1e-12);
+            assert!((b.wrapped_delta(a, bounds) + d).len() < 1e-12 || d.x.abs() == 2.5);
+            assert!(a.wrapped_distance(b, bounds) <= (a - b).len());
+        }
+        let unit = Rect::new(Vec2::new(0, 0), Vec2::new(1, 1));
+        let w = Vec2::new(-1e-20, 1.0).wrap(unit);
+        assert!(w.x >= 0.0 && w.x < 1.0 && w.y == 0.0);
+        let flat = Rect::new(Vec2::new(0, 0), Vec2::new(10, 0));
+        assert_eq!(Vec2::new(1, 2).wrapped_delta(Vec2::new(9, -1), flat), Vec2::new(-2, -3));
+    }
+
+    #[test]
+    fn vec2_pseudo_angle_monotone() {
+        let angles: Vec<f64> = (0..1000).map(|i| i as f64 * 0.00628).collect();
+        let pseudo: Vec<f64> = angles.iter().map(|&t| Vec2::from_polar(2.5, t).pseudo_angle()).collect();
+        assert!(pseudo.windows(2).all(|w| w[0] < w[1]));
+        assert!(pseudo.iter().all(|&p| (0.0..4.0).contains(&p)));
+        let center = Vec2::new(3, -1);
+        let mut points: Vec<Vec2> = angles.iter().rev().map(|&t| center + Vec2::from_polar(1.0, t)).collect();
+        points.push(center + Vec2::new(0.5, 0.0));
+        Vec2::sort_ccw_around(center, &mut points);
+        assert_eq!(points[0], center + Vec2::new(0.5, 0.0));
+        assert!(points.windows(2).skip(1).all(|w| {
+            (w[0] - center).area(w[1] - center) > 0.0
+        }));
+    }
 }
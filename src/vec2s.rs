@@ -0,0 +1,173 @@
+//! Batches of plane vectors in structure-of-arrays layout.
+use vec2::Vec2;
+
+/// Batch of plane vectors stored by coordinate columns
+///
+/// Keeps coordinates of all vectors in separate contiguous arrays, so bulk
+/// operations stream through memory and are easy to vectorize.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Vec2s {
+    /// x coordinates
+    pub x: Vec<f64>,
+    /// y coordinates
+    pub y: Vec<f64>,
+}
+
+impl Vec2s {
+    /// Constructs an empty batch
+    pub fn new() -> Vec2s {
+        Vec2s { x: Vec::new(), y: Vec::new() }
+    }
+    /// Constructs an empty batch with space for `n` vectors
+    pub fn with_capacity(n: usize) -> Vec2s {
+        Vec2s { x: Vec::with_capacity(n), y: Vec::with_capacity(n) }
+    }
+    /// Number of vectors in batch
+    pub fn len(&self) -> usize {
+        self.x.len()
+    }
+    /// Checks if batch is empty
+    pub fn is_empty(&self) -> bool {
+        self.x.is_empty()
+    }
+    /// Appends vector to batch
+    pub fn push(&mut self, v: Vec2) {
+        self.x.push(v.x);
+        self.y.push(v.y);
+    }
+    /// Vector with given index
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Vec2, Vec2s};
+    /// let batch = Vec2s::from(&[Vec2::new(1, 2), Vec2::new(3, 4)][..]);
+    /// assert_eq!(batch.get(1), Vec2::new(3, 4));
+    /// ```
+    pub fn get(&self, i: usize) -> Vec2 {
+        Vec2::new(self.x[i], self.y[i])
+    }
+    /// Converts batch back to array of vectors
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Vec2, Vec2s};
+    /// let points = vec![Vec2::new(1, 2), Vec2::new(3, 4)];
+    /// assert_eq!(Vec2s::from(&points[..]).to_vec(), points);
+    /// ```
+    pub fn to_vec(&self) -> Vec<Vec2> {
+        self.x.iter().zip(&self.y).map(|(&x, &y)| Vec2::new(x, y)).collect()
+    }
+    /// Adds vectors of other batch pairwise
+    ///
+    /// # Panics
+    /// Panics if batches have different lengths.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Vec2, Vec2s};
+    /// let mut a = Vec2s::from(&[Vec2::new(1, 2), Vec2::new(3, 4)][..]);
+    /// let b = Vec2s::from(&[Vec2::new(1, 1), Vec2::new(-3, 0)][..]);
+    /// a.add(&b);
+    /// assert_eq!(a.to_vec(), vec![Vec2::new(2, 3), Vec2::new(0, 4)]);
+    /// ```
+    pub fn add(&mut self, other: &Vec2s) {
+        assert_eq!(self.len(), other.len(), "batches must have equal lengths");
+        for (a, b) in self.x.iter_mut().zip(&other.x) {
+            *a += b;
+        }
+        for (a, b) in self.y.iter_mut().zip(&other.y) {
+            *a += b;
+        }
+    }
+    /// Multiplies all vectors by number
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Vec2, Vec2s};
+    /// let mut a = Vec2s::from(&[Vec2::new(1, 2), Vec2::new(3, 4)][..]);
+    /// a.scale(2);
+    /// assert_eq!(a.get(1), Vec2::new(6, 8));
+    /// ```
+    pub fn scale<I: Into<f64>>(&mut self, k: I) {
+        let k = k.into();
+        for c in self.x.iter_mut().chain(self.y.iter_mut()) {
+            *c *= k;
+        }
+    }
+    /// Scalar products of vectors from two batches taken pairwise
+    ///
+    /// # Panics
+    /// Panics if batches have different lengths.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Vec2, Vec2s};
+    /// let a = Vec2s::from(&[Vec2::new(1, 2), Vec2::new(3, 4)][..]);
+    /// let b = Vec2s::from(&[Vec2::new(1, 1), Vec2::new(-4, 3)][..]);
+    /// assert_eq!(a.dot_pairs(&b), vec![3.0, 0.0]);
+    /// ```
+    pub fn dot_pairs(&self, other: &Vec2s) -> Vec<f64> {
+        assert_eq!(self.len(), other.len(), "batches must have equal lengths");
+        (0..self.len())
+            .map(|i| self.x[i] * other.x[i] + self.y[i] * other.y[i])
+            .collect()
+    }
+    /// Normalizes all vectors to unit length
+    ///
+    /// Zero vectors become NaN, like with [`Vec2::ort`](../vec2/struct.Vec2.html#method.ort).
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Vec2, Vec2s};
+    /// let mut a = Vec2s::from(&[Vec2::new(3, 4), Vec2::new(0, -2)][..]);
+    /// a.normalize_in_place();
+    /// assert_eq!(a.to_vec(), vec![Vec2::new(0.6, 0.8), Vec2::new(0, -1)]);
+    /// ```
+    pub fn normalize_in_place(&mut self) {
+        for (x, y) in self.x.iter_mut().zip(self.y.iter_mut()) {
+            let len = (*x * *x + *y * *y).sqrt();
+            *x /= len;
+            *y /= len;
+        }
+    }
+}
+
+impl From<&[Vec2]> for Vec2s {
+    fn from(vectors: &[Vec2]) -> Vec2s {
+        Vec2s {
+            x: vectors.iter().map(|v| v.x).collect(),
+            y: vectors.iter().map(|v| v.y).collect(),
+        }
+    }
+}
+
+impl ::std::iter::FromIterator<Vec2> for Vec2s {
+    fn from_iter<T: IntoIterator<Item = Vec2>>(iter: T) -> Vec2s {
+        let mut batch = Vec2s::new();
+        for v in iter {
+            batch.push(v);
+        }
+        batch
+    }
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    #[test]
+    fn vec2s_matches_scalar_code() {
+        let a: Vec<Vec2> = (0..100).map(|i| Vec2::from_polar(1.0 + i as f64, i as f64)).collect();
+        let b: Vec<Vec2> = (0..100).map(|i| Vec2::new(i, -i)).collect();
+        let mut batch: Vec2s = a.iter().cloned().collect();
+        let other = Vec2s::from(&b[..]);
+        let dots = batch.dot_pairs(&other);
+        batch.add(&other);
+        batch.scale(0.5);
+        batch.normalize_in_place();
+        for i in 0..100 {
+            assert_eq!(dots[i], a[i].dot(b[i]));
+            assert!((batch.get(i) - ((a[i] + b[i]) * 0.5).ort()).len() < 1e-15);
+        }
+    }
+}
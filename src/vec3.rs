@@ -1,11 +1,19 @@
 //! Vectors in 3-dimensional euclidian space.
-use std::ops::{Add, Sub, Mul, Div, Neg};
-use std::ops::{AddAssign, SubAssign, DivAssign, MulAssign};
+use std::ops::{Add, Sub, Mul, Div, Rem, Neg};
+use std::ops::{AddAssign, SubAssign, DivAssign, MulAssign, RemAssign};
 use std::ops::{Index, IndexMut};
 use std::cmp::PartialEq;
 use std::str::FromStr;
 use std::fmt;
-use std::num;
+use parse::{parse_components, ParseVecError};
+use mat3::{Mat3, orthogonal_complement};
+use aabb3::Aabb3;
+use vec2::{wrap_coord, min_image, snap, OPPOSITE_EPS};
+use vec4::Vec4;
+
+// perpendicular component shorter than this is treated as zero, opposite
+// vectors are exactly antiparallel then
+const PERPENDICULAR_EPS: f64 = 1e-300;
 
 /// 3D vector in cartesian coordinates
 #[derive(Debug, Clone, Copy)]
@@ -128,6 +136,35 @@ impl Vec3 {
     pub fn ort(self) -> Vec3 {
         self / self.len()
     }
+    /// Vector length without overflow and underflow of intermediate squares
+    ///
+    /// Slower than [`len`](#method.len), but accurate for components as
+    /// large as `1e200` or as small as `1e-200`.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Vec3;
+    /// let v = Vec3::new(2e-200, 3e-200, 6e-200);
+    /// assert_eq!(v.len(), 0.0);
+    /// assert!((v.len_stable() - 7e-200).abs() < 1e-214);
+    /// ```
+    pub fn len_stable(self) -> f64 {
+        self.x.hypot(self.y).hypot(self.z)
+    }
+    /// Unary vector, co-directed with given, without overflow and underflow
+    /// of intermediate squares
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Vec3;
+    /// let v = Vec3::new(2e200, 3e200, 6e200);
+    /// assert_eq!(v.ort(), Vec3::zero());
+    /// assert!((v.ort_stable() - Vec3::new(2, 3, 6) / 7.0).len() < 1e-15);
+    /// ```
+    pub fn ort_stable(self) -> Vec3 {
+        let v = self / self.x.abs().max(self.y.abs()).max(self.z.abs());
+        v / v.len()
+    }
     /// Squares of the vector coordinates
     ///
     /// # Example
@@ -181,6 +218,387 @@ impl Vec3 {
          c.cross(a) / triple_prod,
          a.cross(b) / triple_prod)
     }
+    /// Centroid (arithmetic mean) of points
+    ///
+    /// Returns zero vector for empty slice.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Vec3;
+    /// let points = [Vec3::new(0, 0, 0), Vec3::new(2, 0, 4), Vec3::new(1, 3, 2)];
+    /// assert_eq!(Vec3::centroid(&points), Vec3::new(1, 1, 2));
+    /// ```
+    pub fn centroid(points: &[Vec3]) -> Vec3 {
+        if points.is_empty() {
+            return Vec3::zero();
+        }
+        points.iter().fold(Vec3::zero(), |acc, &p| acc + p) / points.len() as f64
+    }
+    /// Covariance matrix of points
+    ///
+    /// Population covariance $\frac{1}{n} \sum_i (\vec{p}_i - \vec{c}) \otimes (\vec{p}_i - \vec{c})$
+    /// around centroid $\vec{c}$. Returns zero matrix for empty slice.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Vec3, Mat3};
+    /// let points = [Vec3::new(-1, 0, 0), Vec3::new(1, 0, 0), Vec3::new(0, 0, -2), Vec3::new(0, 0, 2)];
+    /// let cov = Vec3::covariance(&points);
+    /// assert_eq!(cov, Mat3::diag(Vec3::new(0.5, 0.0, 2.0)));
+    /// ```
+    pub fn covariance(points: &[Vec3]) -> Mat3 {
+        if points.is_empty() {
+            return Mat3::zero();
+        }
+        let c = Vec3::centroid(points);
+        let sum = points.iter().fold(Mat3::zero(), |acc, &p| {
+            let d = p - c;
+            acc + Mat3::new(d * d.x, d * d.y, d * d.z)
+        });
+        sum * (1.0 / points.len() as f64)
+    }
+    /// Principal axes of points
+    ///
+    /// Returns variances along principal axes in descending order and unit
+    /// vectors of corresponding axes, i.e. eigen decomposition of covariance
+    /// matrix. Axes (major, middle, minor) form right-handed basis.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Vec3;
+    /// let points = [Vec3::new(-3, 0, 0), Vec3::new(3, 0, 0),
+    ///               Vec3::new(0, -2, 0), Vec3::new(0, 2, 0),
+    ///               Vec3::new(0, 0, -1), Vec3::new(0, 0, 1)];
+    /// let (variances, (a, b, c)) = Vec3::principal_axes(&points);
+    /// assert!((variances - Vec3::new(3.0, 4.0 / 3.0, 1.0 / 3.0)).len() < 1e-12);
+    /// assert!((a.x.abs() - 1.0).abs() < 1e-12);
+    /// assert!((b.y.abs() - 1.0).abs() < 1e-12);
+    /// assert!((c.z.abs() - 1.0).abs() < 1e-12);
+    /// ```
+    pub fn principal_axes(points: &[Vec3]) -> (Vec3, (Vec3, Vec3, Vec3)) {
+        let (values, (_, b, c)) = Vec3::covariance(points).symmetric_eigen();
+        (Vec3::new(values.z, values.y, values.x), (c, b, c.cross(b)))
+    }
+    /// Homogeneous coordinates of point, $(x, y, z, 1)$
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Vec3, Vec4};
+    /// assert_eq!(Vec3::new(1, 2, 3).to_homogeneous(), Vec4::new(1, 2, 3, 1));
+    /// ```
+    pub fn to_homogeneous(self) -> Vec4 {
+        Vec4::new(self.x, self.y, self.z, 1.0)
+    }
+    /// Homogeneous coordinates of direction, $(x, y, z, 0)$
+    ///
+    /// Directions are not affected by translation part of projective transform.
+    pub fn to_homogeneous_direction(self) -> Vec4 {
+        Vec4::new(self.x, self.y, self.z, 0.0)
+    }
+    /// Point from homogeneous coordinates with perspective divide
+    ///
+    /// Returns `None` for point at infinity ($w = 0$).
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Vec3, Vec4};
+    /// assert_eq!(Vec3::from_homogeneous(Vec4::new(2, 4, 6, 2)), Some(Vec3::new(1, 2, 3)));
+    /// assert_eq!(Vec3::from_homogeneous(Vec4::new(1, 0, 0, 0)), None);
+    /// ```
+    pub fn from_homogeneous(h: Vec4) -> Option<Vec3> {
+        if h.w == 0.0 {
+            None
+        } else {
+            Some(Vec3::new(h.x / h.w, h.y / h.w, h.z / h.w))
+        }
+    }
+    /// Evenly spaced points from `a` to `b`
+    ///
+    /// Yields `n` points including both ends, single point is `a`.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Vec3;
+    /// let points: Vec<Vec3> = Vec3::linspace(Vec3::zero(), Vec3::new(3, 0, -3), 4).collect();
+    /// assert_eq!(points[1], Vec3::new(1, 0, -1));
+    /// assert_eq!(points[3], Vec3::new(3, 0, -3));
+    /// ```
+    pub fn linspace(a: Vec3, b: Vec3, n: usize) -> impl Iterator<Item = Vec3> {
+        let last = n.saturating_sub(1).max(1) as f64;
+        (0..n).map(move |i| {
+            let t = i as f64 / last;
+            a * (1.0 - t) + b * t
+        })
+    }
+    /// Checks if vectors are parallel (or antiparallel) within tolerance
+    ///
+    /// Tolerance bounds sine of angle between vectors, so result doesn't
+    /// depend on their lengths. Zero vector is parallel to any vector.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Vec3;
+    /// let a = Vec3::new(1, 2, 3) * 1e-10;
+    /// assert!(a.is_parallel_to(Vec3::new(-2, -4, -6) * 1e10, 1e-12));
+    /// assert!(!a.is_parallel_to(Vec3::new(1, 2, 4), 1e-3));
+    /// ```
+    pub fn is_parallel_to(self, other: Vec3, eps: f64) -> bool {
+        self.cross(other).len() <= eps * self.len() * other.len()
+    }
+    /// Checks if vectors are perpendicular within tolerance
+    ///
+    /// Tolerance bounds cosine of angle between vectors, so result doesn't
+    /// depend on their lengths. Zero vector is perpendicular to any vector.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Vec3;
+    /// let a = Vec3::new(1, 1, 0);
+    /// assert!(a.is_perpendicular_to(Vec3::new(-1e-20, 1e-20, 5e-20), 1e-12));
+    /// assert!(!a.is_perpendicular_to(Vec3::new(1, 0, 0), 1e-3));
+    /// ```
+    pub fn is_perpendicular_to(self, other: Vec3, eps: f64) -> bool {
+        self.dot(other).abs() <= eps * self.len() * other.len()
+    }
+    /// Checks if four points lie on one plane within tolerance
+    ///
+    /// Tolerance is relative to size of points set: volume of parallelepiped
+    /// spanned by points must not exceed `eps` times cube of largest distance
+    /// between them, which bounds distance from plane through three points to
+    /// the fourth one relative to size. Coinciding or collinear points are
+    /// coplanar.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Vec3;
+    /// let (a, b, c) = (Vec3::new(0, 0, 0), Vec3::new(1, 0, 0), Vec3::new(0, 1, 0));
+    /// assert!(Vec3::are_coplanar(a, b, c, Vec3::new(0.5, 0.5, 1e-12), 1e-9));
+    /// assert!(Vec3::are_coplanar(a * 1e8, b * 1e8, c * 1e8, Vec3::new(0.5, 0.5, 1e-4) * 1e8, 1e-3));
+    /// assert!(!Vec3::are_coplanar(a, b, c, Vec3::new(0.5, 0.5, 1e-3), 1e-9));
+    /// ```
+    pub fn are_coplanar(a: Vec3, b: Vec3, c: Vec3, d: Vec3, eps: f64) -> bool {
+        let points = [a, b, c, d];
+        let mut longest: f64 = 0.0;
+        for i in 0..4 {
+            for j in i + 1..4 {
+                longest = longest.max((points[i] - points[j]).len());
+            }
+        }
+        (b - a).cross(c - a).dot(d - a).abs() <= eps * longest * longest * longest
+    }
+    /// Unit half vector, bisecting angle between vectors
+    ///
+    /// Used for Blinn-Phong shading as normalized sum of light and view
+    /// directions. For opposite vectors any unit vector perpendicular to
+    /// them is returned. Returns `None` if any vector is zero.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Vec3;
+    /// let h = Vec3::new(0, 0, 3).half_vector(Vec3::new(1, 0, 0)).unwrap();
+    /// assert!((h - Vec3::new(1, 0, 1).ort()).len() < 1e-12);
+    /// let a = Vec3::new(1, 2, 3);
+    /// let p = a.half_vector(-a * 2.0).unwrap();
+    /// assert!(p.dot(a).abs() < 1e-12 && (p.len() - 1.0).abs() < 1e-12);
+    /// ```
+    pub fn half_vector(self, other: Vec3) -> Option<Vec3> {
+        let (la, lb) = (self.len(), other.len());
+        if la == 0.0 || lb == 0.0 {
+            return None;
+        }
+        let (a, b) = (self / la, other / lb);
+        let sum = a + b;
+        if sum.len() < OPPOSITE_EPS {
+            // perpendicular component of `b`, or any perpendicular
+            let side = b - a * a.dot(b);
+            let normal = if side.len() > PERPENDICULAR_EPS { side.ort() } else { orthogonal_complement(a).0 };
+            return Some(normal);
+        }
+        Some(sum.ort())
+    }
+    /// Component of vector lying in plane with given normal
+    ///
+    /// Removes component along `normal`, which doesn't have to be unit.
+    /// Zero normal leaves vector unchanged.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Vec3;
+    /// let v = Vec3::new(1, 2, 3);
+    /// assert_eq!(v.project_onto_plane(Vec3::new(0, 0, 5)), Vec3::new(1, 2, 0));
+    /// let p = v.project_onto_plane(Vec3::new(1, 1, 1));
+    /// assert!(p.dot(Vec3::new(1, 1, 1)).abs() < 1e-12);
+    /// ```
+    pub fn project_onto_plane(self, normal: Vec3) -> Vec3 {
+        let nn = normal.dot(normal);
+        if nn == 0.0 {
+            return self;
+        }
+        self - normal * (self.dot(normal) / nn)
+    }
+    /// Point rotated around axis through `pivot`
+    ///
+    /// Rotation is counterclockwise when viewed from the end of `axis`, which
+    /// doesn't need to be normalized. Point is moved to pivot frame, rotated
+    /// and moved back: $R (\vec{p} - \vec{c}) + \vec{c}$.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Vec3;
+    /// # use std::f64::consts::FRAC_PI_2;
+    /// let p = Vec3::new(2, 1, 5).rotate_around(Vec3::new(1, 1, 0), Vec3::new(0, 0, 3), FRAC_PI_2);
+    /// assert!((p - Vec3::new(1, 2, 5)).len() < 1e-12);
+    /// ```
+    pub fn rotate_around<I: Into<f64>>(self, pivot: Vec3, axis: Vec3, angle: I) -> Vec3 {
+        Mat3::rotation(axis, angle) * (self - pivot) + pivot
+    }
+    /// Fused multiply-add $self \cdot a + b$ with single rounding per component
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Vec3;
+    /// // explicit Euler step of position by velocity
+    /// let (x, v) = (Vec3::new(1, 0, 0), Vec3::new(0, 2, 4));
+    /// assert_eq!(v.mul_add(0.5, x), Vec3::new(1, 1, 2));
+    /// ```
+    pub fn mul_add(self, a: f64, b: Vec3) -> Vec3 {
+        Vec3::new(self.x.mul_add(a, b.x), self.y.mul_add(a, b.y), self.z.mul_add(a, b.z))
+    }
+    /// Componentwise fused multiply-add $self_i a_i + b_i$ with single rounding
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Vec3;
+    /// let v = Vec3::new(1, 2, 3).mul_add_vec(Vec3::new(3, 4, 5), Vec3::new(1, 1, 1));
+    /// assert_eq!(v, Vec3::new(4, 9, 16));
+    /// ```
+    pub fn mul_add_vec(self, a: Vec3, b: Vec3) -> Vec3 {
+        Vec3::new(self.x.mul_add(a.x, b.x), self.y.mul_add(a.y, b.y), self.z.mul_add(a.z, b.z))
+    }
+    /// Scalar product accumulated with fused multiply-add
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Vec3;
+    /// assert_eq!(Vec3::new(1, 2, 3).dot_fma(Vec3::new(4, 5, 6)), 32.0);
+    /// ```
+    pub fn dot_fma(self, rhs: Vec3) -> f64 {
+        self.z.mul_add(rhs.z, self.y.mul_add(rhs.y, self.x * rhs.x))
+    }
+    /// Reciprocals of coordinates
+    ///
+    /// Follows IEEE 754 division: zero coordinate gives infinity of the same
+    /// sign as zero, so reciprocal of direction can be used directly in slab
+    /// tests against axis-aligned boxes. See [`try_recip`](#method.try_recip)
+    /// for checked variant.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Vec3;
+    /// let inv = Vec3::new(2.0, 0.0, -4.0).recip();
+    /// assert_eq!(inv.x, 0.5);
+    /// assert_eq!(inv.y, f64::INFINITY);
+    /// assert_eq!(inv.z, -0.25);
+    /// ```
+    pub fn recip(self) -> Vec3 {
+        Vec3::new(1.0 / self.x, 1.0 / self.y, 1.0 / self.z)
+    }
+    /// Reciprocals of coordinates, `None` if any of them is not finite
+    ///
+    /// That is the case for zero coordinates and for ones so small that
+    /// their reciprocals overflow.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Vec3;
+    /// assert_eq!(Vec3::new(4, -2, 1).try_recip(), Some(Vec3::new(0.25, -0.5, 1.0)));
+    /// assert_eq!(Vec3::new(1, 0, 2).try_recip(), None);
+    /// ```
+    pub fn try_recip(self) -> Option<Vec3> {
+        let r = self.recip();
+        if r.x.is_finite() && r.y.is_finite() && r.z.is_finite() {
+            Some(r)
+        } else {
+            None
+        }
+    }
+    /// Nearest node of grid with given cell sizes along axes
+    ///
+    /// Grid has node at origin. Coordinates halfway between nodes are
+    /// rounded up, towards positive infinity, for negative coordinates as
+    /// well, so snapping commutes with shifts by whole cells.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Vec3;
+    /// let cell = Vec3::new(2.0, 0.5, 1.0);
+    /// assert_eq!(Vec3::new(2.9, -0.3, -0.5).snap_to_grid(cell), Vec3::new(2.0, -0.5, 0.0));
+    /// ```
+    pub fn snap_to_grid(self, cell: Vec3) -> Vec3 {
+        Vec3::new(snap(self.x, cell.x), snap(self.y, cell.y), snap(self.z, cell.z))
+    }
+    /// Coordinates rounded to multiples of `step`
+    ///
+    /// Same as [`snap_to_grid`](#method.snap_to_grid) with cubic cells.
+    pub fn quantize<I: Into<f64>>(self, step: I) -> Vec3 {
+        let step = step.into();
+        self.snap_to_grid(Vec3::new(step, step, step))
+    }
+    /// Cell of grid with given cell sizes containing point
+    ///
+    /// Cell $(i, j, k)$ covers $[i c_x, (i + 1) c_x) \times [j c_y, (j + 1) c_y)
+    /// \times [k c_z, (k + 1) c_z)$, so points with small negative coordinates
+    /// belong to cell $-1$, not $0$.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Vec3;
+    /// assert_eq!(Vec3::new(-0.5, 2.5, 7.0).to_cell(Vec3::new(1, 1, 4)), (-1, 2, 1));
+    /// ```
+    pub fn to_cell(self, cell: Vec3) -> (i64, i64, i64) {
+        ((self.x / cell.x).floor() as i64, (self.y / cell.y).floor() as i64, (self.z / cell.z).floor() as i64)
+    }
+    /// Point moved by whole periods into box of periodic domain
+    ///
+    /// Result lies in $[min, max)$ along each axis, so opposite faces of
+    /// `bounds` are identified as on a 3-torus.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Aabb3, Vec3};
+    /// let bounds = Aabb3::new(Vec3::new(-1, -1, -1), Vec3::new(1, 1, 1));
+    /// assert_eq!(Vec3::new(1.5, -3.0, 0.0).wrap(bounds), Vec3::new(-0.5, -1.0, 0.0));
+    /// ```
+    pub fn wrap(self, bounds: Aabb3) -> Vec3 {
+        Vec3::new(wrap_coord(self.x, bounds.min.x, bounds.max.x),
+                  wrap_coord(self.y, bounds.min.y, bounds.max.y),
+                  wrap_coord(self.z, bounds.min.z, bounds.max.z))
+    }
+    /// Shortest vector from point to `other` in periodic domain
+    ///
+    /// Minimum image convention: among all periodic images of `other` the
+    /// nearest one is taken, so each coordinate of result is at most half of
+    /// period of `bounds` by magnitude. Points need not be wrapped. Axis with
+    /// zero extent is not periodic, its coordinate of difference is kept.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Aabb3, Vec3};
+    /// let bounds = Aabb3::new(Vec3::new(0, 0, 0), Vec3::new(10, 10, 10));
+    /// let d = Vec3::new(1, 5, 9).wrapped_delta(Vec3::new(9, 6, 1), bounds);
+    /// assert_eq!(d, Vec3::new(-2, 1, 2));
+    /// ```
+    pub fn wrapped_delta(self, other: Vec3, bounds: Aabb3) -> Vec3 {
+        let d = other - self;
+        let size = bounds.max - bounds.min;
+        Vec3::new(min_image(d.x, size.x), min_image(d.y, size.y), min_image(d.z, size.z))
+    }
+    /// Distance between points in periodic domain
+    ///
+    /// Length of [`wrapped_delta`](#method.wrapped_delta).
+    pub fn wrapped_distance(self, other: Vec3, bounds: Aabb3) -> f64 {
+        self.wrapped_delta(other, bounds).len()
+    }
 
     // need for op_default & op_assign
     fn size(&self) -> usize { 3 }
@@ -189,11 +607,16 @@ impl Vec3 {
 op_default!(add, Add, +=, Vec3);
 op_default!(sub, Sub, -=, Vec3);
 op_default!(mul, Mul, *=, Vec3);
+op_default!(div, Div, /=, Vec3);
+op_default!(rem, Rem, %=, Vec3);
 op_default!(f64, mul, Mul, *=, Vec3);
+op_scalar_left!(Vec3);
 op_default!(f64, div, Div, /=, Vec3);
 op_assign!(add_assign, AddAssign, +=, Vec3);
 op_assign!(sub_assign, SubAssign, -=, Vec3);
 op_assign!(mul_assign, MulAssign, *=, Vec3);
+op_assign!(div_assign, DivAssign, /=, Vec3);
+op_assign!(rem_assign, RemAssign, %=, Vec3);
 op_assign!(f64, mul_assign, MulAssign, *=, Vec3);
 op_assign!(f64, div_assign, DivAssign, /=, Vec3);
 
@@ -235,26 +658,55 @@ impl PartialEq for Vec3 {
     }
 }
 
+/// Formats vector as space separated components
+///
+/// Alternate form `{:#}` gives `(x, y, z)`. Precision, width, fill,
+/// alignment and sign flags are applied to every component.
+///
+/// # Example
+/// ```
+/// # use linal::Vec3;
+/// let v = Vec3::new(1.5, -2.0, 0.25);
+/// assert_eq!(format!("{}", v), "1.5 -2 0.25");
+/// assert_eq!(format!("{:#.1}", v), "(1.5, -2.0, 0.2)");
+/// assert_eq!(format!("{:.1}", v), "1.5 -2.0 0.2");
+/// assert_eq!(format!("{:*<5}", v), "1.5** -2*** 0.25*");
+/// ```
 impl fmt::Display for Vec3 {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{} {} {}", self.x, self.y, self.z)
+        let (open, sep, close) = if f.alternate() { ("(", ", ", ")") } else { ("", " ", "") };
+        f.write_str(open)?;
+        fmt::Display::fmt(&self.x, f)?;
+        f.write_str(sep)?;
+        fmt::Display::fmt(&self.y, f)?;
+        f.write_str(sep)?;
+        fmt::Display::fmt(&self.z, f)?;
+        f.write_str(close)
     }
 }
 
+/// Parses vector from three numbers separated by whitespace or comma,
+/// optionally wrapped in parentheses or square brackets
+///
+/// # Example
+/// ```
+/// # use linal::Vec3;
+/// for s in &["1 2 3", "1,2,3", "(1, 2, 3)", "[1 2 3]", "1e0 2e0 3e0"] {
+///     assert_eq!(s.parse::<Vec3>().unwrap(), Vec3::new(1, 2, 3));
+/// }
+/// ```
 impl FromStr for Vec3 {
-    type Err = num::ParseFloatError;
+    type Err = ParseVecError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let words: Vec<&str> = s.split_whitespace().collect();
-        let x: f64 = words[0].parse()?;
-        let y: f64 = words[1].parse()?;
-        let z: f64 = words[2].parse()?;
-        Ok(Self::new(x, y, z))
+        let c = parse_components(s, 3)?;
+        Ok(Self::new(c[0], c[1], c[2]))
     }
 }
 
 #[cfg(test)]
 mod linal_test {
     use super::*;
+    use vec2::Vec2;
 
     #[test]
     fn vec3_mul() {
@@ -268,6 +720,7 @@ mod linal_test {
         assert_eq!(r, b);
         assert_eq!(z, b);
         assert_eq!(x, Vec3::new(3, 12, 27));
+        assert_eq!(3.0 * a, b);
     }
 
     #[test]
@@ -380,4 +833,126 @@ mod linal_test {
         let a: Vec3 = "1 2 3".parse().unwrap();
         assert_eq!(a, Vec3::new(1, 2, 3));
     }
+
+    #[test]
+    fn vec3_display_round_trip() {
+        let v = Vec3::new(0.1, -1e-20, 3e100);
+        assert_eq!(format!("{}", v).parse::<Vec3>(), Ok(v));
+        assert_eq!(format!("{:#}", v).parse::<Vec3>(), Ok(v));
+    }
+
+    #[test]
+    fn vec3_parse_errors() {
+        assert_eq!("1 2".parse::<Vec3>(), Err(ParseVecError::MissingComponent { index: 2 }));
+        assert_eq!("1 2 3 4".parse::<Vec3>(), Err(ParseVecError::ExtraTokens { expected: 3, found: 4 }));
+        assert!("1 2 z".parse::<Vec3>().is_err());
+    }
+
+    #[test]
+    fn vec3_covariance() {
+        let points = [Vec3::new(1, 1, 0), Vec3::new(3, 5, 0), Vec3::new(5, 3, 0)];
+        let cov = Vec3::covariance(&points);
+        let expected = Mat3::new(Vec3::new(8, 4, 0), Vec3::new(4, 8, 0), Vec3::zero()) * (1.0 / 3.0);
+        let d = cov - expected;
+        assert!(d.x.len() + d.y.len() + d.z.len() < 1e-12);
+        assert_eq!(Vec3::covariance(&[]), Mat3::zero());
+    }
+
+    #[test]
+    fn vec3_principal_axes_plane() {
+        let normal = Vec3::new(1, 2, 2).ort();
+        let u = normal.cross(Vec3::new(1, 0, 0)).ort();
+        let v = normal.cross(u);
+        let points: Vec<Vec3> = (0..20).map(|i| {
+            let t = i as f64;
+            u * (t * 0.7).cos() * 3.0 + v * (t * 1.3).sin() + Vec3::new(1, 1, 1)
+        }).collect();
+        let (variances, (a, b, c)) = Vec3::principal_axes(&points);
+        assert!(variances.z < 1e-12);
+        assert!((c.dot(normal).abs() - 1.0).abs() < 1e-12);
+        assert!((a.cross(b).dot(c) - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn vec3_div_rem_componentwise() {
+        let a = Vec3::new(7, -9, 5);
+        let b = Vec3::new(2, 4, 5);
+        assert_eq!(a / b, Vec3::new(3.5, -2.25, 1.0));
+        assert_eq!(a % b, Vec3::new(1, -1, 0));
+        let mut z = a;
+        z /= b;
+        z %= Vec3::new(2, 2, 2);
+        assert_eq!(z, Vec3::new(1.5, -0.25, 1.0));
+    }
+
+    #[test]
+    fn vec3_homogeneous() {
+        let p = Vec3::new(0.5, -2.0, 7.0);
+        assert_eq!(Vec3::from_homogeneous(p.to_homogeneous()), Some(p));
+        assert_eq!(Vec3::from_homogeneous(p.to_homogeneous() * -3.0), Some(p));
+        assert_eq!(Vec3::from_homogeneous(p.to_homogeneous_direction()), None);
+        let q = Vec2::new(3, -1);
+        assert_eq!(Vec2::from_homogeneous(q.to_homogeneous() * 0.25), Some(q));
+    }
+
+    #[test]
+    fn vec3_parallel_perpendicular() {
+        let a = Vec3::new(1, 2, 2);
+        let b = Vec3::new(1.0, 2.0, 2.0 + 3e-6);
+        for &k in &[1e-12, 1.0, 1e12] {
+            assert!((a * k).is_parallel_to(b, 1e-6));
+            assert!(!(a * k).is_parallel_to(b, 1e-7));
+            assert!((a * k).is_perpendicular_to(Vec3::new(2, -1, 0), 0.0));
+        }
+    }
+
+    #[test]
+    fn vec3_coplanar_order_independent() {
+        let p = [Vec3::new(1, 0, 0), Vec3::new(0, 2, 0), Vec3::new(0, 0, 3), Vec3::new(1.0, 2.0, -3.0)];
+        assert!(Vec3::are_coplanar(p[0], p[1], p[2], p[3], 1e-12));
+        assert!(Vec3::are_coplanar(p[3], p[1], p[0], p[2], 1e-12));
+        let q = p[3] + Vec3::new(0.0, 0.0, 1e-3);
+        assert!(!Vec3::are_coplanar(p[0], p[1], p[2], q, 1e-6));
+        assert!(Vec3::are_coplanar(p[0], p[0], p[0], q, 0.0));
+    }
+
+    #[test]
+    fn vec3_fma() {
+        // product 1 + 2^-30 squared needs more bits than f64 has
+        let e = 2f64.powi(-30);
+        let a = Vec3::new(1.0 + e, 0.0, 0.0);
+        let b = Vec3::new(-1.0, 0.0, 0.0);
+        assert_eq!(a.mul_add_vec(a, b).x, 2.0 * e + e * e);
+        assert_eq!(a.mul_add(1.0 + e, b).x, 2.0 * e + e * e);
+        let c = Vec3::new(-1.0, 0.0, 1.0 + e);
+        let d = Vec3::new(1.0, 0.0, 1.0 + e);
+        assert_eq!(c.dot_fma(d), 2.0 * e + e * e);
+        assert_eq!(c.dot(d), 2.0 * e);
+    }
+
+    #[test]
+    fn vec3_recip() {
+        let v = Vec3::new(-0.0, 1e-310, 8.0);
+        assert_eq!(v.recip().x, f64::NEG_INFINITY);
+        assert_eq!(v.recip().y, f64::INFINITY);
+        assert_eq!(v.try_recip(), None);
+        assert!(Vec3::new(1e-300, 1.0, 8.0).try_recip().is_some());
+    }
+
+    #[test]
+    fn vec3_grid_snapping() {
+        let one = Vec3::new(1, 1, 1);
+        let p = Vec3::new(0.49999999999999994, -0.5, 4503599627370497.0);
+        assert_eq!(p.snap_to_grid(one), Vec3::new(0.0, 0.0, 4503599627370497.0));
+        assert_eq!(Vec3::new(-1.25, 0.75, 2.0).quantize(0.5), Vec3::new(-1.0, 1.0, 2.0));
+    }
+
+    #[test]
+    fn vec3_periodic_flat() {
+        let flat = Aabb3::new(Vec3::new(0, 0, 0), Vec3::new(10, 10, 0));
+        let (a, b) = (Vec3::new(1, 5, 0), Vec3::new(9, 6, 4));
+        assert_eq!(a.wrapped_delta(b, flat), Vec3::new(-2, 1, 4));
+        assert_eq!(a.wrapped_distance(b, flat), Vec3::new(-2, 1, 4).len());
+        assert_eq!(b.wrap(flat), Vec3::new(9, 6, 0));
+    }
 }
@@ -0,0 +1,181 @@
+//! Batches of space vectors in structure-of-arrays layout.
+use vec3::Vec3;
+
+/// Batch of space vectors stored by coordinate columns
+///
+/// Keeps coordinates of all vectors in separate contiguous arrays, so bulk
+/// operations stream through memory and are easy to vectorize.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Vec3s {
+    /// x coordinates
+    pub x: Vec<f64>,
+    /// y coordinates
+    pub y: Vec<f64>,
+    /// z coordinates
+    pub z: Vec<f64>,
+}
+
+impl Vec3s {
+    /// Constructs an empty batch
+    pub fn new() -> Vec3s {
+        Vec3s { x: Vec::new(), y: Vec::new(), z: Vec::new() }
+    }
+    /// Constructs an empty batch with space for `n` vectors
+    pub fn with_capacity(n: usize) -> Vec3s {
+        Vec3s { x: Vec::with_capacity(n), y: Vec::with_capacity(n), z: Vec::with_capacity(n) }
+    }
+    /// Number of vectors in batch
+    pub fn len(&self) -> usize {
+        self.x.len()
+    }
+    /// Checks if batch is empty
+    pub fn is_empty(&self) -> bool {
+        self.x.is_empty()
+    }
+    /// Appends vector to batch
+    pub fn push(&mut self, v: Vec3) {
+        self.x.push(v.x);
+        self.y.push(v.y);
+        self.z.push(v.z);
+    }
+    /// Vector with given index
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Vec3, Vec3s};
+    /// let batch = Vec3s::from(&[Vec3::new(1, 2, 0), Vec3::new(3, 4, 5)][..]);
+    /// assert_eq!(batch.get(1), Vec3::new(3, 4, 5));
+    /// ```
+    pub fn get(&self, i: usize) -> Vec3 {
+        Vec3::new(self.x[i], self.y[i], self.z[i])
+    }
+    /// Converts batch back to array of vectors
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Vec3, Vec3s};
+    /// let points = vec![Vec3::new(1, 2, 0), Vec3::new(3, 4, 5)];
+    /// assert_eq!(Vec3s::from(&points[..]).to_vec(), points);
+    /// ```
+    pub fn to_vec(&self) -> Vec<Vec3> {
+        (0..self.len()).map(|i| self.get(i)).collect()
+    }
+    /// Adds vectors of other batch pairwise
+    ///
+    /// # Panics
+    /// Panics if batches have different lengths.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Vec3, Vec3s};
+    /// let mut a = Vec3s::from(&[Vec3::new(1, 2, 0), Vec3::new(3, 4, 5)][..]);
+    /// let b = Vec3s::from(&[Vec3::new(1, 1, 1), Vec3::new(-3, 0, -5)][..]);
+    /// a.add(&b);
+    /// assert_eq!(a.to_vec(), vec![Vec3::new(2, 3, 1), Vec3::new(0, 4, 0)]);
+    /// ```
+    pub fn add(&mut self, other: &Vec3s) {
+        assert_eq!(self.len(), other.len(), "batches must have equal lengths");
+        for (a, b) in self.x.iter_mut().zip(&other.x) {
+            *a += b;
+        }
+        for (a, b) in self.y.iter_mut().zip(&other.y) {
+            *a += b;
+        }
+        for (a, b) in self.z.iter_mut().zip(&other.z) {
+            *a += b;
+        }
+    }
+    /// Multiplies all vectors by number
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Vec3, Vec3s};
+    /// let mut a = Vec3s::from(&[Vec3::new(1, 2, 0), Vec3::new(3, 4, 5)][..]);
+    /// a.scale(2);
+    /// assert_eq!(a.get(1), Vec3::new(6, 8, 10));
+    /// ```
+    pub fn scale<I: Into<f64>>(&mut self, k: I) {
+        let k = k.into();
+        for c in self.x.iter_mut().chain(self.y.iter_mut()).chain(self.z.iter_mut()) {
+            *c *= k;
+        }
+    }
+    /// Scalar products of vectors from two batches taken pairwise
+    ///
+    /// # Panics
+    /// Panics if batches have different lengths.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Vec3, Vec3s};
+    /// let a = Vec3s::from(&[Vec3::new(1, 2, 0), Vec3::new(3, 4, 5)][..]);
+    /// let b = Vec3s::from(&[Vec3::new(1, 1, 1), Vec3::new(-4, 3, 0)][..]);
+    /// assert_eq!(a.dot_pairs(&b), vec![3.0, 0.0]);
+    /// ```
+    pub fn dot_pairs(&self, other: &Vec3s) -> Vec<f64> {
+        assert_eq!(self.len(), other.len(), "batches must have equal lengths");
+        (0..self.len())
+            .map(|i| self.x[i] * other.x[i] + self.y[i] * other.y[i] + self.z[i] * other.z[i])
+            .collect()
+    }
+    /// Normalizes all vectors to unit length
+    ///
+    /// Zero vectors become NaN, like with [`Vec3::ort`](../vec3/struct.Vec3.html#method.ort).
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::{Vec3, Vec3s};
+    /// let mut a = Vec3s::from(&[Vec3::new(3, 0, 4), Vec3::new(0, -2, 0)][..]);
+    /// a.normalize_in_place();
+    /// assert_eq!(a.to_vec(), vec![Vec3::new(0.6, 0.0, 0.8), Vec3::new(0, -1, 0)]);
+    /// ```
+    pub fn normalize_in_place(&mut self) {
+        for ((x, y), z) in self.x.iter_mut().zip(self.y.iter_mut()).zip(self.z.iter_mut()) {
+            let len = (*x * *x + *y * *y + *z * *z).sqrt();
+            *x /= len;
+            *y /= len;
+            *z /= len;
+        }
+    }
+}
+
+impl From<&[Vec3]> for Vec3s {
+    fn from(vectors: &[Vec3]) -> Vec3s {
+        Vec3s {
+            x: vectors.iter().map(|v| v.x).collect(),
+            y: vectors.iter().map(|v| v.y).collect(),
+            z: vectors.iter().map(|v| v.z).collect(),
+        }
+    }
+}
+
+impl ::std::iter::FromIterator<Vec3> for Vec3s {
+    fn from_iter<T: IntoIterator<Item = Vec3>>(iter: T) -> Vec3s {
+        let mut batch = Vec3s::new();
+        for v in iter {
+            batch.push(v);
+        }
+        batch
+    }
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    #[test]
+    fn vec3s_matches_scalar_code() {
+        let a: Vec<Vec3> = (0..100).map(|i| Vec3::from_spherical(1.0 + i as f64, i as f64, 0.5 * i as f64)).collect();
+        let b: Vec<Vec3> = (0..100).map(|i| Vec3::new(i, -i, 2 * i)).collect();
+        let mut batch: Vec3s = a.iter().cloned().collect();
+        let other = Vec3s::from(&b[..]);
+        let dots = batch.dot_pairs(&other);
+        batch.add(&other);
+        batch.scale(0.5);
+        batch.normalize_in_place();
+        for i in 0..100 {
+            assert_eq!(dots[i], a[i].dot(b[i]));
+            assert!((batch.get(i) - ((a[i] + b[i]) * 0.5).ort()).len() < 1e-15);
+        }
+    }
+}
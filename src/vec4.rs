@@ -0,0 +1,165 @@
+//! Vectors in 4-dimensional space, mostly homogeneous coordinates of 3D points.
+use std::ops::{Add, Sub, Mul, Div, Rem, Neg};
+use std::ops::{AddAssign, SubAssign, DivAssign, MulAssign, RemAssign};
+use std::ops::{Index, IndexMut};
+use std::cmp::PartialEq;
+
+/// 4D vector in cartesian coordinates
+#[derive(Debug, Clone, Copy)]
+pub struct Vec4 {
+    /// component of vector
+    pub x: f64,
+    /// component of vector
+    pub y: f64,
+    /// component of vector
+    pub z: f64,
+    /// component of vector
+    pub w: f64,
+}
+
+impl Vec4 {
+    /// Constructs a new `Vec4`.
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Vec4;
+    /// // create `Vec4` with int
+    /// let a = Vec4::new(10, 20, 30, 1);
+    /// // create `Vec4` with float
+    /// let b = Vec4::new(3.5, 2.5, 1.5, 1.0);
+    /// ```
+    pub fn new<I: Into<f64>>(x: I, y: I, z: I, w: I) -> Vec4 {
+        Vec4 {
+            x: x.into(),
+            y: y.into(),
+            z: z.into(),
+            w: w.into(),
+        }
+    }
+    /// Create a zero `Vec4`
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Vec4;
+    /// let zero = Vec4::zero();
+    /// assert_eq!(zero, Vec4::new(0, 0, 0, 0));
+    /// ```
+    pub fn zero() -> Vec4 {
+        Vec4::new(0.0, 0.0, 0.0, 0.0)
+    }
+    /// Scalar product
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Vec4;
+    /// let a = Vec4::new(1, 2, 3, 4);
+    /// let b = Vec4::new(4, 3, 2, 1);
+    /// assert_eq!(a.dot(b), 20.0);
+    /// ```
+    pub fn dot(self, rhs: Vec4) -> f64 {
+        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z + self.w * rhs.w
+    }
+    /// Vector length
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::Vec4;
+    /// let a = Vec4::new(1, 1, 1, 1);
+    /// assert_eq!(a.len(), 2.0);
+    /// ```
+    pub fn len(self) -> f64 {
+        self.dot(self).sqrt()
+    }
+
+    // need for op_default & op_assign
+    fn size(&self) -> usize { 4 }
+}
+
+op_default!(add, Add, +=, Vec4);
+op_default!(sub, Sub, -=, Vec4);
+op_default!(mul, Mul, *=, Vec4);
+op_default!(div, Div, /=, Vec4);
+op_default!(rem, Rem, %=, Vec4);
+op_default!(f64, mul, Mul, *=, Vec4);
+op_scalar_left!(Vec4);
+op_default!(f64, div, Div, /=, Vec4);
+op_assign!(add_assign, AddAssign, +=, Vec4);
+op_assign!(sub_assign, SubAssign, -=, Vec4);
+op_assign!(mul_assign, MulAssign, *=, Vec4);
+op_assign!(div_assign, DivAssign, /=, Vec4);
+op_assign!(rem_assign, RemAssign, %=, Vec4);
+op_assign!(f64, mul_assign, MulAssign, *=, Vec4);
+op_assign!(f64, div_assign, DivAssign, /=, Vec4);
+
+impl Neg for Vec4 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y, -self.z, -self.w)
+    }
+}
+
+impl Index<usize> for Vec4 {
+    type Output = f64;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            3 => &self.w,
+            i => panic!("Index {} out of [0, 3] range", i)
+        }
+    }
+}
+
+impl IndexMut<usize> for Vec4 {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            3 => &mut self.w,
+            i => panic!("Index {} out of [0, 3] range", i)
+        }
+    }
+}
+
+impl PartialEq for Vec4 {
+    fn eq(&self, other: &Self) -> bool {
+        self.x == other.x && self.y == other.y && self.z == other.z && self.w == other.w
+    }
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    #[test]
+    fn vec4_ops() {
+        let a = Vec4::new(1, 2, 3, 4);
+        let b = Vec4::new(-1, 0, 1, 2);
+        assert_eq!(a + b, Vec4::new(0, 2, 4, 6));
+        assert_eq!(a - b, Vec4::new(2, 2, 2, 2));
+        assert_eq!(a * 2, Vec4::new(2, 4, 6, 8));
+        assert_eq!(a / 2, Vec4::new(0.5, 1.0, 1.5, 2.0));
+        assert_eq!(-a, Vec4::new(-1, -2, -3, -4));
+        assert_eq!(a.dot(b), 10.0);
+    }
+
+    #[test]
+    fn vec4_index() {
+        let mut a = Vec4::zero();
+        for i in 0..4 {
+            a[i] = i as f64;
+        }
+        assert_eq!(a, Vec4::new(0, 1, 2, 3));
+    }
+
+    #[test]
+    #[should_panic]
+    fn vec4_index_out_of_range() {
+        let a = Vec4::zero();
+        let _ = a[4];
+    }
+}
@@ -0,0 +1,206 @@
+//! Vectors with arbitrary number of components.
+use std::ops::{Add, Sub, Mul, Div, Rem, Neg};
+use std::ops::{AddAssign, SubAssign, DivAssign, MulAssign, RemAssign};
+use std::ops::{Index, IndexMut};
+use vec2::Vec2;
+use vec3::Vec3;
+use vec4::Vec4;
+
+/// Vector with `N` components, e.g. state vector of a system
+///
+/// Supports the same arithmetic as fixed size vectors: componentwise
+/// operations with vectors and scaling by numbers.
+///
+/// # Example
+/// ```
+/// # use linal::VecN;
+/// let a = VecN::new([1, 2, 3, 4, 5, 6]);
+/// let b = VecN::new([0.5; 6]);
+/// assert_eq!((a - b)[5], 5.5);
+/// assert_eq!((a * 2).dot(b), 21.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VecN<const N: usize>(pub [f64; N]);
+
+impl<const N: usize> VecN<N> {
+    /// Constructs a new `VecN` from array of components
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::VecN;
+    /// let v = VecN::new([1, 2, 3, 4, 5]);
+    /// assert_eq!(v[4], 5.0);
+    /// ```
+    pub fn new<I: Into<f64>>(components: [I; N]) -> VecN<N> {
+        VecN(components.map(Into::into))
+    }
+    /// Constructs a new `VecN` with components computed by index
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::VecN;
+    /// let v = VecN::<3>::from_fn(|i| i as f64 * 2.0);
+    /// assert_eq!(v, VecN::new([0, 2, 4]));
+    /// ```
+    pub fn from_fn<F: FnMut(usize) -> f64>(f: F) -> VecN<N> {
+        VecN(std::array::from_fn(f))
+    }
+    /// Create a zero `VecN`
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::VecN;
+    /// assert_eq!(VecN::<5>::zero(), VecN::new([0; 5]));
+    /// ```
+    pub fn zero() -> VecN<N> {
+        VecN([0.0; N])
+    }
+    /// Scalar product
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::VecN;
+    /// let a = VecN::new([1, 2, 3, 4, 5]);
+    /// let b = VecN::new([5, 4, 3, 2, 1]);
+    /// assert_eq!(a.dot(b), 35.0);
+    /// ```
+    pub fn dot(self, rhs: VecN<N>) -> f64 {
+        self.0.iter().zip(&rhs.0).map(|(a, b)| a * b).sum()
+    }
+    /// Length of vector
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::VecN;
+    /// assert_eq!(VecN::new([1, 2, 2, 4]).len(), 5.0);
+    /// ```
+    pub fn len(self) -> f64 {
+        self.dot(self).sqrt()
+    }
+    /// Unit vector in direction of current
+    ///
+    /// # Example
+    /// ```
+    /// # use linal::VecN;
+    /// assert_eq!(VecN::new([0, 3, 0, 4, 0]).ort(), VecN::new([0.0, 0.6, 0.0, 0.8, 0.0]));
+    /// ```
+    pub fn ort(self) -> VecN<N> {
+        self / self.len()
+    }
+    /// Components as slice
+    pub fn as_slice(&self) -> &[f64] {
+        &self.0
+    }
+    // need for op_default & op_assign
+    fn size(&self) -> usize { N }
+}
+
+impl<const N: usize> Default for VecN<N> {
+    fn default() -> VecN<N> {
+        VecN::zero()
+    }
+}
+
+op_default!(add, Add, +=, VecN<const N>);
+op_default!(sub, Sub, -=, VecN<const N>);
+op_default!(mul, Mul, *=, VecN<const N>);
+op_default!(div, Div, /=, VecN<const N>);
+op_default!(rem, Rem, %=, VecN<const N>);
+op_default!(f64, mul, Mul, *=, VecN<const N>);
+op_scalar_left!(VecN<const N>);
+op_default!(f64, div, Div, /=, VecN<const N>);
+op_assign!(add_assign, AddAssign, +=, VecN<const N>);
+op_assign!(sub_assign, SubAssign, -=, VecN<const N>);
+op_assign!(mul_assign, MulAssign, *=, VecN<const N>);
+op_assign!(div_assign, DivAssign, /=, VecN<const N>);
+op_assign!(rem_assign, RemAssign, %=, VecN<const N>);
+op_assign!(f64, mul_assign, MulAssign, *=, VecN<const N>);
+op_assign!(f64, div_assign, DivAssign, /=, VecN<const N>);
+
+impl<const N: usize> Neg for VecN<N> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        VecN(self.0.map(|x| -x))
+    }
+}
+
+impl<const N: usize> Index<usize> for VecN<N> {
+    type Output = f64;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+impl<const N: usize> IndexMut<usize> for VecN<N> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.0[index]
+    }
+}
+
+impl From<Vec2> for VecN<2> {
+    fn from(v: Vec2) -> VecN<2> {
+        VecN([v.x, v.y])
+    }
+}
+
+impl From<VecN<2>> for Vec2 {
+    fn from(v: VecN<2>) -> Vec2 {
+        Vec2::new(v[0], v[1])
+    }
+}
+
+impl From<Vec3> for VecN<3> {
+    fn from(v: Vec3) -> VecN<3> {
+        VecN([v.x, v.y, v.z])
+    }
+}
+
+impl From<VecN<3>> for Vec3 {
+    fn from(v: VecN<3>) -> Vec3 {
+        Vec3::new(v[0], v[1], v[2])
+    }
+}
+
+impl From<Vec4> for VecN<4> {
+    fn from(v: Vec4) -> VecN<4> {
+        VecN([v.x, v.y, v.z, v.w])
+    }
+}
+
+impl From<VecN<4>> for Vec4 {
+    fn from(v: VecN<4>) -> Vec4 {
+        Vec4::new(v[0], v[1], v[2], v[3])
+    }
+}
+
+#[cfg(test)]
+mod linal_test {
+    use super::*;
+
+    #[test]
+    fn vecn_ops() {
+        let mut a = VecN::new([1, 2, 3, 4, 5, 6, 7]);
+        let b = VecN::new([7, 6, 5, 4, 3, 2, 1]);
+        assert_eq!(a + b, VecN::new([8; 7]));
+        assert_eq!(2 * a, a * 2.0);
+        assert_eq!(a % VecN::new([3; 7]), VecN::new([1, 2, 0, 1, 2, 0, 1]));
+        assert_eq!(-a / 2, VecN::new([-0.5, -1.0, -1.5, -2.0, -2.5, -3.0, -3.5]));
+        a -= b;
+        a *= 0.5;
+        assert_eq!(a, VecN::new([-3, -2, -1, 0, 1, 2, 3]));
+        a[3] = 4.0;
+        assert_eq!(a.as_slice()[3], 4.0);
+    }
+
+    #[test]
+    fn vecn_conversions() {
+        let v = Vec3::new(1, 2, 2);
+        let n = VecN::from(v);
+        assert_eq!(n.len(), v.len());
+        assert_eq!(Vec3::from(n), v);
+        assert_eq!(Vec2::from(VecN::from(Vec2::new(3, 4))), Vec2::new(3, 4));
+        assert_eq!(Vec4::from(VecN::from(Vec4::new(1, 2, 3, 4))), Vec4::new(1, 2, 3, 4));
+    }
+}